@@ -25,14 +25,25 @@ async fn should_respond_200_when_hits() {
         shop_name: "Hans Volkers Shop".into(),
         title_de: None,
         title_en: Some("Chopin Etudes Op.10 1833".to_string()),
+        title_fr: None,
+        title_es: None,
         description_de: None,
         description_en: None,
+        description_fr: None,
+        description_es: None,
         price_eur: Some(1400000),
         price_usd: Some(1500000),
         price_gbp: Some(1600000),
         price_aud: Some(1700000),
         price_cad: Some(1800000),
         price_nzd: Some(1990000),
+        price_chf: Some(1990000),
+        price_sek: Some(1990000),
+        price_dkk: Some(1990000),
+        price_nok: Some(1990000),
+        price_pln: Some(1990000),
+        price_czk: Some(1990000),
+        price_jpy: Some(1990000),
         state: ItemStateDocument::Available,
         is_available: true,
         url: Url::parse("https://hans-volker.com/chopin-etudes-op10-1833").unwrap(),
@@ -187,6 +187,7 @@ async fn should_materialize_item_in_opensearch_for_create_item_command() {
                 &common::currency::domain::Currency::Eur,
                 &None,
                 &None,
+                &None,
             )
             .await
             .unwrap()
@@ -279,6 +280,7 @@ async fn should_materialize_item_in_opensearch_for_update_item_command() {
                 &common::currency::domain::Currency::Usd,
                 &None,
                 &None,
+                &None,
             )
             .await
             .unwrap()
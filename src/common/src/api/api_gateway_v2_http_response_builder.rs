@@ -2,7 +2,8 @@ use crate::language::data::LanguageData;
 use aws_lambda_events::apigw::ApiGatewayV2httpResponse;
 use aws_lambda_events::encodings::Body;
 use http::header::{
-    ACCESS_CONTROL_ALLOW_ORIGIN, CONTENT_LANGUAGE, CONTENT_TYPE, ETAG, LAST_MODIFIED,
+    ACCESS_CONTROL_ALLOW_ORIGIN, CACHE_CONTROL, CONTENT_LANGUAGE, CONTENT_TYPE, ETAG,
+    LAST_MODIFIED, VARY,
 };
 use http::{HeaderMap, HeaderName, HeaderValue};
 use httpdate::fmt_http_date;
@@ -124,6 +125,27 @@ impl ApiGatewayV2HttpResponseBuilder {
         self
     }
 
+    pub fn cache_control(mut self, cache_control: &str) -> Self {
+        match HeaderValue::from_str(cache_control) {
+            Ok(cache_control_value) => {
+                self.headers.insert(CACHE_CONTROL, cache_control_value);
+            }
+            Err(err) => {
+                error!(
+                    error = %err,
+                    cacheControl = %cache_control,
+                    "Failed to convert cache_control to HeaderValue when setting HTTP Cache-Control."
+                )
+            }
+        }
+        self
+    }
+
+    pub fn vary(mut self, vary: &'static str) -> Self {
+        self.headers.insert(VARY, HeaderValue::from_static(vary));
+        self
+    }
+
     pub fn body<T: Into<Body>>(mut self, body: T) -> Self {
         self.body = Some(body.into());
         self
@@ -172,7 +194,21 @@ pub mod tests {
     #[case::try_content_language(ApiGatewayV2HttpResponseBuilder::new(200).try_content_language(Some(LanguageData::En)))]
     #[case::e_tag(ApiGatewayV2HttpResponseBuilder::new(200).e_tag("123456"))]
     #[case::last_modified(ApiGatewayV2HttpResponseBuilder::new(200).last_modified(SystemTime::now()))]
+    #[case::cache_control(ApiGatewayV2HttpResponseBuilder::new(200).cache_control("public, no-cache"))]
+    #[case::vary(ApiGatewayV2HttpResponseBuilder::new(200).vary("Accept-Language"))]
     fn should_build_api_gateway_proxy_response(#[case] builder: ApiGatewayV2HttpResponseBuilder) {
         let _ = builder.build();
     }
+
+    #[test]
+    fn should_set_cache_control_header() {
+        let response = ApiGatewayV2HttpResponseBuilder::new(200)
+            .cache_control("public, max-age=60")
+            .build();
+
+        assert_eq!(
+            "public, max-age=60",
+            response.headers.get(http::header::CACHE_CONTROL).unwrap()
+        );
+    }
 }
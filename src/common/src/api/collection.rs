@@ -1,14 +1,20 @@
 use serde::Serialize;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-pub struct CollectionData<T> {
+pub struct CollectionData<T, F = ()> {
     pub items: Vec<T>,
     pub pagination: PaginationData,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<F>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct PaginationData {
     pub from: u64,
     pub size: u64,
     pub total: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
 }
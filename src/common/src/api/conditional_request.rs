@@ -0,0 +1,102 @@
+use http::HeaderMap;
+use http::header::{IF_MODIFIED_SINCE, IF_NONE_MATCH};
+use httpdate::parse_http_date;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Evaluates the preconditions of a conditional GET according to RFC 9110.
+///
+/// `If-None-Match` takes precedence, `If-Modified-Since` is only considered if the former is absent.
+/// Unparsable header values are ignored, so the full representation is served instead.
+pub fn is_not_modified(
+    headers: &HeaderMap,
+    e_tag: &str,
+    last_modified: impl Into<SystemTime>,
+) -> bool {
+    if let Some(if_none_match) = headers.get(IF_NONE_MATCH) {
+        return if_none_match
+            .to_str()
+            .map(|if_none_match| matches_any_e_tag(if_none_match, e_tag))
+            .unwrap_or(false);
+    }
+
+    headers
+        .get(IF_MODIFIED_SINCE)
+        .and_then(|if_modified_since| if_modified_since.to_str().ok())
+        .and_then(|if_modified_since| parse_http_date(if_modified_since).ok())
+        .map(|if_modified_since| truncate_to_seconds(last_modified.into()) <= if_modified_since)
+        .unwrap_or(false)
+}
+
+// weak comparison, i.e. 'W/"abc"' matches 'abc', as required for If-None-Match
+fn matches_any_e_tag(if_none_match: &str, e_tag: &str) -> bool {
+    let e_tag = opaque_tag(e_tag);
+    if_none_match
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || opaque_tag(candidate) == e_tag)
+}
+
+fn opaque_tag(e_tag: &str) -> &str {
+    let e_tag = e_tag.trim();
+    let e_tag = e_tag.strip_prefix("W/").unwrap_or(e_tag);
+    e_tag
+        .strip_prefix('"')
+        .and_then(|e_tag| e_tag.strip_suffix('"'))
+        .unwrap_or(e_tag)
+}
+
+// HTTP-dates only have second precision
+fn truncate_to_seconds(time: SystemTime) -> SystemTime {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since_epoch) => UNIX_EPOCH + Duration::from_secs(since_epoch.as_secs()),
+        Err(_) => time,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::conditional_request::is_not_modified;
+    use http::header::{IF_MODIFIED_SINCE, IF_NONE_MATCH};
+    use http::{HeaderMap, HeaderValue};
+    use time::macros::datetime;
+
+    fn mk_headers(if_none_match: Option<&str>, if_modified_since: Option<&str>) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if let Some(if_none_match) = if_none_match {
+            headers.insert(IF_NONE_MATCH, HeaderValue::from_str(if_none_match).unwrap());
+        }
+        if let Some(if_modified_since) = if_modified_since {
+            headers.insert(
+                IF_MODIFIED_SINCE,
+                HeaderValue::from_str(if_modified_since).unwrap(),
+            );
+        }
+        headers
+    }
+
+    #[rstest::rstest]
+    #[case::unquoted(Some("abc"), None, true)]
+    #[case::quoted(Some(r#""abc""#), None, true)]
+    #[case::weak(Some(r#"W/"abc""#), None, true)]
+    #[case::any(Some("*"), None, true)]
+    #[case::list(Some(r#""xyz", "abc""#), None, true)]
+    #[case::other(Some(r#""xyz""#), None, false)]
+    #[case::other_list(Some(r#""xyz", W/"uvw""#), None, false)]
+    #[case::same_second(None, Some("Wed, 01 Jan 2020 12:00:00 GMT"), true)]
+    #[case::later(None, Some("Thu, 02 Jan 2020 00:00:00 GMT"), true)]
+    #[case::earlier(None, Some("Wed, 01 Jan 2020 11:59:59 GMT"), false)]
+    #[case::unparsable_date(None, Some("yesterday"), false)]
+    #[case::e_tag_takes_precedence(Some(r#""xyz""#), Some("Thu, 02 Jan 2020 00:00:00 GMT"), false)]
+    #[case::no_preconditions(None, None, false)]
+    fn should_evaluate_preconditions(
+        #[case] if_none_match: Option<&str>,
+        #[case] if_modified_since: Option<&str>,
+        #[case] expected: bool,
+    ) {
+        let headers = mk_headers(if_none_match, if_modified_since);
+
+        let actual = is_not_modified(&headers, "abc", datetime!(2020-01-01 12:00:00.123 UTC));
+
+        assert_eq!(expected, actual);
+    }
+}
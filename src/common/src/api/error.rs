@@ -101,6 +101,10 @@ impl ApiError {
         Self::new(StatusCode::CONFLICT, error)
     }
 
+    pub fn gone(error: ApiErrorCode) -> Self {
+        Self::new(StatusCode::GONE, error)
+    }
+
     pub fn unprocessable_entity(error: ApiErrorCode) -> Self {
         Self::new(StatusCode::UNPROCESSABLE_ENTITY, error)
     }
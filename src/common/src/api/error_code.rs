@@ -23,10 +23,16 @@ pub const BAD_PAGE_FROM_VALUE: ApiErrorCode = ApiErrorCode("BAD_PAGE_FROM_VALUE"
 pub const BAD_PAGE_SIZE_VALUE: ApiErrorCode = ApiErrorCode("BAD_PAGE_SIZE_VALUE");
 pub const BAD_SORT_VALUE: ApiErrorCode = ApiErrorCode("BAD_SORT_VALUE");
 pub const BAD_ORDER_VALUE: ApiErrorCode = ApiErrorCode("BAD_ORDER_VALUE");
+pub const BAD_CURSOR_VALUE: ApiErrorCode = ApiErrorCode("BAD_CURSOR_VALUE");
+pub const CURSOR_EXPIRED: ApiErrorCode = ApiErrorCode("CURSOR_EXPIRED");
 
 pub const ITEM_NOT_FOUND: ApiErrorCode = ApiErrorCode("ITEM_NOT_FOUND");
 pub const MONETARY_AMOUNT_OVERFLOW: ApiErrorCode = ApiErrorCode("MONETARY_AMOUNT_OVERFLOW");
 pub const TEXT_QUERY_TOO_SHORT: ApiErrorCode = ApiErrorCode("TEXT_QUERY_TOO_SHORT");
+pub const BAD_PRICE_VALUE: ApiErrorCode = ApiErrorCode("BAD_PRICE_VALUE");
+pub const BAD_STATE_VALUE: ApiErrorCode = ApiErrorCode("BAD_STATE_VALUE");
+pub const BAD_DATETIME_VALUE: ApiErrorCode = ApiErrorCode("BAD_DATETIME_VALUE");
+pub const BAD_RANGE_VALUE: ApiErrorCode = ApiErrorCode("BAD_RANGE_VALUE");
 
 // region impl ApiErrorCode
 
@@ -1,4 +1,5 @@
 pub mod api_gateway_v2_http_response_builder;
 pub mod collection;
+pub mod conditional_request;
 pub mod error;
 pub mod error_code;
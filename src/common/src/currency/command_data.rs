@@ -11,6 +11,13 @@ pub enum CurrencyCommandData {
     Aud,
     Cad,
     Nzd,
+    Chf,
+    Sek,
+    Dkk,
+    Nok,
+    Pln,
+    Czk,
+    Jpy,
 }
 
 impl From<CurrencyData> for CurrencyCommandData {
@@ -22,6 +29,13 @@ impl From<CurrencyData> for CurrencyCommandData {
             CurrencyData::Aud => CurrencyCommandData::Aud,
             CurrencyData::Cad => CurrencyCommandData::Cad,
             CurrencyData::Nzd => CurrencyCommandData::Nzd,
+            CurrencyData::Chf => CurrencyCommandData::Chf,
+            CurrencyData::Sek => CurrencyCommandData::Sek,
+            CurrencyData::Dkk => CurrencyCommandData::Dkk,
+            CurrencyData::Nok => CurrencyCommandData::Nok,
+            CurrencyData::Pln => CurrencyCommandData::Pln,
+            CurrencyData::Czk => CurrencyCommandData::Czk,
+            CurrencyData::Jpy => CurrencyCommandData::Jpy,
         }
     }
 }
@@ -38,6 +52,13 @@ mod tests {
     #[case(CurrencyCommandData::Aud, "\"AUD\"")]
     #[case(CurrencyCommandData::Cad, "\"CAD\"")]
     #[case(CurrencyCommandData::Nzd, "\"NZD\"")]
+    #[case(CurrencyCommandData::Chf, "\"CHF\"")]
+    #[case(CurrencyCommandData::Sek, "\"SEK\"")]
+    #[case(CurrencyCommandData::Dkk, "\"DKK\"")]
+    #[case(CurrencyCommandData::Nok, "\"NOK\"")]
+    #[case(CurrencyCommandData::Pln, "\"PLN\"")]
+    #[case(CurrencyCommandData::Czk, "\"CZK\"")]
+    #[case(CurrencyCommandData::Jpy, "\"JPY\"")]
     fn should_serialize_currency_according_to_iso_4217(
         #[case] currency: CurrencyCommandData,
         #[case] expected: &str,
@@ -53,6 +74,13 @@ mod tests {
     #[case("\"AUD\"", CurrencyCommandData::Aud)]
     #[case("\"CAD\"", CurrencyCommandData::Cad)]
     #[case("\"NZD\"", CurrencyCommandData::Nzd)]
+    #[case("\"CHF\"", CurrencyCommandData::Chf)]
+    #[case("\"SEK\"", CurrencyCommandData::Sek)]
+    #[case("\"DKK\"", CurrencyCommandData::Dkk)]
+    #[case("\"NOK\"", CurrencyCommandData::Nok)]
+    #[case("\"PLN\"", CurrencyCommandData::Pln)]
+    #[case("\"CZK\"", CurrencyCommandData::Czk)]
+    #[case("\"JPY\"", CurrencyCommandData::Jpy)]
     fn should_deserialize_currency_according_to_iso_4217(
         #[case] currency: &str,
         #[case] expected: CurrencyCommandData,
@@ -12,6 +12,13 @@ pub enum CurrencyData {
     Aud,
     Cad,
     Nzd,
+    Chf,
+    Sek,
+    Dkk,
+    Nok,
+    Pln,
+    Czk,
+    Jpy,
 }
 
 impl HasMinorUnitExponent for CurrencyData {
@@ -23,6 +30,13 @@ impl HasMinorUnitExponent for CurrencyData {
             CurrencyData::Aud => MinorUnitExponent(2),
             CurrencyData::Cad => MinorUnitExponent(2),
             CurrencyData::Nzd => MinorUnitExponent(2),
+            CurrencyData::Chf => MinorUnitExponent(2),
+            CurrencyData::Sek => MinorUnitExponent(2),
+            CurrencyData::Dkk => MinorUnitExponent(2),
+            CurrencyData::Nok => MinorUnitExponent(2),
+            CurrencyData::Pln => MinorUnitExponent(2),
+            CurrencyData::Czk => MinorUnitExponent(2),
+            CurrencyData::Jpy => MinorUnitExponent(0),
         }
     }
 }
@@ -36,6 +50,13 @@ impl From<Currency> for CurrencyData {
             Currency::Aud => CurrencyData::Aud,
             Currency::Cad => CurrencyData::Cad,
             Currency::Nzd => CurrencyData::Nzd,
+            Currency::Chf => CurrencyData::Chf,
+            Currency::Sek => CurrencyData::Sek,
+            Currency::Dkk => CurrencyData::Dkk,
+            Currency::Nok => CurrencyData::Nok,
+            Currency::Pln => CurrencyData::Pln,
+            Currency::Czk => CurrencyData::Czk,
+            Currency::Jpy => CurrencyData::Jpy,
         }
     }
 }
@@ -84,6 +105,13 @@ pub mod api {
         #[case::aud("AUD", CurrencyData::Aud)]
         #[case::cad("CAD", CurrencyData::Cad)]
         #[case::nzd("NZD", CurrencyData::Nzd)]
+        #[case::chf("CHF", CurrencyData::Chf)]
+        #[case::sek("SEK", CurrencyData::Sek)]
+        #[case::dkk("DKK", CurrencyData::Dkk)]
+        #[case::nok("NOK", CurrencyData::Nok)]
+        #[case::pln("PLN", CurrencyData::Pln)]
+        #[case::czk("CZK", CurrencyData::Czk)]
+        #[case::jpy("JPY", CurrencyData::Jpy)]
         fn should_extract_currency(#[case] query_value: String, #[case] expected: CurrencyData) {
             let query = QueryMap::from(HashMap::from_iter([("currency".to_string(), query_value)]));
 
@@ -130,6 +158,13 @@ mod tests {
     #[case(CurrencyData::Aud, "\"AUD\"")]
     #[case(CurrencyData::Cad, "\"CAD\"")]
     #[case(CurrencyData::Nzd, "\"NZD\"")]
+    #[case(CurrencyData::Chf, "\"CHF\"")]
+    #[case(CurrencyData::Sek, "\"SEK\"")]
+    #[case(CurrencyData::Dkk, "\"DKK\"")]
+    #[case(CurrencyData::Nok, "\"NOK\"")]
+    #[case(CurrencyData::Pln, "\"PLN\"")]
+    #[case(CurrencyData::Czk, "\"CZK\"")]
+    #[case(CurrencyData::Jpy, "\"JPY\"")]
     fn should_serialize_currency_according_to_iso_4217(
         #[case] currency: CurrencyData,
         #[case] expected: &str,
@@ -145,6 +180,13 @@ mod tests {
     #[case("\"AUD\"", CurrencyData::Aud)]
     #[case("\"CAD\"", CurrencyData::Cad)]
     #[case("\"NZD\"", CurrencyData::Nzd)]
+    #[case("\"CHF\"", CurrencyData::Chf)]
+    #[case("\"SEK\"", CurrencyData::Sek)]
+    #[case("\"DKK\"", CurrencyData::Dkk)]
+    #[case("\"NOK\"", CurrencyData::Nok)]
+    #[case("\"PLN\"", CurrencyData::Pln)]
+    #[case("\"CZK\"", CurrencyData::Czk)]
+    #[case("\"JPY\"", CurrencyData::Jpy)]
     fn should_deserialize_currency_according_to_iso_4217(
         #[case] currency: &str,
         #[case] expected: CurrencyData,
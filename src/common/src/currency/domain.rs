@@ -1,6 +1,7 @@
 use crate::currency::command_data::CurrencyCommandData;
 use crate::currency::data::CurrencyData;
 use crate::currency::record::CurrencyRecord;
+use strum::IntoEnumIterator;
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
 pub struct MinorUnitExponent(pub u8);
@@ -25,17 +26,37 @@ impl From<MinorUnitExponent> for u8 {
     PartialEq,
     Debug,
     Hash,
+    Default,
     strum_macros::EnumIter,
     strum_macros::Display,
     strum_macros::EnumCount,
 )]
 pub enum Currency {
+    #[default]
     Eur,
     Gbp,
     Usd,
     Aud,
     Cad,
     Nzd,
+    Chf,
+    Sek,
+    Dkk,
+    Nok,
+    Pln,
+    Czk,
+    Jpy,
+}
+
+impl Currency {
+    /// ISO 4217 code, e.g. `EUR`.
+    pub fn iso_code(&self) -> String {
+        self.to_string().to_uppercase()
+    }
+
+    pub fn from_iso_code(iso_code: &str) -> Option<Currency> {
+        Currency::iter().find(|currency| currency.to_string().eq_ignore_ascii_case(iso_code))
+    }
 }
 
 pub trait HasMinorUnitExponent {
@@ -51,16 +72,17 @@ impl HasMinorUnitExponent for Currency {
             Currency::Aud => MinorUnitExponent(2),
             Currency::Cad => MinorUnitExponent(2),
             Currency::Nzd => MinorUnitExponent(2),
+            Currency::Chf => MinorUnitExponent(2),
+            Currency::Sek => MinorUnitExponent(2),
+            Currency::Dkk => MinorUnitExponent(2),
+            Currency::Nok => MinorUnitExponent(2),
+            Currency::Pln => MinorUnitExponent(2),
+            Currency::Czk => MinorUnitExponent(2),
+            Currency::Jpy => MinorUnitExponent(0),
         }
     }
 }
 
-impl Default for Currency {
-    fn default() -> Self {
-        Self::Eur
-    }
-}
-
 impl From<CurrencyCommandData> for Currency {
     fn from(cmd: CurrencyCommandData) -> Self {
         match cmd {
@@ -70,6 +92,13 @@ impl From<CurrencyCommandData> for Currency {
             CurrencyCommandData::Aud => Currency::Aud,
             CurrencyCommandData::Cad => Currency::Cad,
             CurrencyCommandData::Nzd => Currency::Nzd,
+            CurrencyCommandData::Chf => Currency::Chf,
+            CurrencyCommandData::Sek => Currency::Sek,
+            CurrencyCommandData::Dkk => Currency::Dkk,
+            CurrencyCommandData::Nok => Currency::Nok,
+            CurrencyCommandData::Pln => Currency::Pln,
+            CurrencyCommandData::Czk => Currency::Czk,
+            CurrencyCommandData::Jpy => Currency::Jpy,
         }
     }
 }
@@ -83,6 +112,13 @@ impl From<CurrencyRecord> for Currency {
             CurrencyRecord::Aud => Currency::Aud,
             CurrencyRecord::Cad => Currency::Cad,
             CurrencyRecord::Nzd => Currency::Nzd,
+            CurrencyRecord::Chf => Currency::Chf,
+            CurrencyRecord::Sek => Currency::Sek,
+            CurrencyRecord::Dkk => Currency::Dkk,
+            CurrencyRecord::Nok => Currency::Nok,
+            CurrencyRecord::Pln => Currency::Pln,
+            CurrencyRecord::Czk => Currency::Czk,
+            CurrencyRecord::Jpy => Currency::Jpy,
         }
     }
 }
@@ -96,6 +132,56 @@ impl From<CurrencyData> for Currency {
             CurrencyData::Aud => Currency::Aud,
             CurrencyData::Cad => Currency::Cad,
             CurrencyData::Nzd => Currency::Nzd,
+            CurrencyData::Chf => Currency::Chf,
+            CurrencyData::Sek => Currency::Sek,
+            CurrencyData::Dkk => Currency::Dkk,
+            CurrencyData::Nok => Currency::Nok,
+            CurrencyData::Pln => Currency::Pln,
+            CurrencyData::Czk => Currency::Czk,
+            CurrencyData::Jpy => Currency::Jpy,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::currency::domain::{Currency, HasMinorUnitExponent, MinorUnitExponent};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Currency::Eur, "EUR")]
+    #[case(Currency::Gbp, "GBP")]
+    #[case(Currency::Usd, "USD")]
+    #[case(Currency::Aud, "AUD")]
+    #[case(Currency::Cad, "CAD")]
+    #[case(Currency::Nzd, "NZD")]
+    #[case(Currency::Chf, "CHF")]
+    #[case(Currency::Sek, "SEK")]
+    #[case(Currency::Dkk, "DKK")]
+    #[case(Currency::Nok, "NOK")]
+    #[case(Currency::Pln, "PLN")]
+    #[case(Currency::Czk, "CZK")]
+    #[case(Currency::Jpy, "JPY")]
+    fn should_map_iso_code(#[case] currency: Currency, #[case] iso_code: &str) {
+        assert_eq!(iso_code, currency.iso_code());
+        assert_eq!(Some(currency), Currency::from_iso_code(iso_code));
+        assert_eq!(
+            Some(currency),
+            Currency::from_iso_code(&iso_code.to_lowercase())
+        );
+    }
+
+    #[rstest]
+    #[case::eur(Currency::Eur, 2)]
+    #[case::chf(Currency::Chf, 2)]
+    #[case::czk(Currency::Czk, 2)]
+    #[case::jpy(Currency::Jpy, 0)]
+    fn should_have_iso_4217_minor_unit_exponent(#[case] currency: Currency, #[case] expected: u8) {
+        assert_eq!(MinorUnitExponent(expected), currency.minor_unit_exponent());
+    }
+
+    #[test]
+    fn should_not_map_unknown_iso_code() {
+        assert_eq!(None, Currency::from_iso_code("XAU"));
+    }
+}
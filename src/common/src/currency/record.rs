@@ -11,6 +11,13 @@ pub enum CurrencyRecord {
     Aud,
     Cad,
     Nzd,
+    Chf,
+    Sek,
+    Dkk,
+    Nok,
+    Pln,
+    Czk,
+    Jpy,
 }
 
 impl From<Currency> for CurrencyRecord {
@@ -22,6 +29,13 @@ impl From<Currency> for CurrencyRecord {
             Currency::Aud => CurrencyRecord::Aud,
             Currency::Cad => CurrencyRecord::Cad,
             Currency::Nzd => CurrencyRecord::Nzd,
+            Currency::Chf => CurrencyRecord::Chf,
+            Currency::Sek => CurrencyRecord::Sek,
+            Currency::Dkk => CurrencyRecord::Dkk,
+            Currency::Nok => CurrencyRecord::Nok,
+            Currency::Pln => CurrencyRecord::Pln,
+            Currency::Czk => CurrencyRecord::Czk,
+            Currency::Jpy => CurrencyRecord::Jpy,
         }
     }
 }
@@ -38,6 +52,13 @@ mod tests {
     #[case(CurrencyRecord::Aud, "\"AUD\"")]
     #[case(CurrencyRecord::Cad, "\"CAD\"")]
     #[case(CurrencyRecord::Nzd, "\"NZD\"")]
+    #[case(CurrencyRecord::Chf, "\"CHF\"")]
+    #[case(CurrencyRecord::Sek, "\"SEK\"")]
+    #[case(CurrencyRecord::Dkk, "\"DKK\"")]
+    #[case(CurrencyRecord::Nok, "\"NOK\"")]
+    #[case(CurrencyRecord::Pln, "\"PLN\"")]
+    #[case(CurrencyRecord::Czk, "\"CZK\"")]
+    #[case(CurrencyRecord::Jpy, "\"JPY\"")]
     fn should_serialize_currency_in_screaming_snake_case(
         #[case] currency: CurrencyRecord,
         #[case] expected: &str,
@@ -53,6 +74,13 @@ mod tests {
     #[case("\"AUD\"", CurrencyRecord::Aud)]
     #[case("\"CAD\"", CurrencyRecord::Cad)]
     #[case("\"NZD\"", CurrencyRecord::Nzd)]
+    #[case("\"CHF\"", CurrencyRecord::Chf)]
+    #[case("\"SEK\"", CurrencyRecord::Sek)]
+    #[case("\"DKK\"", CurrencyRecord::Dkk)]
+    #[case("\"NOK\"", CurrencyRecord::Nok)]
+    #[case("\"PLN\"", CurrencyRecord::Pln)]
+    #[case("\"CZK\"", CurrencyRecord::Czk)]
+    #[case("\"JPY\"", CurrencyRecord::Jpy)]
     fn should_deserialize_currency_in_screaming_snake_case(
         #[case] currency: &str,
         #[case] expected: CurrencyRecord,
@@ -6,7 +6,7 @@ use crate::language::record::LanguageRecord;
 use crate::localized::Localized;
 
 #[cfg_attr(feature = "test-data", derive(fake::Dummy))]
-#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Default)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Default, strum_macros::EnumIter)]
 pub enum Language {
     #[default]
     De,
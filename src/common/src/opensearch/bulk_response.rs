@@ -44,6 +44,9 @@ pub struct BulkOpResult {
     #[serde(rename = "_version", default)]
     pub version: Option<u64>,
 
+    #[serde(default)]
+    pub result: Option<String>,
+
     pub status: u16,
 
     #[serde(default)]
@@ -54,6 +57,11 @@ impl BulkOpResult {
     pub fn is_err(&self) -> bool {
         self.error.is_some()
     }
+
+    /// Whether an update has been discarded on purpose, e.g. by a script setting `ctx.op = 'noop'`.
+    pub fn is_noop(&self) -> bool {
+        self.result.as_deref() == Some("noop")
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -170,6 +178,31 @@ mod tests {
         assert_eq!(err.index_uuid.as_deref(), Some("uuid123"));
     }
 
+    #[test]
+    fn should_parse_noop_for_bulk_response() {
+        let json = json!({
+            "took": 3,
+            "errors": false,
+            "items": [
+                {
+                    "update": {
+                        "_index": "items",
+                        "_id": "4",
+                        "_version": 5,
+                        "result": "noop",
+                        "status": 200
+                    }
+                }
+            ]
+        });
+
+        let response: BulkResponse = serde_json::from_value(json).unwrap();
+
+        let update = response.items[0].clone().unwrap_update();
+        assert!(update.is_noop());
+        assert!(!update.is_err());
+    }
+
     #[test]
     fn should_default_to_empty_items_when_missing_for_bulk_response() {
         let json = json!({
@@ -1,3 +1,4 @@
 pub mod bulk_response;
+pub mod search_after;
 pub mod search_response;
 pub mod search_result;
@@ -0,0 +1,382 @@
+use serde::{Deserialize, Serialize};
+
+/// Position after which the next page of a sorted search starts.
+///
+/// `sort_values` are the sort-values of the last hit of the previous page, i.e. one per sort-field
+/// including the trailing tiebreak on `itemId`. If empty, the search starts from the beginning.
+/// If `pit_id` is given, the search runs against that point-in-time instead of the live index.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SearchAfter {
+    #[serde(rename = "v", default, skip_serializing_if = "Vec::is_empty")]
+    pub sort_values: Vec<serde_json::Value>,
+
+    #[serde(rename = "p", default, skip_serializing_if = "Option::is_none")]
+    pub pit_id: Option<String>,
+}
+
+#[cfg(feature = "api")]
+pub mod api {
+    use crate::{
+        api::{error::ApiError, error_code::BAD_CURSOR_VALUE},
+        language::{data::LanguageData, domain::Language},
+        opensearch::search_after::SearchAfter,
+    };
+    use aws_lambda_events::query_map::QueryMap;
+    use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+    use hmac::{Hmac, Mac};
+    use serde::{Deserialize, Serialize};
+    use sha2::{Digest, Sha256};
+
+    type HmacSha256 = Hmac<Sha256>;
+
+    /// Secret used to sign cursors so clients cannot forge arbitrary `search_after`-values.
+    #[derive(Clone)]
+    pub struct CursorSigningKey(Vec<u8>);
+
+    impl CursorSigningKey {
+        pub fn new(key: impl Into<Vec<u8>>) -> Self {
+            Self(key.into())
+        }
+
+        fn mac(&self) -> HmacSha256 {
+            HmacSha256::new_from_slice(&self.0)
+                .expect("shouldn't fail because HMAC accepts keys of any length.")
+        }
+    }
+
+    impl std::fmt::Debug for CursorSigningKey {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("CursorSigningKey(***)")
+        }
+    }
+
+    /// The search a cursor was issued for, so that it cannot continue any other.
+    ///
+    /// `sort` identifies the sort the `search_after`-values belong to, `query_hash` digests the
+    /// resolved language, which picks the searched text-fields, and the query parameters which
+    /// select and price the hits.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct CursorBinding {
+        #[serde(rename = "s")]
+        pub sort: String,
+
+        #[serde(rename = "h")]
+        pub query_hash: String,
+    }
+
+    impl CursorBinding {
+        /// Binds to `sort`, `language` and the values of the query parameters `fields`.
+        /// Repeated parameters match regardless of their order.
+        pub fn new(
+            sort: impl Into<String>,
+            language: &Language,
+            query: &QueryMap,
+            fields: &[&str],
+        ) -> Self {
+            let mut hasher = Sha256::new();
+            hasher.update(
+                serde_json::to_vec(&LanguageData::from(*language))
+                    .expect("shouldn't fail serializing because LanguageData is a plain enum."),
+            );
+            for field in fields {
+                let mut values = query
+                    .all(field)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .collect::<Vec<_>>();
+                values.sort_unstable();
+                hasher.update(
+                    serde_json::to_vec(&(field, values))
+                        .expect("shouldn't fail serializing because it only contains strings."),
+                );
+            }
+
+            CursorBinding {
+                sort: sort.into(),
+                query_hash: BASE64_URL_SAFE_NO_PAD.encode(hasher.finalize()),
+            }
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct CursorPayload {
+        #[serde(flatten)]
+        search_after: SearchAfter,
+
+        #[serde(flatten)]
+        binding: CursorBinding,
+    }
+
+    /// Encodes `search_after` as opaque cursor `<base64url payload>.<base64url signature>`.
+    pub fn encode_cursor(
+        search_after: &SearchAfter,
+        binding: &CursorBinding,
+        key: &CursorSigningKey,
+    ) -> String {
+        let payload = serde_json::to_vec(&CursorPayload {
+            search_after: search_after.clone(),
+            binding: binding.clone(),
+        })
+        .expect("shouldn't fail serializing CursorPayload because it only contains JSON-values.");
+        let payload = BASE64_URL_SAFE_NO_PAD.encode(payload);
+        let mut mac = key.mac();
+        mac.update(payload.as_bytes());
+        let signature = BASE64_URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+
+        format!("{payload}.{signature}")
+    }
+
+    pub fn decode_cursor(
+        cursor: &str,
+        binding: &CursorBinding,
+        key: &CursorSigningKey,
+    ) -> Result<SearchAfter, String> {
+        let (payload, signature) = cursor
+            .split_once('.')
+            .ok_or_else(|| "Cursor is malformed.".to_string())?;
+        let signature = BASE64_URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| "Cursor is malformed.".to_string())?;
+        let mut mac = key.mac();
+        mac.update(payload.as_bytes());
+        mac.verify_slice(&signature)
+            .map_err(|_| "Cursor signature is invalid.".to_string())?;
+        let payload = BASE64_URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_| "Cursor is malformed.".to_string())?;
+
+        let payload =
+            serde_json::from_slice::<CursorPayload>(&payload).map_err(|err| err.to_string())?;
+        if payload.binding.sort != binding.sort {
+            return Err("Cursor was issued for another sort.".to_string());
+        }
+        if payload.binding.query_hash != binding.query_hash {
+            return Err("Cursor was issued for another query.".to_string());
+        }
+
+        Ok(payload.search_after)
+    }
+
+    pub fn extract_cursor_query(
+        query: &QueryMap,
+        binding: &CursorBinding,
+        key: &CursorSigningKey,
+    ) -> Result<Option<SearchAfter>, ApiError> {
+        query
+            .first("cursor")
+            .map(str::trim)
+            .filter(|cursor| !cursor.is_empty())
+            .map(|cursor| decode_cursor(cursor, binding, key))
+            .transpose()
+            .map_err(|err| {
+                ApiError::bad_request(BAD_CURSOR_VALUE)
+                    .with_query_field("cursor")
+                    .with_message(err)
+            })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::api::error::{ApiErrorSource, ApiErrorSourceType};
+        use crate::api::error_code::BAD_CURSOR_VALUE;
+        use crate::language::domain::Language;
+        use crate::opensearch::search_after::SearchAfter;
+        use crate::opensearch::search_after::api::{
+            CursorBinding, CursorSigningKey, encode_cursor, extract_cursor_query,
+        };
+        use aws_lambda_events::query_map::QueryMap;
+        use serde_json::json;
+        use std::collections::HashMap;
+
+        fn mk_query(cursor: &str) -> QueryMap {
+            QueryMap::from(HashMap::from([("cursor".to_string(), cursor.to_string())]))
+        }
+
+        fn mk_binding() -> CursorBinding {
+            CursorBinding {
+                sort: "price:asc".to_string(),
+                query_hash: "h4sh".to_string(),
+            }
+        }
+
+        #[rstest::rstest]
+        #[case::price(SearchAfter { sort_values: vec![json!(4200), json!("abc")], pit_id: None })]
+        #[case::score(SearchAfter { sort_values: vec![json!(1.337), json!("abc")], pit_id: None })]
+        #[case::missing_price(SearchAfter { sort_values: vec![json!(null), json!("abc")], pit_id: None })]
+        #[case::pit_only(SearchAfter { sort_values: vec![], pit_id: Some("o463QQEJ".to_string()) })]
+        #[case::pit(SearchAfter { sort_values: vec![json!(1735689600000u64), json!("abc")], pit_id: Some("o463QQEJ".to_string()) })]
+        fn should_extract_cursor_when_encoded_with_same_key(#[case] search_after: SearchAfter) {
+            let key = CursorSigningKey::new("top-secret");
+            let cursor = encode_cursor(&search_after, &mk_binding(), &key);
+
+            let actual = extract_cursor_query(&mk_query(&cursor), &mk_binding(), &key).unwrap();
+
+            assert_eq!(Some(search_after), actual);
+        }
+
+        #[test]
+        fn should_extract_nothing_when_cursor_is_missing() {
+            let key = CursorSigningKey::new("top-secret");
+
+            let actual = extract_cursor_query(&QueryMap::default(), &mk_binding(), &key).unwrap();
+
+            assert_eq!(None, actual);
+        }
+
+        #[rstest::rstest]
+        #[case::garbage("boop")]
+        #[case::no_signature("eyJ2IjpbNDIwMCwiYWJjIl19")]
+        #[case::bad_signature("eyJ2IjpbNDIwMCwiYWJjIl19.c2lnbmF0dXJl")]
+        #[case::not_base64("!!!.???")]
+        fn should_400_when_cursor_is_invalid(#[case] cursor: &str) {
+            let key = CursorSigningKey::new("top-secret");
+
+            let actual = extract_cursor_query(&mk_query(cursor), &mk_binding(), &key).unwrap_err();
+
+            assert_eq!(400, actual.status);
+            assert_eq!(BAD_CURSOR_VALUE, actual.error);
+            assert_eq!(
+                Some(ApiErrorSource {
+                    field: "cursor",
+                    source_type: ApiErrorSourceType::Query,
+                }),
+                actual.source
+            )
+        }
+
+        #[test]
+        fn should_400_when_cursor_was_signed_with_other_key() {
+            let search_after = SearchAfter {
+                sort_values: vec![json!(4200), json!("abc")],
+                pit_id: None,
+            };
+            let cursor = encode_cursor(
+                &search_after,
+                &mk_binding(),
+                &CursorSigningKey::new("other-secret"),
+            );
+
+            let actual = extract_cursor_query(
+                &mk_query(&cursor),
+                &mk_binding(),
+                &CursorSigningKey::new("top-secret"),
+            )
+            .unwrap_err();
+
+            assert_eq!(BAD_CURSOR_VALUE, actual.error);
+        }
+
+        #[test]
+        fn should_400_when_cursor_payload_was_tampered_with() {
+            let key = CursorSigningKey::new("top-secret");
+            let cursor = encode_cursor(
+                &SearchAfter {
+                    sort_values: vec![json!(4200), json!("abc")],
+                    pit_id: None,
+                },
+                &mk_binding(),
+                &key,
+            );
+            let (_, signature) = cursor.split_once('.').unwrap();
+            let forged = encode_cursor(
+                &SearchAfter {
+                    sort_values: vec![json!(1), json!("abc")],
+                    pit_id: None,
+                },
+                &mk_binding(),
+                &key,
+            );
+            let (forged_payload, _) = forged.split_once('.').unwrap();
+
+            let actual = extract_cursor_query(
+                &mk_query(&format!("{forged_payload}.{signature}")),
+                &mk_binding(),
+                &key,
+            )
+            .unwrap_err();
+
+            assert_eq!(BAD_CURSOR_VALUE, actual.error);
+        }
+
+        #[rstest::rstest]
+        #[case::sort(CursorBinding { sort: "created:desc".to_string(), query_hash: "h4sh".to_string() })]
+        #[case::query(CursorBinding { sort: "price:asc".to_string(), query_hash: "0th3r".to_string() })]
+        fn should_400_when_cursor_was_issued_for_other_search(#[case] binding: CursorBinding) {
+            let key = CursorSigningKey::new("top-secret");
+            let cursor = encode_cursor(
+                &SearchAfter {
+                    sort_values: vec![json!(4200), json!("abc")],
+                    pit_id: None,
+                },
+                &mk_binding(),
+                &key,
+            );
+
+            let actual = extract_cursor_query(&mk_query(&cursor), &binding, &key).unwrap_err();
+
+            assert_eq!(400, actual.status);
+            assert_eq!(BAD_CURSOR_VALUE, actual.error);
+        }
+
+        #[test]
+        fn should_bind_to_same_query_regardless_of_unrelated_parameters() {
+            let fields = ["q", "currency"];
+            let query = QueryMap::from(HashMap::from([
+                ("q".to_string(), "pickelhaube".to_string()),
+                ("currency".to_string(), "EUR".to_string()),
+            ]));
+            let other_query = QueryMap::from(HashMap::from([
+                ("q".to_string(), " pickelhaube ".to_string()),
+                ("currency".to_string(), "EUR".to_string()),
+                ("size".to_string(), "42".to_string()),
+            ]));
+
+            let actual = CursorBinding::new("_score", &Language::De, &query, &fields);
+            let other = CursorBinding::new("_score", &Language::De, &other_query, &fields);
+
+            assert_eq!(actual, other);
+        }
+
+        #[rstest::rstest]
+        #[case::value("q", "tschako")]
+        #[case::added("minPrice", "1000")]
+        fn should_bind_to_other_query_when_parameter_differs(
+            #[case] field: &str,
+            #[case] value: &str,
+        ) {
+            let fields = ["q", "minPrice"];
+            let query = QueryMap::from(HashMap::from([(
+                "q".to_string(),
+                "pickelhaube".to_string(),
+            )]));
+            let mut other_query = HashMap::from([("q".to_string(), "pickelhaube".to_string())]);
+            other_query.insert(field.to_string(), value.to_string());
+
+            let actual = CursorBinding::new("_score", &Language::De, &query, &fields);
+            let other = CursorBinding::new(
+                "_score",
+                &Language::De,
+                &QueryMap::from(other_query),
+                &fields,
+            );
+
+            assert_ne!(actual.query_hash, other.query_hash);
+        }
+
+        #[test]
+        fn should_bind_to_other_query_when_language_differs() {
+            let fields = ["q"];
+            let query = QueryMap::from(HashMap::from([(
+                "q".to_string(),
+                "pickelhaube".to_string(),
+            )]));
+
+            let actual = CursorBinding::new("_score", &Language::De, &query, &fields);
+            let other = CursorBinding::new("_score", &Language::En, &query, &fields);
+
+            assert_ne!(actual.query_hash, other.query_hash);
+        }
+    }
+}
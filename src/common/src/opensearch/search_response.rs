@@ -1,4 +1,5 @@
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct SearchResponse<T> {
@@ -8,6 +9,12 @@ pub struct SearchResponse<T> {
     #[serde(rename = "_shards")]
     pub shards: ShardStats,
     pub hits: HitsMetadata<T>,
+
+    #[serde(default)]
+    pub aggregations: Aggregations,
+
+    #[serde(default)]
+    pub pit_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Clone, Copy)]
@@ -44,6 +51,61 @@ pub struct SearchHit<T> {
 
     #[serde(rename = "_source")]
     pub source: T,
+
+    #[serde(default)]
+    pub sort: Vec<serde_json::Value>,
+}
+
+/// Bucket-aggregations (`terms`, `histogram`, `date_histogram`, ...) keyed by the name they were requested with.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct Aggregations(pub HashMap<String, BucketAggregation>);
+
+impl Aggregations {
+    /// Buckets of the aggregation, also if it's wrapped by a single-bucket aggregation of the same
+    /// name, e.g. a `filter`-aggregation.
+    pub fn buckets(&self, name: &str) -> &[Bucket] {
+        self.0
+            .get(name)
+            .map(|aggregation| aggregation.nested.get(name).unwrap_or(aggregation))
+            .map(|aggregation| aggregation.buckets.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct BucketAggregation {
+    #[serde(default)]
+    pub buckets: Vec<Bucket>,
+
+    /// Sub-aggregations of a single-bucket aggregation, keyed by name.
+    #[serde(flatten, deserialize_with = "deserialize_nested")]
+    pub nested: HashMap<String, BucketAggregation>,
+}
+
+/// Keeps only the object-valued fields, as others are metadata such as `doc_count`.
+fn deserialize_nested<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, BucketAggregation>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    HashMap::<String, serde_json::Value>::deserialize(deserializer)?
+        .into_iter()
+        .filter(|(_, value)| value.is_object())
+        .map(|(name, value)| {
+            serde_json::from_value(value)
+                .map(|aggregation| (name, aggregation))
+                .map_err(serde::de::Error::custom)
+        })
+        .collect()
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Bucket {
+    pub key: serde_json::Value, // string for terms, number for (date-)histograms
+    pub key_as_string: Option<String>,
+    pub doc_count: u64,
 }
 
 #[cfg(test)]
@@ -268,4 +330,106 @@ mod tests {
         assert_eq!(resp.shards.failed, 1);
         assert_eq!(resp.hits.hits.len(), 1);
     }
+
+    #[test]
+    fn should_default_aggregations_to_empty_when_none_requested() {
+        let json = r#"
+           {
+               "took": 1,
+               "timed_out": false,
+               "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+               "hits": { "total": { "value": 0, "relation": "eq" },
+                         "max_score": null, "hits": [] }
+           }
+           "#;
+
+        let resp: SearchResponse<MyDoc> = serde_json::from_str(json).unwrap();
+
+        assert!(resp.aggregations.0.is_empty());
+        assert!(resp.aggregations.buckets("state").is_empty());
+    }
+
+    #[test]
+    fn should_deserialize_bucket_aggregations_when_present() {
+        let json = r#"
+           {
+               "took": 4,
+               "timed_out": false,
+               "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+               "hits": { "total": { "value": 3, "relation": "eq" },
+                         "max_score": null, "hits": [] },
+               "aggregations": {
+                   "state": {
+                       "doc_count_error_upper_bound": 0,
+                       "sum_other_doc_count": 0,
+                       "buckets": [
+                           { "key": "SOLD", "doc_count": 2 },
+                           { "key": "AVAILABLE", "doc_count": 1 }
+                       ]
+                   },
+                   "price": {
+                       "buckets": [
+                           { "key": 10000.0, "doc_count": 3 }
+                       ]
+                   },
+                   "created": {
+                       "buckets": [
+                           { "key_as_string": "2025-01-01T00:00:00.000Z", "key": 1735689600000, "doc_count": 3 }
+                       ]
+                   }
+               }
+           }
+           "#;
+
+        let resp: SearchResponse<MyDoc> = serde_json::from_str(json).unwrap();
+
+        let states = resp.aggregations.buckets("state");
+        assert_eq!(2, states.len());
+        assert_eq!(Some("SOLD"), states[0].key.as_str());
+        assert_eq!(2, states[0].doc_count);
+        assert_eq!(
+            Some(10000.0),
+            resp.aggregations.buckets("price")[0].key.as_f64()
+        );
+        let created = &resp.aggregations.buckets("created")[0];
+        assert_eq!(Some(1735689600000), created.key.as_i64());
+        assert_eq!(
+            Some("2025-01-01T00:00:00.000Z"),
+            created.key_as_string.as_deref()
+        );
+        assert!(resp.aggregations.buckets("shopName").is_empty());
+    }
+
+    #[test]
+    fn should_deserialize_bucket_aggregations_when_wrapped_by_filter_aggregation() {
+        let json = r#"
+           {
+               "took": 4,
+               "timed_out": false,
+               "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+               "hits": { "total": { "value": 3, "relation": "eq" },
+                         "max_score": null, "hits": [] },
+               "aggregations": {
+                   "state": {
+                       "doc_count": 3,
+                       "state": {
+                           "doc_count_error_upper_bound": 0,
+                           "sum_other_doc_count": 0,
+                           "buckets": [
+                               { "key": "SOLD", "doc_count": 2 },
+                               { "key": "AVAILABLE", "doc_count": 1 }
+                           ]
+                       }
+                   }
+               }
+           }
+           "#;
+
+        let resp: SearchResponse<MyDoc> = serde_json::from_str(json).unwrap();
+
+        let states = resp.aggregations.buckets("state");
+        assert_eq!(2, states.len());
+        assert_eq!(Some("SOLD"), states[0].key.as_str());
+        assert_eq!(2, states[0].doc_count);
+    }
 }
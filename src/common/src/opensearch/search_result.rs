@@ -1,5 +1,11 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct SearchResult<T> {
+use crate::opensearch::search_after::SearchAfter;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult<T, F> {
     pub hits: Vec<T>,
     pub total: u64,
+    pub facets: F,
+
+    /// Position to continue from for the next page, `None` if there are no further hits.
+    pub search_after: Option<SearchAfter>,
 }
@@ -1,13 +1,16 @@
-use crate::currency::domain::Currency;
+use crate::currency::domain::{Currency, HasMinorUnitExponent};
 use crate::price::command_data::PriceCommandData;
 use crate::price::data::PriceData;
 use crate::price::record::PriceRecord;
 use std::collections::HashMap;
 use std::ops::{Add, Deref, Sub};
 use strum::{EnumCount, IntoEnumIterator};
+use time::Date;
+use time::macros::date;
 
-type Rate = u64;
-const FX_RATE_SCALE: Rate = 1_000_000;
+/// Fixed-point exchange rate, scaled by [`FX_RATE_SCALE`].
+pub type Rate = u64;
+pub const FX_RATE_SCALE: Rate = 1_000_000;
 
 pub trait FxRate {
     fn exchange(
@@ -31,6 +34,11 @@ pub trait FxRate {
         }
         Ok(exchanged)
     }
+
+    /// Date of the rates, recorded alongside prices exchanged with them.
+    fn snapshot_date(&self) -> Option<Date> {
+        None
+    }
 }
 
 /// as of 2025-07-15
@@ -81,6 +89,30 @@ impl FixedFxRate {
             (Currency::Nzd, Currency::Aud) => 913_200,
             (Currency::Nzd, Currency::Cad) => 820_300,
             (Currency::Nzd, Currency::Nzd) => 1_000_000,
+
+            (from, to) => {
+                let (from_rate, to_rate) = (Self::eur_rate(from), Self::eur_rate(to));
+                (to_rate * FX_RATE_SCALE + from_rate / 2) / from_rate
+            }
+        }
+    }
+
+    /// Units of `currency` per EUR, from which all other rates are crossed.
+    fn eur_rate(currency: Currency) -> Rate {
+        match currency {
+            Currency::Eur => 1_000_000,
+            Currency::Gbp => 867_800,
+            Currency::Usd => 1_167_000,
+            Currency::Aud => 1_778_000,
+            Currency::Cad => 1_597_000,
+            Currency::Nzd => 1_947_000,
+            Currency::Chf => 931_400,
+            Currency::Sek => 11_215_000,
+            Currency::Dkk => 7_463_000,
+            Currency::Nok => 11_857_000,
+            Currency::Pln => 4_264_500,
+            Currency::Czk => 24_645_000,
+            Currency::Jpy => 172_230_000,
         }
     }
 }
@@ -98,15 +130,24 @@ impl FxRate for FixedFxRate {
     ) -> Result<MonetaryAmount, MonetaryAmountOverflowError> {
         let rate = self.get_rate(from_currency, to_currency);
 
-        // Half-Up Rounding
-        let numerator = from_amount
-            .0
-            .checked_mul(rate)
+        // Amounts are in minor units, so their exponents have to be accounted for as well.
+        let to_scale = 10u128.pow(u8::from(to_currency.minor_unit_exponent()).into());
+        let from_scale = 10u128.pow(u8::from(from_currency.minor_unit_exponent()).into());
+        let numerator = u128::from(from_amount.0)
+            .checked_mul(u128::from(rate) * to_scale)
             .ok_or(MonetaryAmountOverflowError)?;
-        let half = FX_RATE_SCALE / 2;
-        let converted = (numerator + half) / FX_RATE_SCALE;
+        let denominator = u128::from(FX_RATE_SCALE) * from_scale;
+
+        // Half-Up Rounding
+        let converted = (numerator + denominator / 2) / denominator;
+
+        u64::try_from(converted)
+            .map(MonetaryAmount)
+            .map_err(|_| MonetaryAmountOverflowError)
+    }
 
-        Ok(MonetaryAmount(converted))
+    fn snapshot_date(&self) -> Option<Date> {
+        Some(date!(2025 - 07 - 15))
     }
 }
 
@@ -196,11 +237,7 @@ impl Price {
         currency: Currency,
     ) -> Result<Price, MonetaryAmountOverflowError> {
         let exchanged = Price {
-            monetary_amount: fx_rate.exchange(
-                self.currency,
-                self.currency,
-                self.monetary_amount,
-            )?,
+            monetary_amount: fx_rate.exchange(self.currency, currency, self.monetary_amount)?,
             currency,
         };
         Ok(exchanged)
@@ -211,8 +248,7 @@ impl Price {
         fx_rate: &impl FxRate,
         currency: Currency,
     ) -> Result<(), MonetaryAmountOverflowError> {
-        self.monetary_amount =
-            fx_rate.exchange(self.currency, self.currency, self.monetary_amount)?;
+        self.monetary_amount = fx_rate.exchange(self.currency, currency, self.monetary_amount)?;
         self.currency = currency;
         Ok(())
     }
@@ -248,7 +284,9 @@ impl From<PriceRecord> for Price {
 #[cfg(test)]
 mod tests {
     use crate::currency::domain::Currency;
-    use crate::price::domain::{FxRate, MonetaryAmount, MonetaryAmountOverflowError, Price};
+    use crate::price::domain::{
+        FixedFxRate, FxRate, MonetaryAmount, MonetaryAmountOverflowError, Price,
+    };
 
     struct DummyFxRate;
     impl FxRate for DummyFxRate {
@@ -274,6 +312,35 @@ mod tests {
         assert_eq!(1000, exchanged.unwrap().monetary_amount.0);
     }
 
+    #[test]
+    fn should_into_exchanged_to_target_currency() {
+        let price = Price {
+            monetary_amount: MonetaryAmount(10000),
+            currency: Currency::Eur,
+        };
+
+        let exchanged = price.into_exchanged(&FixedFxRate(), Currency::Usd).unwrap();
+
+        assert_eq!(Currency::Usd, exchanged.currency);
+        assert_eq!(11670, exchanged.monetary_amount.0);
+    }
+
+    #[rstest::rstest]
+    #[case::to_zero_decimal(Currency::Eur, Currency::Jpy, 10000, 17223)]
+    #[case::from_zero_decimal(Currency::Jpy, Currency::Eur, 17223, 10000)]
+    #[case::cross(Currency::Chf, Currency::Sek, 10000, 120410)]
+    #[case::identity(Currency::Jpy, Currency::Jpy, 500, 500)]
+    fn should_exchange_respecting_minor_unit_exponents(
+        #[case] from: Currency,
+        #[case] to: Currency,
+        #[case] amount: u64,
+        #[case] expected: u64,
+    ) {
+        let actual = FixedFxRate().exchange(from, to, MonetaryAmount(amount));
+
+        assert_eq!(Ok(MonetaryAmount(expected)), actual);
+    }
+
     #[test]
     fn should_exchange() {
         let mut price = Price {
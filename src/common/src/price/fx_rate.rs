@@ -0,0 +1,364 @@
+use crate::currency::domain::{Currency, HasMinorUnitExponent};
+use crate::price::domain::{
+    FX_RATE_SCALE, FxRate, MonetaryAmount, MonetaryAmountOverflowError, Rate,
+};
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+use time::Date;
+use time::format_description::FormatItem;
+use time::macros::format_description;
+use xmlparser::{ElementEnd, Token, Tokenizer};
+
+/// ECB reference rates shipped with the code, used whenever no newer snapshot can be loaded.
+const BUNDLED_EUROFXREF: &str = include_str!("../../resources/eurofxref-daily.xml");
+
+/// Dates of `eurofxref-hist.csv` and `eurofxref.csv`, e.g. `2025-10-17` and `17 October 2025`.
+const ECB_CSV_DATE_FORMATS: &[&[FormatItem<'static>]] = &[
+    format_description!("[year]-[month]-[day]"),
+    format_description!("[day] [month repr:long] [year]"),
+    format_description!("[day padding:none] [month repr:long] [year]"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FxRateSnapshotError {
+    #[error("Rate snapshot has no date.")]
+    MissingDate,
+
+    #[error("Rate snapshot has invalid date '{0}'.")]
+    InvalidDate(String),
+
+    #[error("Rate snapshot has invalid rate '{rate}' for '{currency}'.")]
+    InvalidRate { currency: String, rate: String },
+
+    #[error("Rate snapshot has no rate for '{0}'.")]
+    MissingRate(Currency),
+}
+
+/// Exchange rates of a single day, quoted as units of each currency per unit of the base currency.
+///
+/// Rates between any other two currencies are cross rates derived through the base currency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FxRateSnapshot {
+    date: Date,
+    base: Currency,
+    rates: HashMap<Currency, Rate>,
+}
+
+impl FxRateSnapshot {
+    /// Fails unless there is a positive rate for every [`Currency`] other than the base.
+    pub fn new(
+        date: Date,
+        base: Currency,
+        mut rates: HashMap<Currency, Rate>,
+    ) -> Result<Self, FxRateSnapshotError> {
+        rates.insert(base, FX_RATE_SCALE);
+        for currency in Currency::iter() {
+            match rates.get(&currency) {
+                None => return Err(FxRateSnapshotError::MissingRate(currency)),
+                Some(0) => {
+                    return Err(FxRateSnapshotError::InvalidRate {
+                        currency: currency.to_string(),
+                        rate: "0".to_string(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(FxRateSnapshot { date, base, rates })
+    }
+
+    /// Reference rates of the ECB as of the last release of this code.
+    pub fn bundled() -> Self {
+        Self::from_ecb_xml(BUNDLED_EUROFXREF)
+            .expect("shouldn't fail because the bundled reference rates are complete")
+    }
+
+    /// Parses the ECB's euro foreign exchange reference rates, e.g. `eurofxref-daily.xml`.
+    ///
+    /// Of multiple days, e.g. `eurofxref-hist-90d.xml`, only the first and latest one is taken.
+    /// Currencies other than [`Currency`] are ignored.
+    pub fn from_ecb_xml(xml: &str) -> Result<Self, FxRateSnapshotError> {
+        let mut date = None;
+        let mut rates = HashMap::new();
+        let mut in_cube = false;
+        let mut currency = None;
+        let mut rate = None;
+        for token in Tokenizer::from(xml).filter_map(Result::ok) {
+            match token {
+                Token::ElementStart { local, .. } => {
+                    in_cube = local.as_str() == "Cube";
+                    currency = None;
+                    rate = None;
+                }
+                Token::Attribute { local, value, .. } if in_cube => match local.as_str() {
+                    "time" if date.is_some() => break,
+                    "time" => date = Some(parse_date(value.as_str())?),
+                    "currency" => currency = Some(value.as_str()),
+                    "rate" => rate = Some(value.as_str()),
+                    _ => {}
+                },
+                Token::ElementEnd {
+                    end: ElementEnd::Open | ElementEnd::Empty,
+                    ..
+                } if in_cube => {
+                    if let (Some(currency), Some(rate)) = (currency, rate) {
+                        insert_rate(&mut rates, currency, rate)?;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self::new(
+            date.ok_or(FxRateSnapshotError::MissingDate)?,
+            Currency::Eur,
+            rates,
+        )
+    }
+
+    /// Parses the ECB's euro foreign exchange reference rates, e.g. `eurofxref.csv`.
+    ///
+    /// Of multiple days, e.g. `eurofxref-hist.csv`, only the first and latest one is taken.
+    /// Currencies other than [`Currency`] and rates `N/A` are ignored.
+    pub fn from_ecb_csv(csv: &str) -> Result<Self, FxRateSnapshotError> {
+        let mut lines = csv.lines().filter(|line| !line.trim().is_empty());
+        let header = lines.next().unwrap_or_default().split(',').map(str::trim);
+        let mut row = lines
+            .next()
+            .ok_or(FxRateSnapshotError::MissingDate)?
+            .split(',')
+            .map(str::trim);
+        let date = parse_date(row.next().unwrap_or_default())?;
+        let mut rates = HashMap::new();
+        for (currency, rate) in header.skip(1).zip(row) {
+            if rate != "N/A" && !currency.is_empty() {
+                insert_rate(&mut rates, currency, rate)?;
+            }
+        }
+
+        Self::new(date, Currency::Eur, rates)
+    }
+
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    pub fn base(&self) -> Currency {
+        self.base
+    }
+
+    /// Rates by currency, including the base currency's rate of [`FX_RATE_SCALE`].
+    pub fn rates(&self) -> &HashMap<Currency, Rate> {
+        &self.rates
+    }
+}
+
+impl FxRate for FxRateSnapshot {
+    fn exchange(
+        &self,
+        from_currency: Currency,
+        to_currency: Currency,
+        from_amount: MonetaryAmount,
+    ) -> Result<MonetaryAmount, MonetaryAmountOverflowError> {
+        // Amounts are in minor units, so their exponents have to be accounted for as well.
+        let to_scale = 10u128.pow(u8::from(to_currency.minor_unit_exponent()).into());
+        let from_scale = 10u128.pow(u8::from(from_currency.minor_unit_exponent()).into());
+        let numerator = u128::from(*from_amount)
+            .checked_mul(u128::from(self.rates[&to_currency]))
+            .and_then(|product| product.checked_mul(to_scale))
+            .ok_or(MonetaryAmountOverflowError)?;
+        let denominator = u128::from(self.rates[&from_currency]) * from_scale;
+
+        // Half-Up Rounding
+        let converted = (numerator + denominator / 2) / denominator;
+
+        u64::try_from(converted)
+            .map(MonetaryAmount::from)
+            .map_err(|_| MonetaryAmountOverflowError)
+    }
+
+    fn snapshot_date(&self) -> Option<Date> {
+        Some(self.date)
+    }
+}
+
+fn parse_date(date: &str) -> Result<Date, FxRateSnapshotError> {
+    ECB_CSV_DATE_FORMATS
+        .iter()
+        .find_map(|format| Date::parse(date, format).ok())
+        .ok_or_else(|| FxRateSnapshotError::InvalidDate(date.to_string()))
+}
+
+fn insert_rate(
+    rates: &mut HashMap<Currency, Rate>,
+    iso_code: &str,
+    rate: &str,
+) -> Result<(), FxRateSnapshotError> {
+    if let Some(currency) = Currency::from_iso_code(iso_code) {
+        let rate = parse_rate(rate).ok_or_else(|| FxRateSnapshotError::InvalidRate {
+            currency: iso_code.to_string(),
+            rate: rate.to_string(),
+        })?;
+        rates.insert(currency, rate);
+    }
+    Ok(())
+}
+
+/// Parses a decimal like `1.1670` without any loss of precision up to [`FX_RATE_SCALE`].
+fn parse_rate(rate: &str) -> Option<Rate> {
+    let (integer, fraction) = rate.split_once('.').unwrap_or((rate, ""));
+    let fraction_digits = FX_RATE_SCALE.ilog10() as usize;
+    if integer.is_empty()
+        || fraction.len() > fraction_digits
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let integer = integer.parse::<Rate>().ok()?.checked_mul(FX_RATE_SCALE)?;
+    let fraction = format!("{fraction:0<fraction_digits$}")
+        .parse::<Rate>()
+        .ok()?;
+    integer.checked_add(fraction)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::currency::domain::Currency;
+    use crate::price::domain::{FxRate, MonetaryAmount};
+    use crate::price::fx_rate::{FxRateSnapshot, FxRateSnapshotError, parse_rate};
+    use rstest::rstest;
+    use std::collections::HashMap;
+    use time::macros::date;
+
+    const EUROFXREF_HIST_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gesmes:Envelope xmlns:gesmes="http://www.gesmes.org/xml/2002-08-01" xmlns="http://www.ecb.int/vocabulary/2002-08-01/eurofxref">
+	<gesmes:subject>Reference rates</gesmes:subject>
+	<gesmes:Sender>
+		<gesmes:name>European Central Bank</gesmes:name>
+	</gesmes:Sender>
+	<Cube>
+		<Cube time="2025-10-17">
+			<Cube currency="USD" rate="1.1681"/>
+			<Cube currency="JPY" rate="175.48"/>
+			<Cube currency="GBP" rate="0.86905"/>
+			<Cube currency="CZK" rate="24.322"/>
+			<Cube currency="DKK" rate="7.4691"/>
+			<Cube currency="PLN" rate="4.2425"/>
+			<Cube currency="SEK" rate="10.9965"/>
+			<Cube currency="CHF" rate="0.9263"/>
+			<Cube currency="NOK" rate="11.7275"/>
+			<Cube currency="AUD" rate="1.7983"/>
+			<Cube currency="CAD" rate="1.6391"/>
+			<Cube currency="NZD" rate="2.0418"/>
+		</Cube>
+		<Cube time="2025-10-16">
+			<Cube currency="USD" rate="1.1692"/>
+			<Cube currency="JPY" rate="175.01"/>
+			<Cube currency="GBP" rate="0.8699"/>
+			<Cube currency="CZK" rate="24.335"/>
+			<Cube currency="DKK" rate="7.4690"/>
+			<Cube currency="PLN" rate="4.2490"/>
+			<Cube currency="SEK" rate="11.0080"/>
+			<Cube currency="CHF" rate="0.9297"/>
+			<Cube currency="NOK" rate="11.7650"/>
+			<Cube currency="AUD" rate="1.7958"/>
+			<Cube currency="CAD" rate="1.6402"/>
+			<Cube currency="NZD" rate="2.0391"/>
+		</Cube>
+	</Cube>
+</gesmes:Envelope>"#;
+
+    fn mk_expected_rates() -> HashMap<Currency, u64> {
+        HashMap::from([
+            (Currency::Eur, 1_000_000),
+            (Currency::Usd, 1_168_100),
+            (Currency::Gbp, 869_050),
+            (Currency::Aud, 1_798_300),
+            (Currency::Cad, 1_639_100),
+            (Currency::Nzd, 2_041_800),
+            (Currency::Chf, 926_300),
+            (Currency::Sek, 10_996_500),
+            (Currency::Dkk, 7_469_100),
+            (Currency::Nok, 11_727_500),
+            (Currency::Pln, 4_242_500),
+            (Currency::Czk, 24_322_000),
+            (Currency::Jpy, 175_480_000),
+        ])
+    }
+
+    #[test]
+    fn should_parse_latest_day_of_ecb_xml() {
+        let actual = FxRateSnapshot::from_ecb_xml(EUROFXREF_HIST_XML).unwrap();
+
+        assert_eq!(date!(2025 - 10 - 17), actual.date());
+        assert_eq!(Currency::Eur, actual.base());
+        assert_eq!(&mk_expected_rates(), actual.rates());
+    }
+
+    #[rstest]
+    #[case::daily(
+        "Date, USD, JPY, CZK, DKK, PLN, SEK, CHF, NOK, GBP, AUD, CAD, NZD, \n17 October 2025, 1.1681, 175.48, 24.322, 7.4691, 4.2425, 10.9965, 0.9263, 11.7275, 0.86905, 1.7983, 1.6391, 2.0418, \n"
+    )]
+    #[case::hist(
+        "Date,USD,JPY,CYP,CZK,DKK,PLN,SEK,CHF,NOK,GBP,AUD,CAD,NZD,\n2025-10-17,1.1681,175.48,N/A,24.322,7.4691,4.2425,10.9965,0.9263,11.7275,0.86905,1.7983,1.6391,2.0418,\n2025-10-16,1.1692,175.01,N/A,24.335,7.4690,4.2490,11.0080,0.9297,11.7650,0.8699,1.7958,1.6402,2.0391,\n"
+    )]
+    fn should_parse_latest_day_of_ecb_csv(#[case] csv: &str) {
+        let actual = FxRateSnapshot::from_ecb_csv(csv).unwrap();
+
+        assert_eq!(date!(2025 - 10 - 17), actual.date());
+        assert_eq!(&mk_expected_rates(), actual.rates());
+    }
+
+    #[test]
+    fn should_fail_parsing_ecb_csv_when_currency_is_missing() {
+        let csv = "Date,USD,GBP,AUD,CAD\n2025-10-17,1.1681,0.86905,1.7983,1.6391\n";
+
+        let actual = FxRateSnapshot::from_ecb_csv(csv);
+
+        assert_eq!(Err(FxRateSnapshotError::MissingRate(Currency::Nzd)), actual);
+    }
+
+    #[test]
+    fn should_parse_bundled_rates() {
+        let actual = FxRateSnapshot::bundled();
+
+        assert_eq!(Some(actual.date()), actual.snapshot_date());
+    }
+
+    #[rstest]
+    #[case("1.1681", Some(1_168_100))]
+    #[case("175.48", Some(175_480_000))]
+    #[case("25.123456", Some(25_123_456))]
+    #[case("2", Some(2_000_000))]
+    #[case("0.1234567", None)]
+    #[case("-1.2", None)]
+    #[case("N/A", None)]
+    #[case(".5", None)]
+    fn should_parse_rate(#[case] rate: &str, #[case] expected: Option<u64>) {
+        assert_eq!(expected, parse_rate(rate));
+    }
+
+    #[rstest]
+    #[case::from_base(Currency::Eur, Currency::Usd, 10000, 11681)]
+    #[case::to_base(Currency::Usd, Currency::Eur, 11681, 10000)]
+    #[case::cross(Currency::Gbp, Currency::Usd, 10000, 13441)]
+    #[case::identity(Currency::Nzd, Currency::Nzd, 12345, 12345)]
+    #[case::rounding(Currency::Usd, Currency::Gbp, 1, 1)]
+    #[case::to_zero_decimal(Currency::Eur, Currency::Jpy, 10000, 17548)]
+    #[case::from_zero_decimal(Currency::Jpy, Currency::Chf, 17548, 9263)]
+    fn should_exchange_via_base_currency(
+        #[case] from: Currency,
+        #[case] to: Currency,
+        #[case] amount: u64,
+        #[case] expected: u64,
+    ) {
+        let snapshot = FxRateSnapshot::from_ecb_xml(EUROFXREF_HIST_XML).unwrap();
+
+        let actual = snapshot.exchange(from, to, amount.into()).unwrap();
+
+        assert_eq!(MonetaryAmount::from(expected), actual);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod command_data;
 pub mod data;
 pub mod domain;
+pub mod fx_rate;
 pub mod record;
@@ -0,0 +1,345 @@
+use aws_lambda_events::apigw::{ApiGatewayV2httpRequest, ApiGatewayV2httpResponse};
+use aws_lambda_events::query_map::QueryMap;
+use common::api::api_gateway_v2_http_response_builder::ApiGatewayV2HttpResponseBuilder;
+use common::api::error::ApiError;
+use common::api::error_code::{
+    BAD_DATETIME_VALUE, BAD_PAGE_SIZE_VALUE, BAD_PARAMETER, INTERNAL_SERVER_ERROR,
+};
+use common::currency::data::api::extract_currency_query;
+use common::shop_id::ShopId;
+use common::shops_item_id::ShopsItemId;
+use item_data::item_event_data::ItemEventHistoryData;
+use item_service::get_service::GetItemService;
+use lambda_runtime::LambdaEvent;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tracing::error;
+
+const DEFAULT_SIZE: u16 = 50;
+const MAX_SIZE: u16 = 100;
+
+#[tracing::instrument(
+    skip(event, service),
+    fields(
+        requestId = %event.context.request_id,
+        path = &event.payload.raw_path,
+        query = &event.payload.raw_query_string,
+    )
+)]
+pub async fn handler(
+    event: LambdaEvent<ApiGatewayV2httpRequest>,
+    service: &impl GetItemService,
+) -> Result<ApiGatewayV2httpResponse, lambda_runtime::Error> {
+    match handle(event, service).await {
+        Ok(response) => Ok(response),
+        Err(err) => Ok(ApiGatewayV2httpResponse::from(err)),
+    }
+}
+
+pub async fn handle(
+    event: LambdaEvent<ApiGatewayV2httpRequest>,
+    service: &impl GetItemService,
+) -> Result<ApiGatewayV2httpResponse, ApiError> {
+    let currency = extract_currency_query(&event.payload.query_string_parameters)?.into();
+    let after = extract_after_query(&event.payload.query_string_parameters)?;
+    let size = extract_size_query(&event.payload.query_string_parameters)?;
+    let shop_id = event
+        .payload
+        .path_parameters
+        .get("shopId")
+        .filter(|str| !str.is_empty())
+        .map(ShopId::from)
+        .ok_or(ApiError::bad_request(BAD_PARAMETER).with_path_field("shopId"))?;
+    let shops_item_id = event
+        .payload
+        .path_parameters
+        .get("shopsItemId")
+        .filter(|str| !str.is_empty())
+        .map(ShopsItemId::from)
+        .ok_or(ApiError::bad_request(BAD_PARAMETER).with_path_field("shopsItemId"))?;
+
+    let history_data: ItemEventHistoryData = service
+        .view_item_events(&shop_id, &shops_item_id, &currency, after, size)
+        .await?
+        .into();
+    let response = serde_json::to_string(&history_data).map_err(|err| {
+        error!(error = %err, payload = ?history_data, type = %std::any::type_name::<ItemEventHistoryData>(), "Failed serializing ItemEventHistoryData.");
+        ApiError::internal_server_error(INTERNAL_SERVER_ERROR)
+    })?;
+
+    Ok(ApiGatewayV2HttpResponseBuilder::json(200)
+        .body(response)
+        .cors()
+        .build())
+}
+
+fn extract_after_query(query: &QueryMap) -> Result<Option<OffsetDateTime>, ApiError> {
+    query
+        .first("after")
+        .map(str::trim)
+        .filter(|after| !after.is_empty())
+        .map(|after| OffsetDateTime::parse(after, &Rfc3339))
+        .transpose()
+        .map_err(|err| {
+            ApiError::bad_request(BAD_DATETIME_VALUE)
+                .with_query_field("after")
+                .with_message(err.to_string())
+        })
+}
+
+fn extract_size_query(query: &QueryMap) -> Result<u16, ApiError> {
+    let size = query
+        .first("size")
+        .map(str::trim)
+        .filter(|size| !size.is_empty())
+        .map(|size| size.parse::<u16>())
+        .transpose()
+        .map_err(|err| {
+            ApiError::bad_request(BAD_PAGE_SIZE_VALUE)
+                .with_query_field("size")
+                .with_message(err.to_string())
+        })?
+        .unwrap_or(DEFAULT_SIZE);
+
+    if size == 0 {
+        Err(ApiError::bad_request(BAD_PAGE_SIZE_VALUE)
+            .with_query_field("size")
+            .with_message("Size must be greater than 0."))
+    } else {
+        Ok(size.min(MAX_SIZE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::handler;
+    use common::currency::domain::Currency;
+    use common::event_id::EventId;
+    use common::item_state::domain::ItemState;
+    use common::price::domain::Price;
+    use common::shop_id::ShopId;
+    use common::shops_item_id::ShopsItemId;
+    use item_core::item_event::{ItemEventHistoryView, ItemEventType, ItemEventView};
+    use item_service::get_service::{GetItemError, MockGetItemService};
+    use lambda_runtime::LambdaEvent;
+    use test_api::{ApiGatewayV2httpRequestProxy, extract_apigw_response_json_body};
+    use time::macros::datetime;
+
+    #[tokio::test]
+    async fn should_return_timeline_with_prices_in_requested_currency() {
+        let mut service = MockGetItemService::default();
+        service
+            .expect_view_item_events()
+            .withf(|_, _, currency, _, _| *currency == Currency::Gbp)
+            .return_once(|_, _, currency, _, _| {
+                let currency = *currency;
+                Box::pin(async move {
+                    Ok(ItemEventHistoryView {
+                        events: vec![
+                            ItemEventView {
+                                event_id: EventId::new(),
+                                event_type: ItemEventType::Created,
+                                price: Some(Price::new(45000u64.into(), currency)),
+                                state: Some(ItemState::Available),
+                                timestamp: datetime!(2025-05-01 0:00 UTC),
+                            },
+                            ItemEventView {
+                                event_id: EventId::new(),
+                                event_type: ItemEventType::PriceDropped,
+                                price: Some(Price::new(38000u64.into(), currency)),
+                                state: None,
+                                timestamp: datetime!(2025-05-10 0:00 UTC),
+                            },
+                            ItemEventView {
+                                event_id: EventId::new(),
+                                event_type: ItemEventType::StateSold,
+                                price: None,
+                                state: Some(ItemState::Sold),
+                                timestamp: datetime!(2025-05-31 0:00 UTC),
+                            },
+                        ],
+                        next: None,
+                    })
+                })
+            });
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .query_string_parameter("currency", "GBP")
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(200, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        let events = json["events"].as_array().unwrap();
+        assert_eq!(3, events.len());
+        assert_eq!("CREATED", events[0]["eventType"]);
+        assert_eq!("GBP", events[0]["price"]["currency"]);
+        assert_eq!(45000, events[0]["price"]["amount"]);
+        assert_eq!("PRICE_DROPPED", events[1]["eventType"]);
+        assert_eq!(38000, events[1]["price"]["amount"]);
+        assert_eq!("STATE_SOLD", events[2]["eventType"]);
+        assert_eq!("SOLD", events[2]["state"]);
+        assert!(json.get("next").is_none());
+    }
+
+    #[tokio::test]
+    async fn should_pass_after_and_size_and_return_next() {
+        let mut service = MockGetItemService::default();
+        service
+            .expect_view_item_events()
+            .withf(|_, _, _, after, size| {
+                *after == Some(datetime!(2025-05-10 0:00 UTC)) && *size == 2
+            })
+            .return_once(|_, _, _, _, _| {
+                Box::pin(async move {
+                    Ok(ItemEventHistoryView {
+                        events: vec![],
+                        next: Some(datetime!(2025-05-31 0:00 UTC)),
+                    })
+                })
+            });
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .query_string_parameter("after", "2025-05-10T00:00:00Z")
+                .query_string_parameter("size", "2")
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(200, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!("2025-05-31T00:00:00Z", json["next"]);
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::default(None, 50)]
+    #[case::explicit(Some("7"), 7)]
+    #[case::enforce_max(Some("1000"), 100)]
+    async fn should_limit_size(#[case] size: Option<&str>, #[case] expected: u16) {
+        let mut service = MockGetItemService::default();
+        service
+            .expect_view_item_events()
+            .withf(move |_, _, _, _, actual| *actual == expected)
+            .return_once(|_, _, _, _, _| {
+                Box::pin(async move {
+                    Ok(ItemEventHistoryView {
+                        events: vec![],
+                        next: None,
+                    })
+                })
+            });
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .try_query_string_parameter("size", size)
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(200, response.status_code);
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::after_not_rfc3339("after", "yesterday", "BAD_DATETIME_VALUE")]
+    #[case::size_not_numeric("size", "ten", "BAD_PAGE_SIZE_VALUE")]
+    #[case::size_zero("size", "0", "BAD_PAGE_SIZE_VALUE")]
+    #[case::currency_unknown("currency", "DOGE", "BAD_QUERY_PARAMETER_VALUE")]
+    async fn should_400_when_query_parameter_is_invalid(
+        #[case] field: &str,
+        #[case] value: &str,
+        #[case] expected_error: &str,
+    ) {
+        let mut service = MockGetItemService::default();
+        service.expect_view_item_events().never();
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .query_string_parameter(field, value)
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(400, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!(expected_error, json["error"]);
+        assert_eq!(field, json["source"]["field"]);
+    }
+
+    #[tokio::test]
+    async fn should_400_when_path_param_shop_id_is_missing() {
+        let mut service = MockGetItemService::default();
+        service.expect_view_item_events().never();
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(400, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!("shopId", json["source"]["field"]);
+    }
+
+    #[tokio::test]
+    async fn should_400_when_path_param_shops_item_id_is_missing() {
+        let mut service = MockGetItemService::default();
+        service.expect_view_item_events().never();
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(400, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!("shopsItemId", json["source"]["field"]);
+    }
+
+    #[tokio::test]
+    async fn should_404_when_item_does_not_exist() {
+        let mut service = MockGetItemService::default();
+        service
+            .expect_view_item_events()
+            .return_once(|shop_id, shops_item_id, _, _, _| {
+                let shop_id = shop_id.clone();
+                let shops_item_id = shops_item_id.clone();
+                Box::pin(async move { Err(GetItemError::ItemNotFound(shop_id, shops_item_id)) })
+            });
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(404, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!(404, json["status"]);
+    }
+}
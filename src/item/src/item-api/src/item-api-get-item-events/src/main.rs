@@ -0,0 +1,43 @@
+use aws_config::BehaviorVersion;
+use aws_lambda_events::apigw::ApiGatewayV2httpRequest;
+use aws_sdk_dynamodb::Client;
+use item_api_get_item_events::handler;
+use item_dynamodb::repository::{FxRateSnapshotCache, ItemDynamoDbRepositoryImpl};
+use item_service::get_service::GetItemServiceImpl;
+use lambda_runtime::tracing::info;
+use lambda_runtime::{Error, LambdaEvent, run, service_fn};
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    tracing_subscriber::fmt()
+        .json()
+        .with_max_level(tracing::Level::INFO)
+        .with_current_span(true)
+        .with_ansi(false)
+        .without_time()
+        .init();
+
+    let aws_config = aws_config::defaults(BehaviorVersion::v2025_08_07())
+        .load()
+        .await;
+
+    let table_name = std::env::var("DYNAMODB_TABLE_NAME")?;
+    let client = Client::new(&aws_config);
+    let repository = ItemDynamoDbRepositoryImpl::new(&client, &table_name);
+    // reloaded once expired, as the rates are refreshed while instances are warm
+    let fx_rate_cache = FxRateSnapshotCache::default();
+
+    info!(
+        dynamoDbTableName = %table_name,
+        "Lambda cold start completed, client initialized."
+    );
+
+    run(service_fn(
+        |event: LambdaEvent<ApiGatewayV2httpRequest>| async {
+            let fx_rate = fx_rate_cache.get(&repository).await;
+            let service = GetItemServiceImpl::new(&repository, fx_rate.as_ref());
+            handler(event, &service).await
+        },
+    ))
+    .await
+}
@@ -1,17 +1,28 @@
 use aws_lambda_events::apigw::{ApiGatewayV2httpRequest, ApiGatewayV2httpResponse};
+use aws_lambda_events::query_map::QueryMap;
 use common::api::api_gateway_v2_http_response_builder::ApiGatewayV2HttpResponseBuilder;
+use common::api::conditional_request::is_not_modified;
 use common::api::error::ApiError;
-use common::api::error_code::{BAD_PARAMETER, INTERNAL_SERVER_ERROR};
+use common::api::error_code::{BAD_PARAMETER, BAD_QUERY_PARAMETER_VALUE, INTERNAL_SERVER_ERROR};
 use common::currency::data::api::extract_currency_query;
 use common::language::data::api::extract_languages_header;
 use common::language::domain::Language;
 use common::shop_id::ShopId;
 use common::shops_item_id::ShopsItemId;
+use item_core::item::LocalizedItemView;
 use item_data::get_data::GetItemData;
+use item_data::price_history_data::PriceHistoryData;
 use item_service::get_service::GetItemService;
 use lambda_runtime::LambdaEvent;
 use tracing::error;
 
+/// Clients and shared caches may store the item, but have to revalidate it before every reuse.
+const CACHE_CONTROL: &str = "public, no-cache";
+
+/// The item is localized by `Accept-Language`, so shared caches must not serve one language's
+/// representation for another. Query parameters are part of the cache key already.
+const VARY: &str = "Accept-Language";
+
 #[tracing::instrument(
     skip(event, service),
     fields(
@@ -39,6 +50,8 @@ pub async fn handle(
         .map(Language::from)
         .collect::<Vec<_>>();
     let currency = extract_currency_query(&event.payload.query_string_parameters)?.into();
+    let include_price_history =
+        extract_price_history_query(&event.payload.query_string_parameters)?;
     let shop_id = event
         .payload
         .path_parameters
@@ -54,10 +67,30 @@ pub async fn handle(
         .map(ShopsItemId::from)
         .ok_or(ApiError::bad_request(BAD_PARAMETER).with_path_field("shopsItemId"))?;
 
-    let item_data: GetItemData = service
+    let item = service
         .view_item(&shop_id, &shops_item_id, languages.as_slice(), &currency)
-        .await?
-        .into();
+        .await?;
+    let e_tag = mk_e_tag(&item);
+    let last_modified = item.last_modified();
+    let mut item_data: GetItemData = item.into();
+
+    if is_not_modified(&event.payload.headers, &e_tag, last_modified) {
+        return Ok(ApiGatewayV2HttpResponseBuilder::new(304)
+            .e_tag(&e_tag)
+            .last_modified(last_modified)
+            .cache_control(CACHE_CONTROL)
+            .vary(VARY)
+            .cors()
+            .build());
+    }
+
+    if include_price_history {
+        let price_history = service
+            .view_price_history(&shop_id, &shops_item_id, &currency)
+            .await?;
+        item_data.price_history = Some(PriceHistoryData::new(price_history, currency));
+    }
+
     let response = serde_json::to_string(&item_data).map_err(|err| {
         error!(error = %err, payload = ?item_data, type = %std::any::type_name::<GetItemData>(), "Failed serializing GetItemData.");
         ApiError::internal_server_error(INTERNAL_SERVER_ERROR)
@@ -68,29 +101,58 @@ pub async fn handle(
     Ok(ApiGatewayV2HttpResponseBuilder::json(200)
         .body(response)
         .content_language(content_language)
-        .e_tag(item_data.event_id.to_string().as_str())
-        .last_modified(item_data.updated)
+        .e_tag(&e_tag)
+        .last_modified(last_modified)
+        .cache_control(CACHE_CONTROL)
+        .vary(VARY)
         .cors()
         .build())
 }
 
+/// Repricing re-exchanges the prices without a new event, so the `event_id` alone would keep
+/// matching stale prices.
+fn mk_e_tag(item: &LocalizedItemView) -> String {
+    match item.fx_rate_date {
+        Some(fx_rate_date) => format!("{}-{fx_rate_date}", item.event_id),
+        None => item.event_id.to_string(),
+    }
+}
+
+fn extract_price_history_query(query: &QueryMap) -> Result<bool, ApiError> {
+    match query.first("priceHistory").map(str::trim) {
+        None | Some("") | Some("false") => Ok(false),
+        Some("true") => Ok(true),
+        Some(invalid) => Err(ApiError::bad_request(BAD_QUERY_PARAMETER_VALUE)
+            .with_query_field("priceHistory")
+            .with_message(format!(
+                "Expected any of: 'true', 'false'. Got: '{invalid}'"
+            ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::handler;
+    use common::currency::domain::Currency;
     use common::event_id::EventId;
     use common::item_state::domain::ItemState;
     use common::language::data::LanguageData;
     use common::language::domain::Language;
     use common::localized::Localized;
+    use common::price::domain::Price;
     use common::shop_id::ShopId;
     use common::shops_item_id::ShopsItemId;
-    use http::header::{ACCEPT_LANGUAGE, CONTENT_LANGUAGE, ETAG, LAST_MODIFIED};
-    use item_core::{hash::ItemHash, item::LocalizedItemView};
+    use http::header::{
+        ACCEPT_LANGUAGE, CACHE_CONTROL, CONTENT_LANGUAGE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH,
+        LAST_MODIFIED, VARY,
+    };
+    use item_core::{hash::ItemHash, item::LocalizedItemView, price_history::PriceHistory};
     use item_service::get_service::{GetItemError, MockGetItemService};
     use lambda_runtime::LambdaEvent;
+    use serde_json::json;
     use test_api::{ApiGatewayV2httpRequestProxy, extract_apigw_response_json_body};
-    use time::OffsetDateTime;
-    use time::macros::datetime;
+    use time::macros::{date, datetime};
+    use time::{Date, OffsetDateTime};
     use url::Url;
 
     #[tokio::test]
@@ -134,6 +196,8 @@ mod tests {
                     hash: ItemHash::new(&None, &ItemState::Listed),
                     created: OffsetDateTime::now_utc(),
                     updated: OffsetDateTime::now_utc(),
+                    fx_rate_date: None,
+                    repriced: None,
                 };
                 Box::pin(async move { Ok(item) })
             });
@@ -173,6 +237,8 @@ mod tests {
                     hash: ItemHash::new(&None, &ItemState::Listed),
                     created: OffsetDateTime::now_utc(),
                     updated: OffsetDateTime::now_utc(),
+                    fx_rate_date: None,
+                    repriced: None,
                 };
                 Box::pin(async move { Ok(item) })
             });
@@ -217,6 +283,8 @@ mod tests {
                     hash: ItemHash::new(&None, &ItemState::Listed),
                     created: timestamp,
                     updated: timestamp,
+                    fx_rate_date: None,
+                    repriced: None,
                 };
                 Box::pin(async move { Ok(item) })
             });
@@ -238,6 +306,286 @@ mod tests {
         );
     }
 
+    fn mk_view_item_service(event_id: EventId, updated: OffsetDateTime) -> MockGetItemService {
+        mk_repriced_view_item_service(event_id, updated, None, None)
+    }
+
+    fn mk_repriced_view_item_service(
+        event_id: EventId,
+        updated: OffsetDateTime,
+        fx_rate_date: Option<Date>,
+        repriced: Option<OffsetDateTime>,
+    ) -> MockGetItemService {
+        let mut service = MockGetItemService::default();
+        service
+            .expect_view_item()
+            .return_once(move |shop_id, shops_item_id, _, _| {
+                let item = LocalizedItemView {
+                    item_id: Default::default(),
+                    event_id,
+                    shop_id: shop_id.clone(),
+                    shops_item_id: shops_item_id.clone(),
+                    shop_name: "".into(),
+                    title: Localized::new(Language::De, "Native title".into()),
+                    description: None,
+                    price: None,
+                    state: ItemState::Listed,
+                    url: Url::parse("https://foo.com/boop").unwrap(),
+                    images: vec![],
+                    hash: ItemHash::new(&None, &ItemState::Listed),
+                    created: updated,
+                    updated,
+                    fx_rate_date,
+                    repriced,
+                };
+                Box::pin(async move { Ok(item) })
+            });
+        service
+    }
+
+    #[tokio::test]
+    async fn should_include_headers_cache_control_and_vary() {
+        let service = mk_view_item_service(EventId::new(), OffsetDateTime::now_utc());
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(200, response.status_code);
+        assert_eq!(
+            "public, no-cache",
+            response.headers.get(CACHE_CONTROL).unwrap()
+        );
+        assert_eq!("Accept-Language", response.headers.get(VARY).unwrap());
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::if_none_match_quoted(IF_NONE_MATCH.as_str(), "\"{event_id}\"")]
+    #[case::if_none_match_unquoted(IF_NONE_MATCH.as_str(), "{event_id}")]
+    #[case::if_none_match_any(IF_NONE_MATCH.as_str(), "*")]
+    #[case::if_modified_since_same(IF_MODIFIED_SINCE.as_str(), "Wed, 01 Jan 2020 00:00:00 GMT")]
+    #[case::if_modified_since_later(IF_MODIFIED_SINCE.as_str(), "Thu, 02 Jan 2020 00:00:00 GMT")]
+    async fn should_304_when_precondition_matches(
+        #[case] header_name: &str,
+        #[case] header_value: &str,
+    ) {
+        let event_id = EventId::new();
+        let service = mk_view_item_service(event_id, datetime!(2020-01-01 0:00 UTC));
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .header(
+                    header_name,
+                    header_value.replace("{event_id}", &event_id.to_string()),
+                )
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(304, response.status_code);
+        assert!(response.body.is_none());
+        assert_eq!(
+            event_id.to_string().as_str(),
+            response.headers.get(ETAG).unwrap()
+        );
+        assert_eq!(
+            "Wed, 01 Jan 2020 00:00:00 GMT",
+            response.headers.get(LAST_MODIFIED).unwrap()
+        );
+        assert_eq!(
+            "public, no-cache",
+            response.headers.get(CACHE_CONTROL).unwrap()
+        );
+        assert_eq!("Accept-Language", response.headers.get(VARY).unwrap());
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::if_none_match_other(IF_NONE_MATCH.as_str(), "\"some-other-event-id\"")]
+    #[case::if_modified_since_earlier(IF_MODIFIED_SINCE.as_str(), "Tue, 31 Dec 2019 23:59:59 GMT")]
+    #[case::if_modified_since_unparsable(IF_MODIFIED_SINCE.as_str(), "yesterday")]
+    async fn should_200_when_precondition_does_not_match(
+        #[case] header_name: &str,
+        #[case] header_value: &str,
+    ) {
+        let service = mk_view_item_service(EventId::new(), datetime!(2020-01-01 0:00 UTC));
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .header(header_name, header_value)
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(200, response.status_code);
+        assert!(response.body.is_some());
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::if_none_match_event_id(IF_NONE_MATCH.as_str(), "\"{event_id}\"")]
+    #[case::if_modified_since_updated(IF_MODIFIED_SINCE.as_str(), "Wed, 01 Jan 2020 00:00:00 GMT")]
+    async fn should_200_when_repriced_since_precondition(
+        #[case] header_name: &str,
+        #[case] header_value: &str,
+    ) {
+        let event_id = EventId::new();
+        let service = mk_repriced_view_item_service(
+            event_id,
+            datetime!(2020-01-01 0:00 UTC),
+            Some(date!(2020 - 01 - 02)),
+            Some(datetime!(2020-01-02 17:00 UTC)),
+        );
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .header(
+                    header_name,
+                    header_value.replace("{event_id}", &event_id.to_string()),
+                )
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(200, response.status_code);
+        assert!(response.body.is_some());
+        assert_eq!(
+            format!("{event_id}-2020-01-02").as_str(),
+            response.headers.get(ETAG).unwrap()
+        );
+        assert_eq!(
+            "Thu, 02 Jan 2020 17:00:00 GMT",
+            response.headers.get(LAST_MODIFIED).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_304_when_precondition_matches_repriced_item() {
+        let event_id = EventId::new();
+        let service = mk_repriced_view_item_service(
+            event_id,
+            datetime!(2020-01-01 0:00 UTC),
+            Some(date!(2020 - 01 - 02)),
+            Some(datetime!(2020-01-02 17:00 UTC)),
+        );
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .header(IF_NONE_MATCH.as_str(), format!("\"{event_id}-2020-01-02\""))
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(304, response.status_code);
+    }
+
+    #[tokio::test]
+    async fn should_include_price_history_when_requested() {
+        let mut service = mk_view_item_service(EventId::new(), OffsetDateTime::now_utc());
+        service
+            .expect_view_price_history()
+            .withf(|_, _, currency| *currency == Currency::Usd)
+            .return_once(|_, _, _| {
+                let mut price_history = PriceHistory::default();
+                price_history.push(
+                    datetime!(2025-05-01 0:00 UTC),
+                    Price::new(45000u64.into(), Currency::Usd),
+                );
+                price_history.push(
+                    datetime!(2025-05-10 0:00 UTC),
+                    Price::new(38000u64.into(), Currency::Usd),
+                );
+                Box::pin(async move { Ok(price_history) })
+            });
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .query_string_parameter("currency", "USD")
+                .query_string_parameter("priceHistory", "true")
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(200, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!(
+            json!({
+                "currency": "USD",
+                "points": [
+                    { "timestamp": "2025-05-01T00:00:00Z", "amount": 45000 },
+                    { "timestamp": "2025-05-10T00:00:00Z", "amount": 38000 }
+                ]
+            }),
+            json["priceHistory"]
+        );
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::absent(None)]
+    #[case::disabled(Some("false"))]
+    async fn should_not_include_price_history_when_not_requested(
+        #[case] price_history: Option<&str>,
+    ) {
+        let mut service = mk_view_item_service(EventId::new(), OffsetDateTime::now_utc());
+        service.expect_view_price_history().never();
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .try_query_string_parameter("priceHistory", price_history)
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(200, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert!(json.get("priceHistory").is_none());
+    }
+
+    #[tokio::test]
+    async fn should_400_when_price_history_is_invalid() {
+        let mut service = MockGetItemService::default();
+        service.expect_view_item().never();
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .path_parameter("shopId", ShopId::new())
+                .path_parameter("shopsItemId", ShopsItemId::new())
+                .query_string_parameter("priceHistory", "yes")
+                .build(),
+            context: Default::default(),
+        };
+
+        let response = handler(lambda_event, &service).await.unwrap();
+        assert_eq!(400, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!("priceHistory", json["source"]["field"]);
+    }
+
     #[tokio::test]
     async fn should_400_when_path_param_shop_id_is_missing() {
         let mut service = MockGetItemService::default();
@@ -2,7 +2,7 @@ use aws_config::BehaviorVersion;
 use aws_lambda_events::apigw::ApiGatewayV2httpRequest;
 use aws_sdk_dynamodb::Client;
 use item_api_get_item::handler;
-use item_dynamodb::repository::ItemDynamoDbRepositoryImpl;
+use item_dynamodb::repository::{FxRateSnapshotCache, ItemDynamoDbRepositoryImpl};
 use item_service::get_service::GetItemServiceImpl;
 use lambda_runtime::tracing::info;
 use lambda_runtime::{Error, LambdaEvent, run, service_fn};
@@ -24,7 +24,8 @@ async fn main() -> Result<(), Error> {
     let table_name = std::env::var("DYNAMODB_TABLE_NAME")?;
     let client = Client::new(&aws_config);
     let repository = ItemDynamoDbRepositoryImpl::new(&client, &table_name);
-    let service = GetItemServiceImpl::new(&repository);
+    // reloaded once expired, as the rates are refreshed while instances are warm
+    let fx_rate_cache = FxRateSnapshotCache::default();
 
     info!(
         dynamoDbTableName = %table_name,
@@ -32,7 +33,11 @@ async fn main() -> Result<(), Error> {
     );
 
     run(service_fn(
-        |event: LambdaEvent<ApiGatewayV2httpRequest>| async { handler(event, &service).await },
+        |event: LambdaEvent<ApiGatewayV2httpRequest>| async {
+            let fx_rate = fx_rate_cache.get(&repository).await;
+            let service = GetItemServiceImpl::new(&repository, fx_rate.as_ref());
+            handler(event, &service).await
+        },
     ))
     .await
 }
@@ -1,28 +1,61 @@
-use aws_lambda_events::apigw::{ApiGatewayV2httpRequest, ApiGatewayV2httpResponse};
+use aws_lambda_events::{
+    apigw::{ApiGatewayV2httpRequest, ApiGatewayV2httpResponse},
+    query_map::QueryMap,
+};
 use common::{
     api::{
         api_gateway_v2_http_response_builder::ApiGatewayV2HttpResponseBuilder,
         collection::{CollectionData, PaginationData},
         error::ApiError,
-        error_code::{BAD_PARAMETER, INTERNAL_SERVER_ERROR, TEXT_QUERY_TOO_SHORT},
+        error_code::{
+            BAD_DATETIME_VALUE, BAD_PAGE_FROM_VALUE, BAD_PARAMETER, BAD_PRICE_VALUE,
+            BAD_QUERY_PARAMETER_VALUE, BAD_RANGE_VALUE, BAD_STATE_VALUE, INTERNAL_SERVER_ERROR,
+            TEXT_QUERY_TOO_SHORT,
+        },
     },
     currency::{data::api::extract_currency_query, domain::Currency},
+    item_state::domain::ItemState,
     language::{data::api::extract_language_query, domain::Language},
+    opensearch::search_after::api::{
+        CursorBinding, CursorSigningKey, encode_cursor, extract_cursor_query,
+    },
     page::{Page, api::extract_page_query},
+    price::domain::MonetaryAmount,
     sort::api::extract_sort_query,
 };
 use item_core::sort_item_field::SortItemField;
-use item_data::{get_data::GetItemData, sort_item_field_data::SortItemFieldData};
+use item_data::{
+    facet_data::ItemFacetsData, get_data::GetItemData, item_state_data::ItemStateData,
+    sort_item_field_data::SortItemFieldData,
+};
 use item_service::query_service::QueryItemService;
 use lambda_runtime::LambdaEvent;
 use search_filter_core::{
+    array_query::AnyOfQuery,
+    range_query::RangeQuery,
     search_filter::SearchFilter,
     text_query::{TextQuery, TextQueryTooShortError},
 };
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
 use tracing::error;
 
+/// Query parameters which select or price the hits, a cursor only continues a search with the same.
+/// The language is bound in its resolved form, see [CursorBinding::new].
+const CURSOR_BOUND_QUERY_FIELDS: &[&str] = &[
+    "q",
+    "currency",
+    "shopName",
+    "minPrice",
+    "maxPrice",
+    "state",
+    "createdFrom",
+    "createdTo",
+    "updatedFrom",
+    "updatedTo",
+];
+
 #[tracing::instrument(
-    skip(event, service),
+    skip(event, service, cursor_signing_key),
     fields(
         requestId = %event.context.request_id,
         path = &event.payload.raw_path,
@@ -32,8 +65,9 @@ use tracing::error;
 pub async fn handler(
     event: LambdaEvent<ApiGatewayV2httpRequest>,
     service: &impl QueryItemService,
+    cursor_signing_key: &CursorSigningKey,
 ) -> Result<ApiGatewayV2httpResponse, lambda_runtime::Error> {
-    match handle(event, service).await {
+    match handle(event, service, cursor_signing_key).await {
         Ok(response) => Ok(response),
         Err(err) => Ok(ApiGatewayV2httpResponse::from(err)),
     }
@@ -42,11 +76,21 @@ pub async fn handler(
 pub async fn handle(
     event: LambdaEvent<ApiGatewayV2httpRequest>,
     service: &impl QueryItemService,
+    cursor_signing_key: &CursorSigningKey,
 ) -> Result<ApiGatewayV2httpResponse, ApiError> {
     let language: Language = extract_language_query(&event.payload.query_string_parameters)?.into();
     let currency: Currency = extract_currency_query(&event.payload.query_string_parameters)?.into();
-    let sort = extract_sort_query::<SortItemFieldData>(&event.payload.query_string_parameters)?
-        .map(|sort_data| sort_data.map(SortItemField::from));
+    let sort_data =
+        extract_sort_query::<SortItemFieldData>(&event.payload.query_string_parameters)?;
+    let cursor_binding = CursorBinding::new(
+        sort_data
+            .map(|sort_data| format!("{}:{}", sort_data.sort.as_str(), sort_data.order.as_str()))
+            .unwrap_or_else(|| "_score".to_string()),
+        &language,
+        &event.payload.query_string_parameters,
+        CURSOR_BOUND_QUERY_FIELDS,
+    );
+    let sort = sort_data.map(|sort_data| sort_data.map(SortItemField::from));
     let page = extract_page_query(&event.payload.query_string_parameters)?
         .unwrap_or(Page { from: 0, size: 21 });
     let item_query: TextQuery = event
@@ -63,15 +107,55 @@ pub async fn handle(
         })?;
     let search_filter = SearchFilter {
         item_query,
-        shop_name_query: None,
-        price_query: None,
-        state_query: Default::default(),
-        created_query: None,
-        updated_query: None,
+        shop_name_query: extract_shop_name_query(&event.payload.query_string_parameters)?,
+        price_query: extract_price_query(&event.payload.query_string_parameters)?,
+        state_query: extract_state_query(&event.payload.query_string_parameters)?,
+        created_query: extract_datetime_range_query(
+            &event.payload.query_string_parameters,
+            "createdFrom",
+            "createdTo",
+        )?,
+        updated_query: extract_datetime_range_query(
+            &event.payload.query_string_parameters,
+            "updatedFrom",
+            "updatedTo",
+        )?,
+    };
+
+    let cursor = extract_cursor_query(
+        &event.payload.query_string_parameters,
+        &cursor_binding,
+        cursor_signing_key,
+    )?;
+    // a cursor already carries the position, an offset on top of it would be ambiguous
+    if cursor.is_some()
+        && event
+            .payload
+            .query_string_parameters
+            .first("from")
+            .is_some()
+    {
+        return Err(ApiError::bad_request(BAD_PAGE_FROM_VALUE)
+            .with_query_field("from")
+            .with_message("'from' must not be combined with 'cursor'."));
+    }
+    let search_after = match cursor {
+        Some(search_after) => Some(search_after),
+        None if extract_pit_query(&event.payload.query_string_parameters)? => {
+            Some(service.open_point_in_time().await?)
+        }
+        None => None,
     };
 
     let search_result = service
-        .search_items(&search_filter, &language, &currency, &sort, &Some(page))
+        .search_items(
+            &search_filter,
+            &language,
+            &currency,
+            &sort,
+            &Some(page),
+            &search_after,
+        )
         .await?;
 
     let items = search_result
@@ -83,14 +167,21 @@ pub async fn handle(
         from: page.from as u64,
         size: page.size as u64,
         total: search_result.total,
+        cursor: search_result
+            .search_after
+            .map(|search_after| encode_cursor(&search_after, &cursor_binding, cursor_signing_key)),
+    };
+    let collection = CollectionData {
+        items,
+        pagination,
+        facets: Some(ItemFacetsData::from(search_result.facets)),
     };
-    let collection = CollectionData { items, pagination };
 
     let response = serde_json::to_string(&collection).map_err(|err| {
         error!(
             error = %err,
             payload = ?collection,
-            type = %std::any::type_name::<CollectionData<GetItemData>>(),
+            type = %std::any::type_name::<CollectionData<GetItemData, ItemFacetsData>>(),
             "Failed serializing collection of items"
         );
         ApiError::internal_server_error(INTERNAL_SERVER_ERROR)
@@ -102,17 +193,145 @@ pub async fn handle(
         .build())
 }
 
+fn extract_shop_name_query(query: &QueryMap) -> Result<Option<TextQuery>, ApiError> {
+    query
+        .first("shopName")
+        .map(str::trim)
+        .filter(|shop_name| !shop_name.is_empty())
+        .map(TextQuery::try_from)
+        .transpose()
+        .map_err(|err| {
+            ApiError::bad_request(TEXT_QUERY_TOO_SHORT)
+                .with_query_field("shopName")
+                .with_message(err.to_string())
+        })
+}
+
+fn extract_price_query(query: &QueryMap) -> Result<Option<RangeQuery<MonetaryAmount>>, ApiError> {
+    extract_range_query(query, "minPrice", "maxPrice", |value, field| {
+        value
+            .parse::<u64>()
+            .map(MonetaryAmount::from)
+            .map_err(|err| {
+                ApiError::bad_request(BAD_PRICE_VALUE)
+                    .with_query_field(field)
+                    .with_message(err.to_string())
+            })
+    })
+}
+
+fn extract_state_query(query: &QueryMap) -> Result<AnyOfQuery<ItemState>, ApiError> {
+    // API Gateway joins repeated query parameters with commas, so we accept both forms
+    let states = query
+        .all("state")
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|states| states.split(','))
+        .map(str::trim)
+        .filter(|state| !state.is_empty())
+        .map(|state| {
+            serde_json::from_str::<ItemStateData>(&format!(r#""{state}""#))
+                .map(ItemState::from)
+                .map_err(|err| {
+                    ApiError::bad_request(BAD_STATE_VALUE)
+                        .with_query_field("state")
+                        .with_message(err.to_string())
+                })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(AnyOfQuery(states))
+}
+
+fn extract_datetime_range_query(
+    query: &QueryMap,
+    min_field: &'static str,
+    max_field: &'static str,
+) -> Result<Option<RangeQuery<OffsetDateTime>>, ApiError> {
+    extract_range_query(query, min_field, max_field, |value, field| {
+        OffsetDateTime::parse(value, &Rfc3339).map_err(|err| {
+            ApiError::bad_request(BAD_DATETIME_VALUE)
+                .with_query_field(field)
+                .with_message(err.to_string())
+        })
+    })
+}
+
+fn extract_pit_query(query: &QueryMap) -> Result<bool, ApiError> {
+    match query.first("pit").map(str::trim) {
+        None | Some("") | Some("false") => Ok(false),
+        Some("true") => Ok(true),
+        Some(invalid) => Err(ApiError::bad_request(BAD_QUERY_PARAMETER_VALUE)
+            .with_query_field("pit")
+            .with_message(format!(
+                "Expected any of: 'true', 'false'. Got: '{invalid}'"
+            ))),
+    }
+}
+
+fn extract_range_query<T: Ord>(
+    query: &QueryMap,
+    min_field: &'static str,
+    max_field: &'static str,
+    parse: impl Fn(&str, &'static str) -> Result<T, ApiError>,
+) -> Result<Option<RangeQuery<T>>, ApiError> {
+    let min = query
+        .first(min_field)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| parse(value, min_field))
+        .transpose()?;
+    let max = query
+        .first(max_field)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| parse(value, max_field))
+        .transpose()?;
+
+    match (min, max) {
+        (None, None) => Ok(None),
+        (Some(min), Some(max)) if min > max => Err(ApiError::bad_request(BAD_RANGE_VALUE)
+            .with_query_field(min_field)
+            .with_message(format!(
+                "'{min_field}' must not be greater than '{max_field}'."
+            ))),
+        (min, max) => Ok(Some(RangeQuery { min, max })),
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::too_many_arguments)]
 mod tests {
-    use crate::handler;
+    use crate::{CURSOR_BOUND_QUERY_FIELDS, handler};
+    use aws_lambda_events::query_map::QueryMap;
+    use common::item_state::domain::ItemState;
+    use common::language::domain::Language;
+    use common::opensearch::search_after::SearchAfter;
+    use common::opensearch::search_after::api::{CursorBinding, CursorSigningKey, encode_cursor};
     use common::opensearch::search_result::SearchResult;
     use http::header::ACCEPT_LANGUAGE;
+    use item_core::facet::{ItemFacets, TermFacet};
     use item_core::item::LocalizedItemView;
-    use item_service::query_service::MockQueryItemService;
+    use item_service::query_service::{MockQueryItemService, SearchItemsError};
     use lambda_runtime::LambdaEvent;
+    use search_filter_core::range_query::RangeQuery;
+    use std::collections::{HashMap, HashSet};
     use test_api::ApiGatewayV2httpRequestProxy;
     use test_api::extract_apigw_response_json_body;
+    use time::macros::datetime;
+
+    fn mk_cursor_signing_key() -> CursorSigningKey {
+        CursorSigningKey::new("top-secret")
+    }
+
+    fn mk_cursor_binding(sort: &str, language: Language, q: &str) -> CursorBinding {
+        CursorBinding::new(
+            sort,
+            &language,
+            &QueryMap::from(HashMap::from([("q".to_string(), q.to_string())])),
+            CURSOR_BOUND_QUERY_FIELDS,
+        )
+    }
 
     #[tokio::test]
     #[rstest::rstest]
@@ -171,15 +390,19 @@ mod tests {
         let mut service = MockQueryItemService::default();
         service
             .expect_search_items()
-            .return_once(|_, _, _, _, page| {
+            .return_once(|_, _, _, _, page, _| {
                 let count = page.map(|page| page.size).unwrap_or(20) as usize;
                 let search_result = SearchResult {
                     hits: fake::vec![LocalizedItemView; count],
                     total: 789,
+                    facets: Default::default(),
+                    search_after: None,
                 };
                 Box::pin(async move { Ok(search_result) })
             });
-        let response = handler(lambda_event, &service).await.unwrap();
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
 
         assert_eq!(200, response.status_code);
     }
@@ -207,15 +430,19 @@ mod tests {
         let mut service = MockQueryItemService::default();
         service
             .expect_search_items()
-            .return_once(|_, _, _, _, page| {
+            .return_once(|_, _, _, _, page, _| {
                 let count = page.map(|page| page.size).unwrap() as usize;
                 let search_result = SearchResult {
                     hits: fake::vec![LocalizedItemView; count],
                     total: 789,
+                    facets: Default::default(),
+                    search_after: None,
                 };
                 Box::pin(async move { Ok(search_result) })
             });
-        let response = handler(lambda_event, &service).await.unwrap();
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
 
         assert_eq!(200, response.status_code);
         let json = extract_apigw_response_json_body!(response);
@@ -242,11 +469,472 @@ mod tests {
 
         let mut service = MockQueryItemService::default();
         service.expect_search_items().never();
-        let response = handler(lambda_event, &service).await.unwrap();
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
 
         assert_eq!(400, response.status_code);
         let json = extract_apigw_response_json_body!(response);
         assert_eq!(400, json["status"]);
         assert_eq!("q", json["source"]["field"]);
     }
+
+    #[tokio::test]
+    async fn should_pass_search_filter_from_query_parameters() {
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .query_string_parameter("q", "pickelhaube")
+                .query_string_parameter("shopName", "Militaria Mart")
+                .query_string_parameter("minPrice", "1000")
+                .query_string_parameter("maxPrice", "250000")
+                .query_string_parameter("state", "AVAILABLE,RESERVED")
+                .query_string_parameter("createdFrom", "2025-01-01T00:00:00Z")
+                .query_string_parameter("updatedTo", "2025-06-30T12:00:00+02:00")
+                .build(),
+            context: Default::default(),
+        };
+
+        let mut service = MockQueryItemService::default();
+        service
+            .expect_search_items()
+            .withf(|search_filter, _, _, _, _, _| {
+                search_filter.shop_name_query.as_deref() == Some("Militaria Mart")
+                    && search_filter.price_query
+                        == Some(RangeQuery {
+                            min: Some(1000u64.into()),
+                            max: Some(250000u64.into()),
+                        })
+                    && search_filter.state_query.0
+                        == HashSet::from([ItemState::Available, ItemState::Reserved])
+                    && search_filter.created_query
+                        == Some(RangeQuery {
+                            min: Some(datetime!(2025-01-01 00:00:00 UTC)),
+                            max: None,
+                        })
+                    && search_filter.updated_query
+                        == Some(RangeQuery {
+                            min: None,
+                            max: Some(datetime!(2025-06-30 10:00:00 UTC)),
+                        })
+            })
+            .return_once(|_, _, _, _, _, _| {
+                Box::pin(async move {
+                    Ok(SearchResult {
+                        hits: vec![],
+                        total: 0,
+                        facets: Default::default(),
+                        search_after: None,
+                    })
+                })
+            });
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
+
+        assert_eq!(200, response.status_code);
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::negative_min_price("minPrice", "-1", "BAD_PRICE_VALUE")]
+    #[case::decimal_max_price("maxPrice", "12.50", "BAD_PRICE_VALUE")]
+    #[case::unknown_state("state", "LOST", "BAD_STATE_VALUE")]
+    #[case::lowercase_state("state", "available", "BAD_STATE_VALUE")]
+    #[case::partially_unknown_state("state", "SOLD,BOOP", "BAD_STATE_VALUE")]
+    #[case::date_only("createdFrom", "2025-01-01", "BAD_DATETIME_VALUE")]
+    #[case::garbage_datetime("updatedTo", "yesterday", "BAD_DATETIME_VALUE")]
+    #[case::short_shop_name("shopName", "ab", "TEXT_QUERY_TOO_SHORT")]
+    async fn should_400_when_filter_query_parameter_is_invalid(
+        #[case] field: &str,
+        #[case] value: &str,
+        #[case] expected_error: &str,
+    ) {
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .query_string_parameter("q", "boop doop")
+                .query_string_parameter(field, value)
+                .build(),
+            context: Default::default(),
+        };
+
+        let mut service = MockQueryItemService::default();
+        service.expect_search_items().never();
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
+
+        assert_eq!(400, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!(expected_error, json["error"]);
+        assert_eq!(field, json["source"]["field"]);
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::price("minPrice", "5000", "maxPrice", "4999")]
+    #[case::created(
+        "createdFrom",
+        "2025-02-01T00:00:00Z",
+        "createdTo",
+        "2025-01-01T00:00:00Z"
+    )]
+    #[case::updated(
+        "updatedFrom",
+        "2025-01-01T00:00:01Z",
+        "updatedTo",
+        "2025-01-01T00:00:00Z"
+    )]
+    async fn should_400_when_range_min_is_greater_than_max(
+        #[case] min_field: &str,
+        #[case] min_value: &str,
+        #[case] max_field: &str,
+        #[case] max_value: &str,
+    ) {
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .query_string_parameter("q", "boop doop")
+                .query_string_parameter(min_field, min_value)
+                .query_string_parameter(max_field, max_value)
+                .build(),
+            context: Default::default(),
+        };
+
+        let mut service = MockQueryItemService::default();
+        service.expect_search_items().never();
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
+
+        assert_eq!(400, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!("BAD_RANGE_VALUE", json["error"]);
+        assert_eq!(min_field, json["source"]["field"]);
+    }
+
+    #[tokio::test]
+    async fn should_include_facets_in_collection() {
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .query_string_parameter("q", "boop doop")
+                .build(),
+            context: Default::default(),
+        };
+
+        let mut service = MockQueryItemService::default();
+        service
+            .expect_search_items()
+            .return_once(|_, _, _, _, _, _| {
+                let search_result = SearchResult {
+                    hits: fake::vec![LocalizedItemView; 2],
+                    total: 579,
+                    facets: ItemFacets {
+                        state: vec![
+                            TermFacet {
+                                value: ItemState::Sold,
+                                count: 123,
+                            },
+                            TermFacet {
+                                value: ItemState::Available,
+                                count: 456,
+                            },
+                        ],
+                        ..Default::default()
+                    },
+                    search_after: None,
+                };
+                Box::pin(async move { Ok(search_result) })
+            });
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
+
+        assert_eq!(200, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!("SOLD", json["facets"]["state"][0]["value"]);
+        assert_eq!(123, json["facets"]["state"][0]["count"]);
+        assert_eq!("AVAILABLE", json["facets"]["state"][1]["value"]);
+        assert_eq!(456, json["facets"]["state"][1]["count"]);
+        assert!(json["facets"]["shopName"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_return_cursor_when_more_hits_exist() {
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .query_string_parameter("q", "boop doop")
+                .build(),
+            context: Default::default(),
+        };
+        let next = SearchAfter {
+            sort_values: vec![serde_json::json!(4200), serde_json::json!("abc")],
+            pit_id: None,
+        };
+        let expected_cursor = encode_cursor(
+            &next,
+            &mk_cursor_binding("_score", Language::De, "boop doop"),
+            &mk_cursor_signing_key(),
+        );
+
+        let mut service = MockQueryItemService::default();
+        service
+            .expect_search_items()
+            .withf(|_, _, _, _, _, search_after| search_after.is_none())
+            .return_once(|_, _, _, _, _, _| {
+                let search_result = SearchResult {
+                    hits: fake::vec![LocalizedItemView; 21],
+                    total: 789,
+                    facets: Default::default(),
+                    search_after: Some(next),
+                };
+                Box::pin(async move { Ok(search_result) })
+            });
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
+
+        assert_eq!(200, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!(expected_cursor, json["pagination"]["cursor"]);
+    }
+
+    #[tokio::test]
+    async fn should_search_after_cursor_when_given() {
+        let search_after = SearchAfter {
+            sort_values: vec![serde_json::json!(4200), serde_json::json!("abc")],
+            pit_id: Some("o463QQEJ".to_string()),
+        };
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .query_string_parameter("q", "boop doop")
+                .query_string_parameter(
+                    "cursor",
+                    encode_cursor(
+                        &search_after,
+                        &mk_cursor_binding("_score", Language::De, "boop doop"),
+                        &mk_cursor_signing_key(),
+                    ),
+                )
+                .build(),
+            context: Default::default(),
+        };
+
+        let mut service = MockQueryItemService::default();
+        service.expect_open_point_in_time().never();
+        service
+            .expect_search_items()
+            .withf(move |_, _, _, _, _, actual| actual.as_ref() == Some(&search_after))
+            .return_once(|_, _, _, _, _, _| {
+                let search_result = SearchResult {
+                    hits: fake::vec![LocalizedItemView; 3],
+                    total: 789,
+                    facets: Default::default(),
+                    search_after: None,
+                };
+                Box::pin(async move { Ok(search_result) })
+            });
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
+
+        assert_eq!(200, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert!(json["pagination"].get("cursor").is_none());
+    }
+
+    #[tokio::test]
+    async fn should_open_point_in_time_when_requested_without_cursor() {
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .query_string_parameter("q", "boop doop")
+                .query_string_parameter("pit", "true")
+                .build(),
+            context: Default::default(),
+        };
+
+        let mut service = MockQueryItemService::default();
+        service.expect_open_point_in_time().return_once(|| {
+            Box::pin(async {
+                Ok(SearchAfter {
+                    sort_values: vec![],
+                    pit_id: Some("o463QQEJ".to_string()),
+                })
+            })
+        });
+        service
+            .expect_search_items()
+            .withf(|_, _, _, _, _, search_after| {
+                search_after
+                    .as_ref()
+                    .and_then(|search_after| search_after.pit_id.as_deref())
+                    == Some("o463QQEJ")
+            })
+            .return_once(|_, _, _, _, _, _| {
+                let search_result = SearchResult {
+                    hits: vec![],
+                    total: 0,
+                    facets: Default::default(),
+                    search_after: None,
+                };
+                Box::pin(async move { Ok(search_result) })
+            });
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
+
+        assert_eq!(200, response.status_code);
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::forged("cursor", "eyJ2IjpbNDIwMCwiYWJjIl19.c2lnbmF0dXJl", "BAD_CURSOR_VALUE")]
+    #[case::garbage("cursor", "boop", "BAD_CURSOR_VALUE")]
+    #[case::pit("pit", "yes", "BAD_QUERY_PARAMETER_VALUE")]
+    async fn should_400_when_cursor_parameter_is_invalid(
+        #[case] field: &str,
+        #[case] value: &str,
+        #[case] expected_error: &str,
+    ) {
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .query_string_parameter("q", "boop doop")
+                .query_string_parameter(field, value)
+                .build(),
+            context: Default::default(),
+        };
+
+        let mut service = MockQueryItemService::default();
+        service.expect_search_items().never();
+        service.expect_open_point_in_time().never();
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
+
+        assert_eq!(400, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!(expected_error, json["error"]);
+        assert_eq!(field, json["source"]["field"]);
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::other_sort("price:asc", Language::De, "boop doop")]
+    #[case::other_language("_score", Language::En, "boop doop")]
+    #[case::other_query("_score", Language::De, "pickelhaube")]
+    async fn should_400_when_cursor_was_issued_for_other_search(
+        #[case] sort: &str,
+        #[case] language: Language,
+        #[case] q: &str,
+    ) {
+        let search_after = SearchAfter {
+            sort_values: vec![serde_json::json!(4200), serde_json::json!("abc")],
+            pit_id: None,
+        };
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .query_string_parameter("q", "boop doop")
+                .query_string_parameter(
+                    "cursor",
+                    encode_cursor(
+                        &search_after,
+                        &mk_cursor_binding(sort, language, q),
+                        &mk_cursor_signing_key(),
+                    ),
+                )
+                .build(),
+            context: Default::default(),
+        };
+
+        let mut service = MockQueryItemService::default();
+        service.expect_search_items().never();
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
+
+        assert_eq!(400, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!("BAD_CURSOR_VALUE", json["error"]);
+        assert_eq!("cursor", json["source"]["field"]);
+    }
+
+    #[tokio::test]
+    async fn should_400_when_from_is_given_with_cursor() {
+        let search_after = SearchAfter {
+            sort_values: vec![serde_json::json!(4200), serde_json::json!("abc")],
+            pit_id: None,
+        };
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .query_string_parameter("q", "boop doop")
+                .query_string_parameter("from", "21")
+                .query_string_parameter(
+                    "cursor",
+                    encode_cursor(
+                        &search_after,
+                        &mk_cursor_binding("_score", Language::De, "boop doop"),
+                        &mk_cursor_signing_key(),
+                    ),
+                )
+                .build(),
+            context: Default::default(),
+        };
+
+        let mut service = MockQueryItemService::default();
+        service.expect_search_items().never();
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
+
+        assert_eq!(400, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!("BAD_PAGE_FROM_VALUE", json["error"]);
+        assert_eq!("from", json["source"]["field"]);
+    }
+
+    #[tokio::test]
+    async fn should_410_when_point_in_time_of_cursor_expired() {
+        let search_after = SearchAfter {
+            sort_values: vec![serde_json::json!(4200), serde_json::json!("abc")],
+            pit_id: Some("o463QQEJ".to_string()),
+        };
+        let lambda_event = LambdaEvent {
+            payload: ApiGatewayV2httpRequestProxy::builder()
+                .http_method(http::Method::GET)
+                .query_string_parameter("q", "boop doop")
+                .query_string_parameter(
+                    "cursor",
+                    encode_cursor(
+                        &search_after,
+                        &mk_cursor_binding("_score", Language::De, "boop doop"),
+                        &mk_cursor_signing_key(),
+                    ),
+                )
+                .build(),
+            context: Default::default(),
+        };
+
+        let mut service = MockQueryItemService::default();
+        service
+            .expect_search_items()
+            .return_once(|_, _, _, _, _, _| {
+                Box::pin(async { Err(SearchItemsError::PointInTimeExpired) })
+            });
+        let response = handler(lambda_event, &service, &mk_cursor_signing_key())
+            .await
+            .unwrap();
+
+        assert_eq!(410, response.status_code);
+        let json = extract_apigw_response_json_body!(response);
+        assert_eq!("CURSOR_EXPIRED", json["error"]);
+        assert_eq!("cursor", json["source"]["field"]);
+    }
 }
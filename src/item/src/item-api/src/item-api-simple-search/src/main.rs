@@ -1,5 +1,6 @@
 use aws_config::BehaviorVersion;
 use aws_lambda_events::apigw::ApiGatewayV2httpRequest;
+use common::opensearch::search_after::api::CursorSigningKey;
 use item_api_simple_search::handler;
 use item_opensearch::repository::ItemOpenSearchRepositoryImpl;
 use item_service::query_service::QueryItemServiceImpl;
@@ -32,6 +33,7 @@ async fn main() -> Result<(), Error> {
     let client = opensearch::OpenSearch::new(transport);
     let repository = ItemOpenSearchRepositoryImpl::new(&client);
     let service = QueryItemServiceImpl::new(&repository);
+    let cursor_signing_key = CursorSigningKey::new(env::var("CURSOR_SIGNING_KEY")?);
 
     info!(
         domainEndpointUrl = %item_domain_endpoint,
@@ -39,7 +41,9 @@ async fn main() -> Result<(), Error> {
     );
 
     run(service_fn(
-        |event: LambdaEvent<ApiGatewayV2httpRequest>| async { handler(event, &service).await },
+        |event: LambdaEvent<ApiGatewayV2httpRequest>| async {
+            handler(event, &service, &cursor_signing_key).await
+        },
     ))
     .await
 }
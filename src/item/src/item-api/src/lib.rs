@@ -1,2 +1,3 @@
 pub use item_api_get_item;
+pub use item_api_get_item_events;
 pub use item_api_simple_search;
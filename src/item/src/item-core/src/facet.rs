@@ -0,0 +1,26 @@
+use crate::shop_name::ShopName;
+use common::item_state::domain::ItemState;
+use common::price::domain::MonetaryAmount;
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ItemFacets {
+    pub state: Vec<TermFacet<ItemState>>,
+    pub shop_name: Vec<TermFacet<ShopName>>,
+    pub price: Vec<RangeFacet<MonetaryAmount>>,
+    pub created: Vec<RangeFacet<OffsetDateTime>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TermFacet<T> {
+    pub value: T,
+    pub count: u64,
+}
+
+/// Bucket of a histogram with inclusive lower bound `min` and exclusive upper bound `max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeFacet<T> {
+    pub min: T,
+    pub max: T,
+    pub count: u64,
+}
@@ -116,6 +116,13 @@ impl ItemHashContributor for Currency {
             Currency::Aud => ItemHashContribution("Currency::Aud".to_string()),
             Currency::Cad => ItemHashContribution("Currency::Cad".to_string()),
             Currency::Nzd => ItemHashContribution("Currency::Nzd".to_string()),
+            Currency::Chf => ItemHashContribution("Currency::Chf".to_string()),
+            Currency::Sek => ItemHashContribution("Currency::Sek".to_string()),
+            Currency::Dkk => ItemHashContribution("Currency::Dkk".to_string()),
+            Currency::Nok => ItemHashContribution("Currency::Nok".to_string()),
+            Currency::Pln => ItemHashContribution("Currency::Pln".to_string()),
+            Currency::Czk => ItemHashContribution("Currency::Czk".to_string()),
+            Currency::Jpy => ItemHashContribution("Currency::Jpy".to_string()),
         }
     }
 }
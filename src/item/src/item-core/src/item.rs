@@ -9,19 +9,34 @@ use common::price::domain::{FxRate, MonetaryAmount, Price};
 use common::shop_id::ShopId;
 use common::shops_item_id::ShopsItemId;
 use std::collections::HashMap;
-use time::OffsetDateTime;
+use time::{Date, OffsetDateTime};
 use url::Url;
 
 use crate::description::Description;
 use crate::hash::ItemHash;
 use crate::item_event::{
     ItemCreatedEventPayload, ItemEvent, ItemEventPayload, ItemPriceChangeEventPayload,
-    ItemStateChangeEventPayload,
+    ItemStateChangeEventPayload, ItemTranslatedEventPayload,
 };
 use crate::shop_name::ShopName;
 use crate::title::Title;
 use common::item_state::domain::ItemState;
 
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum ApplyItemEventError {
+    #[error("Cannot rebuild Item from an empty event stream.")]
+    NoEvents,
+
+    #[error("Cannot rebuild Item because its first event '{0}' is not a Created-event.")]
+    NotCreated(EventId),
+
+    #[error("Item '{0}' has already been created.")]
+    AlreadyCreated(ItemId),
+
+    #[error("Event for Item '{actual}' cannot be applied to Item '{expected}'.")]
+    AggregateMismatch { expected: ItemId, actual: ItemId },
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Item {
     pub item_id: ItemId,
@@ -55,6 +70,7 @@ impl Item {
         other_description: HashMap<Language, Description>,
         native_price: Option<Price>,
         other_price: HashMap<Currency, MonetaryAmount>,
+        fx_rate_date: Option<Date>,
         state: ItemState,
         url: Url,
         images: Vec<Url>,
@@ -69,6 +85,7 @@ impl Item {
             native_description,
             native_price,
             other_price,
+            fx_rate_date,
             state,
             url,
             images,
@@ -125,6 +142,7 @@ impl Item {
             shops_item_id: self.shops_item_id.clone(),
             native_price: new_price,
             other_price: new_other_price,
+            fx_rate_date: fx_rate.snapshot_date(),
             hash: self.hash,
         };
 
@@ -165,6 +183,144 @@ impl Item {
         }
     }
 
+    /// Adds translations of the native title and description.
+    ///
+    /// Languages that are already available are kept as is, so are the native ones.
+    /// If no translation remains, no event is emitted.
+    pub fn translate(
+        &mut self,
+        title_translations: HashMap<Language, Title>,
+        description_translations: HashMap<Language, Description>,
+    ) -> Option<ItemEvent> {
+        let native_description_language = self
+            .native_description
+            .as_ref()
+            .map(|native_description| native_description.localization);
+        let other_title: HashMap<Language, Title> = title_translations
+            .into_iter()
+            .filter(|(language, _)| {
+                *language != self.native_title.localization
+                    && !self.other_title.contains_key(language)
+            })
+            .collect();
+        let other_description: HashMap<Language, Description> = description_translations
+            .into_iter()
+            .filter(|(language, _)| {
+                Some(*language) != native_description_language
+                    && !self.other_description.contains_key(language)
+            })
+            .collect();
+        if other_title.is_empty() && other_description.is_empty() {
+            return None;
+        }
+
+        self.other_title.extend(other_title.clone());
+        self.other_description.extend(other_description.clone());
+        let event = Event {
+            aggregate_id: self.item_id,
+            event_id: EventId::new(),
+            timestamp: OffsetDateTime::now_utc(),
+            payload: ItemEventPayload::Translated(ItemTranslatedEventPayload {
+                shop_id: self.shop_id.clone(),
+                shops_item_id: self.shops_item_id.clone(),
+                other_title,
+                other_description,
+                hash: self.hash,
+            }),
+        };
+        Some(event)
+    }
+
+    /// Rebuilds an Item by folding its events which must be in chronological order.
+    ///
+    /// The first event has to be the Created-event.
+    pub fn from_events(
+        events: impl IntoIterator<Item = ItemEvent>,
+    ) -> Result<Item, ApplyItemEventError> {
+        let mut events = events.into_iter();
+        let first = events.next().ok_or(ApplyItemEventError::NoEvents)?;
+        let mut item = match first.payload {
+            ItemEventPayload::Created(payload) => Item {
+                item_id: first.aggregate_id,
+                event_id: first.event_id,
+                shop_id: payload.shop_id,
+                shops_item_id: payload.shops_item_id,
+                shop_name: payload.shop_name,
+                native_title: payload.native_title,
+                other_title: payload.other_title,
+                native_description: payload.native_description,
+                other_description: payload.other_description,
+                native_price: payload.native_price,
+                other_price: payload.other_price,
+                state: payload.state,
+                url: payload.url,
+                images: payload.images,
+                hash: payload.hash,
+                created: first.timestamp,
+                updated: first.timestamp,
+            },
+            _ => return Err(ApplyItemEventError::NotCreated(first.event_id)),
+        };
+
+        for event in events {
+            item.apply(&event)?;
+        }
+
+        Ok(item)
+    }
+
+    /// Applies a state- or price-change or translations to this Item.
+    ///
+    /// Unlike [`Item::change_state`] and [`Item::change_price`], the hash is taken from the event
+    /// as is, so that the result matches what has been materialized at the time.
+    pub fn apply(&mut self, event: &ItemEvent) -> Result<(), ApplyItemEventError> {
+        if event.aggregate_id != self.item_id {
+            return Err(ApplyItemEventError::AggregateMismatch {
+                expected: self.item_id,
+                actual: event.aggregate_id,
+            });
+        }
+
+        match &event.payload {
+            ItemEventPayload::Created(_) => {
+                return Err(ApplyItemEventError::AlreadyCreated(self.item_id));
+            }
+            ItemEventPayload::StateListed(payload) => self.apply_state(ItemState::Listed, payload),
+            ItemEventPayload::StateAvailable(payload) => {
+                self.apply_state(ItemState::Available, payload)
+            }
+            ItemEventPayload::StateReserved(payload) => {
+                self.apply_state(ItemState::Reserved, payload)
+            }
+            ItemEventPayload::StateSold(payload) => self.apply_state(ItemState::Sold, payload),
+            ItemEventPayload::StateRemoved(payload) => {
+                self.apply_state(ItemState::Removed, payload)
+            }
+            ItemEventPayload::PriceDiscovered(payload)
+            | ItemEventPayload::PriceDropped(payload)
+            | ItemEventPayload::PriceIncreased(payload) => {
+                self.native_price = Some(payload.native_price);
+                self.other_price = payload.other_price.clone();
+                self.hash = payload.hash;
+            }
+            ItemEventPayload::Translated(payload) => {
+                self.other_title.extend(payload.other_title.clone());
+                self.other_description
+                    .extend(payload.other_description.clone());
+                self.hash = payload.hash;
+            }
+        }
+        self.event_id = event.event_id;
+        self.updated = event.timestamp;
+
+        Ok(())
+    }
+
+    fn apply_state(&mut self, state: ItemState, payload: &ItemStateChangeEventPayload) {
+        self.state = state;
+        self.hash = payload.hash;
+    }
+
     fn hash(&mut self) {
         self.hash = ItemHash::new(&self.native_price, &self.state);
     }
@@ -197,6 +353,18 @@ pub struct LocalizedItemView {
     pub hash: ItemHash,
     pub created: OffsetDateTime,
     pub updated: OffsetDateTime,
+    /// Date of the FX rates `price` has been exchanged with, if known.
+    pub fx_rate_date: Option<Date>,
+    /// Time `price` has last been re-exchanged without a new event, if ever.
+    pub repriced: Option<OffsetDateTime>,
+}
+
+impl LocalizedItemView {
+    /// The later of `updated` and `repriced`, as repricing changes the view without an event.
+    pub fn last_modified(&self) -> OffsetDateTime {
+        self.repriced
+            .map_or(self.updated, |repriced| repriced.max(self.updated))
+    }
 }
 
 #[cfg(feature = "test-data")]
@@ -296,6 +464,8 @@ mod faker {
                 hash: ItemHash::new(&native_price, &state),
                 created: OffsetDateTime::now_utc(),
                 updated: OffsetDateTime::now_utc(),
+                fx_rate_date: None,
+                repriced: None,
             }
         }
     }
@@ -615,6 +785,43 @@ mod tests {
             }
         }
 
+        #[test]
+        fn should_record_fx_rate_date_for_change_price() {
+            let mut item = Item::from_events(vec![Item::create(
+                Default::default(),
+                Default::default(),
+                "Boop".into(),
+                Localized::new(Language::De, "Boop".into()),
+                Default::default(),
+                None,
+                Default::default(),
+                None,
+                Default::default(),
+                None,
+                ItemState::Listed,
+                Url::parse("https://example.com").unwrap(),
+                vec![],
+            )])
+            .unwrap();
+
+            let actual = item
+                .change_price(
+                    Price::new(42u64.into(), Currency::Eur),
+                    &common::price::domain::FixedFxRate(),
+                )
+                .unwrap();
+
+            match actual.payload {
+                ItemEventPayload::PriceDiscovered(payload) => {
+                    assert_eq!(
+                        Some(time::macros::date!(2025 - 07 - 15)),
+                        payload.fx_rate_date
+                    );
+                }
+                _ => panic!("Expected ItemEventPayload::PriceDiscovered"),
+            }
+        }
+
         #[rstest::rstest]
         #[case::eur_non_zero(Price::new(420u64.into(), Currency::Eur))]
         #[case::gbp_non_zero(Price::new(430u64.into(), Currency::Gbp))]
@@ -705,4 +912,236 @@ mod tests {
             }
         }
     }
+
+    mod translate {
+        use crate::hash::ItemHash;
+        use crate::item::Item;
+        use crate::item_event::ItemEventPayload;
+        use common::item_state::domain::ItemState;
+        use common::language::domain::Language;
+        use common::localized::Localized;
+        use std::collections::HashMap;
+        use time::OffsetDateTime;
+        use url::Url;
+
+        fn mk_item() -> Item {
+            Item {
+                item_id: Default::default(),
+                event_id: Default::default(),
+                shop_id: Default::default(),
+                shops_item_id: Default::default(),
+                shop_name: "Boop".into(),
+                native_title: Localized::new(Language::De, "Pickelhaube".into()),
+                other_title: HashMap::from([(Language::En, "Spiked helmet".into())]),
+                native_description: Some(Localized::new(Language::De, "Sehr gut".into())),
+                other_description: Default::default(),
+                native_price: None,
+                other_price: Default::default(),
+                state: ItemState::Available,
+                url: Url::parse("https://example.com").unwrap(),
+                images: vec![],
+                hash: ItemHash::new(&None, &ItemState::Available),
+                created: OffsetDateTime::now_utc(),
+                updated: OffsetDateTime::now_utc(),
+            }
+        }
+
+        #[test]
+        fn should_only_add_missing_languages_for_translate() {
+            let mut item = mk_item();
+
+            let actual = item
+                .translate(
+                    HashMap::from([
+                        (Language::De, "Helm".into()),
+                        (Language::En, "Helmet".into()),
+                        (Language::Fr, "Casque à pointe".into()),
+                    ]),
+                    HashMap::from([
+                        (Language::De, "Gut".into()),
+                        (Language::Es, "Muy bien".into()),
+                    ]),
+                )
+                .unwrap();
+
+            match actual.payload {
+                ItemEventPayload::Translated(payload) => {
+                    assert_eq!(
+                        HashMap::from([(Language::Fr, "Casque à pointe".into())]),
+                        payload.other_title
+                    );
+                    assert_eq!(
+                        HashMap::from([(Language::Es, "Muy bien".into())]),
+                        payload.other_description
+                    );
+                    assert_eq!(item.hash, payload.hash);
+                }
+                other => panic!("Expected Translated-event but got: {other:?}"),
+            }
+            assert_eq!("Spiked helmet", item.other_title[&Language::En].as_ref());
+            assert_eq!("Casque à pointe", item.other_title[&Language::Fr].as_ref());
+            assert_eq!("Muy bien", item.other_description[&Language::Es].as_ref());
+        }
+
+        #[test]
+        fn should_return_none_when_no_language_is_missing_for_translate() {
+            let mut item = mk_item();
+            let initial_item = item.clone();
+
+            let actual = item.translate(
+                HashMap::from([(Language::En, "Helmet".into())]),
+                HashMap::new(),
+            );
+
+            assert!(actual.is_none());
+            assert_eq!(initial_item, item);
+        }
+    }
+
+    mod apply {
+        use crate::hash::ItemHash;
+        use crate::item::{ApplyItemEventError, Item};
+        use crate::item_event::{ItemEvent, ItemEventPayload, ItemStateChangeEventPayload};
+        use common::currency::domain::Currency;
+        use common::event_id::EventId;
+        use common::item_id::ItemId;
+        use common::item_state::domain::ItemState;
+        use common::language::domain::Language;
+        use common::localized::Localized;
+        use common::price::domain::{FixedFxRate, Price};
+        use common::shop_id::ShopId;
+        use common::shops_item_id::ShopsItemId;
+        use std::collections::HashMap;
+        use time::OffsetDateTime;
+        use url::Url;
+
+        fn mk_created_event() -> ItemEvent {
+            Item::create(
+                ShopId::new(),
+                ShopsItemId::new(),
+                "Boop".into(),
+                Localized::new(Language::De, "Boop".into()),
+                HashMap::new(),
+                None,
+                HashMap::new(),
+                None,
+                HashMap::new(),
+                None,
+                ItemState::Available,
+                Url::parse("https://example.com").unwrap(),
+                vec![],
+            )
+        }
+
+        fn mk_state_event(aggregate_id: ItemId) -> ItemEvent {
+            ItemEvent {
+                aggregate_id,
+                event_id: EventId::new(),
+                timestamp: OffsetDateTime::now_utc(),
+                payload: ItemEventPayload::StateSold(ItemStateChangeEventPayload {
+                    shop_id: ShopId::new(),
+                    shops_item_id: ShopsItemId::new(),
+                    hash: ItemHash::new(&None, &ItemState::Sold),
+                }),
+            }
+        }
+
+        #[test]
+        fn should_rebuild_item_from_events() {
+            let created = mk_created_event();
+            let mut expected = Item::from_events(vec![created.clone()]).unwrap();
+            let price_event = expected
+                .change_price(Price::new(10000u64.into(), Currency::Eur), &FixedFxRate())
+                .unwrap();
+            let state_event = expected.change_state(ItemState::Sold).unwrap();
+            expected.event_id = state_event.event_id;
+            expected.updated = state_event.timestamp;
+
+            let actual = Item::from_events(vec![created, price_event, state_event]).unwrap();
+
+            assert_eq!(expected, actual);
+        }
+
+        #[test]
+        fn should_rebuild_item_with_translations_from_events() {
+            let created = mk_created_event();
+            let mut expected = Item::from_events(vec![created.clone()]).unwrap();
+            let translated_event = expected
+                .translate(
+                    HashMap::from([(Language::En, "Boop".into())]),
+                    HashMap::new(),
+                )
+                .unwrap();
+            expected.event_id = translated_event.event_id;
+            expected.updated = translated_event.timestamp;
+
+            let actual = Item::from_events(vec![created, translated_event]).unwrap();
+
+            assert_eq!(expected, actual);
+            assert_eq!("Boop", actual.other_title[&Language::En].as_ref());
+        }
+
+        #[test]
+        fn should_take_created_and_updated_from_event_timestamps() {
+            let created = mk_created_event();
+            let state_event = mk_state_event(created.aggregate_id);
+            let created_timestamp = created.timestamp;
+            let state_event_id = state_event.event_id;
+            let state_timestamp = state_event.timestamp;
+
+            let actual = Item::from_events(vec![created, state_event]).unwrap();
+
+            assert_eq!(created_timestamp, actual.created);
+            assert_eq!(state_timestamp, actual.updated);
+            assert_eq!(state_event_id, actual.event_id);
+            assert_eq!(ItemState::Sold, actual.state);
+            assert_eq!(ItemHash::new(&None, &ItemState::Sold), actual.hash);
+        }
+
+        #[test]
+        fn should_err_when_no_events() {
+            let actual = Item::from_events(vec![]);
+
+            assert_eq!(Err(ApplyItemEventError::NoEvents), actual);
+        }
+
+        #[test]
+        fn should_err_when_first_event_is_not_created() {
+            let state_event = mk_state_event(ItemId::new());
+            let event_id = state_event.event_id;
+
+            let actual = Item::from_events(vec![state_event]);
+
+            assert_eq!(Err(ApplyItemEventError::NotCreated(event_id)), actual);
+        }
+
+        #[test]
+        fn should_err_when_applying_created_twice() {
+            let created = mk_created_event();
+            let mut item = Item::from_events(vec![created.clone()]).unwrap();
+
+            let actual = item.apply(&created);
+
+            assert_eq!(
+                Err(ApplyItemEventError::AlreadyCreated(item.item_id)),
+                actual
+            );
+        }
+
+        #[test]
+        fn should_err_when_event_belongs_to_other_item() {
+            let mut item = Item::from_events(vec![mk_created_event()]).unwrap();
+            let other_item_id = ItemId::new();
+
+            let actual = item.apply(&mk_state_event(other_item_id));
+
+            assert_eq!(
+                Err(ApplyItemEventError::AggregateMismatch {
+                    expected: item.item_id,
+                    actual: other_item_id,
+                }),
+                actual
+            );
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use common::currency::domain::Currency;
 use common::event::Event;
+use common::event_id::EventId;
 use common::has_key::HasKey;
 use common::item_id::{ItemId, ItemKey};
 use common::language::domain::Language;
@@ -8,6 +9,7 @@ use common::price::domain::{MonetaryAmount, Price};
 use common::shop_id::ShopId;
 use common::shops_item_id::ShopsItemId;
 use std::collections::HashMap;
+use time::{Date, OffsetDateTime};
 use url::Url;
 
 use crate::description::Description;
@@ -31,6 +33,7 @@ pub enum ItemEventPayload {
     PriceDiscovered(ItemPriceChangeEventPayload),
     PriceDropped(ItemPriceChangeEventPayload),
     PriceIncreased(ItemPriceChangeEventPayload),
+    Translated(ItemTranslatedEventPayload),
 }
 
 impl HasKey for ItemEventPayload {
@@ -58,6 +61,7 @@ impl ItemCommonEventPayload for ItemEventPayload {
             ItemEventPayload::PriceDiscovered(payload) => payload.shop_id(),
             ItemEventPayload::PriceDropped(payload) => payload.shop_id(),
             ItemEventPayload::PriceIncreased(payload) => payload.shop_id(),
+            ItemEventPayload::Translated(payload) => payload.shop_id(),
         }
     }
 
@@ -72,6 +76,7 @@ impl ItemCommonEventPayload for ItemEventPayload {
             ItemEventPayload::PriceDiscovered(payload) => payload.shops_item_id(),
             ItemEventPayload::PriceDropped(payload) => payload.shops_item_id(),
             ItemEventPayload::PriceIncreased(payload) => payload.shops_item_id(),
+            ItemEventPayload::Translated(payload) => payload.shops_item_id(),
         }
     }
 }
@@ -87,6 +92,9 @@ pub struct ItemCreatedEventPayload {
     pub other_description: HashMap<Language, Description>,
     pub native_price: Option<Price>,
     pub other_price: HashMap<Currency, MonetaryAmount>,
+
+    /// Date of the [`common::price::domain::FxRate`] snapshot `other_price` was exchanged with.
+    pub fx_rate_date: Option<Date>,
     pub state: ItemState,
     pub url: Url,
     pub images: Vec<Url>,
@@ -126,6 +134,9 @@ pub struct ItemPriceChangeEventPayload {
     pub shops_item_id: ShopsItemId,
     pub native_price: Price,
     pub other_price: HashMap<Currency, MonetaryAmount>,
+
+    /// Date of the [`common::price::domain::FxRate`] snapshot `other_price` was exchanged with.
+    pub fx_rate_date: Option<Date>,
     pub hash: ItemHash,
 }
 
@@ -139,6 +150,81 @@ impl ItemCommonEventPayload for ItemPriceChangeEventPayload {
     }
 }
 
+/// Translations of the native title and description added after creation.
+///
+/// Only contains languages that haven't been available before.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemTranslatedEventPayload {
+    pub shop_id: ShopId,
+    pub shops_item_id: ShopsItemId,
+    pub other_title: HashMap<Language, Title>,
+    pub other_description: HashMap<Language, Description>,
+    pub hash: ItemHash,
+}
+
+impl ItemCommonEventPayload for ItemTranslatedEventPayload {
+    fn shop_id(&self) -> &ShopId {
+        &self.shop_id
+    }
+
+    fn shops_item_id(&self) -> &ShopsItemId {
+        &self.shops_item_id
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemEventType {
+    Created,
+    StateListed,
+    StateAvailable,
+    StateReserved,
+    StateSold,
+    StateRemoved,
+    PriceDiscovered,
+    PriceDropped,
+    PriceIncreased,
+    Translated,
+}
+
+impl From<&ItemEventPayload> for ItemEventType {
+    fn from(payload: &ItemEventPayload) -> Self {
+        match payload {
+            ItemEventPayload::Created(_) => ItemEventType::Created,
+            ItemEventPayload::StateListed(_) => ItemEventType::StateListed,
+            ItemEventPayload::StateAvailable(_) => ItemEventType::StateAvailable,
+            ItemEventPayload::StateReserved(_) => ItemEventType::StateReserved,
+            ItemEventPayload::StateSold(_) => ItemEventType::StateSold,
+            ItemEventPayload::StateRemoved(_) => ItemEventType::StateRemoved,
+            ItemEventPayload::PriceDiscovered(_) => ItemEventType::PriceDiscovered,
+            ItemEventPayload::PriceDropped(_) => ItemEventType::PriceDropped,
+            ItemEventPayload::PriceIncreased(_) => ItemEventType::PriceIncreased,
+            ItemEventPayload::Translated(_) => ItemEventType::Translated,
+        }
+    }
+}
+
+/// Single entry of an item's timeline with the price in the requested currency.
+///
+/// `price` is only present for [`ItemEventType::Created`] and price-events,
+/// `state` only for [`ItemEventType::Created`] and state-events.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemEventView {
+    pub event_id: EventId,
+    pub event_type: ItemEventType,
+    pub price: Option<Price>,
+    pub state: Option<ItemState>,
+    pub timestamp: OffsetDateTime,
+}
+
+/// Page of an item's timeline in chronological order.
+///
+/// If `next` is present, more events may exist after that timestamp.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemEventHistoryView {
+    pub events: Vec<ItemEventView>,
+    pub next: Option<OffsetDateTime>,
+}
+
 #[cfg(feature = "test-data")]
 mod faker {
     use super::*;
@@ -154,6 +240,7 @@ mod faker {
                     .exchange_all(price.currency, price.monetary_amount)
                     .unwrap(),
             };
+            let fx_rate_date = native_price.and(FixedFxRate().snapshot_date());
             let state = config.fake_with_rng(rng);
             ItemCreatedEventPayload {
                 shop_id: config.fake_with_rng(rng),
@@ -165,6 +252,7 @@ mod faker {
                 other_description: config.fake_with_rng(rng),
                 native_price,
                 other_price,
+                fx_rate_date,
                 state,
                 url: Url::parse(&format!(
                     "https://foo.bar/item/{}",
@@ -217,15 +305,31 @@ mod faker {
                 shops_item_id: config.fake_with_rng(rng),
                 native_price,
                 other_price,
+                fx_rate_date: FixedFxRate().snapshot_date(),
                 hash: ItemHash::new(&Some(native_price), &state),
             }
         }
     }
 
+    impl Dummy<Faker> for ItemTranslatedEventPayload {
+        fn dummy_with_rng<R: Rng + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+            let native_price: Option<Price> = config.fake_with_rng(rng);
+            let state = config.fake_with_rng(rng);
+            ItemTranslatedEventPayload {
+                shop_id: config.fake_with_rng(rng),
+                shops_item_id: config.fake_with_rng(rng),
+                other_title: config.fake_with_rng(rng),
+                other_description: config.fake_with_rng(rng),
+                hash: ItemHash::new(&native_price, &state),
+            }
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use crate::item_event::{
             ItemCreatedEventPayload, ItemEvent, ItemEventPayload, ItemStateChangeEventPayload,
+            ItemTranslatedEventPayload,
         };
         use fake::{Fake, Faker};
 
@@ -244,6 +348,11 @@ mod faker {
             let _ = Faker.fake::<ItemStateChangeEventPayload>();
         }
 
+        #[test]
+        fn should_fake_item_translated_event_payload() {
+            let _ = Faker.fake::<ItemTranslatedEventPayload>();
+        }
+
         #[test]
         fn should_fake_item_event_payload() {
             let _ = Faker.fake::<ItemEventPayload>();
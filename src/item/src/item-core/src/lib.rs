@@ -1,7 +1,9 @@
 pub mod description;
+pub mod facet;
 pub mod hash;
 pub mod item;
 pub mod item_event;
+pub mod price_history;
 pub mod shop_name;
 pub mod sort_item_field;
 pub mod title;
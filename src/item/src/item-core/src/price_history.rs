@@ -0,0 +1,100 @@
+use common::currency::domain::Currency;
+use common::price::domain::{FxRate, MonetaryAmountOverflowError, Price};
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PricePoint {
+    pub timestamp: OffsetDateTime,
+    pub price: Price,
+}
+
+/// Chronological projection of an item's prices.
+///
+/// Consecutive points with the same price are collapsed into the first one.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PriceHistory {
+    pub points: Vec<PricePoint>,
+}
+
+impl PriceHistory {
+    pub fn push(&mut self, timestamp: OffsetDateTime, price: Price) {
+        if self.points.last().map(|last| last.price) != Some(price) {
+            self.points.push(PricePoint { timestamp, price });
+        }
+    }
+
+    /// Exchanges all points into `currency` using the same rates, so that the history
+    /// only reflects the shop's price changes and not fluctuating exchange rates.
+    pub fn into_exchanged(
+        self,
+        fx_rate: &impl FxRate,
+        currency: Currency,
+    ) -> Result<PriceHistory, MonetaryAmountOverflowError> {
+        let points = self
+            .points
+            .into_iter()
+            .map(|point| {
+                Ok(PricePoint {
+                    timestamp: point.timestamp,
+                    price: point.price.into_exchanged(fx_rate, currency)?,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PriceHistory { points })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::price_history::{PriceHistory, PricePoint};
+    use common::currency::domain::Currency;
+    use common::price::domain::{FixedFxRate, Price};
+    use time::macros::datetime;
+
+    #[test]
+    fn should_collapse_consecutive_equal_prices() {
+        let mut price_history = PriceHistory::default();
+        let price = Price::new(45000u64.into(), Currency::Eur);
+
+        price_history.push(datetime!(2025-01-01 0:00 UTC), price);
+        price_history.push(datetime!(2025-01-02 0:00 UTC), price);
+
+        assert_eq!(
+            vec![PricePoint {
+                timestamp: datetime!(2025-01-01 0:00 UTC),
+                price,
+            }],
+            price_history.points
+        );
+    }
+
+    #[test]
+    fn should_exchange_all_points_into_currency() {
+        let mut price_history = PriceHistory::default();
+        price_history.push(
+            datetime!(2025-01-01 0:00 UTC),
+            Price::new(10000u64.into(), Currency::Eur),
+        );
+        price_history.push(
+            datetime!(2025-01-02 0:00 UTC),
+            Price::new(10000u64.into(), Currency::Usd),
+        );
+
+        let actual = price_history
+            .into_exchanged(&FixedFxRate(), Currency::Usd)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                Price::new(11670u64.into(), Currency::Usd),
+                Price::new(10000u64.into(), Currency::Usd),
+            ],
+            actual
+                .points
+                .into_iter()
+                .map(|point| point.price)
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use crate::item_state_data::ItemStateData;
+use common::price::domain::MonetaryAmount;
+use item_core::facet::{ItemFacets, RangeFacet};
+use serde::Serialize;
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemFacetsData {
+    pub state: Vec<TermFacetData<ItemStateData>>,
+
+    pub shop_name: Vec<TermFacetData<String>>,
+
+    pub price: Vec<PriceFacetData>,
+
+    pub created: Vec<DateTimeFacetData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TermFacetData<T> {
+    pub value: T,
+    pub count: u64,
+}
+
+/// Price-bucket in minor units of the requested currency, `min` inclusive and `max` exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceFacetData {
+    pub min: u64,
+    pub max: u64,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DateTimeFacetData {
+    #[serde(with = "time::serde::rfc3339")]
+    pub min: OffsetDateTime,
+
+    #[serde(with = "time::serde::rfc3339")]
+    pub max: OffsetDateTime,
+
+    pub count: u64,
+}
+
+impl From<ItemFacets> for ItemFacetsData {
+    fn from(facets: ItemFacets) -> Self {
+        ItemFacetsData {
+            state: facets
+                .state
+                .into_iter()
+                .map(|facet| TermFacetData {
+                    value: facet.value.into(),
+                    count: facet.count,
+                })
+                .collect(),
+            shop_name: facets
+                .shop_name
+                .into_iter()
+                .map(|facet| TermFacetData {
+                    value: facet.value.into(),
+                    count: facet.count,
+                })
+                .collect(),
+            price: facets.price.into_iter().map(PriceFacetData::from).collect(),
+            created: facets
+                .created
+                .into_iter()
+                .map(DateTimeFacetData::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<RangeFacet<MonetaryAmount>> for PriceFacetData {
+    fn from(facet: RangeFacet<MonetaryAmount>) -> Self {
+        PriceFacetData {
+            min: facet.min.into(),
+            max: facet.max.into(),
+            count: facet.count,
+        }
+    }
+}
+
+impl From<RangeFacet<OffsetDateTime>> for DateTimeFacetData {
+    fn from(facet: RangeFacet<OffsetDateTime>) -> Self {
+        DateTimeFacetData {
+            min: facet.min,
+            max: facet.max,
+            count: facet.count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::item_state::domain::ItemState;
+    use item_core::facet::TermFacet;
+    use time::macros::datetime;
+
+    #[test]
+    fn should_serialize_item_facets_data_in_camel_case() {
+        let facets = ItemFacets {
+            state: vec![TermFacet {
+                value: ItemState::Sold,
+                count: 123,
+            }],
+            shop_name: vec![TermFacet {
+                value: "Militaria Mart".into(),
+                count: 42,
+            }],
+            price: vec![RangeFacet {
+                min: 10000u64.into(),
+                max: 20000u64.into(),
+                count: 7,
+            }],
+            created: vec![RangeFacet {
+                min: datetime!(2025-01-01 0:00 UTC),
+                max: datetime!(2025-02-01 0:00 UTC),
+                count: 9,
+            }],
+        };
+
+        let actual = serde_json::to_value(ItemFacetsData::from(facets)).unwrap();
+
+        assert_eq!(
+            serde_json::json!({
+                "state": [{ "value": "SOLD", "count": 123 }],
+                "shopName": [{ "value": "Militaria Mart", "count": 42 }],
+                "price": [{ "min": 10000, "max": 20000, "count": 7 }],
+                "created": [{
+                    "min": "2025-01-01T00:00:00Z",
+                    "max": "2025-02-01T00:00:00Z",
+                    "count": 9
+                }]
+            }),
+            actual
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::item_state_data::ItemStateData;
+use crate::price_history_data::PriceHistoryData;
 use common::event_id::EventId;
 use common::has_key::HasKey;
 use common::item_id::{ItemId, ItemKey};
@@ -44,6 +45,9 @@ pub struct GetItemData {
 
     #[serde(with = "time::serde::rfc3339")]
     pub updated: OffsetDateTime,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_history: Option<PriceHistoryData>,
 }
 
 impl HasKey for GetItemData {
@@ -73,6 +77,7 @@ impl From<LocalizedItemView> for GetItemData {
             images: item_view.images,
             created: item_view.created,
             updated: item_view.updated,
+            price_history: None,
         }
     }
 }
@@ -140,6 +145,7 @@ mod tests {
             ],
             created: utc_datetime!(2025 - 05 - 05 0:00).into(),
             updated: utc_datetime!(2025 - 05 - 05 0:00).into(),
+            price_history: None,
         };
 
         let expected = json!({
@@ -0,0 +1,176 @@
+use crate::item_state_data::ItemStateData;
+use common::event_id::EventId;
+use common::price::data::PriceData;
+use item_core::item_event::{ItemEventHistoryView, ItemEventType, ItemEventView};
+use serde::Serialize;
+use time::OffsetDateTime;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ItemEventTypeData {
+    Created,
+    StateListed,
+    StateAvailable,
+    StateReserved,
+    StateSold,
+    StateRemoved,
+    PriceDiscovered,
+    PriceDropped,
+    PriceIncreased,
+    Translated,
+}
+
+impl From<ItemEventType> for ItemEventTypeData {
+    fn from(domain: ItemEventType) -> Self {
+        match domain {
+            ItemEventType::Created => ItemEventTypeData::Created,
+            ItemEventType::StateListed => ItemEventTypeData::StateListed,
+            ItemEventType::StateAvailable => ItemEventTypeData::StateAvailable,
+            ItemEventType::StateReserved => ItemEventTypeData::StateReserved,
+            ItemEventType::StateSold => ItemEventTypeData::StateSold,
+            ItemEventType::StateRemoved => ItemEventTypeData::StateRemoved,
+            ItemEventType::PriceDiscovered => ItemEventTypeData::PriceDiscovered,
+            ItemEventType::PriceDropped => ItemEventTypeData::PriceDropped,
+            ItemEventType::PriceIncreased => ItemEventTypeData::PriceIncreased,
+            ItemEventType::Translated => ItemEventTypeData::Translated,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemEventData {
+    pub event_id: EventId,
+
+    pub event_type: ItemEventTypeData,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<PriceData>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<ItemStateData>,
+
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+}
+
+impl From<ItemEventView> for ItemEventData {
+    fn from(view: ItemEventView) -> Self {
+        ItemEventData {
+            event_id: view.event_id,
+            event_type: view.event_type.into(),
+            price: view.price.map(PriceData::from),
+            state: view.state.map(ItemStateData::from),
+            timestamp: view.timestamp,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemEventHistoryData {
+    pub events: Vec<ItemEventData>,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "time::serde::rfc3339::option"
+    )]
+    pub next: Option<OffsetDateTime>,
+}
+
+impl From<ItemEventHistoryView> for ItemEventHistoryData {
+    fn from(view: ItemEventHistoryView) -> Self {
+        ItemEventHistoryData {
+            events: view.events.into_iter().map(ItemEventData::from).collect(),
+            next: view.next,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::item_event_data::{ItemEventData, ItemEventHistoryData, ItemEventTypeData};
+    use crate::item_state_data::ItemStateData;
+    use common::{currency::data::CurrencyData, event_id::EventId, price::data::PriceData};
+    use serde_json::json;
+    use time::macros::datetime;
+
+    #[test]
+    fn should_serialize_item_event_history_data() {
+        let created_event_id = EventId::new();
+        let dropped_event_id = EventId::new();
+        let sold_event_id = EventId::new();
+        let dto = ItemEventHistoryData {
+            events: vec![
+                ItemEventData {
+                    event_id: created_event_id,
+                    event_type: ItemEventTypeData::Created,
+                    price: Some(PriceData::new(CurrencyData::Eur, 45000)),
+                    state: Some(ItemStateData::Available),
+                    timestamp: datetime!(2025-05-01 0:00 UTC),
+                },
+                ItemEventData {
+                    event_id: dropped_event_id,
+                    event_type: ItemEventTypeData::PriceDropped,
+                    price: Some(PriceData::new(CurrencyData::Eur, 38000)),
+                    state: None,
+                    timestamp: datetime!(2025-05-10 0:00 UTC),
+                },
+                ItemEventData {
+                    event_id: sold_event_id,
+                    event_type: ItemEventTypeData::StateSold,
+                    price: None,
+                    state: Some(ItemStateData::Sold),
+                    timestamp: datetime!(2025-05-31 0:00 UTC),
+                },
+            ],
+            next: Some(datetime!(2025-05-31 0:00 UTC)),
+        };
+
+        let expected = json!({
+            "events": [
+                {
+                    "eventId": created_event_id,
+                    "eventType": "CREATED",
+                    "price": {
+                        "currency": "EUR",
+                        "amount": 45000
+                    },
+                    "state": "AVAILABLE",
+                    "timestamp": "2025-05-01T00:00:00Z"
+                },
+                {
+                    "eventId": dropped_event_id,
+                    "eventType": "PRICE_DROPPED",
+                    "price": {
+                        "currency": "EUR",
+                        "amount": 38000
+                    },
+                    "timestamp": "2025-05-10T00:00:00Z"
+                },
+                {
+                    "eventId": sold_event_id,
+                    "eventType": "STATE_SOLD",
+                    "state": "SOLD",
+                    "timestamp": "2025-05-31T00:00:00Z"
+                }
+            ],
+            "next": "2025-05-31T00:00:00Z"
+        });
+        let actual = serde_json::to_value(dto).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn should_skip_next_when_last_page() {
+        let dto = ItemEventHistoryData {
+            events: vec![],
+            next: None,
+        };
+
+        let actual = serde_json::to_value(dto).unwrap();
+
+        assert_eq!(json!({ "events": [] }), actual);
+    }
+}
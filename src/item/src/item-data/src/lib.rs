@@ -1,3 +1,6 @@
+pub mod facet_data;
 pub mod get_data;
+pub mod item_event_data;
 pub mod item_state_data;
+pub mod price_history_data;
 pub mod sort_item_field_data;
@@ -0,0 +1,75 @@
+use common::currency::data::CurrencyData;
+use common::currency::domain::Currency;
+use item_core::price_history::PriceHistory;
+use serde::Serialize;
+use time::OffsetDateTime;
+
+/// Compact price-history, i.e. the currency is shared by all points.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceHistoryData {
+    pub currency: CurrencyData,
+
+    pub points: Vec<PricePointData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PricePointData {
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+
+    pub amount: u64,
+}
+
+impl PriceHistoryData {
+    /// Expects all points of `price_history` to already be exchanged into `currency`.
+    pub fn new(price_history: PriceHistory, currency: Currency) -> Self {
+        PriceHistoryData {
+            currency: currency.into(),
+            points: price_history
+                .points
+                .into_iter()
+                .map(|point| PricePointData {
+                    timestamp: point.timestamp,
+                    amount: point.price.monetary_amount.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::price_history_data::PriceHistoryData;
+    use common::currency::domain::Currency;
+    use common::price::domain::Price;
+    use item_core::price_history::PriceHistory;
+    use serde_json::json;
+    use time::macros::datetime;
+
+    #[test]
+    fn should_serialize_price_history_data() {
+        let mut price_history = PriceHistory::default();
+        price_history.push(
+            datetime!(2025-05-01 0:00 UTC),
+            Price::new(45000u64.into(), Currency::Eur),
+        );
+        price_history.push(
+            datetime!(2025-05-10 0:00 UTC),
+            Price::new(38000u64.into(), Currency::Eur),
+        );
+        let dto = PriceHistoryData::new(price_history, Currency::Eur);
+
+        let expected = json!({
+            "currency": "EUR",
+            "points": [
+                { "timestamp": "2025-05-01T00:00:00Z", "amount": 45000 },
+                { "timestamp": "2025-05-10T00:00:00Z", "amount": 38000 }
+            ]
+        });
+        let actual = serde_json::to_value(dto).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,107 @@
+use common::currency::domain::Currency;
+use common::currency::record::CurrencyRecord;
+use common::price::domain::Rate;
+use common::price::fx_rate::{FxRateSnapshot, FxRateSnapshotError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use time::Date;
+
+pub const FX_RATE_SNAPSHOT_PK: &str = "fx_rate#snapshot";
+
+/// Single day's [`FxRateSnapshot`].
+///
+/// All snapshots share a partition sorted by date, so that the latest one is a single query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FxRateSnapshotRecord {
+    pub pk: String,
+
+    pub sk: String,
+
+    #[serde(with = "common::serde::date")]
+    pub date: Date,
+
+    pub base: CurrencyRecord,
+
+    /// Rates by ISO 4217 code, scaled by [`common::price::domain::FX_RATE_SCALE`].
+    pub rates: HashMap<String, Rate>,
+}
+
+pub fn mk_fx_rate_snapshot_sk(date: Date) -> String {
+    format!(
+        "date#{}",
+        date.format(common::serde::date::FORMAT)
+            .expect("shouldn't fail because the date-format is valid for any date")
+    )
+}
+
+impl From<FxRateSnapshot> for FxRateSnapshotRecord {
+    fn from(domain: FxRateSnapshot) -> Self {
+        FxRateSnapshotRecord {
+            pk: FX_RATE_SNAPSHOT_PK.to_string(),
+            sk: mk_fx_rate_snapshot_sk(domain.date()),
+            date: domain.date(),
+            base: domain.base().into(),
+            rates: domain
+                .rates()
+                .iter()
+                .map(|(currency, rate)| (currency.iso_code(), *rate))
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<FxRateSnapshotRecord> for FxRateSnapshot {
+    type Error = FxRateSnapshotError;
+
+    /// Rates of currencies no longer supported are ignored.
+    fn try_from(record: FxRateSnapshotRecord) -> Result<Self, Self::Error> {
+        let rates = record
+            .rates
+            .iter()
+            .filter_map(|(iso_code, rate)| {
+                Currency::from_iso_code(iso_code).map(|currency| (currency, *rate))
+            })
+            .collect();
+        FxRateSnapshot::new(record.date, record.base.into(), rates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fx_rate_record::FxRateSnapshotRecord;
+    use common::currency::domain::Currency;
+    use common::price::fx_rate::{FxRateSnapshot, FxRateSnapshotError};
+
+    #[test]
+    fn should_convert_fx_rate_snapshot_back_and_forth() {
+        let expected = FxRateSnapshot::bundled();
+
+        let record = FxRateSnapshotRecord::from(expected.clone());
+        let actual = FxRateSnapshot::try_from(record.clone()).unwrap();
+
+        assert_eq!("fx_rate#snapshot", record.pk);
+        assert_eq!("date#2025-07-15", record.sk);
+        assert_eq!(Some(&1_167_000), record.rates.get("USD"));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn should_serialize_date_as_iso_8601() {
+        let record = FxRateSnapshotRecord::from(FxRateSnapshot::bundled());
+
+        let actual = serde_json::to_value(&record).unwrap();
+
+        assert_eq!("2025-07-15", actual["date"]);
+        assert_eq!("EUR", actual["base"]);
+    }
+
+    #[test]
+    fn should_fail_converting_record_missing_rates() {
+        let mut record = FxRateSnapshotRecord::from(FxRateSnapshot::bundled());
+        record.rates.remove("NZD");
+
+        let actual = FxRateSnapshot::try_from(record);
+
+        assert_eq!(Err(FxRateSnapshotError::MissingRate(Currency::Nzd)), actual);
+    }
+}
@@ -1,22 +1,30 @@
 use crate::item_event_type_record::ItemEventTypeRecord;
 use crate::item_state_record::ItemStateRecord;
 use common::currency::domain::Currency;
+use common::error::mapping_error::PersistenceMappingError;
+use common::error::missing_field::MissingPersistenceField;
 use common::event_id::EventId;
 use common::has_key::HasKey;
 use common::item_id::{ItemId, ItemKey};
 use common::language::domain::Language;
 use common::language::record::TextRecord;
+use common::localized::Localized;
+use common::price::domain::{MonetaryAmount, Price};
 use common::price::record::PriceRecord;
 use common::shop_id::ShopId;
 use common::shops_item_id::ShopsItemId;
+use field::field;
+use item_core::description::Description;
 use item_core::hash::ItemHash;
 use item_core::item_event::{
-    ItemCommonEventPayload, ItemEvent, ItemEventPayload, ItemPriceChangeEventPayload,
-    ItemStateChangeEventPayload,
+    ItemCommonEventPayload, ItemCreatedEventPayload, ItemEvent, ItemEventPayload,
+    ItemPriceChangeEventPayload, ItemStateChangeEventPayload, ItemTranslatedEventPayload,
 };
+use item_core::title::Title;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use time::format_description::well_known::Rfc3339;
-use time::{OffsetDateTime, error};
+use time::{Date, OffsetDateTime, error};
 use url::Url;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -25,6 +33,10 @@ pub struct ItemEventRecord {
 
     pub sk: String,
 
+    /// The `sk` of events carrying a price, which makes up the sparse index `gsi_3`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub gsi_3_sk: Option<String>,
+
     pub item_id: ItemId,
 
     pub event_id: EventId,
@@ -47,6 +59,12 @@ pub struct ItemEventRecord {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub title_en: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_fr: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_es: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub description_native: Option<TextRecord>,
 
@@ -56,6 +74,12 @@ pub struct ItemEventRecord {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub description_en: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_fr: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_es: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub price_native: Option<PriceRecord>,
 
@@ -77,6 +101,34 @@ pub struct ItemEventRecord {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub price_nzd: Option<u64>,
 
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_chf: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_sek: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_dkk: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_nok: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_pln: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_czk: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_jpy: Option<u64>,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "common::serde::date::option"
+    )]
+    pub fx_rate_date: Option<Date>,
+
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub state: Option<ItemStateRecord>,
 
@@ -132,6 +184,8 @@ impl TryFrom<ItemEvent> for ItemEventRecord {
 
                 let title_de = payload.other_title.remove(&Language::De).map(String::from);
                 let title_en = payload.other_title.remove(&Language::En).map(String::from);
+                let title_fr = payload.other_title.remove(&Language::Fr).map(String::from);
+                let title_es = payload.other_title.remove(&Language::Es).map(String::from);
 
                 if let Some(description_native) = payload.native_description.as_ref() {
                     payload.other_description.insert(
@@ -147,10 +201,20 @@ impl TryFrom<ItemEvent> for ItemEventRecord {
                     .other_description
                     .remove(&Language::En)
                     .map(String::from);
+                let description_fr = payload
+                    .other_description
+                    .remove(&Language::Fr)
+                    .map(String::from);
+                let description_es = payload
+                    .other_description
+                    .remove(&Language::Es)
+                    .map(String::from);
 
+                let gsi_3_sk = payload.native_price.map(|_| sk.clone());
                 let record = ItemEventRecord {
                     pk,
                     sk,
+                    gsi_3_sk,
                     item_id,
                     event_id,
                     event_type,
@@ -160,9 +224,13 @@ impl TryFrom<ItemEvent> for ItemEventRecord {
                     title_native: Some(payload.native_title.into()),
                     title_de,
                     title_en,
+                    title_fr,
+                    title_es,
                     description_native: payload.native_description.map(TextRecord::from),
                     description_de,
                     description_en,
+                    description_fr,
+                    description_es,
                     price_native: payload.native_price.map(PriceRecord::from),
                     price_eur: payload
                         .other_price
@@ -194,6 +262,42 @@ impl TryFrom<ItemEvent> for ItemEventRecord {
                         .get(&Currency::Nzd)
                         .copied()
                         .map(u64::from),
+                    price_chf: payload
+                        .other_price
+                        .get(&Currency::Chf)
+                        .copied()
+                        .map(u64::from),
+                    price_sek: payload
+                        .other_price
+                        .get(&Currency::Sek)
+                        .copied()
+                        .map(u64::from),
+                    price_dkk: payload
+                        .other_price
+                        .get(&Currency::Dkk)
+                        .copied()
+                        .map(u64::from),
+                    price_nok: payload
+                        .other_price
+                        .get(&Currency::Nok)
+                        .copied()
+                        .map(u64::from),
+                    price_pln: payload
+                        .other_price
+                        .get(&Currency::Pln)
+                        .copied()
+                        .map(u64::from),
+                    price_czk: payload
+                        .other_price
+                        .get(&Currency::Czk)
+                        .copied()
+                        .map(u64::from),
+                    price_jpy: payload
+                        .other_price
+                        .get(&Currency::Jpy)
+                        .copied()
+                        .map(u64::from),
+                    fx_rate_date: payload.fx_rate_date,
                     state: Some(payload.state.into()),
                     url: Some(payload.url),
                     images: Some(payload.images),
@@ -295,10 +399,167 @@ impl TryFrom<ItemEvent> for ItemEventRecord {
                 shops_item_id,
                 domain.timestamp,
             )),
+            ItemEventPayload::Translated(payload) => Ok(mk_translated_event_record(
+                payload,
+                pk,
+                sk,
+                item_id,
+                event_id,
+                event_type,
+                shop_id,
+                shops_item_id,
+                domain.timestamp,
+            )),
         }
     }
 }
 
+impl TryFrom<ItemEventRecord> for ItemEvent {
+    type Error = PersistenceMappingError;
+
+    fn try_from(record: ItemEventRecord) -> Result<Self, Self::Error> {
+        let other_price = mk_other_price(&record);
+        let state_payload = || ItemStateChangeEventPayload {
+            shop_id: record.shop_id.clone(),
+            shops_item_id: record.shops_item_id.clone(),
+            hash: record.hash,
+        };
+        let price_payload = || -> Result<_, PersistenceMappingError> {
+            Ok(ItemPriceChangeEventPayload {
+                shop_id: record.shop_id.clone(),
+                shops_item_id: record.shops_item_id.clone(),
+                native_price: record.price_native.map(Price::from).ok_or_else(|| {
+                    MissingPersistenceField::new(field!(price_native@ItemEventRecord))
+                })?,
+                other_price: other_price.clone(),
+                fx_rate_date: record.fx_rate_date,
+                hash: record.hash,
+            })
+        };
+
+        let payload = match record.event_type {
+            ItemEventTypeRecord::Created => {
+                let other_title = mk_other_title(&record);
+                let other_description = mk_other_description(&record);
+
+                ItemEventPayload::Created(ItemCreatedEventPayload {
+                    shop_id: record.shop_id.clone(),
+                    shops_item_id: record.shops_item_id.clone(),
+                    shop_name: record
+                        .shop_name
+                        .clone()
+                        .ok_or_else(|| {
+                            MissingPersistenceField::new(field!(shop_name@ItemEventRecord))
+                        })?
+                        .into(),
+                    native_title: record
+                        .title_native
+                        .clone()
+                        .map(Localized::from)
+                        .ok_or_else(|| {
+                            MissingPersistenceField::new(field!(title_native@ItemEventRecord))
+                        })?,
+                    other_title,
+                    native_description: record.description_native.clone().map(Localized::from),
+                    other_description,
+                    native_price: record.price_native.map(Price::from),
+                    other_price: other_price.clone(),
+                    fx_rate_date: record.fx_rate_date,
+                    state: record
+                        .state
+                        .ok_or_else(|| MissingPersistenceField::new(field!(state@ItemEventRecord)))?
+                        .into(),
+                    url: record
+                        .url
+                        .clone()
+                        .ok_or_else(|| MissingPersistenceField::new(field!(url@ItemEventRecord)))?,
+                    images: record.images.clone().unwrap_or_default(),
+                    hash: record.hash,
+                })
+            }
+            ItemEventTypeRecord::StateListed => ItemEventPayload::StateListed(state_payload()),
+            ItemEventTypeRecord::StateAvailable => {
+                ItemEventPayload::StateAvailable(state_payload())
+            }
+            ItemEventTypeRecord::StateReserved => ItemEventPayload::StateReserved(state_payload()),
+            ItemEventTypeRecord::StateSold => ItemEventPayload::StateSold(state_payload()),
+            ItemEventTypeRecord::StateRemoved => ItemEventPayload::StateRemoved(state_payload()),
+            ItemEventTypeRecord::PriceDiscovered => {
+                ItemEventPayload::PriceDiscovered(price_payload()?)
+            }
+            ItemEventTypeRecord::PriceDropped => ItemEventPayload::PriceDropped(price_payload()?),
+            ItemEventTypeRecord::PriceIncreased => {
+                ItemEventPayload::PriceIncreased(price_payload()?)
+            }
+            ItemEventTypeRecord::Translated => {
+                ItemEventPayload::Translated(ItemTranslatedEventPayload {
+                    shop_id: record.shop_id.clone(),
+                    shops_item_id: record.shops_item_id.clone(),
+                    other_title: mk_other_title(&record),
+                    other_description: mk_other_description(&record),
+                    hash: record.hash,
+                })
+            }
+        };
+
+        Ok(ItemEvent {
+            aggregate_id: record.item_id,
+            event_id: record.event_id,
+            timestamp: record.timestamp,
+            payload,
+        })
+    }
+}
+
+fn mk_other_title(record: &ItemEventRecord) -> HashMap<Language, Title> {
+    [
+        (Language::De, &record.title_de),
+        (Language::En, &record.title_en),
+        (Language::Fr, &record.title_fr),
+        (Language::Es, &record.title_es),
+    ]
+    .into_iter()
+    .filter_map(|(language, title)| title.clone().map(|title| (language, title.into())))
+    .collect()
+}
+
+fn mk_other_description(record: &ItemEventRecord) -> HashMap<Language, Description> {
+    [
+        (Language::De, &record.description_de),
+        (Language::En, &record.description_en),
+        (Language::Fr, &record.description_fr),
+        (Language::Es, &record.description_es),
+    ]
+    .into_iter()
+    .filter_map(|(language, description)| {
+        description
+            .clone()
+            .map(|description| (language, description.into()))
+    })
+    .collect()
+}
+
+fn mk_other_price(record: &ItemEventRecord) -> HashMap<Currency, MonetaryAmount> {
+    [
+        (Currency::Eur, record.price_eur),
+        (Currency::Usd, record.price_usd),
+        (Currency::Gbp, record.price_gbp),
+        (Currency::Aud, record.price_aud),
+        (Currency::Cad, record.price_cad),
+        (Currency::Nzd, record.price_nzd),
+        (Currency::Chf, record.price_chf),
+        (Currency::Sek, record.price_sek),
+        (Currency::Dkk, record.price_dkk),
+        (Currency::Nok, record.price_nok),
+        (Currency::Pln, record.price_pln),
+        (Currency::Czk, record.price_czk),
+        (Currency::Jpy, record.price_jpy),
+    ]
+    .into_iter()
+    .filter_map(|(currency, amount)| amount.map(|amount| (currency, amount.into())))
+    .collect()
+}
+
 #[allow(clippy::too_many_arguments)]
 fn mk_state_event_record(
     item_state_record: ItemStateRecord,
@@ -315,6 +576,7 @@ fn mk_state_event_record(
     ItemEventRecord {
         pk,
         sk,
+        gsi_3_sk: None,
         item_id,
         event_id,
         event_type,
@@ -324,9 +586,13 @@ fn mk_state_event_record(
         title_native: None,
         title_de: None,
         title_en: None,
+        title_fr: None,
+        title_es: None,
         description_native: None,
         description_de: None,
         description_en: None,
+        description_fr: None,
+        description_es: None,
         price_native: None,
         price_eur: None,
         price_usd: None,
@@ -334,6 +600,14 @@ fn mk_state_event_record(
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
         state: Some(item_state_record),
         url: None,
         images: None,
@@ -356,6 +630,7 @@ fn mk_price_event_record(
 ) -> ItemEventRecord {
     ItemEventRecord {
         pk,
+        gsi_3_sk: Some(sk.clone()),
         sk,
         item_id,
         event_id,
@@ -366,9 +641,13 @@ fn mk_price_event_record(
         title_native: None,
         title_de: None,
         title_en: None,
+        title_fr: None,
+        title_es: None,
         description_native: None,
         description_de: None,
         description_en: None,
+        description_fr: None,
+        description_es: None,
         price_native: Some(item_price_change_event_payload.native_price.into()),
         price_eur: item_price_change_event_payload
             .other_price
@@ -400,6 +679,42 @@ fn mk_price_event_record(
             .get(&Currency::Nzd)
             .copied()
             .map(u64::from),
+        price_chf: item_price_change_event_payload
+            .other_price
+            .get(&Currency::Chf)
+            .copied()
+            .map(u64::from),
+        price_sek: item_price_change_event_payload
+            .other_price
+            .get(&Currency::Sek)
+            .copied()
+            .map(u64::from),
+        price_dkk: item_price_change_event_payload
+            .other_price
+            .get(&Currency::Dkk)
+            .copied()
+            .map(u64::from),
+        price_nok: item_price_change_event_payload
+            .other_price
+            .get(&Currency::Nok)
+            .copied()
+            .map(u64::from),
+        price_pln: item_price_change_event_payload
+            .other_price
+            .get(&Currency::Pln)
+            .copied()
+            .map(u64::from),
+        price_czk: item_price_change_event_payload
+            .other_price
+            .get(&Currency::Czk)
+            .copied()
+            .map(u64::from),
+        price_jpy: item_price_change_event_payload
+            .other_price
+            .get(&Currency::Jpy)
+            .copied()
+            .map(u64::from),
+        fx_rate_date: item_price_change_event_payload.fx_rate_date,
         state: None,
         url: None,
         images: None,
@@ -408,6 +723,63 @@ fn mk_price_event_record(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+fn mk_translated_event_record(
+    item_translated_event_payload: ItemTranslatedEventPayload,
+    pk: String,
+    sk: String,
+    item_id: ItemId,
+    event_id: EventId,
+    event_type: ItemEventTypeRecord,
+    shop_id: ShopId,
+    shops_item_id: ShopsItemId,
+    timestamp: OffsetDateTime,
+) -> ItemEventRecord {
+    let mut other_title = item_translated_event_payload.other_title;
+    let mut other_description = item_translated_event_payload.other_description;
+    ItemEventRecord {
+        pk,
+        sk,
+        gsi_3_sk: None,
+        item_id,
+        event_id,
+        event_type,
+        shop_id,
+        shops_item_id,
+        shop_name: None,
+        title_native: None,
+        title_de: other_title.remove(&Language::De).map(String::from),
+        title_en: other_title.remove(&Language::En).map(String::from),
+        title_fr: other_title.remove(&Language::Fr).map(String::from),
+        title_es: other_title.remove(&Language::Es).map(String::from),
+        description_native: None,
+        description_de: other_description.remove(&Language::De).map(String::from),
+        description_en: other_description.remove(&Language::En).map(String::from),
+        description_fr: other_description.remove(&Language::Fr).map(String::from),
+        description_es: other_description.remove(&Language::Es).map(String::from),
+        price_native: None,
+        price_eur: None,
+        price_usd: None,
+        price_gbp: None,
+        price_aud: None,
+        price_cad: None,
+        price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        state: None,
+        url: None,
+        images: None,
+        hash: item_translated_event_payload.hash,
+        timestamp,
+    }
+}
+
 #[cfg(feature = "test-data")]
 mod faker {
     use super::*;
@@ -431,5 +803,65 @@ mod faker {
         fn should_fake_get_item_event_record() {
             let _ = Faker.fake::<ItemEventRecord>();
         }
+
+        #[test]
+        fn should_index_only_events_carrying_price() {
+            for record in fake::vec![ItemEventRecord; 100] {
+                assert_eq!(
+                    record.price_native.as_ref().map(|_| &record.sk),
+                    record.gsi_3_sk.as_ref()
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::item_event_record::ItemEventRecord;
+    use crate::item_event_type_record::ItemEventTypeRecord;
+    use common::item_state::domain::ItemState;
+    use common::language::domain::Language;
+    use common::localized::Localized;
+    use item_core::item::Item;
+    use item_core::item_event::ItemEvent;
+    use std::collections::HashMap;
+    use url::Url;
+
+    #[test]
+    fn should_map_translated_event_to_record_and_back() {
+        let created = Item::create(
+            Default::default(),
+            Default::default(),
+            "Boop".into(),
+            Localized::new(Language::De, "Pickelhaube".into()),
+            HashMap::new(),
+            None,
+            HashMap::new(),
+            None,
+            HashMap::new(),
+            None,
+            ItemState::Available,
+            Url::parse("https://example.com").unwrap(),
+            vec![],
+        );
+        let translated = Item::from_events(vec![created])
+            .unwrap()
+            .translate(
+                HashMap::from([(Language::Fr, "Casque à pointe".into())]),
+                HashMap::from([(Language::En, "Very good".into())]),
+            )
+            .unwrap();
+
+        let record = ItemEventRecord::try_from(translated.clone()).unwrap();
+        assert_eq!(ItemEventTypeRecord::Translated, record.event_type);
+        assert_eq!(Some("Casque à pointe".to_string()), record.title_fr);
+        assert_eq!(Some("Very good".to_string()), record.description_en);
+        assert_eq!(None, record.title_de);
+        assert_eq!(None, record.state);
+        assert_eq!(None, record.price_native);
+
+        let actual = ItemEvent::try_from(record).unwrap();
+        assert_eq!(translated, actual);
     }
 }
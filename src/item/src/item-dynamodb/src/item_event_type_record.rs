@@ -1,4 +1,4 @@
-use item_core::item_event::ItemEventPayload;
+use item_core::item_event::{ItemEventPayload, ItemEventType};
 use serde::{Deserialize, Serialize};
 
 #[cfg_attr(feature = "test-data", derive(fake::Dummy))]
@@ -14,6 +14,7 @@ pub enum ItemEventTypeRecord {
     PriceDiscovered,
     PriceDropped,
     PriceIncreased,
+    Translated,
 }
 
 impl From<&ItemEventPayload> for ItemEventTypeRecord {
@@ -28,6 +29,24 @@ impl From<&ItemEventPayload> for ItemEventTypeRecord {
             ItemEventPayload::PriceDiscovered(_) => ItemEventTypeRecord::PriceDiscovered,
             ItemEventPayload::PriceDropped(_) => ItemEventTypeRecord::PriceDropped,
             ItemEventPayload::PriceIncreased(_) => ItemEventTypeRecord::PriceIncreased,
+            ItemEventPayload::Translated(_) => ItemEventTypeRecord::Translated,
+        }
+    }
+}
+
+impl From<ItemEventTypeRecord> for ItemEventType {
+    fn from(record: ItemEventTypeRecord) -> Self {
+        match record {
+            ItemEventTypeRecord::Created => ItemEventType::Created,
+            ItemEventTypeRecord::StateListed => ItemEventType::StateListed,
+            ItemEventTypeRecord::StateAvailable => ItemEventType::StateAvailable,
+            ItemEventTypeRecord::StateReserved => ItemEventType::StateReserved,
+            ItemEventTypeRecord::StateSold => ItemEventType::StateSold,
+            ItemEventTypeRecord::StateRemoved => ItemEventType::StateRemoved,
+            ItemEventTypeRecord::PriceDiscovered => ItemEventType::PriceDiscovered,
+            ItemEventTypeRecord::PriceDropped => ItemEventType::PriceDropped,
+            ItemEventTypeRecord::PriceIncreased => ItemEventType::PriceIncreased,
+            ItemEventTypeRecord::Translated => ItemEventType::Translated,
         }
     }
 }
@@ -47,6 +66,7 @@ mod tests {
     #[case(ItemEventTypeRecord::PriceDiscovered, "\"PRICE_DISCOVERED\"")]
     #[case(ItemEventTypeRecord::PriceDropped, "\"PRICE_DROPPED\"")]
     #[case(ItemEventTypeRecord::PriceIncreased, "\"PRICE_INCREASED\"")]
+    #[case(ItemEventTypeRecord::Translated, "\"TRANSLATED\"")]
     fn should_serialize_item_event_type_record_in_screaming_snake_case(
         #[case] item_state_record: ItemEventTypeRecord,
         #[case] expected: &str,
@@ -65,6 +85,7 @@ mod tests {
     #[case("\"PRICE_DISCOVERED\"", ItemEventTypeRecord::PriceDiscovered)]
     #[case("\"PRICE_DROPPED\"", ItemEventTypeRecord::PriceDropped)]
     #[case("\"PRICE_INCREASED\"", ItemEventTypeRecord::PriceIncreased)]
+    #[case("\"TRANSLATED\"", ItemEventTypeRecord::Translated)]
     fn should_deserialize_item_event_type_record_in_screaming_snake_case(
         #[case] currency: &str,
         #[case] expected: ItemEventTypeRecord,
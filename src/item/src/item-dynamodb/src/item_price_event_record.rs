@@ -0,0 +1,27 @@
+use crate::item_event_type_record::ItemEventTypeRecord;
+use common::price::record::PriceRecord;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// Projection of an [`ItemEventRecord`](crate::item_event_record::ItemEventRecord) carrying a
+/// price, as included in the sparse index `gsi_3`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemPriceEventRecord {
+    pub event_type: ItemEventTypeRecord,
+
+    pub price_native: PriceRecord,
+
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+}
+
+impl ItemPriceEventRecord {
+    /// Whether this is the item's first price, i.e. it was created with or discovered one. The
+    /// price can't be unset, so every later price event follows it.
+    pub fn is_first_price(&self) -> bool {
+        matches!(
+            self.event_type,
+            ItemEventTypeRecord::Created | ItemEventTypeRecord::PriceDiscovered
+        )
+    }
+}
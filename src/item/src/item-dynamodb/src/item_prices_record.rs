@@ -0,0 +1,292 @@
+use common::currency::domain::Currency;
+use common::event_id::EventId;
+use common::has_key::HasKey;
+use common::item_id::{ItemId, ItemKey};
+use common::price::domain::{FxRate, MonetaryAmountOverflowError, Price};
+use common::price::record::PriceRecord;
+use common::shop_id::ShopId;
+use common::shops_item_id::ShopsItemId;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+
+use crate::item_record::ItemRecord;
+
+/// Projection of an [`ItemRecord`] onto its prices, used for re-exchanging them with current rates.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemPricesRecord {
+    pub item_id: ItemId,
+
+    pub event_id: EventId,
+
+    pub shop_id: ShopId,
+
+    pub shops_item_id: ShopsItemId,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_native: Option<PriceRecord>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_eur: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_usd: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_gbp: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_aud: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_cad: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_nzd: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_chf: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_sek: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_dkk: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_nok: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_pln: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_czk: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_jpy: Option<u64>,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "common::serde::date::option"
+    )]
+    pub fx_rate_date: Option<Date>,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "time::serde::rfc3339::option"
+    )]
+    pub repriced: Option<OffsetDateTime>,
+}
+
+impl ItemPricesRecord {
+    /// Exchanges `price_native` into all other currencies anew.
+    ///
+    /// Returns `None` if there is no native price or none of the exchanged prices changed.
+    /// Otherwise, records the date of `fx_rate` and the time of repricing and drops prices it
+    /// can't exchange into.
+    pub fn reprice(
+        &self,
+        fx_rate: &impl FxRate,
+    ) -> Result<Option<ItemPricesRecord>, MonetaryAmountOverflowError> {
+        let Some(price_native) = self.price_native else {
+            return Ok(None);
+        };
+        let price = Price::from(price_native);
+        let other_price = fx_rate.exchange_all(price.currency, price.monetary_amount)?;
+        let exchanged = |currency| other_price.get(&currency).copied().map(u64::from);
+        let repriced = ItemPricesRecord {
+            price_eur: exchanged(Currency::Eur),
+            price_usd: exchanged(Currency::Usd),
+            price_gbp: exchanged(Currency::Gbp),
+            price_aud: exchanged(Currency::Aud),
+            price_cad: exchanged(Currency::Cad),
+            price_nzd: exchanged(Currency::Nzd),
+            price_chf: exchanged(Currency::Chf),
+            price_sek: exchanged(Currency::Sek),
+            price_dkk: exchanged(Currency::Dkk),
+            price_nok: exchanged(Currency::Nok),
+            price_pln: exchanged(Currency::Pln),
+            price_czk: exchanged(Currency::Czk),
+            price_jpy: exchanged(Currency::Jpy),
+            fx_rate_date: self.fx_rate_date,
+            ..self.clone()
+        };
+        if &repriced == self {
+            Ok(None)
+        } else {
+            Ok(Some(ItemPricesRecord {
+                fx_rate_date: fx_rate.snapshot_date(),
+                repriced: Some(OffsetDateTime::now_utc()),
+                ..repriced
+            }))
+        }
+    }
+}
+
+impl HasKey for ItemPricesRecord {
+    type Key = ItemKey;
+
+    fn key(&self) -> Self::Key {
+        ItemKey {
+            shop_id: self.shop_id.clone(),
+            shops_item_id: self.shops_item_id.clone(),
+        }
+    }
+}
+
+impl From<ItemRecord> for ItemPricesRecord {
+    fn from(record: ItemRecord) -> Self {
+        ItemPricesRecord {
+            item_id: record.item_id,
+            event_id: record.event_id,
+            shop_id: record.shop_id,
+            shops_item_id: record.shops_item_id,
+            price_native: record.price_native,
+            price_eur: record.price_eur,
+            price_usd: record.price_usd,
+            price_gbp: record.price_gbp,
+            price_aud: record.price_aud,
+            price_cad: record.price_cad,
+            price_nzd: record.price_nzd,
+            price_chf: record.price_chf,
+            price_sek: record.price_sek,
+            price_dkk: record.price_dkk,
+            price_nok: record.price_nok,
+            price_pln: record.price_pln,
+            price_czk: record.price_czk,
+            price_jpy: record.price_jpy,
+            fx_rate_date: record.fx_rate_date,
+            repriced: record.repriced,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::item_prices_record::ItemPricesRecord;
+    use common::currency::domain::Currency;
+    use common::currency::record::CurrencyRecord;
+    use common::event_id::EventId;
+    use common::item_id::ItemId;
+    use common::price::domain::{FixedFxRate, FxRate, MonetaryAmount, MonetaryAmountOverflowError};
+    use common::price::record::PriceRecord;
+    use common::shop_id::ShopId;
+    use std::collections::HashMap;
+
+    fn mk_record(price_native: Option<PriceRecord>) -> ItemPricesRecord {
+        ItemPricesRecord {
+            item_id: ItemId::new(),
+            event_id: EventId::new(),
+            shop_id: ShopId::new(),
+            shops_item_id: "123".into(),
+            price_native,
+            price_eur: None,
+            price_usd: None,
+            price_gbp: None,
+            price_aud: None,
+            price_cad: None,
+            price_nzd: None,
+            price_chf: None,
+            price_sek: None,
+            price_dkk: None,
+            price_nok: None,
+            price_pln: None,
+            price_czk: None,
+            price_jpy: None,
+            fx_rate_date: None,
+            repriced: None,
+        }
+    }
+
+    /// Exchanges into every currency but JPY.
+    struct FxRateWithoutJpy;
+
+    impl FxRate for FxRateWithoutJpy {
+        fn exchange(
+            &self,
+            from_currency: Currency,
+            to_currency: Currency,
+            from_amount: MonetaryAmount,
+        ) -> Result<MonetaryAmount, MonetaryAmountOverflowError> {
+            FixedFxRate().exchange(from_currency, to_currency, from_amount)
+        }
+
+        fn exchange_all(
+            &self,
+            from_currency: Currency,
+            from_amount: MonetaryAmount,
+        ) -> Result<HashMap<Currency, MonetaryAmount>, MonetaryAmountOverflowError> {
+            let mut exchanged = FixedFxRate().exchange_all(from_currency, from_amount)?;
+            exchanged.remove(&Currency::Jpy);
+            Ok(exchanged)
+        }
+    }
+
+    #[test]
+    fn should_reprice_when_exchanged_prices_are_stale() {
+        let mut record = mk_record(Some(PriceRecord {
+            currency: CurrencyRecord::Eur,
+            amount: 10000,
+        }));
+        record.price_eur = Some(10000);
+        record.price_usd = Some(11000);
+
+        let actual = record.reprice(&FixedFxRate()).unwrap().unwrap();
+
+        assert_eq!(record.event_id, actual.event_id);
+        assert_eq!(record.price_native, actual.price_native);
+        assert_eq!(Some(10000), actual.price_eur);
+        assert_eq!(Some(11670), actual.price_usd);
+        assert_eq!(Some(8678), actual.price_gbp);
+        assert_eq!(Some(17780), actual.price_aud);
+        assert_eq!(Some(15970), actual.price_cad);
+        assert_eq!(Some(19470), actual.price_nzd);
+        assert_eq!(Some(9314), actual.price_chf);
+        assert_eq!(Some(112150), actual.price_sek);
+        assert_eq!(Some(74630), actual.price_dkk);
+        assert_eq!(Some(118570), actual.price_nok);
+        assert_eq!(Some(42645), actual.price_pln);
+        assert_eq!(Some(246450), actual.price_czk);
+        assert_eq!(Some(17223), actual.price_jpy);
+        assert_eq!(FixedFxRate().snapshot_date(), actual.fx_rate_date);
+        assert!(actual.repriced.is_some());
+    }
+
+    #[test]
+    fn should_drop_exchanged_price_when_currency_is_missing_from_fx_rate() {
+        let mut record = mk_record(Some(PriceRecord {
+            currency: CurrencyRecord::Eur,
+            amount: 10000,
+        }));
+        record.price_eur = Some(10000);
+        record.price_jpy = Some(17000);
+
+        let actual = record.reprice(&FxRateWithoutJpy).unwrap().unwrap();
+
+        assert_eq!(Some(10000), actual.price_eur);
+        assert_eq!(None, actual.price_jpy);
+    }
+
+    #[test]
+    fn should_not_reprice_when_exchanged_prices_are_current() {
+        let record = mk_record(Some(PriceRecord {
+            currency: CurrencyRecord::Eur,
+            amount: 10000,
+        }));
+        let current = record.reprice(&FixedFxRate()).unwrap().unwrap();
+
+        let actual = current.reprice(&FixedFxRate()).unwrap();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn should_not_reprice_when_price_native_is_missing() {
+        let record = mk_record(None);
+
+        let actual = record.reprice(&FixedFxRate()).unwrap();
+
+        assert_eq!(None, actual);
+    }
+}
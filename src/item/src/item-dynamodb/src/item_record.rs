@@ -17,10 +17,11 @@ use common::shop_id::ShopId;
 use common::shops_item_id::ShopsItemId;
 use field::field;
 use item_core::hash::ItemHash;
-use item_core::item::Item;
+use item_core::item::{ApplyItemEventError, Item};
+use item_core::item_event::ItemEvent;
 use serde::{Deserialize, Serialize};
-use time::OffsetDateTime;
 use time::format_description::well_known;
+use time::{Date, OffsetDateTime};
 use url::Url;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -51,6 +52,12 @@ pub struct ItemRecord {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub title_en: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_fr: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_es: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub description_native: Option<TextRecord>,
 
@@ -60,6 +67,12 @@ pub struct ItemRecord {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub description_en: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_fr: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_es: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub price_native: Option<PriceRecord>,
 
@@ -81,6 +94,45 @@ pub struct ItemRecord {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub price_nzd: Option<u64>,
 
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_chf: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_sek: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_dkk: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_nok: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_pln: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_czk: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_jpy: Option<u64>,
+
+    /// Date of the FX rates the prices have been exchanged with, either by the latest price-event
+    /// or by repricing.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "common::serde::date::option"
+    )]
+    pub fx_rate_date: Option<Date>,
+
+    /// Time the exchanged prices have last been recomputed by repricing, which leaves `updated`
+    /// untouched. Missing if they never have been.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "time::serde::rfc3339::option"
+    )]
+    pub repriced: Option<OffsetDateTime>,
+
     pub state: ItemStateRecord,
 
     pub url: Url,
@@ -95,6 +147,54 @@ pub struct ItemRecord {
 
     #[serde(with = "time::serde::rfc3339")]
     pub updated: OffsetDateTime,
+
+    /// `updated` as Unix-timestamp in nanoseconds. See [`mk_updated_nanos`].
+    ///
+    /// Missing for records materialized before it has been introduced.
+    #[serde(default)]
+    pub updated_nanos: i64,
+}
+
+/// Numeric representation of `updated` that conditional updates compare against.
+///
+/// RFC3339-strings don't order lexicographically, as the length of their fractional seconds varies.
+pub fn mk_updated_nanos(updated: &OffsetDateTime) -> i64 {
+    i64::try_from(updated.unix_timestamp_nanos()).unwrap_or(i64::MAX)
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ReplayItemEventRecordsError {
+    #[error("PersistenceMappingError: {0}")]
+    PersistenceMappingError(#[from] PersistenceMappingError),
+
+    #[error("ApplyItemEventError: {0}")]
+    ApplyItemEventError(#[from] ApplyItemEventError),
+}
+
+impl ItemRecord {
+    /// Rebuilds the materialized record by replaying the given event-records.
+    ///
+    /// The event-records must be in chronological order, which is the natural sort-order of
+    /// their sort-keys, and start with the Created-event.
+    pub fn try_from_event_records(
+        event_records: impl IntoIterator<Item = ItemEventRecord>,
+    ) -> Result<ItemRecord, ReplayItemEventRecordsError> {
+        let mut fx_rate_date = None;
+        let events = event_records
+            .into_iter()
+            .inspect(|event_record| {
+                if event_record.price_native.is_some() {
+                    fx_rate_date = event_record.fx_rate_date;
+                }
+            })
+            .map(ItemEvent::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let item = Item::from_events(events)?;
+        let mut record = ItemRecord::try_from(item)?;
+        record.fx_rate_date = fx_rate_date;
+
+        Ok(record)
+    }
 }
 
 impl HasKey for ItemRecord {
@@ -110,18 +210,30 @@ impl HasKey for ItemRecord {
 
 impl From<ItemRecord> for Item {
     fn from(record: ItemRecord) -> Self {
-        let mut other_title = HashMap::with_capacity(2);
+        let mut other_title = HashMap::with_capacity(4);
         if let Some(title_en) = record.title_en {
             other_title.insert(Language::En, title_en.into());
         }
+        if let Some(title_fr) = record.title_fr {
+            other_title.insert(Language::Fr, title_fr.into());
+        }
+        if let Some(title_es) = record.title_es {
+            other_title.insert(Language::Es, title_es.into());
+        }
         if let Some(title_de) = record.title_de {
             other_title.insert(Language::De, title_de.into());
         }
 
-        let mut other_description = HashMap::with_capacity(2);
+        let mut other_description = HashMap::with_capacity(4);
         if let Some(description_en) = record.description_en {
             other_description.insert(Language::En, description_en.into());
         }
+        if let Some(description_fr) = record.description_fr {
+            other_description.insert(Language::Fr, description_fr.into());
+        }
+        if let Some(description_es) = record.description_es {
+            other_description.insert(Language::Es, description_es.into());
+        }
         if let Some(description_de) = record.description_de {
             other_description.insert(Language::De, description_de.into());
         }
@@ -145,6 +257,27 @@ impl From<ItemRecord> for Item {
         if let Some(price_eur) = record.price_nzd {
             other_price.insert(Currency::Nzd, price_eur.into());
         }
+        if let Some(price_eur) = record.price_chf {
+            other_price.insert(Currency::Chf, price_eur.into());
+        }
+        if let Some(price_eur) = record.price_sek {
+            other_price.insert(Currency::Sek, price_eur.into());
+        }
+        if let Some(price_eur) = record.price_dkk {
+            other_price.insert(Currency::Dkk, price_eur.into());
+        }
+        if let Some(price_eur) = record.price_nok {
+            other_price.insert(Currency::Nok, price_eur.into());
+        }
+        if let Some(price_eur) = record.price_pln {
+            other_price.insert(Currency::Pln, price_eur.into());
+        }
+        if let Some(price_eur) = record.price_czk {
+            other_price.insert(Currency::Czk, price_eur.into());
+        }
+        if let Some(price_eur) = record.price_jpy {
+            other_price.insert(Currency::Jpy, price_eur.into());
+        }
 
         Item {
             item_id: record.item_id,
@@ -173,6 +306,141 @@ impl From<ItemRecord> for Item {
     }
 }
 
+impl TryFrom<Item> for ItemRecord {
+    type Error = PersistenceMappingError;
+
+    fn try_from(domain: Item) -> Result<Self, Self::Error> {
+        let mut domain = domain;
+        let updated_str = domain.updated.format(&well_known::Rfc3339)?;
+
+        domain.other_title.insert(
+            domain.native_title.localization,
+            domain.native_title.payload.clone(),
+        );
+        if let Some(description_native) = domain.native_description.as_ref() {
+            domain.other_description.insert(
+                description_native.localization,
+                description_native.payload.clone(),
+            );
+        }
+
+        let record = ItemRecord {
+            pk: format!(
+                "item#shop_id#{}#shops_item_id#{}",
+                domain.shop_id, domain.shops_item_id
+            ),
+            sk: "item#materialized".to_string(),
+            gsi_1_pk: format!("shop_id#{}", domain.shop_id),
+            gsi_1_sk: format!("updated#{updated_str}"),
+            item_id: domain.item_id,
+            event_id: domain.event_id,
+            shop_id: domain.shop_id,
+            shops_item_id: domain.shops_item_id,
+            shop_name: domain.shop_name.into(),
+            title_native: domain.native_title.into(),
+            title_de: domain.other_title.remove(&Language::De).map(String::from),
+            title_en: domain.other_title.remove(&Language::En).map(String::from),
+            title_fr: domain.other_title.remove(&Language::Fr).map(String::from),
+            title_es: domain.other_title.remove(&Language::Es).map(String::from),
+            description_native: domain.native_description.map(TextRecord::from),
+            description_de: domain
+                .other_description
+                .remove(&Language::De)
+                .map(String::from),
+            description_en: domain
+                .other_description
+                .remove(&Language::En)
+                .map(String::from),
+            description_fr: domain
+                .other_description
+                .remove(&Language::Fr)
+                .map(String::from),
+            description_es: domain
+                .other_description
+                .remove(&Language::Es)
+                .map(String::from),
+            price_native: domain.native_price.map(PriceRecord::from),
+            price_eur: domain
+                .other_price
+                .get(&Currency::Eur)
+                .copied()
+                .map(u64::from),
+            price_usd: domain
+                .other_price
+                .get(&Currency::Usd)
+                .copied()
+                .map(u64::from),
+            price_gbp: domain
+                .other_price
+                .get(&Currency::Gbp)
+                .copied()
+                .map(u64::from),
+            price_aud: domain
+                .other_price
+                .get(&Currency::Aud)
+                .copied()
+                .map(u64::from),
+            price_cad: domain
+                .other_price
+                .get(&Currency::Cad)
+                .copied()
+                .map(u64::from),
+            price_nzd: domain
+                .other_price
+                .get(&Currency::Nzd)
+                .copied()
+                .map(u64::from),
+            price_chf: domain
+                .other_price
+                .get(&Currency::Chf)
+                .copied()
+                .map(u64::from),
+            price_sek: domain
+                .other_price
+                .get(&Currency::Sek)
+                .copied()
+                .map(u64::from),
+            price_dkk: domain
+                .other_price
+                .get(&Currency::Dkk)
+                .copied()
+                .map(u64::from),
+            price_nok: domain
+                .other_price
+                .get(&Currency::Nok)
+                .copied()
+                .map(u64::from),
+            price_pln: domain
+                .other_price
+                .get(&Currency::Pln)
+                .copied()
+                .map(u64::from),
+            price_czk: domain
+                .other_price
+                .get(&Currency::Czk)
+                .copied()
+                .map(u64::from),
+            price_jpy: domain
+                .other_price
+                .get(&Currency::Jpy)
+                .copied()
+                .map(u64::from),
+            // not tracked by the domain, see ItemRecord::try_from_event_records
+            fx_rate_date: None,
+            repriced: None,
+            state: domain.state.into(),
+            url: domain.url,
+            images: domain.images,
+            hash: domain.hash,
+            created: domain.created,
+            updated: domain.updated,
+            updated_nanos: mk_updated_nanos(&domain.updated),
+        };
+
+        Ok(record)
+    }
+}
+
 impl TryFrom<ItemEventRecord> for ItemRecord {
     type Error = PersistenceMappingError;
 
@@ -195,9 +463,13 @@ impl TryFrom<ItemEventRecord> for ItemRecord {
             })?,
             title_de: event_record.title_de,
             title_en: event_record.title_en,
+            title_fr: event_record.title_fr,
+            title_es: event_record.title_es,
             description_native: event_record.description_native,
             description_de: event_record.description_de,
             description_en: event_record.description_en,
+            description_fr: event_record.description_fr,
+            description_es: event_record.description_es,
             price_native: event_record.price_native,
             price_eur: event_record.price_eur,
             price_usd: event_record.price_usd,
@@ -205,6 +477,15 @@ impl TryFrom<ItemEventRecord> for ItemRecord {
             price_aud: event_record.price_aud,
             price_cad: event_record.price_cad,
             price_nzd: event_record.price_nzd,
+            price_chf: event_record.price_chf,
+            price_sek: event_record.price_sek,
+            price_dkk: event_record.price_dkk,
+            price_nok: event_record.price_nok,
+            price_pln: event_record.price_pln,
+            price_czk: event_record.price_czk,
+            price_jpy: event_record.price_jpy,
+            fx_rate_date: event_record.fx_rate_date,
+            repriced: None,
             state: event_record
                 .state
                 .ok_or_else(|| MissingPersistenceField::new(field!(state@ItemEventRecord)))?,
@@ -215,6 +496,7 @@ impl TryFrom<ItemEventRecord> for ItemRecord {
             hash: event_record.hash,
             created: event_record.timestamp,
             updated: event_record.timestamp,
+            updated_nanos: mk_updated_nanos(&event_record.timestamp),
         };
 
         Ok(record)
@@ -256,12 +538,16 @@ mod faker {
                 ),
                 title_de: Some(config.fake_with_rng::<Title, _>(rng).to_string()),
                 title_en: Some(config.fake_with_rng::<Title, _>(rng).to_string()),
+                title_fr: Some(config.fake_with_rng::<Title, _>(rng).to_string()),
+                title_es: Some(config.fake_with_rng::<Title, _>(rng).to_string()),
                 description_native: Some(TextRecord::new(
                     config.fake_with_rng::<Description, _>(rng).to_string(),
                     config.fake_with_rng(rng),
                 )),
                 description_de: Some(config.fake_with_rng::<Description, _>(rng).to_string()),
                 description_en: Some(config.fake_with_rng::<Description, _>(rng).to_string()),
+                description_fr: Some(config.fake_with_rng::<Description, _>(rng).to_string()),
+                description_es: Some(config.fake_with_rng::<Description, _>(rng).to_string()),
                 price_native,
                 price_eur: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_usd: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
@@ -269,6 +555,15 @@ mod faker {
                 price_aud: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_cad: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_nzd: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_chf: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_sek: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_dkk: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_nok: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_pln: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_czk: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_jpy: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                fx_rate_date: Some(now.date()),
+                repriced: None,
                 state,
                 url: Url::parse(&format!(
                     "https://foo.bar/item/{}",
@@ -295,6 +590,7 @@ mod faker {
                 hash: ItemHash::new(&price_native.map(Price::from), &state.into()),
                 created: now,
                 updated: now,
+                updated_nanos: mk_updated_nanos(&now),
             }
         }
     }
@@ -310,3 +606,143 @@ mod faker {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::item_event_record::ItemEventRecord;
+    use crate::item_record::{ItemRecord, ReplayItemEventRecordsError, mk_updated_nanos};
+    use crate::item_state_record::ItemStateRecord;
+    use common::currency::domain::Currency;
+    use common::error::mapping_error::PersistenceMappingError;
+    use common::item_state::domain::ItemState;
+    use common::language::domain::Language;
+    use common::localized::Localized;
+    use common::price::domain::{FixedFxRate, Price};
+    use common::shop_id::ShopId;
+    use common::shops_item_id::ShopsItemId;
+    use item_core::item::{ApplyItemEventError, Item};
+    use item_core::item_event::ItemEvent;
+    use std::collections::HashMap;
+    use time::format_description::well_known::Rfc3339;
+    use url::Url;
+
+    fn mk_created_event() -> ItemEvent {
+        Item::create(
+            ShopId::new(),
+            ShopsItemId::new(),
+            "Boop".into(),
+            Localized::new(Language::De, "Der Boop".into()),
+            HashMap::from([(Language::En, "The Boop".into())]),
+            None,
+            HashMap::new(),
+            Some(Price::new(10000u64.into(), Currency::Eur)),
+            HashMap::from([(Currency::Eur, 10000u64.into())]),
+            None,
+            ItemState::Available,
+            Url::parse("https://example.com").unwrap(),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn should_rebuild_item_record_from_event_records() {
+        let created = mk_created_event();
+        let mut item = Item::from_events(vec![created.clone()]).unwrap();
+        let price_event = item
+            .change_price(Price::new(8000u64.into(), Currency::Eur), &FixedFxRate())
+            .unwrap();
+        let state_event = item.change_state(ItemState::Sold).unwrap();
+        let event_records = [created, price_event, state_event]
+            .into_iter()
+            .map(ItemEventRecord::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let mut expected = ItemRecord::try_from(event_records[0].clone()).unwrap();
+        for update in &event_records[1..] {
+            expected.event_id = update.event_id;
+            if update.price_native.is_some() {
+                expected.price_native = update.price_native;
+                expected.price_eur = update.price_eur;
+                expected.price_usd = update.price_usd;
+                expected.price_gbp = update.price_gbp;
+                expected.price_aud = update.price_aud;
+                expected.price_cad = update.price_cad;
+                expected.price_nzd = update.price_nzd;
+                expected.price_chf = update.price_chf;
+                expected.price_sek = update.price_sek;
+                expected.price_dkk = update.price_dkk;
+                expected.price_nok = update.price_nok;
+                expected.price_pln = update.price_pln;
+                expected.price_czk = update.price_czk;
+                expected.price_jpy = update.price_jpy;
+                expected.fx_rate_date = update.fx_rate_date;
+            }
+            if let Some(state) = update.state {
+                expected.state = state;
+            }
+            expected.hash = update.hash;
+            expected.updated = update.timestamp;
+            expected.updated_nanos = mk_updated_nanos(&update.timestamp);
+            expected.gsi_1_sk = format!("updated#{}", update.timestamp.format(&Rfc3339).unwrap());
+        }
+        let actual = ItemRecord::try_from_event_records(event_records).unwrap();
+
+        assert_eq!(expected, actual);
+        assert_eq!(ItemStateRecord::Sold, actual.state);
+        assert_eq!(Some("Der Boop".to_string()), actual.title_de);
+        assert_eq!(Some("The Boop".to_string()), actual.title_en);
+        assert_eq!(None, actual.title_fr);
+        assert_eq!(None, actual.title_es);
+    }
+
+    #[test]
+    fn should_match_item_record_for_only_created_event_record() {
+        let created = ItemEventRecord::try_from(mk_created_event()).unwrap();
+
+        let expected = ItemRecord::try_from(created.clone()).unwrap();
+        let actual = ItemRecord::try_from_event_records(vec![created]).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn should_err_when_replaying_without_created_event_record() {
+        let mut item = Item::from_events(vec![mk_created_event()]).unwrap();
+        let state_event = item.change_state(ItemState::Sold).unwrap();
+        let event_records = vec![ItemEventRecord::try_from(state_event).unwrap()];
+
+        let actual = ItemRecord::try_from_event_records(event_records);
+
+        assert!(matches!(
+            actual,
+            Err(ReplayItemEventRecordsError::ApplyItemEventError(
+                ApplyItemEventError::NotCreated(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn should_err_when_replaying_price_event_record_without_price() {
+        let created = mk_created_event();
+        let mut item = Item::from_events(vec![created.clone()]).unwrap();
+        let price_event = item
+            .change_price(Price::new(8000u64.into(), Currency::Eur), &FixedFxRate())
+            .unwrap();
+        let mut price_event_record = ItemEventRecord::try_from(price_event).unwrap();
+        price_event_record.price_native = None;
+        let event_records = vec![
+            ItemEventRecord::try_from(created).unwrap(),
+            price_event_record,
+        ];
+
+        let actual = ItemRecord::try_from_event_records(event_records);
+
+        assert!(matches!(
+            actual,
+            Err(ReplayItemEventRecordsError::PersistenceMappingError(
+                PersistenceMappingError::MissingPersistenceField(_)
+            ))
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+use common::{has_key::HasKey, item_id::ItemKey, shop_id::ShopId, shops_item_id::ShopsItemId};
+use serde::{Deserialize, Serialize};
+
+use crate::item_record::ItemRecord;
+use crate::item_state_record::ItemStateRecord;
+
+/// Projection of an [`ItemRecord`] onto its state, which `gsi_1` doesn't include.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemSummaryState {
+    pub shop_id: ShopId,
+    pub shops_item_id: ShopsItemId,
+    pub state: ItemStateRecord,
+}
+
+impl HasKey for ItemSummaryState {
+    type Key = ItemKey;
+
+    fn key(&self) -> Self::Key {
+        ItemKey {
+            shop_id: self.shop_id.clone(),
+            shops_item_id: self.shops_item_id.clone(),
+        }
+    }
+}
+
+impl From<ItemRecord> for ItemSummaryState {
+    fn from(value: ItemRecord) -> Self {
+        ItemSummaryState {
+            shop_id: value.shop_id,
+            shops_item_id: value.shops_item_id,
+            state: value.state,
+        }
+    }
+}
+
+#[cfg(feature = "test-data")]
+mod faker {
+    use super::*;
+    use fake::{Dummy, Fake, Faker, Rng};
+
+    impl Dummy<Faker> for ItemSummaryState {
+        fn dummy_with_rng<R: Rng + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+            ItemSummaryState {
+                shop_id: config.fake_with_rng(rng),
+                shops_item_id: config.fake_with_rng(rng),
+                state: config.fake_with_rng(rng),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::item_summary_state::ItemSummaryState;
+        use fake::{Fake, Faker};
+
+        #[test]
+        fn should_fake_item_summary_state() {
+            let _ = Faker.fake::<ItemSummaryState>();
+        }
+    }
+}
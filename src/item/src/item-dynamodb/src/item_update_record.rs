@@ -2,15 +2,40 @@ use common::event_id::EventId;
 use common::price::record::PriceRecord;
 use item_core::hash::ItemHash;
 use serde::Serialize;
-use time::OffsetDateTime;
+use time::{Date, OffsetDateTime};
 
 use crate::item_event_record::ItemEventRecord;
+use crate::item_record::mk_updated_nanos;
 use crate::item_state_record::ItemStateRecord;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ItemRecordUpdate {
     pub event_id: EventId,
 
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_de: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_en: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_fr: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_es: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_de: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_en: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_fr: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_es: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub price_native: Option<PriceRecord>,
 
@@ -32,6 +57,34 @@ pub struct ItemRecordUpdate {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub price_nzd: Option<u64>,
 
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_chf: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_sek: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_dkk: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_nok: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_pln: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_czk: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_jpy: Option<u64>,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "common::serde::date::option"
+    )]
+    pub fx_rate_date: Option<Date>,
+
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub state: Option<ItemStateRecord>,
 
@@ -39,12 +92,22 @@ pub struct ItemRecordUpdate {
 
     #[serde(with = "time::serde::rfc3339")]
     pub updated: OffsetDateTime,
+
+    pub updated_nanos: i64,
 }
 
 impl From<ItemEventRecord> for ItemRecordUpdate {
     fn from(event: ItemEventRecord) -> Self {
         ItemRecordUpdate {
             event_id: event.event_id,
+            title_de: event.title_de,
+            title_en: event.title_en,
+            title_fr: event.title_fr,
+            title_es: event.title_es,
+            description_de: event.description_de,
+            description_en: event.description_en,
+            description_fr: event.description_fr,
+            description_es: event.description_es,
             price_native: event.price_native,
             price_eur: event.price_eur,
             price_usd: event.price_usd,
@@ -52,9 +115,18 @@ impl From<ItemEventRecord> for ItemRecordUpdate {
             price_aud: event.price_aud,
             price_cad: event.price_cad,
             price_nzd: event.price_nzd,
+            price_chf: event.price_chf,
+            price_sek: event.price_sek,
+            price_dkk: event.price_dkk,
+            price_nok: event.price_nok,
+            price_pln: event.price_pln,
+            price_czk: event.price_czk,
+            price_jpy: event.price_jpy,
+            fx_rate_date: event.fx_rate_date,
             state: event.state,
             hash: event.hash,
             updated: event.timestamp,
+            updated_nanos: mk_updated_nanos(&event.timestamp),
         }
     }
 }
@@ -70,9 +142,18 @@ mod faker {
             let price_native: Option<PriceRecord> =
                 Some(config.fake_with_rng::<Price, _>(rng).into());
             let state: ItemStateRecord = config.fake_with_rng(rng);
+            let now = OffsetDateTime::now_utc();
 
             ItemRecordUpdate {
                 event_id: config.fake_with_rng(rng),
+                title_de: None,
+                title_en: None,
+                title_fr: None,
+                title_es: None,
+                description_de: None,
+                description_en: None,
+                description_fr: None,
+                description_es: None,
                 price_native,
                 price_eur: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_usd: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
@@ -80,9 +161,18 @@ mod faker {
                 price_aud: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_cad: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_nzd: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_chf: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_sek: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_dkk: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_nok: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_pln: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_czk: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_jpy: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                fx_rate_date: Some(now.date()),
                 state: Some(state),
                 hash: ItemHash::new(&price_native.map(Price::from), &state.into()),
-                updated: OffsetDateTime::now_utc(),
+                updated: now,
+                updated_nanos: mk_updated_nanos(&now),
             }
         }
     }
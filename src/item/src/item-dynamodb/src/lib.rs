@@ -1,8 +1,12 @@
+pub mod fx_rate_record;
 pub mod item_event_record;
 pub mod item_event_type_record;
+pub mod item_price_event_record;
+pub mod item_prices_record;
 pub mod item_record;
 pub mod item_state_record;
 pub mod item_summary_hash;
+pub mod item_summary_state;
 pub mod item_update_record;
 #[cfg(feature = "repository")]
 pub mod repository;
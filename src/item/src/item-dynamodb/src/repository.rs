@@ -1,6 +1,10 @@
+use crate::fx_rate_record::{FX_RATE_SNAPSHOT_PK, FxRateSnapshotRecord};
 use crate::item_event_record::ItemEventRecord;
+use crate::item_price_event_record::ItemPriceEventRecord;
+use crate::item_prices_record::ItemPricesRecord;
 use crate::item_record::ItemRecord;
 use crate::item_summary_hash::ItemSummaryHash;
+use crate::item_summary_state::ItemSummaryState;
 use crate::item_update_record::ItemRecordUpdate;
 use async_trait::async_trait;
 use aws_sdk_dynamodb::Client;
@@ -9,16 +13,47 @@ use aws_sdk_dynamodb::error::SdkError;
 use aws_sdk_dynamodb::operation::batch_get_item::BatchGetItemError;
 use aws_sdk_dynamodb::operation::batch_write_item::{BatchWriteItemError, BatchWriteItemOutput};
 use aws_sdk_dynamodb::operation::get_item::GetItemError;
+use aws_sdk_dynamodb::operation::put_item::{PutItemError, PutItemOutput};
 use aws_sdk_dynamodb::operation::query::QueryError;
+use aws_sdk_dynamodb::operation::scan::ScanError;
 use aws_sdk_dynamodb::operation::update_item::{UpdateItemError, UpdateItemOutput};
-use aws_sdk_dynamodb::types::{AttributeValue, KeysAndAttributes};
+use aws_sdk_dynamodb::types::{
+    AttributeValue, KeysAndAttributes, ReturnValuesOnConditionCheckFailure,
+};
 use common::batch::Batch;
 use common::batch::dynamodb::BatchGetItemResult;
 use common::item_id::ItemKey;
+use common::price::fx_rate::FxRateSnapshot;
+use common::serde::date;
 use common::shop_id::ShopId;
 use common::shops_item_id::ShopsItemId;
 use std::collections::HashMap;
-use tracing::error;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tracing::{error, info, warn};
+
+/// Page of an item's [`ItemEventRecord`]s in chronological order.
+///
+/// `last_evaluated_timestamp` is present if DynamoDB stopped early, i.e. if more records may exist.
+/// Passing it as `exclusive_start_timestamp` queries the next page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemEventRecordPage {
+    pub items: Vec<ItemEventRecord>,
+    pub last_evaluated_timestamp: Option<OffsetDateTime>,
+}
+
+/// Page of materialized items' [`ItemPricesRecord`]s in no particular order.
+///
+/// `last_evaluated_key` is present if DynamoDB stopped early, i.e. if more records may exist.
+/// Passing it as `exclusive_start_key` scans the next page.
+/// It's the key of the last record evaluated, which need not be an item's, hence kept opaque.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemPricesRecordPage {
+    pub items: Vec<ItemPricesRecord>,
+    pub last_evaluated_key: Option<HashMap<String, AttributeValue>>,
+}
 
 #[async_trait]
 #[allow(clippy::result_large_err)]
@@ -34,6 +69,9 @@ pub trait ItemDynamoDbRepository {
         item_records: Batch<ItemRecord, 25>,
     ) -> Result<BatchWriteItemOutput, SdkError<BatchWriteItemError, HttpResponse>>;
 
+    /// Only applies the update if it is newer than the materialized record.
+    ///
+    /// Otherwise, fails with a conditional check failure. See [`is_stale_update`].
     async fn update_item_record(
         &self,
         shop_id: &ShopId,
@@ -57,11 +95,66 @@ pub trait ItemDynamoDbRepository {
         item_keys: &Batch<ItemKey, 100>,
     ) -> Result<BatchGetItemResult<ItemKey, ItemKey>, SdkError<BatchGetItemError, HttpResponse>>;
 
+    async fn get_item_states(
+        &self,
+        item_keys: &Batch<ItemKey, 100>,
+    ) -> Result<
+        BatchGetItemResult<ItemSummaryState, ItemKey>,
+        SdkError<BatchGetItemError, HttpResponse>,
+    >;
+
     async fn query_item_hashes(
         &self,
         shop_id: &ShopId,
         scan_index_forward: bool,
     ) -> Result<Vec<ItemSummaryHash>, SdkError<QueryError, HttpResponse>>;
+
+    async fn query_item_event_records(
+        &self,
+        shop_id: &ShopId,
+        shops_item_id: &ShopsItemId,
+        exclusive_start_timestamp: Option<OffsetDateTime>,
+        limit: u16,
+    ) -> Result<ItemEventRecordPage, SdkError<QueryError, HttpResponse>>;
+
+    /// All of an item's events carrying a price in chronological order, skipping all others.
+    async fn query_item_price_event_records(
+        &self,
+        shop_id: &ShopId,
+        shops_item_id: &ShopsItemId,
+    ) -> Result<Vec<ItemPriceEventRecord>, SdkError<QueryError, HttpResponse>>;
+
+    /// Scans the whole table, so `limit` bounds the records evaluated rather than those returned.
+    async fn scan_item_prices_records(
+        &self,
+        exclusive_start_key: Option<HashMap<String, AttributeValue>>,
+        limit: u16,
+    ) -> Result<ItemPricesRecordPage, SdkError<ScanError, HttpResponse>>;
+
+    /// Overwrites only the exchanged prices, `fx_rate_date` and `repriced`, leaving `event_id` and
+    /// `updated` untouched. Exchanged prices missing from `item_prices_record` are removed.
+    ///
+    /// Only applies if the materialized record is still at the `event_id` of `item_prices_record`.
+    /// Otherwise, fails with a conditional check failure. See [`is_stale_update`].
+    async fn update_item_record_prices(
+        &self,
+        item_prices_record: ItemPricesRecord,
+    ) -> Result<UpdateItemOutput, SdkError<UpdateItemError, HttpResponse>>;
+}
+
+#[async_trait]
+#[allow(clippy::result_large_err)]
+#[mockall::automock]
+pub trait FxRateRepository {
+    async fn put_fx_rate_snapshot_record(
+        &self,
+        fx_rate_snapshot_record: FxRateSnapshotRecord,
+    ) -> Result<PutItemOutput, SdkError<PutItemError, HttpResponse>>;
+
+    /// Snapshot of the latest date, if any.
+    async fn get_latest_fx_rate_snapshot_record(
+        &self,
+    ) -> Result<Option<FxRateSnapshotRecord>, SdkError<QueryError, HttpResponse>>;
 }
 
 #[derive(Debug, Clone)]
@@ -142,6 +235,13 @@ impl<'a> ItemDynamoDbRepository for ItemDynamoDbRepositoryImpl<'a> {
             .key("pk", AttributeValue::S(pk))
             .key("sk", AttributeValue::S(sk))
             .update_expression(update_expr)
+            // records materialized before 'updated_nanos' has been introduced compare 'updated' instead,
+            // which only misorders updates within the same second, until the update sets it
+            .condition_expression(
+                "attribute_exists(pk) AND (#updated_nanos < :updated_nanos_val \
+                 OR (attribute_not_exists(#updated_nanos) AND #updated < :updated_val))",
+            )
+            .return_values_on_condition_check_failure(ReturnValuesOnConditionCheckFailure::AllOld)
             .set_expression_attribute_names(Some(expr_attr_names))
             .set_expression_attribute_values(Some(expr_attr_values))
             .send()
@@ -331,6 +431,88 @@ impl<'a> ItemDynamoDbRepository for ItemDynamoDbRepositoryImpl<'a> {
         Ok(batch_result)
     }
 
+    async fn get_item_states(
+        &self,
+        item_keys: &Batch<ItemKey, 100>,
+    ) -> Result<
+        BatchGetItemResult<ItemSummaryState, ItemKey>,
+        SdkError<BatchGetItemError, HttpResponse>,
+    > {
+        let keys = item_keys
+            .iter()
+            .map(|item_key| {
+                let mut columns = HashMap::with_capacity(2);
+                columns.insert(
+                    "pk".to_owned(),
+                    AttributeValue::S(mk_pk(&item_key.shop_id, &item_key.shops_item_id)),
+                );
+                columns.insert("sk".to_owned(), AttributeValue::S(mk_sk().to_owned()));
+                columns
+            })
+            .collect();
+        let keys_and_attributes = KeysAndAttributes::builder()
+            .set_keys(Some(keys))
+            .projection_expression("shop_id, shops_item_id, #state")
+            .expression_attribute_names("#state", "state")
+            .build()
+            .expect("shouldn't fail because we previously set the only required field 'keys'.");
+        let request_items = Some(HashMap::from([(self.table.clone(), keys_and_attributes)]));
+        let response = self
+            .client
+            .batch_get_item()
+            .set_request_items(request_items)
+            .send()
+            .await?;
+
+        let records = response
+            .responses
+            .unwrap_or_default()
+            .remove(&self.table)
+            .unwrap_or_default()
+            .into_iter()
+            .map(serde_dynamo::from_item::<_, ItemSummaryState>)
+            .filter_map(|result| match result {
+                Ok(record) => Some(record),
+                Err(err) => {
+                    error!(error = %err, type = %std::any::type_name::<ItemSummaryState>(), "Failed deserializing ItemSummaryState.");
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let unprocessed = response
+            .unprocessed_keys
+            .unwrap_or_default()
+            .remove(&self.table)
+            .map(|keys_and_attributes| keys_and_attributes.keys)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|attr_map| match extract_item_key(attr_map) {
+                Ok(key) => Some(key),
+                Err(err) => {
+                    error!(
+                        error = err,
+                        "Failed extracting ItemKey from BatchGetItemOutput::unprocessed_keys."
+                    );
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let batch_result = BatchGetItemResult {
+            items: records,
+            unprocessed: if unprocessed.is_empty() {
+                None
+            } else {
+                Some(Batch::try_from(unprocessed).expect(
+                    "shouldn't fail creating batch because DynamoDB cannot respond \
+                                with more failed ItemKeys than those requested.",
+                ))
+            },
+        };
+        Ok(batch_result)
+    }
+
     async fn query_item_hashes(
         &self,
         shop_id: &ShopId,
@@ -367,8 +549,356 @@ impl<'a> ItemDynamoDbRepository for ItemDynamoDbRepositoryImpl<'a> {
 
         Ok(records)
     }
+
+    async fn query_item_event_records(
+        &self,
+        shop_id: &ShopId,
+        shops_item_id: &ShopsItemId,
+        exclusive_start_timestamp: Option<OffsetDateTime>,
+        limit: u16,
+    ) -> Result<ItemEventRecordPage, SdkError<QueryError, HttpResponse>> {
+        let pk = mk_pk(shop_id, shops_item_id);
+        let exclusive_start_key = exclusive_start_timestamp
+            .map(|timestamp| mk_event_sk(&timestamp))
+            .transpose()
+            .map_err(SdkError::construction_failure)?
+            .map(|sk| {
+                HashMap::from([
+                    ("pk".to_owned(), AttributeValue::S(pk.clone())),
+                    ("sk".to_owned(), AttributeValue::S(sk)),
+                ])
+            });
+
+        let response = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .key_condition_expression("#pk = :pk_val AND begins_with(#sk, :sk_prefix)")
+            .expression_attribute_names("#pk", "pk")
+            .expression_attribute_names("#sk", "sk")
+            .expression_attribute_values(":pk_val", AttributeValue::S(pk))
+            .expression_attribute_values(
+                ":sk_prefix",
+                AttributeValue::S(EVENT_SK_PREFIX.to_owned()),
+            )
+            .set_exclusive_start_key(exclusive_start_key)
+            .scan_index_forward(true)
+            .limit(limit.into())
+            .send()
+            .await?;
+
+        let items = response
+            .items
+            .unwrap_or_default()
+            .into_iter()
+            .map(serde_dynamo::from_item::<_, ItemEventRecord>)
+            .filter_map(|result| match result {
+                Ok(event) => Some(event),
+                Err(err) => {
+                    error!(error = %err, type = %std::any::type_name::<ItemEventRecord>(), "Failed deserializing ItemEventRecord.");
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        // the key rather than the last item, which may have been dropped as undeserializable
+        let last_evaluated_timestamp = response
+            .last_evaluated_key
+            .as_ref()
+            .and_then(|key| key.get("sk"))
+            .and_then(|sk| sk.as_s().ok())
+            .map(|sk| parse_event_sk(sk))
+            .transpose()
+            .map_err(SdkError::construction_failure)?;
+
+        Ok(ItemEventRecordPage {
+            items,
+            last_evaluated_timestamp,
+        })
+    }
+
+    async fn query_item_price_event_records(
+        &self,
+        shop_id: &ShopId,
+        shops_item_id: &ShopsItemId,
+    ) -> Result<Vec<ItemPriceEventRecord>, SdkError<QueryError, HttpResponse>> {
+        let pk = mk_pk(shop_id, shops_item_id);
+        let records = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .index_name("gsi_3")
+            .key_condition_expression("#pk = :pk_val")
+            .expression_attribute_names("#pk", "pk")
+            .expression_attribute_values(":pk_val", AttributeValue::S(pk))
+            .scan_index_forward(true)
+            .into_paginator()
+            .send()
+            .try_collect()
+            .await?
+            .into_iter()
+            .flat_map(|qo| qo.items.unwrap_or_default())
+            .map(serde_dynamo::from_item::<_, ItemPriceEventRecord>)
+            .filter_map(|result| match result {
+                Ok(record) => Some(record),
+                Err(err) => {
+                    error!(error = %err, type = %std::any::type_name::<ItemPriceEventRecord>(), "Failed deserializing ItemPriceEventRecord.");
+                    None
+                }
+            })
+            .collect();
+
+        Ok(records)
+    }
+
+    async fn scan_item_prices_records(
+        &self,
+        exclusive_start_key: Option<HashMap<String, AttributeValue>>,
+        limit: u16,
+    ) -> Result<ItemPricesRecordPage, SdkError<ScanError, HttpResponse>> {
+        let response = self
+            .client
+            .scan()
+            .table_name(&self.table)
+            .filter_expression("#sk = :sk_val")
+            .projection_expression(
+                "item_id, event_id, shop_id, shops_item_id, price_native, \
+                 price_eur, price_usd, price_gbp, price_aud, price_cad, price_nzd, \
+                 price_chf, price_sek, price_dkk, price_nok, price_pln, price_czk, price_jpy, \
+                 fx_rate_date, repriced",
+            )
+            .expression_attribute_names("#sk", "sk")
+            .expression_attribute_values(":sk_val", AttributeValue::S(mk_sk().to_owned()))
+            .set_exclusive_start_key(exclusive_start_key)
+            .limit(limit.into())
+            .send()
+            .await?;
+
+        let items = response
+            .items
+            .unwrap_or_default()
+            .into_iter()
+            .map(serde_dynamo::from_item::<_, ItemPricesRecord>)
+            .filter_map(|result| match result {
+                Ok(record) => Some(record),
+                Err(err) => {
+                    error!(error = %err, type = %std::any::type_name::<ItemPricesRecord>(), "Failed deserializing ItemPricesRecord.");
+                    None
+                }
+            })
+            .collect();
+
+        Ok(ItemPricesRecordPage {
+            items,
+            last_evaluated_key: response.last_evaluated_key,
+        })
+    }
+
+    async fn update_item_record_prices(
+        &self,
+        item_prices_record: ItemPricesRecord,
+    ) -> Result<UpdateItemOutput, SdkError<UpdateItemError, HttpResponse>> {
+        let pk = mk_pk(
+            &item_prices_record.shop_id,
+            &item_prices_record.shops_item_id,
+        );
+        let prices = [
+            ("price_eur", item_prices_record.price_eur),
+            ("price_usd", item_prices_record.price_usd),
+            ("price_gbp", item_prices_record.price_gbp),
+            ("price_aud", item_prices_record.price_aud),
+            ("price_cad", item_prices_record.price_cad),
+            ("price_nzd", item_prices_record.price_nzd),
+            ("price_chf", item_prices_record.price_chf),
+            ("price_sek", item_prices_record.price_sek),
+            ("price_dkk", item_prices_record.price_dkk),
+            ("price_nok", item_prices_record.price_nok),
+            ("price_pln", item_prices_record.price_pln),
+            ("price_czk", item_prices_record.price_czk),
+            ("price_jpy", item_prices_record.price_jpy),
+        ];
+
+        let mut set_expressions = Vec::new();
+        let mut remove_expressions = Vec::new();
+        let mut expr_attr_names = HashMap::from([("#event_id".to_owned(), "event_id".to_owned())]);
+        let mut expr_attr_values = HashMap::from([(
+            ":event_id_val".to_owned(),
+            AttributeValue::S(item_prices_record.event_id.to_string()),
+        )]);
+
+        for (attr, price) in prices {
+            let attr_placeholder = format!("#{attr}");
+            expr_attr_names.insert(attr_placeholder.clone(), attr.to_owned());
+            match price {
+                Some(price) => {
+                    let val_placeholder = format!(":{attr}_val");
+                    expr_attr_values.insert(
+                        val_placeholder.clone(),
+                        AttributeValue::N(price.to_string()),
+                    );
+                    set_expressions.push(format!("{attr_placeholder} = {val_placeholder}"));
+                }
+                // exchanged with an older snapshot, so it must not linger
+                None => remove_expressions.push(attr_placeholder),
+            }
+        }
+
+        if let Some(fx_rate_date) = item_prices_record.fx_rate_date {
+            let fx_rate_date = fx_rate_date
+                .format(date::FORMAT)
+                .map_err(SdkError::construction_failure)?;
+            expr_attr_names.insert("#fx_rate_date".to_owned(), "fx_rate_date".to_owned());
+            expr_attr_values.insert(
+                ":fx_rate_date_val".to_owned(),
+                AttributeValue::S(fx_rate_date),
+            );
+            set_expressions.push("#fx_rate_date = :fx_rate_date_val".to_owned());
+        }
+
+        if let Some(repriced) = item_prices_record.repriced {
+            let repriced = repriced
+                .format(&Rfc3339)
+                .map_err(SdkError::construction_failure)?;
+            expr_attr_names.insert("#repriced".to_owned(), "repriced".to_owned());
+            expr_attr_values.insert(":repriced_val".to_owned(), AttributeValue::S(repriced));
+            set_expressions.push("#repriced = :repriced_val".to_owned());
+        }
+
+        let mut update_expr = Vec::with_capacity(2);
+        if !set_expressions.is_empty() {
+            update_expr.push(format!("SET {}", set_expressions.join(", ")));
+        }
+        if !remove_expressions.is_empty() {
+            update_expr.push(format!("REMOVE {}", remove_expressions.join(", ")));
+        }
+
+        self.client
+            .update_item()
+            .table_name(&self.table)
+            .key("pk", AttributeValue::S(pk))
+            .key("sk", AttributeValue::S(mk_sk().to_owned()))
+            .update_expression(update_expr.join(" "))
+            .condition_expression("#event_id = :event_id_val")
+            .return_values_on_condition_check_failure(ReturnValuesOnConditionCheckFailure::AllOld)
+            .set_expression_attribute_names(Some(expr_attr_names))
+            .set_expression_attribute_values(Some(expr_attr_values))
+            .send()
+            .await
+    }
+}
+
+#[async_trait]
+impl<'a> FxRateRepository for ItemDynamoDbRepositoryImpl<'a> {
+    async fn put_fx_rate_snapshot_record(
+        &self,
+        fx_rate_snapshot_record: FxRateSnapshotRecord,
+    ) -> Result<PutItemOutput, SdkError<PutItemError, HttpResponse>> {
+        self.client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(
+                serde_dynamo::to_item(fx_rate_snapshot_record)
+                    .map_err(SdkError::construction_failure)?,
+            ))
+            .send()
+            .await
+    }
+
+    async fn get_latest_fx_rate_snapshot_record(
+        &self,
+    ) -> Result<Option<FxRateSnapshotRecord>, SdkError<QueryError, HttpResponse>> {
+        let record = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .key_condition_expression("#pk = :pk_val")
+            .expression_attribute_names("#pk", "pk")
+            .expression_attribute_values(
+                ":pk_val",
+                AttributeValue::S(FX_RATE_SNAPSHOT_PK.to_string()),
+            )
+            .scan_index_forward(false)
+            .limit(1)
+            .send()
+            .await?
+            .items
+            .unwrap_or_default()
+            .into_iter()
+            .next()
+            .map(serde_dynamo::from_item::<_, FxRateSnapshotRecord>)
+            .and_then(|record_res| match record_res {
+                Ok(record) => Some(record),
+                Err(err) => {
+                    error!(error = %err, type = %std::any::type_name::<FxRateSnapshotRecord>(), "Failed deserializing FxRateSnapshotRecord.");
+                    None
+                }
+            });
+
+        Ok(record)
+    }
 }
 
+/// Latest [`FxRateSnapshot`] stored, falling back to [`FxRateSnapshot::bundled`] if there is none
+/// or it cannot be loaded.
+pub async fn load_latest_fx_rate_snapshot(
+    repository: &(impl FxRateRepository + Sync),
+) -> FxRateSnapshot {
+    match repository.get_latest_fx_rate_snapshot_record().await {
+        Ok(Some(record)) => match FxRateSnapshot::try_from(record) {
+            Ok(fx_rate_snapshot) => return fx_rate_snapshot,
+            Err(err) => {
+                error!(error = %err, "Failed converting FxRateSnapshotRecord to FxRateSnapshot.")
+            }
+        },
+        Ok(None) => warn!("Found no FxRateSnapshotRecord."),
+        Err(err) => error!(error = %err, "Failed querying latest FxRateSnapshotRecord."),
+    }
+    let bundled = FxRateSnapshot::bundled();
+    warn!(date = %bundled.date(), "Falling back to bundled FxRateSnapshot.");
+    bundled
+}
+
+/// How long [`FxRateSnapshotCache`] keeps a snapshot before loading the latest one again.
+pub const FX_RATE_SNAPSHOT_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Latest [`FxRateSnapshot`] for warm instances, which would otherwise keep exchanging prices
+/// with the snapshot loaded at their cold start.
+#[derive(Debug)]
+pub struct FxRateSnapshotCache {
+    ttl: Duration,
+    cached: Mutex<Option<(Instant, Arc<FxRateSnapshot>)>>,
+}
+
+impl FxRateSnapshotCache {
+    pub fn new(ttl: Duration) -> Self {
+        FxRateSnapshotCache {
+            ttl,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Cached snapshot, if loaded within the TTL. Otherwise, see [`load_latest_fx_rate_snapshot`].
+    pub async fn get(&self, repository: &(impl FxRateRepository + Sync)) -> Arc<FxRateSnapshot> {
+        if let Some((loaded, fx_rate_snapshot)) = self.cached.lock().unwrap().as_ref()
+            && loaded.elapsed() < self.ttl
+        {
+            return fx_rate_snapshot.clone();
+        }
+
+        let fx_rate_snapshot = Arc::new(load_latest_fx_rate_snapshot(repository).await);
+        info!(fxRateDate = %fx_rate_snapshot.date(), "Loaded latest FxRateSnapshot.");
+        *self.cached.lock().unwrap() = Some((Instant::now(), fx_rate_snapshot.clone()));
+        fx_rate_snapshot
+    }
+}
+
+impl Default for FxRateSnapshotCache {
+    fn default() -> Self {
+        FxRateSnapshotCache::new(FX_RATE_SNAPSHOT_TTL)
+    }
+}
+
+const EVENT_SK_PREFIX: &str = "item#event#";
+
 pub fn mk_pk(shop_id: &ShopId, shops_item_id: &ShopsItemId) -> String {
     format!("item#shop_id#{shop_id}#shops_item_id#{shops_item_id}")
 }
@@ -377,6 +907,27 @@ pub fn mk_sk() -> &'static str {
     "item#materialized"
 }
 
+pub fn mk_event_sk(timestamp: &OffsetDateTime) -> Result<String, time::error::Format> {
+    Ok(format!("{EVENT_SK_PREFIX}{}", timestamp.format(&Rfc3339)?))
+}
+
+/// Inverse of [`mk_event_sk`].
+pub fn parse_event_sk(sk: &str) -> Result<OffsetDateTime, time::error::Parse> {
+    OffsetDateTime::parse(sk.strip_prefix(EVENT_SK_PREFIX).unwrap_or(sk), &Rfc3339)
+}
+
+/// Whether the update has been rejected because the materialized record is at least as recent.
+///
+/// A missing materialized record also fails the condition, but isn't considered stale.
+pub fn is_stale_update(err: &SdkError<UpdateItemError, HttpResponse>) -> bool {
+    match err.as_service_error() {
+        Some(UpdateItemError::ConditionalCheckFailedException(exception)) => {
+            exception.item().is_some()
+        }
+        _ => false,
+    }
+}
+
 fn extract_item_key(map: HashMap<String, AttributeValue>) -> Result<ItemKey, String> {
     let mut map = map;
 
@@ -407,10 +958,12 @@ fn extract_item_key(map: HashMap<String, AttributeValue>) -> Result<ItemKey, Str
 
 #[cfg(test)]
 mod tests {
-    use crate::repository::extract_item_key;
+    use crate::repository::{extract_item_key, mk_event_sk, parse_event_sk};
     use aws_sdk_dynamodb::types::AttributeValue;
     use common::item_id::ItemKey;
     use std::collections::HashMap;
+    use time::OffsetDateTime;
+    use time::macros::datetime;
 
     #[rstest::rstest]
     #[case::differing("abcdefg", "123456")]
@@ -436,4 +989,15 @@ mod tests {
         assert!(actual.is_ok());
         assert_eq!(expected, actual.unwrap());
     }
+    #[rstest::rstest]
+    #[case::whole_second(datetime!(2025-01-01 12:00:00 UTC))]
+    #[case::nanoseconds(datetime!(2025-01-01 12:00:00.123456789 UTC))]
+    #[case::offset(datetime!(2025-06-30 12:00:00 +02:00))]
+    fn should_parse_event_sk_made_from_timestamp(#[case] timestamp: OffsetDateTime) {
+        let sk = mk_event_sk(&timestamp).unwrap();
+
+        let actual = parse_event_sk(&sk).unwrap();
+
+        assert_eq!(timestamp, actual);
+    }
 }
@@ -0,0 +1,94 @@
+use common::currency::domain::Currency;
+use common::price::fx_rate::FxRateSnapshot;
+use item_dynamodb::fx_rate_record::FxRateSnapshotRecord;
+use item_dynamodb::repository::{
+    FxRateRepository, FxRateSnapshotCache, ItemDynamoDbRepositoryImpl, load_latest_fx_rate_snapshot,
+};
+use std::time::Duration;
+use test_api::*;
+use time::Date;
+use time::macros::date;
+
+async fn get_repository() -> ItemDynamoDbRepositoryImpl<'static> {
+    ItemDynamoDbRepositoryImpl::new(get_dynamodb_client().await, "table_1")
+}
+
+fn mk_snapshot(date: Date) -> FxRateSnapshot {
+    let bundled = FxRateSnapshot::bundled();
+    FxRateSnapshot::new(date, Currency::Eur, bundled.rates().clone()).unwrap()
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_return_nothing_for_get_latest_fx_rate_snapshot_record_when_table_is_empty() {
+    let repository = get_repository().await;
+
+    let actual = repository
+        .get_latest_fx_rate_snapshot_record()
+        .await
+        .unwrap();
+
+    assert!(actual.is_none());
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_return_latest_fx_rate_snapshot_record() {
+    let repository = get_repository().await;
+    let expected = FxRateSnapshotRecord::from(mk_snapshot(date!(2025 - 10 - 17)));
+    for snapshot in [
+        mk_snapshot(date!(2025 - 10 - 16)),
+        mk_snapshot(date!(2025 - 10 - 17)),
+        mk_snapshot(date!(2025 - 09 - 30)),
+    ] {
+        repository
+            .put_fx_rate_snapshot_record(snapshot.into())
+            .await
+            .unwrap();
+    }
+
+    let actual = repository
+        .get_latest_fx_rate_snapshot_record()
+        .await
+        .unwrap();
+
+    assert_eq!(Some(expected), actual);
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_load_bundled_fx_rate_snapshot_when_table_is_empty() {
+    let repository = get_repository().await;
+
+    let actual = load_latest_fx_rate_snapshot(&repository).await;
+
+    assert_eq!(FxRateSnapshot::bundled(), actual);
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_keep_cached_fx_rate_snapshot_until_expired() {
+    let repository = get_repository().await;
+    let cache = FxRateSnapshotCache::new(Duration::from_secs(3600));
+    cache.get(&repository).await;
+    repository
+        .put_fx_rate_snapshot_record(mk_snapshot(date!(2025 - 10 - 17)).into())
+        .await
+        .unwrap();
+
+    let actual = cache.get(&repository).await;
+
+    assert_eq!(FxRateSnapshot::bundled(), *actual);
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_load_latest_fx_rate_snapshot_once_cached_one_expired() {
+    let repository = get_repository().await;
+    let cache = FxRateSnapshotCache::new(Duration::ZERO);
+    cache.get(&repository).await;
+    let expected = mk_snapshot(date!(2025 - 10 - 17));
+    repository
+        .put_fx_rate_snapshot_record(expected.clone().into())
+        .await
+        .unwrap();
+
+    let actual = cache.get(&repository).await;
+
+    assert_eq!(expected, *actual);
+}
@@ -0,0 +1,146 @@
+use common::batch::Batch;
+use common::event_id::EventId;
+use fake::{Fake, Faker};
+use item_dynamodb::item_event_record::ItemEventRecord;
+use item_dynamodb::item_prices_record::ItemPricesRecord;
+use item_dynamodb::item_record::ItemRecord;
+use item_dynamodb::repository::{
+    ItemDynamoDbRepository, ItemDynamoDbRepositoryImpl, is_stale_update,
+};
+use std::collections::HashMap;
+use test_api::*;
+use time::macros::{date, datetime};
+
+async fn get_repository() -> ItemDynamoDbRepositoryImpl<'static> {
+    ItemDynamoDbRepositoryImpl::new(get_dynamodb_client().await, "table_1")
+}
+
+#[rstest::rstest]
+#[test_attr(apply(test))]
+#[case::one(1)]
+#[case::fifty(50)]
+#[localstack_test(services = [DynamoDB()])]
+async fn should_scan_all_item_prices_records_across_pages(#[case] n: usize) {
+    let repository = get_repository().await;
+    let records: Vec<ItemRecord> = (0..n).map(|_| Faker.fake()).collect();
+    for chunk in records.chunks(25) {
+        repository
+            .put_item_records(Batch::try_from(chunk.to_vec()).unwrap())
+            .await
+            .unwrap();
+    }
+    let event_records: Vec<ItemEventRecord> = (0..5).map(|_| Faker.fake()).collect();
+    repository
+        .put_item_event_records(Batch::try_from(event_records).unwrap())
+        .await
+        .unwrap();
+    let expected: HashMap<_, _> = records
+        .into_iter()
+        .map(ItemPricesRecord::from)
+        .map(|record| (record.item_id, record))
+        .collect();
+
+    let mut actual = HashMap::new();
+    let mut exclusive_start_key = None;
+    loop {
+        let page = repository
+            .scan_item_prices_records(exclusive_start_key, 7)
+            .await
+            .unwrap();
+        actual.extend(
+            page.items
+                .into_iter()
+                .map(|record| (record.item_id, record)),
+        );
+        match page.last_evaluated_key {
+            None => break,
+            Some(last_evaluated_key) => exclusive_start_key = Some(last_evaluated_key),
+        }
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_update_item_record_prices_only_when_event_id_matches() {
+    let repository = get_repository().await;
+    let mut record: ItemRecord = Faker.fake();
+    record.price_eur = Some(1);
+    record.price_usd = Some(2);
+    repository
+        .put_item_records(Batch::from([record.clone()]))
+        .await
+        .unwrap();
+    let mut prices = ItemPricesRecord::from(record.clone());
+    prices.price_eur = Some(100);
+    prices.price_usd = Some(200);
+    prices.fx_rate_date = Some(date!(2025 - 10 - 17));
+    prices.repriced = Some(datetime!(2025-10-17 16:00 UTC));
+
+    repository.update_item_record_prices(prices).await.unwrap();
+    let actual = repository
+        .get_item_record(&record.shop_id, &record.shops_item_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let mut expected = record;
+    expected.price_eur = Some(100);
+    expected.price_usd = Some(200);
+    expected.fx_rate_date = Some(date!(2025 - 10 - 17));
+    expected.repriced = Some(datetime!(2025-10-17 16:00 UTC));
+    assert_eq!(expected, actual);
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_remove_item_record_prices_missing_from_update() {
+    let repository = get_repository().await;
+    let mut record: ItemRecord = Faker.fake();
+    record.price_eur = Some(1);
+    record.price_jpy = Some(2);
+    repository
+        .put_item_records(Batch::from([record.clone()]))
+        .await
+        .unwrap();
+    let mut prices = ItemPricesRecord::from(record.clone());
+    prices.price_eur = Some(100);
+    prices.price_jpy = None;
+
+    repository.update_item_record_prices(prices).await.unwrap();
+    let actual = repository
+        .get_item_record(&record.shop_id, &record.shops_item_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let mut expected = record;
+    expected.price_eur = Some(100);
+    expected.price_jpy = None;
+    assert_eq!(expected, actual);
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_not_update_item_record_prices_when_item_record_has_been_updated_since() {
+    let repository = get_repository().await;
+    let record: ItemRecord = Faker.fake();
+    repository
+        .put_item_records(Batch::from([record.clone()]))
+        .await
+        .unwrap();
+    let mut prices = ItemPricesRecord::from(record.clone());
+    prices.event_id = EventId::new();
+    prices.price_eur = Some(100);
+
+    let err = repository
+        .update_item_record_prices(prices)
+        .await
+        .unwrap_err();
+    assert!(is_stale_update(&err));
+
+    let actual = repository
+        .get_item_record(&record.shop_id, &record.shops_item_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(record, actual);
+}
@@ -1,3 +1,4 @@
+use aws_sdk_dynamodb::types::AttributeValue;
 use common::batch::Batch;
 use common::currency::record::CurrencyRecord;
 use common::event_id::EventId;
@@ -8,18 +9,23 @@ use common::language::record::{LanguageRecord, TextRecord};
 use common::price::record::PriceRecord;
 use common::shop_id::ShopId;
 use common::shops_item_id::ShopsItemId;
+use fake::{Fake, Faker};
 use item_core::hash::ItemHash;
 use item_dynamodb::item_event_record::ItemEventRecord;
 use item_dynamodb::item_event_type_record::ItemEventTypeRecord;
-use item_dynamodb::item_record::ItemRecord;
+use item_dynamodb::item_price_event_record::ItemPriceEventRecord;
+use item_dynamodb::item_record::{ItemRecord, mk_updated_nanos};
 use item_dynamodb::item_state_record::ItemStateRecord;
 use item_dynamodb::item_summary_hash::ItemSummaryHash;
-use item_dynamodb::repository::{ItemDynamoDbRepository, ItemDynamoDbRepositoryImpl};
+use item_dynamodb::repository::{
+    ItemDynamoDbRepository, ItemDynamoDbRepositoryImpl, mk_event_sk, mk_pk,
+};
 use std::time::Duration;
 use test_api::tokio::time::sleep;
 use test_api::*;
 use time::OffsetDateTime;
 use time::format_description::well_known;
+use time::macros::datetime;
 use url::Url;
 
 async fn get_repository() -> ItemDynamoDbRepositoryImpl<'static> {
@@ -59,9 +65,13 @@ async fn should_return_item_record_for_get_item_record_when_exists() {
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(PriceRecord {
             amount: 110,
             currency: CurrencyRecord::Eur,
@@ -72,12 +82,22 @@ async fn should_return_item_record_for_get_item_record_when_exists() {
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&None, &ItemState::Available),
         created: now,
         updated: now,
+        updated_nanos: mk_updated_nanos(&now),
     };
 
     get_dynamodb_client()
@@ -118,9 +138,13 @@ async fn should_return_nothing_for_get_item_record_when_only_others_exist() {
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(PriceRecord {
             amount: 110,
             currency: CurrencyRecord::Eur,
@@ -131,12 +155,22 @@ async fn should_return_nothing_for_get_item_record_when_only_others_exist() {
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&None, &ItemState::Available),
         created: now,
         updated: now,
+        updated_nanos: mk_updated_nanos(&now),
     };
 
     get_dynamodb_client()
@@ -176,9 +210,13 @@ async fn should_return_nothing_for_get_item_record_when_only_others_exist_mix()
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(PriceRecord {
             amount: 110,
             currency: CurrencyRecord::Eur,
@@ -189,16 +227,27 @@ async fn should_return_nothing_for_get_item_record_when_only_others_exist_mix()
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&None, &ItemState::Available),
         created: now,
         updated: now,
+        updated_nanos: mk_updated_nanos(&now),
     };
     let other2 = ItemEventRecord {
         pk: format!("item#shop_id#{shop_id}#shops_item_id#{shops_item_id}"),
         sk: format!("item#event#{now_str}"),
+        gsi_3_sk: None,
         item_id: ItemId::new(),
         event_id: EventId::new(),
         event_type: ItemEventTypeRecord::StateListed,
@@ -208,9 +257,13 @@ async fn should_return_nothing_for_get_item_record_when_only_others_exist_mix()
         title_native: Some(TextRecord::new("Bar", LanguageRecord::De)),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: None,
         price_eur: None,
         price_usd: None,
@@ -218,6 +271,14 @@ async fn should_return_nothing_for_get_item_record_when_only_others_exist_mix()
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
         state: Some(ItemStateRecord::Listed),
         url: None,
         images: Some(vec![Url::parse("https://foo.bar/123456/image").unwrap()]),
@@ -283,9 +344,13 @@ async fn should_return_item_diff_record_for_query_item_diff_records_when_exists(
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(PriceRecord {
             amount: 110,
             currency: CurrencyRecord::Eur,
@@ -296,12 +361,22 @@ async fn should_return_item_diff_record_for_query_item_diff_records_when_exists(
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&None, &ItemState::Available),
         created: now,
         updated: now,
+        updated_nanos: mk_updated_nanos(&now),
     };
 
     let repository = get_repository().await;
@@ -347,9 +422,13 @@ async fn should_return_item_diff_records_for_query_item_diff_records_when_exists
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(PriceRecord {
             amount: 110,
             currency: CurrencyRecord::Eur,
@@ -360,12 +439,22 @@ async fn should_return_item_diff_records_for_query_item_diff_records_when_exists
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&None, &ItemState::Available),
         created: now1,
         updated: now1,
+        updated_nanos: mk_updated_nanos(&now1),
     };
     let shops_item_id_2: ShopsItemId = "abcdefg".into();
     let now2 = OffsetDateTime::now_utc();
@@ -383,9 +472,13 @@ async fn should_return_item_diff_records_for_query_item_diff_records_when_exists
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(PriceRecord {
             amount: 110,
             currency: CurrencyRecord::Eur,
@@ -396,12 +489,22 @@ async fn should_return_item_diff_records_for_query_item_diff_records_when_exists
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&None, &ItemState::Available),
         created: now2,
         updated: now2,
+        updated_nanos: mk_updated_nanos(&now2),
     };
 
     let repository = get_repository().await;
@@ -459,9 +562,13 @@ async fn should_return_item_diff_records_sorted_by_created_latest_for_query_item
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(PriceRecord {
             amount: 110,
             currency: CurrencyRecord::Eur,
@@ -472,12 +579,22 @@ async fn should_return_item_diff_records_sorted_by_created_latest_for_query_item
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&None, &ItemState::Available),
         created: now1,
         updated: now1,
+        updated_nanos: mk_updated_nanos(&now1),
     };
     let shops_item_id_2: ShopsItemId = "abcdefg".into();
     let now2 = OffsetDateTime::now_utc();
@@ -495,9 +612,13 @@ async fn should_return_item_diff_records_sorted_by_created_latest_for_query_item
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(PriceRecord {
             amount: 110,
             currency: CurrencyRecord::Eur,
@@ -508,12 +629,22 @@ async fn should_return_item_diff_records_sorted_by_created_latest_for_query_item
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&None, &ItemState::Available),
         created: now2,
         updated: now2,
+        updated_nanos: mk_updated_nanos(&now2),
     };
 
     let repository = get_repository().await;
@@ -568,9 +699,13 @@ async fn should_return_nothing_for_query_item_diff_records_when_only_others_exis
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(PriceRecord {
             amount: 110,
             currency: CurrencyRecord::Eur,
@@ -581,12 +716,22 @@ async fn should_return_nothing_for_query_item_diff_records_when_only_others_exis
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&None, &ItemState::Available),
         created: now,
         updated: now,
+        updated_nanos: mk_updated_nanos(&now),
     };
 
     let repository = get_repository().await;
@@ -631,9 +776,13 @@ async fn should_return_nothing_for_query_item_diff_records_when_only_others_exis
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(PriceRecord {
             amount: 110,
             currency: CurrencyRecord::Eur,
@@ -644,16 +793,27 @@ async fn should_return_nothing_for_query_item_diff_records_when_only_others_exis
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&None, &ItemState::Available),
         created: now,
         updated: now,
+        updated_nanos: mk_updated_nanos(&now),
     };
     let other2 = ItemEventRecord {
         pk: format!("item#shop_id#{shop_id}#shops_item_id#{shops_item_id}"),
         sk: format!("item#event#{now_str}"),
+        gsi_3_sk: None,
         item_id: ItemId::new(),
         event_id: EventId::new(),
         event_type: ItemEventTypeRecord::StateListed,
@@ -663,9 +823,13 @@ async fn should_return_nothing_for_query_item_diff_records_when_only_others_exis
         title_native: Some(TextRecord::new("Bar", LanguageRecord::De)),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: None,
         price_eur: None,
         price_usd: None,
@@ -673,6 +837,14 @@ async fn should_return_nothing_for_query_item_diff_records_when_only_others_exis
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
         state: Some(ItemStateRecord::Listed),
         url: None,
         images: Some(vec![Url::parse("https://foo.bar/123456/image").unwrap()]),
@@ -735,9 +907,13 @@ async fn should_return_item_records_for_batch_get_item_records_when_all_exist()
             title_native: TextRecord::new("Bar", LanguageRecord::De),
             title_de: Some("Bar".to_string()),
             title_en: Some("Barr".to_string()),
+            title_fr: Some("Barr".to_string()),
+            title_es: Some("Barr".to_string()),
             description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
             description_de: Some("Baz".to_string()),
             description_en: Some("Bazz".to_string()),
+            description_fr: Some("Bazz".to_string()),
+            description_es: Some("Bazz".to_string()),
             price_native: Some(PriceRecord {
                 amount: 110,
                 currency: CurrencyRecord::Eur,
@@ -748,12 +924,22 @@ async fn should_return_item_records_for_batch_get_item_records_when_all_exist()
             price_aud: None,
             price_cad: None,
             price_nzd: None,
+            price_chf: None,
+            price_sek: None,
+            price_dkk: None,
+            price_nok: None,
+            price_pln: None,
+            price_czk: None,
+            price_jpy: None,
+            fx_rate_date: None,
+            repriced: None,
             state: ItemStateRecord::Available,
             url: Url::parse(&format!("https://foo.bar/{n}")).unwrap(),
             images: vec![Url::parse(&format!("https://foo.bar/{n}/image")).unwrap()],
             hash: ItemHash::new(&None, &ItemState::Available),
             created: now,
             updated: now,
+            updated_nanos: mk_updated_nanos(&now),
         }
     };
     let client = get_dynamodb_client().await;
@@ -816,9 +1002,13 @@ async fn should_return_item_records_for_batch_get_item_records_when_some_do_not_
             title_native: TextRecord::new("Bar", LanguageRecord::De),
             title_de: Some("Bar".to_string()),
             title_en: Some("Barr".to_string()),
+            title_fr: Some("Barr".to_string()),
+            title_es: Some("Barr".to_string()),
             description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
             description_de: Some("Baz".to_string()),
             description_en: Some("Bazz".to_string()),
+            description_fr: Some("Bazz".to_string()),
+            description_es: Some("Bazz".to_string()),
             price_native: Some(PriceRecord {
                 amount: 110,
                 currency: CurrencyRecord::Eur,
@@ -829,12 +1019,22 @@ async fn should_return_item_records_for_batch_get_item_records_when_some_do_not_
             price_aud: None,
             price_cad: None,
             price_nzd: None,
+            price_chf: None,
+            price_sek: None,
+            price_dkk: None,
+            price_nok: None,
+            price_pln: None,
+            price_czk: None,
+            price_jpy: None,
+            fx_rate_date: None,
+            repriced: None,
             state: ItemStateRecord::Available,
             url: Url::parse(&format!("https://foo.bar/{n}")).unwrap(),
             images: vec![Url::parse(&format!("https://foo.bar/{n}/image")).unwrap()],
             hash: ItemHash::new(&None, &ItemState::Available),
             created: now,
             updated: now,
+            updated_nanos: mk_updated_nanos(&now),
         }
     };
     let mut expecteds = Vec::with_capacity(100);
@@ -897,9 +1097,13 @@ async fn should_return_item_records_for_batch_get_item_records_when_more_than_10
             title_native: TextRecord::new("Bar", LanguageRecord::De),
             title_de: Some("Bar".to_string()),
             title_en: Some("Barr".to_string()),
+            title_fr: Some("Barr".to_string()),
+            title_es: Some("Barr".to_string()),
             description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
             description_de: Some("Baz".to_string()),
             description_en: Some("Bazz".to_string()),
+            description_fr: Some("Bazz".to_string()),
+            description_es: Some("Bazz".to_string()),
             price_native: Some(PriceRecord {
                 amount: 110,
                 currency: CurrencyRecord::Eur,
@@ -910,12 +1114,22 @@ async fn should_return_item_records_for_batch_get_item_records_when_more_than_10
             price_aud: None,
             price_cad: None,
             price_nzd: None,
+            price_chf: None,
+            price_sek: None,
+            price_dkk: None,
+            price_nok: None,
+            price_pln: None,
+            price_czk: None,
+            price_jpy: None,
+            fx_rate_date: None,
+            repriced: None,
             state: ItemStateRecord::Available,
             url: Url::parse(&format!("https://foo.bar/{n}")).unwrap(),
             images: vec![Url::parse(&format!("https://foo.bar/{n}/image")).unwrap()],
             hash: ItemHash::new(&None, &ItemState::Available),
             created: now,
             updated: now,
+            updated_nanos: mk_updated_nanos(&now),
         }
     };
     let mut expecteds = Vec::with_capacity(100);
@@ -980,9 +1194,13 @@ async fn should_return_item_keys_for_batch_exist_item_records_when_all_exist() {
             title_native: TextRecord::new("Bar", LanguageRecord::De),
             title_de: Some("Bar".to_string()),
             title_en: Some("Barr".to_string()),
+            title_fr: Some("Barr".to_string()),
+            title_es: Some("Barr".to_string()),
             description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
             description_de: Some("Baz".to_string()),
             description_en: Some("Bazz".to_string()),
+            description_fr: Some("Bazz".to_string()),
+            description_es: Some("Bazz".to_string()),
             price_native: Some(PriceRecord {
                 amount: 110,
                 currency: CurrencyRecord::Eur,
@@ -993,12 +1211,22 @@ async fn should_return_item_keys_for_batch_exist_item_records_when_all_exist() {
             price_aud: None,
             price_cad: None,
             price_nzd: None,
+            price_chf: None,
+            price_sek: None,
+            price_dkk: None,
+            price_nok: None,
+            price_pln: None,
+            price_czk: None,
+            price_jpy: None,
+            fx_rate_date: None,
+            repriced: None,
             state: ItemStateRecord::Available,
             url: Url::parse(&format!("https://foo.bar/{n}")).unwrap(),
             images: vec![Url::parse(&format!("https://foo.bar/{n}/image")).unwrap()],
             hash: ItemHash::new(&None, &ItemState::Available),
             created: now,
             updated: now,
+            updated_nanos: mk_updated_nanos(&now),
         }
     };
     let mut expecteds = Vec::with_capacity(100);
@@ -1059,9 +1287,13 @@ async fn should_return_item_keys_for_batch_exist_item_records_when_some_do_not_e
             title_native: TextRecord::new("Bar", LanguageRecord::De),
             title_de: Some("Bar".to_string()),
             title_en: Some("Barr".to_string()),
+            title_fr: Some("Barr".to_string()),
+            title_es: Some("Barr".to_string()),
             description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
             description_de: Some("Baz".to_string()),
             description_en: Some("Bazz".to_string()),
+            description_fr: Some("Bazz".to_string()),
+            description_es: Some("Bazz".to_string()),
             price_native: Some(PriceRecord {
                 amount: 110,
                 currency: CurrencyRecord::Eur,
@@ -1072,12 +1304,22 @@ async fn should_return_item_keys_for_batch_exist_item_records_when_some_do_not_e
             price_aud: None,
             price_cad: None,
             price_nzd: None,
+            price_chf: None,
+            price_sek: None,
+            price_dkk: None,
+            price_nok: None,
+            price_pln: None,
+            price_czk: None,
+            price_jpy: None,
+            fx_rate_date: None,
+            repriced: None,
             state: ItemStateRecord::Available,
             url: Url::parse(&format!("https://foo.bar/{n}")).unwrap(),
             images: vec![Url::parse(&format!("https://foo.bar/{n}/image")).unwrap()],
             hash: ItemHash::new(&None, &ItemState::Available),
             created: now,
             updated: now,
+            updated_nanos: mk_updated_nanos(&now),
         }
     };
     let mut expecteds = Vec::with_capacity(100);
@@ -1138,9 +1380,13 @@ async fn should_return_item_keys_for_batch_exist_item_records_when_more_than_100
             title_native: TextRecord::new("Bar", LanguageRecord::De),
             title_de: Some("Bar".to_string()),
             title_en: Some("Barr".to_string()),
+            title_fr: Some("Barr".to_string()),
+            title_es: Some("Barr".to_string()),
             description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
             description_de: Some("Baz".to_string()),
             description_en: Some("Bazz".to_string()),
+            description_fr: Some("Bazz".to_string()),
+            description_es: Some("Bazz".to_string()),
             price_native: Some(PriceRecord {
                 amount: 110,
                 currency: CurrencyRecord::Eur,
@@ -1151,12 +1397,22 @@ async fn should_return_item_keys_for_batch_exist_item_records_when_more_than_100
             price_aud: None,
             price_cad: None,
             price_nzd: None,
+            price_chf: None,
+            price_sek: None,
+            price_dkk: None,
+            price_nok: None,
+            price_pln: None,
+            price_czk: None,
+            price_jpy: None,
+            fx_rate_date: None,
+            repriced: None,
             state: ItemStateRecord::Available,
             url: Url::parse(&format!("https://foo.bar/{n}")).unwrap(),
             images: vec![Url::parse(&format!("https://foo.bar/{n}/image")).unwrap()],
             hash: ItemHash::new(&None, &ItemState::Available),
             created: now,
             updated: now,
+            updated_nanos: mk_updated_nanos(&now),
         }
     };
     let mut expecteds = Vec::with_capacity(100);
@@ -1194,3 +1450,197 @@ async fn should_return_item_keys_for_batch_exist_item_records_when_more_than_100
     actuals.items.sort();
     assert_eq!(actuals.items, expecteds);
 }
+
+fn mk_item_event_record(
+    shop_id: &ShopId,
+    shops_item_id: &ShopsItemId,
+    timestamp: OffsetDateTime,
+) -> ItemEventRecord {
+    let mut record: ItemEventRecord = Faker.fake();
+    record.pk = mk_pk(shop_id, shops_item_id);
+    record.sk = mk_event_sk(&timestamp).unwrap();
+    record.gsi_3_sk = record.price_native.as_ref().map(|_| record.sk.clone());
+    record.shop_id = shop_id.clone();
+    record.shops_item_id = shops_item_id.clone();
+    record.timestamp = timestamp;
+    record
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_return_nothing_for_query_item_event_records_when_table_is_empty() {
+    let repository = get_repository().await;
+    let actual = repository
+        .query_item_event_records(&ShopId::new(), &"non-existent".into(), None, 10)
+        .await
+        .unwrap();
+
+    assert!(actual.items.is_empty());
+    assert!(actual.last_evaluated_timestamp.is_none());
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_page_through_item_event_records_in_order_for_query_item_event_records() {
+    let shop_id = ShopId::new();
+    let shops_item_id: ShopsItemId = "123465".into();
+    let records = vec![
+        mk_item_event_record(&shop_id, &shops_item_id, datetime!(2025-01-03 0:00 UTC)),
+        mk_item_event_record(&shop_id, &shops_item_id, datetime!(2025-01-01 0:00 UTC)),
+        mk_item_event_record(&shop_id, &shops_item_id, datetime!(2025-01-02 0:00 UTC)),
+        mk_item_event_record(
+            &ShopId::new(),
+            &shops_item_id,
+            datetime!(2025-01-01 0:00 UTC),
+        ),
+    ];
+    let mut materialized: ItemRecord = Faker.fake();
+    materialized.pk = mk_pk(&shop_id, &shops_item_id);
+    materialized.sk = "item#materialized".to_string();
+
+    let repository = get_repository().await;
+    repository
+        .put_item_event_records(Batch::try_from(records.clone()).unwrap())
+        .await
+        .unwrap();
+    repository
+        .put_item_records(Batch::try_from(vec![materialized]).unwrap())
+        .await
+        .unwrap();
+
+    let first_page = repository
+        .query_item_event_records(&shop_id, &shops_item_id, None, 2)
+        .await
+        .unwrap();
+    assert_eq!(
+        vec![records[1].clone(), records[2].clone()],
+        first_page.items
+    );
+    assert_eq!(
+        Some(datetime!(2025-01-02 0:00 UTC)),
+        first_page.last_evaluated_timestamp
+    );
+
+    let second_page = repository
+        .query_item_event_records(
+            &shop_id,
+            &shops_item_id,
+            first_page.last_evaluated_timestamp,
+            2,
+        )
+        .await
+        .unwrap();
+    assert_eq!(vec![records[0].clone()], second_page.items);
+    assert!(second_page.last_evaluated_timestamp.is_none());
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_page_past_undeserializable_last_item_for_query_item_event_records() {
+    let shop_id = ShopId::new();
+    let shops_item_id: ShopsItemId = "123465".into();
+    let records = vec![
+        mk_item_event_record(&shop_id, &shops_item_id, datetime!(2025-01-01 0:00 UTC)),
+        mk_item_event_record(&shop_id, &shops_item_id, datetime!(2025-01-03 0:00 UTC)),
+    ];
+    let repository = get_repository().await;
+    repository
+        .put_item_event_records(Batch::try_from(records.clone()).unwrap())
+        .await
+        .unwrap();
+    get_dynamodb_client()
+        .await
+        .put_item()
+        .table_name("table_1")
+        .item("pk", AttributeValue::S(mk_pk(&shop_id, &shops_item_id)))
+        .item(
+            "sk",
+            AttributeValue::S(mk_event_sk(&datetime!(2025-01-02 0:00 UTC)).unwrap()),
+        )
+        .send()
+        .await
+        .unwrap();
+
+    let first_page = repository
+        .query_item_event_records(&shop_id, &shops_item_id, None, 2)
+        .await
+        .unwrap();
+    assert_eq!(vec![records[0].clone()], first_page.items);
+    assert_eq!(
+        Some(datetime!(2025-01-02 0:00 UTC)),
+        first_page.last_evaluated_timestamp
+    );
+
+    let second_page = repository
+        .query_item_event_records(
+            &shop_id,
+            &shops_item_id,
+            first_page.last_evaluated_timestamp,
+            2,
+        )
+        .await
+        .unwrap();
+    assert_eq!(vec![records[1].clone()], second_page.items);
+    assert!(second_page.last_evaluated_timestamp.is_none());
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_return_only_price_event_records_in_order_for_query_item_price_event_records() {
+    let shop_id = ShopId::new();
+    let shops_item_id: ShopsItemId = "123465".into();
+    let price = |amount| {
+        Some(PriceRecord {
+            amount,
+            currency: CurrencyRecord::Eur,
+        })
+    };
+    let mut records = vec![
+        mk_item_event_record(&shop_id, &shops_item_id, datetime!(2025-01-03 0:00 UTC)),
+        mk_item_event_record(&shop_id, &shops_item_id, datetime!(2025-01-02 0:00 UTC)),
+        mk_item_event_record(&shop_id, &shops_item_id, datetime!(2025-01-01 0:00 UTC)),
+        mk_item_event_record(
+            &ShopId::new(),
+            &shops_item_id,
+            datetime!(2025-01-01 0:00 UTC),
+        ),
+    ];
+    for ((record, event_type), price_native) in records
+        .iter_mut()
+        .zip([
+            ItemEventTypeRecord::PriceIncreased,
+            ItemEventTypeRecord::StateSold,
+            ItemEventTypeRecord::Created,
+            ItemEventTypeRecord::Created,
+        ])
+        .zip([price(20000), None, price(10000), price(30000)])
+    {
+        record.gsi_3_sk = price_native.as_ref().map(|_| record.sk.clone());
+        record.event_type = event_type;
+        record.price_native = price_native;
+    }
+
+    let repository = get_repository().await;
+    repository
+        .put_item_event_records(Batch::try_from(records).unwrap())
+        .await
+        .unwrap();
+    sleep(Duration::from_secs(3)).await;
+
+    let actual = repository
+        .query_item_price_event_records(&shop_id, &shops_item_id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ItemPriceEventRecord {
+                event_type: ItemEventTypeRecord::Created,
+                price_native: price(10000).unwrap(),
+                timestamp: datetime!(2025-01-01 0:00 UTC),
+            },
+            ItemPriceEventRecord {
+                event_type: ItemEventTypeRecord::PriceIncreased,
+                price_native: price(20000).unwrap(),
+                timestamp: datetime!(2025-01-03 0:00 UTC),
+            },
+        ],
+        actual
+    );
+}
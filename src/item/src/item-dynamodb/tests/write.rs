@@ -1,3 +1,4 @@
+use aws_sdk_dynamodb::types::AttributeValue;
 use common::batch::Batch;
 use common::currency::record::CurrencyRecord;
 use common::event_id::EventId;
@@ -7,16 +8,19 @@ use common::language::record::{LanguageRecord, TextRecord};
 use common::price::record::PriceRecord;
 use common::shop_id::ShopId;
 use common::shops_item_id::ShopsItemId;
+use fake::{Fake, Faker};
 use item_core::hash::ItemHash;
 use item_dynamodb::item_event_record::ItemEventRecord;
 use item_dynamodb::item_event_type_record::ItemEventTypeRecord;
-use item_dynamodb::item_record::ItemRecord;
+use item_dynamodb::item_record::{ItemRecord, mk_updated_nanos};
 use item_dynamodb::item_state_record::ItemStateRecord;
 use item_dynamodb::item_update_record::ItemRecordUpdate;
-use item_dynamodb::repository::{ItemDynamoDbRepository, ItemDynamoDbRepositoryImpl};
+use item_dynamodb::repository::{
+    ItemDynamoDbRepository, ItemDynamoDbRepositoryImpl, is_stale_update,
+};
 use test_api::*;
-use time::OffsetDateTime;
 use time::format_description::well_known;
+use time::{Duration, OffsetDateTime};
 use url::Url;
 
 async fn get_repository() -> ItemDynamoDbRepositoryImpl<'static> {
@@ -42,9 +46,13 @@ async fn should_put_item_records_for_single_record() {
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(PriceRecord {
             amount: 110,
             currency: CurrencyRecord::Eur,
@@ -55,12 +63,22 @@ async fn should_put_item_records_for_single_record() {
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&None, &ItemState::Available),
         created: now,
         updated: now,
+        updated_nanos: mk_updated_nanos(&now),
     };
 
     get_repository()
@@ -98,9 +116,13 @@ async fn should_put_item_records_for_multiple_records() {
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(PriceRecord {
             amount: 110,
             currency: CurrencyRecord::Eur,
@@ -111,12 +133,22 @@ async fn should_put_item_records_for_multiple_records() {
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&None, &ItemState::Available),
         created: now1,
         updated: now1,
+        updated_nanos: mk_updated_nanos(&now1),
     };
     let shops_item_id_2: ShopsItemId = "abcdefg".into();
     let now2 = OffsetDateTime::now_utc();
@@ -134,9 +166,13 @@ async fn should_put_item_records_for_multiple_records() {
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(PriceRecord {
             amount: 110,
             currency: CurrencyRecord::Eur,
@@ -147,12 +183,22 @@ async fn should_put_item_records_for_multiple_records() {
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&None, &ItemState::Available),
         created: now2,
         updated: now2,
+        updated_nanos: mk_updated_nanos(&now2),
     };
 
     get_repository()
@@ -191,6 +237,7 @@ async fn should_put_item_event_records_for_single_record() {
     let expected = ItemEventRecord {
         pk: format!("item#shop_id#{shop_id}#shops_item_id#{shops_item_id}"),
         sk: format!("item#event#{now_str}"),
+        gsi_3_sk: None,
         item_id: ItemId::new(),
         event_id: EventId::new(),
         event_type: ItemEventTypeRecord::Created,
@@ -200,21 +247,33 @@ async fn should_put_item_event_records_for_single_record() {
         title_native: Some(TextRecord::new("Bar", LanguageRecord::De)),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: price,
         price_eur: None,
         price_usd: None,
         price_gbp: None,
         price_aud: None,
         price_cad: None,
+        fx_rate_date: None,
         state: Some(ItemStateRecord::Available),
         url: Some(Url::parse("https://foo.bar/123456").unwrap()),
         images: Some(vec![Url::parse("https://foo.bar/123456/image").unwrap()]),
         hash: ItemHash::new(&price.map(Into::into), &ItemState::Available),
         timestamp: now,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
     };
 
     get_repository()
@@ -256,6 +315,7 @@ async fn should_put_item_event_records_for_multiple_records() {
             shop_id.clone()
         ),
         sk: format!("item#event#{now_str1}"),
+        gsi_3_sk: None,
         item_id: ItemId::new(),
         event_id: EventId::new(),
         event_type: ItemEventTypeRecord::Created,
@@ -265,21 +325,33 @@ async fn should_put_item_event_records_for_multiple_records() {
         title_native: Some(TextRecord::new("Bar", LanguageRecord::De)),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: price,
         price_eur: None,
         price_usd: None,
         price_gbp: None,
         price_aud: None,
         price_cad: None,
+        fx_rate_date: None,
         state: Some(ItemStateRecord::Available),
         url: Some(Url::parse("https://foo.bar/123456").unwrap()),
         images: Some(vec![Url::parse("https://foo.bar/123456/image").unwrap()]),
         hash: ItemHash::new(&price.map(Into::into), &ItemState::Available),
         timestamp: now1,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
     };
 
     let now2 = OffsetDateTime::now_utc();
@@ -291,6 +363,7 @@ async fn should_put_item_event_records_for_multiple_records() {
             shop_id.clone()
         ),
         sk: format!("item#event#{now_str2}"),
+        gsi_3_sk: None,
         item_id: ItemId::new(),
         event_id: EventId::new(),
         event_type: ItemEventTypeRecord::Created,
@@ -300,9 +373,13 @@ async fn should_put_item_event_records_for_multiple_records() {
         title_native: Some(TextRecord::new("Bar", LanguageRecord::De)),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: price,
         price_eur: None,
         price_usd: None,
@@ -310,6 +387,14 @@ async fn should_put_item_event_records_for_multiple_records() {
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
         state: Some(ItemStateRecord::Available),
         url: Some(Url::parse("https://foo.bar/123456").unwrap()),
         images: Some(vec![Url::parse("https://foo.bar/123456/image").unwrap()]),
@@ -363,9 +448,13 @@ async fn should_update_item_record() {
         title_native: TextRecord::new("Bar", LanguageRecord::De),
         title_de: Some("Bar".to_string()),
         title_en: Some("Barr".to_string()),
+        title_fr: Some("Barr".to_string()),
+        title_es: Some("Barr".to_string()),
         description_native: Some(TextRecord::new("Baz", LanguageRecord::De)),
         description_de: Some("Baz".to_string()),
         description_en: Some("Bazz".to_string()),
+        description_fr: Some("Bazz".to_string()),
+        description_es: Some("Bazz".to_string()),
         price_native: Some(price),
         price_eur: None,
         price_usd: None,
@@ -373,17 +462,35 @@ async fn should_update_item_record() {
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Available,
         url: Url::parse("https://foo.bar/123456").unwrap(),
         images: vec![Url::parse("https://foo.bar/123456/image").unwrap()],
         hash: ItemHash::new(&Some(price.into()), &ItemState::Available),
         created: now,
         updated: now,
+        updated_nanos: mk_updated_nanos(&now),
     };
     let now2 = OffsetDateTime::now_utc();
     let event_id2 = EventId::new();
     let update = ItemRecordUpdate {
         event_id: event_id2,
+        title_de: None,
+        title_en: None,
+        title_fr: None,
+        title_es: None,
+        description_de: None,
+        description_en: None,
+        description_fr: None,
+        description_es: None,
         price_native: None,
         price_eur: None,
         price_usd: None,
@@ -393,7 +500,16 @@ async fn should_update_item_record() {
         state: Some(ItemStateRecord::Sold),
         hash: ItemHash::new(&Some(price.into()), &ItemState::Sold),
         updated: now2,
+        updated_nanos: mk_updated_nanos(&now2),
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
     };
     let mut expected = initial.clone();
     expected.event_id = event_id2;
@@ -421,3 +537,309 @@ async fn should_update_item_record() {
 
     assert_eq!(expected, actual);
 }
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_not_update_item_record_when_update_is_stale() {
+    let now = OffsetDateTime::now_utc();
+    let now_str = now.format(&well_known::Rfc3339).unwrap();
+    let shop_id = ShopId::new();
+    let shops_item_id: ShopsItemId = "123465".into();
+    let expected = ItemRecord {
+        pk: format!("item#shop_id#{shop_id}#shops_item_id#{shops_item_id}"),
+        sk: "item#materialized".to_string(),
+        gsi_1_pk: format!("shop_id#{}", shop_id.clone()),
+        gsi_1_sk: format!("updated#{now_str}"),
+        item_id: ItemId::new(),
+        event_id: EventId::new(),
+        shop_id: shop_id.clone(),
+        shops_item_id: shops_item_id.clone(),
+        shop_name: "Foo".to_string(),
+        title_native: TextRecord::new("Bar", LanguageRecord::De),
+        title_de: Some("Bar".to_string()),
+        title_en: None,
+        title_fr: None,
+        title_es: None,
+        description_native: None,
+        description_de: None,
+        description_en: None,
+        description_fr: None,
+        description_es: None,
+        price_native: None,
+        price_eur: None,
+        price_usd: None,
+        price_gbp: None,
+        price_aud: None,
+        price_cad: None,
+        price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
+        state: ItemStateRecord::Sold,
+        url: Url::parse("https://foo.bar/123456").unwrap(),
+        images: vec![],
+        hash: ItemHash::new(&None, &ItemState::Sold),
+        created: now,
+        updated: now,
+        updated_nanos: mk_updated_nanos(&now),
+    };
+    let stale_update = ItemRecordUpdate {
+        event_id: EventId::new(),
+        title_de: None,
+        title_en: None,
+        title_fr: None,
+        title_es: None,
+        description_de: None,
+        description_en: None,
+        description_fr: None,
+        description_es: None,
+        price_native: None,
+        price_eur: None,
+        price_usd: None,
+        price_gbp: None,
+        price_aud: None,
+        price_cad: None,
+        price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        state: Some(ItemStateRecord::Available),
+        hash: ItemHash::new(&None, &ItemState::Available),
+        updated: now - Duration::minutes(5),
+        updated_nanos: mk_updated_nanos(&(now - Duration::minutes(5))),
+    };
+
+    get_repository()
+        .await
+        .put_item_records(Batch::from([expected.clone()]))
+        .await
+        .unwrap();
+    let err = get_repository()
+        .await
+        .update_item_record(&shop_id, &shops_item_id, stale_update)
+        .await
+        .unwrap_err();
+    assert!(is_stale_update(&err));
+
+    let actual = get_repository()
+        .await
+        .get_item_record(&shop_id, &shops_item_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_not_create_item_record_when_updating_missing_item_record() {
+    let now = OffsetDateTime::now_utc();
+    let shop_id = ShopId::new();
+    let shops_item_id: ShopsItemId = "123465".into();
+    let update = ItemRecordUpdate {
+        event_id: EventId::new(),
+        title_de: None,
+        title_en: None,
+        title_fr: None,
+        title_es: None,
+        description_de: None,
+        description_en: None,
+        description_fr: None,
+        description_es: None,
+        price_native: None,
+        price_eur: None,
+        price_usd: None,
+        price_gbp: None,
+        price_aud: None,
+        price_cad: None,
+        price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        state: Some(ItemStateRecord::Sold),
+        hash: ItemHash::new(&None, &ItemState::Sold),
+        updated: now,
+        updated_nanos: mk_updated_nanos(&now),
+    };
+
+    let err = get_repository()
+        .await
+        .update_item_record(&shop_id, &shops_item_id, update)
+        .await
+        .unwrap_err();
+    assert!(!is_stale_update(&err));
+
+    let actual = get_repository()
+        .await
+        .get_item_record(&shop_id, &shops_item_id)
+        .await
+        .unwrap();
+
+    assert!(actual.is_none());
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_only_add_translations_when_updating_item_record_with_translations() {
+    let mut initial: ItemRecord = Faker.fake();
+    initial.title_fr = None;
+    initial.description_fr = None;
+    let shop_id = initial.shop_id.clone();
+    let shops_item_id = initial.shops_item_id.clone();
+    let update = ItemRecordUpdate {
+        event_id: EventId::new(),
+        title_de: None,
+        title_en: None,
+        title_fr: Some("Casque à pointe".to_string()),
+        title_es: None,
+        description_de: None,
+        description_en: None,
+        description_fr: Some("Très bon état".to_string()),
+        description_es: None,
+        price_native: None,
+        price_eur: None,
+        price_usd: None,
+        price_gbp: None,
+        price_aud: None,
+        price_cad: None,
+        price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        state: None,
+        hash: initial.hash,
+        updated: initial.updated + Duration::seconds(1),
+        updated_nanos: mk_updated_nanos(&(initial.updated + Duration::seconds(1))),
+    };
+    let mut expected = initial.clone();
+    expected.event_id = update.event_id;
+    expected.title_fr = update.title_fr.clone();
+    expected.description_fr = update.description_fr.clone();
+    expected.updated = update.updated;
+
+    get_repository()
+        .await
+        .put_item_records(Batch::from([initial]))
+        .await
+        .unwrap();
+    get_repository()
+        .await
+        .update_item_record(&shop_id, &shops_item_id, update)
+        .await
+        .unwrap();
+
+    let actual = get_repository()
+        .await
+        .get_item_record(&shop_id, &shops_item_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_update_item_record_when_update_is_more_recent_by_fractional_seconds() {
+    // "...:00.5Z" orders before "...:00Z" lexicographically
+    let now = OffsetDateTime::now_utc().replace_nanosecond(0).unwrap();
+    let more_recent = now + Duration::milliseconds(500);
+    let mut initial: ItemRecord = Faker.fake();
+    initial.updated = now;
+    initial.updated_nanos = mk_updated_nanos(&now);
+    let mut update: ItemRecordUpdate = Faker.fake();
+    update.updated = more_recent;
+    update.updated_nanos = mk_updated_nanos(&more_recent);
+
+    get_repository()
+        .await
+        .put_item_records(Batch::from([initial.clone()]))
+        .await
+        .unwrap();
+    get_repository()
+        .await
+        .update_item_record(&initial.shop_id, &initial.shops_item_id, update.clone())
+        .await
+        .unwrap();
+
+    let actual = get_repository()
+        .await
+        .get_item_record(&initial.shop_id, &initial.shops_item_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(update.event_id, actual.event_id);
+    assert_eq!(more_recent, actual.updated);
+}
+
+#[rstest::rstest]
+#[test_attr(apply(test))]
+#[case::more_recent(Duration::minutes(5), true)]
+#[case::stale(Duration::minutes(-5), false)]
+#[localstack_test(services = [DynamoDB()])]
+async fn should_compare_updated_when_updating_item_record_without_updated_nanos(
+    #[case] offset: Duration,
+    #[case] expected_applied: bool,
+) {
+    let now = OffsetDateTime::now_utc();
+    let mut initial: ItemRecord = Faker.fake();
+    initial.updated = now;
+    let mut update: ItemRecordUpdate = Faker.fake();
+    update.updated = now + offset;
+    update.updated_nanos = mk_updated_nanos(&update.updated);
+
+    get_repository()
+        .await
+        .put_item_records(Batch::from([initial.clone()]))
+        .await
+        .unwrap();
+    get_dynamodb_client()
+        .await
+        .update_item()
+        .table_name("table_1")
+        .key("pk", AttributeValue::S(initial.pk.clone()))
+        .key("sk", AttributeValue::S(initial.sk.clone()))
+        .update_expression("REMOVE updated_nanos")
+        .send()
+        .await
+        .unwrap();
+    let res = get_repository()
+        .await
+        .update_item_record(&initial.shop_id, &initial.shops_item_id, update.clone())
+        .await;
+
+    let actual = get_repository()
+        .await
+        .get_item_record(&initial.shop_id, &initial.shops_item_id)
+        .await
+        .unwrap()
+        .unwrap();
+    if expected_applied {
+        assert!(res.is_ok());
+        assert_eq!(update.event_id, actual.event_id);
+        assert_eq!(update.updated_nanos, actual.updated_nanos);
+    } else {
+        assert!(is_stale_update(&res.unwrap_err()));
+        assert_eq!(initial.event_id, actual.event_id);
+        assert_eq!(0, actual.updated_nanos);
+    }
+}
@@ -4,7 +4,7 @@ use aws_lambda_events::sqs::{BatchItemFailure, SqsBatchResponse, SqsEvent, SqsMe
 use common::has_key::HasKey;
 use common::item_id::ItemKey;
 use item_dynamodb::item_update_record::ItemRecordUpdate;
-use item_dynamodb::repository::ItemDynamoDbRepository;
+use item_dynamodb::repository::{ItemDynamoDbRepository, is_stale_update};
 use item_lambda_common::extract_item_event_record;
 use lambda_runtime::LambdaEvent;
 use tracing::{error, info};
@@ -19,33 +19,37 @@ pub async fn handler(
 
     let mut failed_message_ids = Vec::new();
     let mut skipped_count = 0;
-    let mut updates = Vec::with_capacity(records_count);
-    let mut message_ids: HashMap<ItemKey, String> = HashMap::with_capacity(records_count);
+    let mut updates: HashMap<ItemKey, Vec<(String, ItemRecordUpdate)>> =
+        HashMap::with_capacity(records_count);
 
     for message in event.payload.records {
-        if let Some(update) = extract_message_data(
-            message,
-            &mut failed_message_ids,
-            &mut skipped_count,
-            &mut message_ids,
-        ) {
-            updates.push(update);
+        if let Some((key, message_id, update)) =
+            extract_message_data(message, &mut failed_message_ids, &mut skipped_count)
+        {
+            updates.entry(key).or_default().push((message_id, update));
         }
     }
 
-    for (key, update) in updates {
-        let update_res = repository
-            .update_item_record(&key.shop_id, &key.shops_item_id, update)
-            .await;
-        if let Err(err) = update_res {
-            error!(error = ?err, itemKey = %key, "Failed update.");
-            match message_ids.remove(&key) {
-                Some(message_id) => failed_message_ids.push(message_id),
-                None => {
-                    error!(
-                        itemKey = %key,
-                        "There exists no message_id for failed ItemRecord."
-                    );
+    for (key, mut item_updates) in updates {
+        // updates are partial, so all of an item's updates are applied oldest to newest
+        item_updates.sort_by_key(|(_, update)| update.updated);
+        let mut item_updates = item_updates.into_iter();
+        while let Some((message_id, update)) = item_updates.next() {
+            let update_res = repository
+                .update_item_record(&key.shop_id, &key.shops_item_id, update)
+                .await;
+            match update_res {
+                Ok(_) => {}
+                Err(err) if is_stale_update(&err) => {
+                    info!(itemKey = %key, "Skipped stale update.");
+                    skipped_count += 1;
+                }
+                Err(err) => {
+                    error!(error = ?err, itemKey = %key, "Failed update.");
+                    // newer updates are retried as well, otherwise the failed one would be stale on retry
+                    failed_message_ids.push(message_id);
+                    failed_message_ids
+                        .extend(item_updates.by_ref().map(|(message_id, _)| message_id));
                 }
             }
         }
@@ -71,8 +75,7 @@ fn extract_message_data(
     message: SqsMessage,
     failed_message_ids: &mut Vec<String>,
     skipped_count: &mut usize,
-    message_ids: &mut HashMap<ItemKey, String>,
-) -> Option<(ItemKey, ItemRecordUpdate)> {
+) -> Option<(ItemKey, String, ItemRecordUpdate)> {
     let message_id = message
         .message_id
         .clone()
@@ -80,8 +83,7 @@ fn extract_message_data(
     let item_event_record = extract_item_event_record(message, failed_message_ids, skipped_count)?;
     let key = item_event_record.key();
     let update_record = ItemRecordUpdate::from(item_event_record);
-    message_ids.insert(key.clone(), message_id);
-    Some((key, update_record))
+    Some((key, message_id, update_record))
 }
 
 #[cfg(test)]
@@ -90,14 +92,21 @@ mod tests {
     use aws_lambda_events::dynamodb::{EventRecord, StreamRecord};
     use aws_lambda_events::eventbridge::EventBridgeEvent;
     use aws_lambda_events::sqs::{SqsEvent, SqsMessage};
+    use aws_sdk_dynamodb::config::http::HttpResponse;
     use aws_sdk_dynamodb::error::SdkError;
-    use aws_sdk_dynamodb::operation::update_item::UpdateItemOutput;
+    use aws_sdk_dynamodb::operation::update_item::{UpdateItemError, UpdateItemOutput};
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use aws_sdk_dynamodb::types::error::ConditionalCheckFailedException;
+    use common::event_id::EventId;
     use fake::{Fake, Faker};
     use item_core::item_event::{ItemCommonEventPayload, ItemEvent};
     use item_dynamodb::item_event_record::ItemEventRecord;
     use item_dynamodb::repository::MockItemDynamoDbRepository;
     use lambda_runtime::{Context, LambdaEvent};
+    use mockall::Sequence;
+    use std::collections::HashMap;
     use std::time::SystemTime;
+    use time::Duration;
     use uuid::Uuid;
 
     fn mk_event_bridge_payload(item_event_record: &ItemEventRecord) -> String {
@@ -263,4 +272,169 @@ mod tests {
 
         assert_eq!(expected_failed_message_ids, actual_failed_message_ids);
     }
+
+    fn mk_sqs_message(message_id: &str, item_event_record: &ItemEventRecord) -> SqsMessage {
+        SqsMessage {
+            message_id: Some(message_id.to_string()),
+            receipt_handle: None,
+            body: Some(mk_event_bridge_payload(item_event_record)),
+            md5_of_body: None,
+            md5_of_message_attributes: None,
+            attributes: Default::default(),
+            message_attributes: Default::default(),
+            event_source_arn: None,
+            event_source: None,
+            aws_region: None,
+        }
+    }
+
+    fn mk_conditional_check_failed(
+        item: Option<HashMap<String, AttributeValue>>,
+    ) -> SdkError<UpdateItemError, HttpResponse> {
+        SdkError::service_error(
+            UpdateItemError::ConditionalCheckFailedException(
+                ConditionalCheckFailedException::builder()
+                    .set_item(item)
+                    .build(),
+            ),
+            HttpResponse::new(400u16.try_into().unwrap(), "{}".into()),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_skip_stale_update() {
+        let item_event_record = ItemEventRecord::try_from(Faker.fake::<ItemEvent>()).unwrap();
+        let lambda_event = LambdaEvent {
+            payload: SqsEvent {
+                records: vec![mk_sqs_message("msg1", &item_event_record)],
+            },
+            context: Context::default(),
+        };
+        let mut repository = MockItemDynamoDbRepository::default();
+        repository
+            .expect_update_item_record()
+            .times(1)
+            .returning(|_, _, _| {
+                Box::pin(async move {
+                    Err(mk_conditional_check_failed(Some(HashMap::from([(
+                        "updated".to_string(),
+                        AttributeValue::S("2100-01-01T00:00:00Z".to_string()),
+                    )]))))
+                })
+            });
+
+        let actual = handler(&repository, lambda_event).await.unwrap();
+
+        assert!(actual.batch_item_failures.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_fail_update_when_item_record_does_not_exist() {
+        let item_event_record = ItemEventRecord::try_from(Faker.fake::<ItemEvent>()).unwrap();
+        let lambda_event = LambdaEvent {
+            payload: SqsEvent {
+                records: vec![mk_sqs_message("msg1", &item_event_record)],
+            },
+            context: Context::default(),
+        };
+        let mut repository = MockItemDynamoDbRepository::default();
+        repository
+            .expect_update_item_record()
+            .times(1)
+            .returning(|_, _, _| Box::pin(async move { Err(mk_conditional_check_failed(None)) }));
+
+        let actual = handler(&repository, lambda_event).await.unwrap();
+
+        assert_eq!(
+            vec!["msg1".to_string()],
+            actual
+                .batch_item_failures
+                .into_iter()
+                .map(|failure| failure.item_identifier)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::newer_first(true)]
+    #[case::older_first(false)]
+    async fn should_apply_all_updates_per_item_of_batch_oldest_first(#[case] newer_first: bool) {
+        let newer = ItemEventRecord::try_from(Faker.fake::<ItemEvent>()).unwrap();
+        let mut older = newer.clone();
+        older.event_id = EventId::new();
+        older.timestamp = newer.timestamp - Duration::minutes(5);
+        let records = if newer_first {
+            vec![
+                mk_sqs_message("newer", &newer),
+                mk_sqs_message("older", &older),
+            ]
+        } else {
+            vec![
+                mk_sqs_message("older", &older),
+                mk_sqs_message("newer", &newer),
+            ]
+        };
+        let lambda_event = LambdaEvent {
+            payload: SqsEvent { records },
+            context: Context::default(),
+        };
+        let older_event_id = older.event_id;
+        let newer_event_id = newer.event_id;
+        let mut sequence = Sequence::new();
+        let mut repository = MockItemDynamoDbRepository::default();
+        repository
+            .expect_update_item_record()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .withf(move |_, _, update| update.event_id == older_event_id)
+            .returning(|_, _, _| Box::pin(async move { Ok(UpdateItemOutput::builder().build()) }));
+        repository
+            .expect_update_item_record()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .withf(move |_, _, update| update.event_id == newer_event_id)
+            .returning(|_, _, _| Box::pin(async move { Ok(UpdateItemOutput::builder().build()) }));
+
+        let actual = handler(&repository, lambda_event).await.unwrap();
+
+        assert!(actual.batch_item_failures.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_fail_newer_updates_of_item_when_older_update_fails() {
+        let newer = ItemEventRecord::try_from(Faker.fake::<ItemEvent>()).unwrap();
+        let mut older = newer.clone();
+        older.event_id = EventId::new();
+        older.timestamp = newer.timestamp - Duration::minutes(5);
+        let lambda_event = LambdaEvent {
+            payload: SqsEvent {
+                records: vec![
+                    mk_sqs_message("older", &older),
+                    mk_sqs_message("newer", &newer),
+                ],
+            },
+            context: Context::default(),
+        };
+        let mut repository = MockItemDynamoDbRepository::default();
+        repository
+            .expect_update_item_record()
+            .times(1)
+            .returning(|_, _, _| {
+                Box::pin(
+                    async move { Err(SdkError::construction_failure("Something went wrong.")) },
+                )
+            });
+
+        let actual = handler(&repository, lambda_event).await.unwrap();
+
+        assert_eq!(
+            vec!["older".to_string(), "newer".to_string()],
+            actual
+                .batch_item_failures
+                .into_iter()
+                .map(|failure| failure.item_identifier)
+                .collect::<Vec<_>>()
+        );
+    }
 }
@@ -321,13 +321,13 @@ mod tests {
                     .filter(|&item_document| {
                         expected_failures_clone.contains(&item_document.item_id)
                     })
-                    .cloned()
                     .map(|unprocessed_doc| {
                         let index: String = Faker.fake();
                         BulkOpResult {
                             index: index.clone(),
                             id: unprocessed_doc.item_id.to_string(),
                             version: Some(2),
+                            result: None,
                             status: 409,
                             error: Some(BulkError {
                                 error_type: "boop".to_string(),
@@ -354,6 +354,7 @@ mod tests {
                             index: index.clone(),
                             id: unprocessed_doc.item_id.to_string(),
                             version: Some(2),
+                            result: None,
                             status: 201,
                             error: None,
                         }
@@ -18,26 +18,31 @@ pub async fn handler(
 
     let mut failed_message_ids = Vec::new();
     let mut skipped_count = 0;
-    let mut update_documents = HashMap::with_capacity(records_count);
-    let mut message_ids: HashMap<ItemId, String> = HashMap::with_capacity(records_count);
+    let mut update_documents: Vec<(ItemId, ItemUpdateDocument)> = Vec::with_capacity(records_count);
+    let mut message_ids: HashMap<ItemId, Vec<String>> = HashMap::with_capacity(records_count);
 
     for message in event.payload.records {
-        if let Some((item_id, item_document)) = extract_message_data(
-            message,
-            &mut failed_message_ids,
-            &mut skipped_count,
-            &mut message_ids,
-        ) {
-            update_documents.insert(item_id, item_document);
+        if let Some((item_id, message_id, update_document)) =
+            extract_message_data(message, &mut failed_message_ids, &mut skipped_count)
+        {
+            message_ids.entry(item_id).or_default().push(message_id);
+            update_documents.push((item_id, update_document));
         }
     }
+    // updates are partial, so all of an item's updates are applied oldest to newest
+    update_documents.sort_by_key(|(_, update_document)| update_document.updated);
 
     let result = repository.update_item_documents(update_documents).await;
     match result {
-        Ok(response) => handle_bulk_response(response, &mut failed_message_ids, &mut message_ids),
+        Ok(response) => handle_bulk_response(
+            response,
+            &mut failed_message_ids,
+            &mut skipped_count,
+            &mut message_ids,
+        ),
         Err(err) => {
             error!(error = ?err, "Failed entire batch.");
-            failed_message_ids.extend(message_ids.into_values());
+            failed_message_ids.extend(message_ids.into_values().flatten());
         }
     }
 
@@ -61,8 +66,7 @@ fn extract_message_data(
     message: SqsMessage,
     failed_message_ids: &mut Vec<String>,
     skipped_count: &mut usize,
-    message_ids: &mut HashMap<ItemId, String>,
-) -> Option<(ItemId, ItemUpdateDocument)> {
+) -> Option<(ItemId, String, ItemUpdateDocument)> {
     let message_id = message
         .message_id
         .clone()
@@ -70,17 +74,17 @@ fn extract_message_data(
     let item_event_record = extract_item_event_record(message, failed_message_ids, skipped_count)?;
     let item_id = item_event_record.item_id;
     let update_document = ItemUpdateDocument::from(item_event_record);
-    message_ids.insert(item_id, message_id);
-    Some((item_id, update_document))
+    Some((item_id, message_id, update_document))
 }
 
 fn handle_bulk_response(
     response: BulkResponse,
     failed_message_ids: &mut Vec<String>,
-    message_ids: &mut HashMap<ItemId, String>,
+    skipped_count: &mut usize,
+    message_ids: &mut HashMap<ItemId, Vec<String>>,
 ) {
-    if response.errors {
-        let failures = response
+    let results =
+        response
             .items
             .into_iter()
             .filter_map(|bulk_item_result| match bulk_item_result {
@@ -89,36 +93,40 @@ fn handle_bulk_response(
                     error!(actual = ?other, "Expected BulkItemResult::Update.");
                     None
                 }
-            })
-            .filter(|bulk_op_result| bulk_op_result.is_err());
+            });
 
-        for failure in failures {
+    for result in results {
+        if result.is_noop() {
+            info!(itemId = result.id, "Skipped stale update.");
+            *skipped_count += 1;
+        } else if result.is_err() {
             warn!(
-                index = failure.index,
-                itemId = failure.id,
-                status = failure.status,
-                error = ?failure.error,
+                index = result.index,
+                itemId = result.id,
+                status = result.status,
+                error = ?result.error,
                 "Failed updating item in OpenSearch."
             );
-            match ItemId::try_from(failure.id.as_str()) {
-                Ok(item_id) => match message_ids.remove(&item_id) {
-                    Some(message_id) => {
-                        failed_message_ids.push(message_id);
+            match ItemId::try_from(result.id.as_str()) {
+                // all of the item's updates are retried, otherwise the failed one would be stale on retry
+                Ok(item_id) => match message_ids.get_mut(&item_id) {
+                    Some(item_message_ids) => {
+                        failed_message_ids.append(item_message_ids);
                     }
                     None => {
                         error!(
-                            index = failure.index,
-                            itemId = failure.id,
+                            index = result.index,
+                            itemId = result.id,
                             "Failed re-mapping item-id to message-id. Cannot retry."
                         );
                     }
                 },
                 Err(err) => {
                     error!(
-                        index = failure.index,
-                        itemId = failure.id,
+                        index = result.index,
+                        itemId = result.id,
                         error = %err,
-                        payload = ?failure,
+                        payload = ?result,
                         "Failed parsing '_id' from OpenSearch-Response as 'ItemId'. Cannot retry."
                     );
                 }
@@ -134,6 +142,7 @@ mod tests {
     use aws_lambda_events::eventbridge::EventBridgeEvent;
     use aws_lambda_events::sqs::{SqsEvent, SqsMessage};
     use common::event::Event;
+    use common::event_id::EventId;
     use common::opensearch::bulk_response::BulkItemResult;
     use common::opensearch::bulk_response::BulkOpResult;
     use common::opensearch::bulk_response::{BulkError, BulkResponse};
@@ -304,6 +313,7 @@ mod tests {
                             index: index.clone(),
                             id: item_id.to_string(),
                             version: Some(2),
+                            result: None,
                             status: 409,
                             error: Some(BulkError {
                                 error_type: "boop".to_string(),
@@ -328,6 +338,7 @@ mod tests {
                             index: index.clone(),
                             id: item_id.to_string(),
                             version: Some(2),
+                            result: None,
                             status: 200,
                             error: None,
                         }
@@ -361,4 +372,112 @@ mod tests {
 
         assert_eq!(expected_failed_message_ids, actual_failed_message_ids);
     }
+
+    fn mk_sqs_message(message_id: &str, item_event_record: &ItemEventRecord) -> SqsMessage {
+        SqsMessage {
+            message_id: Some(message_id.to_string()),
+            receipt_handle: None,
+            body: Some(mk_event_bridge_payload(item_event_record)),
+            md5_of_body: None,
+            md5_of_message_attributes: None,
+            attributes: Default::default(),
+            message_attributes: Default::default(),
+            event_source_arn: None,
+            event_source: None,
+            aws_region: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_skip_stale_update() {
+        let item_event_record = ItemEventRecord::try_from(Faker.fake::<ItemEvent>()).unwrap();
+        let lambda_event = LambdaEvent {
+            payload: SqsEvent {
+                records: vec![mk_sqs_message("msg1", &item_event_record)],
+            },
+            context: Default::default(),
+        };
+        let mut repository = MockItemOpenSearchRepository::default();
+        repository
+            .expect_update_item_documents()
+            .return_once(move |batch| {
+                let items = batch
+                    .iter()
+                    .map(|(item_id, _)| BulkOpResult {
+                        index: "items".to_string(),
+                        id: item_id.to_string(),
+                        version: Some(2),
+                        result: Some("noop".to_string()),
+                        status: 200,
+                        error: None,
+                    })
+                    .map(|update| BulkItemResult::Update { update })
+                    .collect();
+                Box::pin(async move {
+                    Ok(BulkResponse {
+                        took: 500,
+                        errors: false,
+                        items,
+                    })
+                })
+            });
+
+        let actual = handler(&repository, lambda_event).await.unwrap();
+
+        assert!(actual.batch_item_failures.is_empty());
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::newer_first(true)]
+    #[case::older_first(false)]
+    async fn should_apply_all_updates_per_item_of_batch_oldest_first(#[case] newer_first: bool) {
+        let newer = ItemEventRecord::try_from(Faker.fake::<ItemEvent>()).unwrap();
+        let mut older = newer.clone();
+        older.event_id = EventId::new();
+        older.timestamp = newer.timestamp - time::Duration::minutes(5);
+        let records = if newer_first {
+            vec![
+                mk_sqs_message("newer", &newer),
+                mk_sqs_message("older", &older),
+            ]
+        } else {
+            vec![
+                mk_sqs_message("older", &older),
+                mk_sqs_message("newer", &newer),
+            ]
+        };
+        let lambda_event = LambdaEvent {
+            payload: SqsEvent { records },
+            context: Default::default(),
+        };
+        let expected_event_ids = [older.event_id, newer.event_id];
+        let mut repository = MockItemOpenSearchRepository::default();
+        repository
+            .expect_update_item_documents()
+            .withf(move |batch| {
+                batch
+                    .iter()
+                    .map(|(_, doc)| doc.event_id)
+                    .eq(expected_event_ids.iter().copied())
+            })
+            .return_once(|_| {
+                Box::pin(async move {
+                    Err(opensearch::Error::from(
+                        serde_json::from_str::<()>("boop").unwrap_err(),
+                    ))
+                })
+            });
+
+        let mut actual = handler(&repository, lambda_event)
+            .await
+            .unwrap()
+            .batch_item_failures
+            .into_iter()
+            .map(|failure| failure.item_identifier)
+            .collect::<Vec<_>>();
+        actual.sort();
+
+        assert_eq!(vec!["newer".to_string(), "older".to_string()], actual);
+    }
 }
@@ -0,0 +1,153 @@
+use aws_sdk_dynamodb::config::http::HttpResponse;
+use aws_sdk_dynamodb::error::SdkError;
+use aws_sdk_dynamodb::operation::put_item::PutItemError;
+use common::price::fx_rate::{FxRateSnapshot, FxRateSnapshotError};
+use item_dynamodb::fx_rate_record::FxRateSnapshotRecord;
+use item_dynamodb::repository::FxRateRepository;
+use lambda_runtime::LambdaEvent;
+use tracing::{error, info};
+
+/// Reference rates of the ECB, published on working days around 16:00 CET.
+pub const ECB_EUROFXREF_DAILY_URL: &str =
+    "https://www.ecb.europa.eu/stats/eurofxref/eurofxref-daily.xml";
+
+#[derive(Debug, thiserror::Error)]
+pub enum RefreshFxRateError {
+    #[error("Failed fetching reference rates: {0}")]
+    Fetch(#[from] reqwest::Error),
+
+    #[error("Failed parsing reference rates: {0}")]
+    Parse(#[from] FxRateSnapshotError),
+
+    #[error("Encountered DynamoDB SdkError for PutItem: {0}")]
+    SdkPutItemError(#[from] Box<SdkError<PutItemError, HttpResponse>>),
+}
+
+/// Stores the latest reference rates as [`FxRateSnapshot`], which the writers and readers pick up
+/// within [`FX_RATE_SNAPSHOT_TTL`](item_dynamodb::repository::FX_RATE_SNAPSHOT_TTL).
+///
+/// Invoked on schedule, so the event's payload is ignored.
+#[tracing::instrument(skip(http_client, repository, event), fields(requestId = %event.context.request_id))]
+pub async fn handler(
+    http_client: &reqwest::Client,
+    eurofxref_url: &str,
+    repository: &(impl FxRateRepository + Sync),
+    event: LambdaEvent<serde_json::Value>,
+) -> Result<(), lambda_runtime::Error> {
+    info!(url = eurofxref_url, "Handler invoked.");
+
+    let eurofxref = match fetch_eurofxref(http_client, eurofxref_url).await {
+        Ok(eurofxref) => eurofxref,
+        Err(err) => {
+            error!(error = %err, url = eurofxref_url, "Failed fetching reference rates.");
+            return Err(err.into());
+        }
+    };
+    match refresh_fx_rate(&eurofxref, repository).await {
+        Ok(fx_rate_snapshot) => {
+            info!(date = %fx_rate_snapshot.date(), "Handler finished.");
+            Ok(())
+        }
+        Err(err) => {
+            error!(error = %err, "Failed refreshing FxRateSnapshot.");
+            Err(err.into())
+        }
+    }
+}
+
+async fn fetch_eurofxref(
+    http_client: &reqwest::Client,
+    eurofxref_url: &str,
+) -> Result<String, RefreshFxRateError> {
+    let eurofxref = http_client
+        .get(eurofxref_url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    Ok(eurofxref)
+}
+
+/// Parses the ECB's `eurofxref-daily.xml` and stores it as [`FxRateSnapshotRecord`].
+///
+/// Storing the same day twice overwrites it.
+pub async fn refresh_fx_rate(
+    eurofxref_xml: &str,
+    repository: &(impl FxRateRepository + Sync),
+) -> Result<FxRateSnapshot, RefreshFxRateError> {
+    let fx_rate_snapshot = FxRateSnapshot::from_ecb_xml(eurofxref_xml)?;
+    repository
+        .put_fx_rate_snapshot_record(FxRateSnapshotRecord::from(fx_rate_snapshot.clone()))
+        .await
+        .map_err(Box::new)?;
+    Ok(fx_rate_snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{RefreshFxRateError, refresh_fx_rate};
+    use aws_sdk_dynamodb::operation::put_item::PutItemOutput;
+    use item_dynamodb::repository::MockFxRateRepository;
+    use time::macros::date;
+
+    const EUROFXREF_DAILY_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gesmes:Envelope xmlns:gesmes="http://www.gesmes.org/xml/2002-08-01" xmlns="http://www.ecb.int/vocabulary/2002-08-01/eurofxref">
+	<gesmes:subject>Reference rates</gesmes:subject>
+	<gesmes:Sender>
+		<gesmes:name>European Central Bank</gesmes:name>
+	</gesmes:Sender>
+	<Cube>
+		<Cube time='2025-10-17'>
+			<Cube currency='USD' rate='1.1681'/>
+			<Cube currency='JPY' rate='175.48'/>
+			<Cube currency='GBP' rate='0.86905'/>
+			<Cube currency='CZK' rate='24.322'/>
+			<Cube currency='DKK' rate='7.4691'/>
+			<Cube currency='PLN' rate='4.2425'/>
+			<Cube currency='SEK' rate='10.9965'/>
+			<Cube currency='CHF' rate='0.9263'/>
+			<Cube currency='NOK' rate='11.7275'/>
+			<Cube currency='AUD' rate='1.7983'/>
+			<Cube currency='CAD' rate='1.6391'/>
+			<Cube currency='NZD' rate='2.0418'/>
+		</Cube>
+	</Cube>
+</gesmes:Envelope>"#;
+
+    #[tokio::test]
+    async fn should_put_fx_rate_snapshot_record() {
+        let mut repository = MockFxRateRepository::default();
+        repository
+            .expect_put_fx_rate_snapshot_record()
+            .withf(|record| {
+                record.sk == "date#2025-10-17" && record.rates.get("USD") == Some(&1_168_100)
+            })
+            .times(1)
+            .return_once(|_| Box::pin(async { Ok(PutItemOutput::builder().build()) }));
+
+        let actual = refresh_fx_rate(EUROFXREF_DAILY_XML, &repository)
+            .await
+            .unwrap();
+
+        assert_eq!(date!(2025 - 10 - 17), actual.date());
+    }
+
+    #[rstest::rstest]
+    #[case::empty("")]
+    #[case::html("<html><body>Service unavailable</body></html>")]
+    #[case::incomplete(
+        "<Cube><Cube time='2025-10-17'><Cube currency='USD' rate='1.1681'/></Cube></Cube>"
+    )]
+    #[tokio::test]
+    async fn should_not_put_fx_rate_snapshot_record_when_reference_rates_are_invalid(
+        #[case] eurofxref_xml: &str,
+    ) {
+        let mut repository = MockFxRateRepository::default();
+        repository.expect_put_fx_rate_snapshot_record().never();
+
+        let actual = refresh_fx_rate(eurofxref_xml, &repository).await;
+
+        assert!(matches!(actual, Err(RefreshFxRateError::Parse(_))));
+    }
+}
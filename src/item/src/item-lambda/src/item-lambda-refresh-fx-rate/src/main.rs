@@ -0,0 +1,42 @@
+use aws_config::BehaviorVersion;
+use aws_sdk_dynamodb::Client;
+use item_dynamodb::repository::ItemDynamoDbRepositoryImpl;
+use item_lambda_refresh_fx_rate::{ECB_EUROFXREF_DAILY_URL, handler};
+use lambda_runtime::{Error, LambdaEvent, run, service_fn};
+use tracing::info;
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    tracing_subscriber::fmt()
+        .json()
+        .with_max_level(tracing::Level::INFO)
+        .with_current_span(true)
+        .with_ansi(false)
+        .without_time()
+        .init();
+
+    let aws_config = aws_config::defaults(BehaviorVersion::v2025_08_07())
+        .load()
+        .await;
+
+    let table_name = std::env::var("DYNAMODB_TABLE_NAME")?;
+    let client = Client::new(&aws_config);
+    let dynamodb_repository = ItemDynamoDbRepositoryImpl::new(&client, &table_name);
+    let http_client = reqwest::Client::new();
+
+    info!(
+        dynamoDbTableName = %table_name,
+        "Lambda cold start completed, client initialized."
+    );
+
+    run(service_fn(|event: LambdaEvent<serde_json::Value>| async {
+        handler(
+            &http_client,
+            ECB_EUROFXREF_DAILY_URL,
+            &dynamodb_repository,
+            event,
+        )
+        .await
+    }))
+    .await
+}
@@ -0,0 +1,58 @@
+use crate::RepriceEvent;
+use async_trait::async_trait;
+use aws_sdk_lambda::config::http::HttpResponse;
+use aws_sdk_lambda::error::SdkError;
+use aws_sdk_lambda::operation::invoke::InvokeError;
+use aws_sdk_lambda::primitives::Blob;
+use aws_sdk_lambda::types::InvocationType;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ContinueError {
+    #[error("Failed serializing RepriceEvent: {0}")]
+    Serde(#[from] serde_json::Error),
+
+    #[error("Encountered Lambda SdkError for Invoke: {0}")]
+    SdkInvokeError(#[from] Box<SdkError<InvokeError, HttpResponse>>),
+}
+
+/// Hands the remainder of a scan over to another invocation.
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait Continuation {
+    async fn continue_with(&self, event: RepriceEvent) -> Result<(), ContinueError>;
+}
+
+/// Invokes the given function, usually the running one, asynchronously.
+///
+/// Failed asynchronous invocations are retried with the same event, so they resume from its
+/// checkpoint instead of starting over.
+#[derive(Debug, Clone)]
+pub struct LambdaContinuation {
+    client: aws_sdk_lambda::Client,
+    function_name: String,
+}
+
+impl LambdaContinuation {
+    pub fn new(client: aws_sdk_lambda::Client, function_name: impl Into<String>) -> Self {
+        LambdaContinuation {
+            client,
+            function_name: function_name.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl Continuation for LambdaContinuation {
+    async fn continue_with(&self, event: RepriceEvent) -> Result<(), ContinueError> {
+        let payload = serde_json::to_vec(&event)?;
+        self.client
+            .invoke()
+            .function_name(&self.function_name)
+            .invocation_type(InvocationType::Event)
+            .payload(Blob::new(payload))
+            .send()
+            .await
+            .map_err(Box::new)?;
+        Ok(())
+    }
+}
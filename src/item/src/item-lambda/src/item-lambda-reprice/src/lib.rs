@@ -0,0 +1,738 @@
+use crate::continuation::Continuation;
+use aws_sdk_dynamodb::types::AttributeValue;
+use common::item_id::ItemId;
+use common::opensearch::bulk_response::{BulkItemResult, BulkResponse};
+use common::price::domain::FxRate;
+use futures::StreamExt;
+use futures::stream;
+use item_dynamodb::item_prices_record::ItemPricesRecord;
+use item_dynamodb::repository::{
+    FxRateRepository, ItemDynamoDbRepository, is_stale_update, load_latest_fx_rate_snapshot,
+};
+use item_opensearch::item_prices_document::ItemPricesDocument;
+use item_opensearch::repository::ItemOpenSearchRepository;
+use lambda_runtime::LambdaEvent;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+use tracing::{error, info, warn};
+
+pub mod continuation;
+
+/// Records evaluated per scanned page, most of which aren't materialized items.
+pub const SCAN_LIMIT: u16 = 1000;
+
+/// Conditional updates of materialized items in flight at once.
+pub const UPDATE_CONCURRENCY: usize = 25;
+
+/// Time left before the invocation's deadline at which no further page is started.
+pub const DEADLINE_RESERVE: Duration = Duration::from_secs(60);
+
+/// Payload of an invocation.
+///
+/// Invoked after the FX rates have been refreshed, none of its fields are present and the scan
+/// starts anew. Invoked as [`Continuation`], it resumes the scan at `exclusive_start_key`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepriceEvent {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub exclusive_start_key: Option<ScanCheckpoint>,
+}
+
+/// Serializable `LastEvaluatedKey` of a scan over the base table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanCheckpoint {
+    pub pk: String,
+    pub sk: String,
+}
+
+impl ScanCheckpoint {
+    fn try_from_last_evaluated_key(
+        mut last_evaluated_key: HashMap<String, AttributeValue>,
+    ) -> Option<Self> {
+        match (
+            last_evaluated_key.remove("pk"),
+            last_evaluated_key.remove("sk"),
+        ) {
+            (Some(AttributeValue::S(pk)), Some(AttributeValue::S(sk))) => {
+                Some(ScanCheckpoint { pk, sk })
+            }
+            _ => None,
+        }
+    }
+
+    fn into_exclusive_start_key(self) -> HashMap<String, AttributeValue> {
+        HashMap::from([
+            ("pk".to_string(), AttributeValue::S(self.pk)),
+            ("sk".to_string(), AttributeValue::S(self.sk)),
+        ])
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepriceSummary {
+    /// Materialized items scanned.
+    pub scanned: usize,
+
+    /// Items whose exchanged prices have been overwritten in both DynamoDB and OpenSearch.
+    pub repriced: usize,
+
+    /// Items updated concurrently by their own events, which carry freshly exchanged prices.
+    pub skipped: usize,
+
+    pub failures: usize,
+
+    /// Where the scan continues, if the invocation ran out of time before finishing it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_evaluated_key: Option<ScanCheckpoint>,
+}
+
+/// Re-exchanges all items' native prices with the latest [`FxRateSnapshot`](common::price::fx_rate::FxRateSnapshot).
+///
+/// The snapshot is loaded per invocation, as a warm instance may still hold the previous one.
+/// A single invocation can't outlast its timeout, so it hands the remainder of the scan over
+/// to the next one via `continuation` before running out of time.
+#[tracing::instrument(
+    skip(item_repository, fx_rate_repository, opensearch_repository, continuation, event),
+    fields(requestId = %event.context.request_id)
+)]
+pub async fn handler(
+    item_repository: &(impl ItemDynamoDbRepository + Sync),
+    fx_rate_repository: &(impl FxRateRepository + Sync),
+    opensearch_repository: &impl ItemOpenSearchRepository,
+    continuation: &impl Continuation,
+    event: LambdaEvent<RepriceEvent>,
+) -> Result<RepriceSummary, lambda_runtime::Error> {
+    let fx_rate_snapshot = load_latest_fx_rate_snapshot(fx_rate_repository).await;
+    let exclusive_start_key = event.payload.exclusive_start_key;
+    info!(
+        fxRateDate = %fx_rate_snapshot.date(),
+        continued = exclusive_start_key.is_some(),
+        "Handler invoked."
+    );
+
+    let deadline = event
+        .context
+        .deadline()
+        .checked_sub(DEADLINE_RESERVE)
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+    let summary = reprice_items(
+        item_repository,
+        opensearch_repository,
+        &fx_rate_snapshot,
+        exclusive_start_key,
+        deadline,
+    )
+    .await?;
+
+    if let Some(last_evaluated_key) = &summary.last_evaluated_key {
+        let next = RepriceEvent {
+            exclusive_start_key: Some(last_evaluated_key.clone()),
+        };
+        if let Err(err) = continuation.continue_with(next).await {
+            error!(error = %err, "Failed continuing scan in next invocation.");
+            return Err(err.into());
+        }
+        info!(
+            pk = last_evaluated_key.pk,
+            "Running out of time, continuing scan in next invocation."
+        );
+    }
+
+    info!(
+        scanned = summary.scanned,
+        repriced = summary.repriced,
+        skipped = summary.skipped,
+        failures = summary.failures,
+        "Handler finished."
+    );
+    Ok(summary)
+}
+
+/// Scans all materialized items and overwrites their stale exchanged prices, first in DynamoDB,
+/// then in OpenSearch.
+///
+/// This is a silent projection update, so no events are emitted and neither `event_id` nor
+/// `updated` change. Items updated by an event since being scanned are skipped, because that
+/// event's prices are exchanged anew anyway.
+///
+/// Starts the scan at `exclusive_start_key`, if any, and doesn't start another page once
+/// `deadline` has passed. Then, the summary holds where to continue.
+pub async fn reprice_items(
+    item_repository: &(impl ItemDynamoDbRepository + Sync),
+    opensearch_repository: &impl ItemOpenSearchRepository,
+    fx_rate: &(impl FxRate + Sync),
+    exclusive_start_key: Option<ScanCheckpoint>,
+    deadline: SystemTime,
+) -> Result<RepriceSummary, lambda_runtime::Error> {
+    let mut summary = RepriceSummary::default();
+    let mut exclusive_start_key = exclusive_start_key.map(ScanCheckpoint::into_exclusive_start_key);
+
+    loop {
+        let page = match item_repository
+            .scan_item_prices_records(exclusive_start_key, SCAN_LIMIT)
+            .await
+        {
+            Ok(page) => page,
+            Err(err) => {
+                error!(error = %err, "Failed scanning ItemPricesRecords.");
+                return Err(err.into());
+            }
+        };
+        summary.scanned += page.items.len();
+
+        let repriced = page
+            .items
+            .into_iter()
+            .filter_map(|record| reprice_record(record, fx_rate, &mut summary))
+            .collect::<Vec<_>>();
+        let documents = update_item_records(item_repository, repriced, &mut summary).await;
+        if !documents.is_empty() {
+            update_item_documents(opensearch_repository, documents, &mut summary).await;
+        }
+
+        match page.last_evaluated_key {
+            None => break,
+            Some(last_evaluated_key) if SystemTime::now() >= deadline => {
+                let checkpoint = ScanCheckpoint::try_from_last_evaluated_key(last_evaluated_key)
+                    .ok_or("Scan returned LastEvaluatedKey without pk and sk.")?;
+                summary.last_evaluated_key = Some(checkpoint);
+                break;
+            }
+            Some(last_evaluated_key) => exclusive_start_key = Some(last_evaluated_key),
+        }
+    }
+
+    Ok(summary)
+}
+
+fn reprice_record(
+    record: ItemPricesRecord,
+    fx_rate: &impl FxRate,
+    summary: &mut RepriceSummary,
+) -> Option<ItemPricesRecord> {
+    match record.reprice(fx_rate) {
+        Ok(repriced) => repriced,
+        Err(err) => {
+            warn!(
+                error = %err,
+                itemId = %record.item_id,
+                "Failed exchanging native price of ItemPricesRecord."
+            );
+            summary.failures += 1;
+            None
+        }
+    }
+}
+
+async fn update_item_records(
+    item_repository: &(impl ItemDynamoDbRepository + Sync),
+    repriced: Vec<ItemPricesRecord>,
+    summary: &mut RepriceSummary,
+) -> HashMap<ItemId, ItemPricesDocument> {
+    let results = stream::iter(repriced)
+        .map(|record| async move {
+            let result = item_repository
+                .update_item_record_prices(record.clone())
+                .await;
+            (record, result)
+        })
+        .buffer_unordered(UPDATE_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut documents = HashMap::with_capacity(results.len());
+    for (record, result) in results {
+        match result {
+            Ok(_) => {
+                documents.insert(record.item_id, ItemPricesDocument::from(record));
+            }
+            Err(err) if is_stale_update(&err) => {
+                info!(itemId = %record.item_id, "Skipped item updated since scanning.");
+                summary.skipped += 1;
+            }
+            Err(err) => {
+                error!(error = %err, itemId = %record.item_id, "Failed updating prices of ItemRecord.");
+                summary.failures += 1;
+            }
+        }
+    }
+    documents
+}
+
+async fn update_item_documents(
+    opensearch_repository: &impl ItemOpenSearchRepository,
+    documents: HashMap<ItemId, ItemPricesDocument>,
+    summary: &mut RepriceSummary,
+) {
+    let documents_count = documents.len();
+    match opensearch_repository
+        .update_item_document_prices(documents)
+        .await
+    {
+        Ok(response) => handle_bulk_response(response, summary),
+        Err(err) => {
+            error!(error = ?err, total = documents_count, "Failed updating prices of entire batch in OpenSearch.");
+            summary.failures += documents_count;
+        }
+    }
+}
+
+fn handle_bulk_response(response: BulkResponse, summary: &mut RepriceSummary) {
+    for bulk_item_result in response.items {
+        match bulk_item_result {
+            BulkItemResult::Update { update } if update.is_noop() => {
+                info!(
+                    itemId = update.id,
+                    "Skipped item document not yet at the scanned event."
+                );
+                summary.skipped += 1;
+            }
+            BulkItemResult::Update { update } if update.is_err() => {
+                warn!(
+                    index = update.index,
+                    itemId = update.id,
+                    status = update.status,
+                    error = ?update.error,
+                    "Failed updating prices of item in OpenSearch."
+                );
+                summary.failures += 1;
+            }
+            BulkItemResult::Update { .. } => summary.repriced += 1,
+            other => {
+                error!(actual = ?other, "Expected BulkItemResult::Update.");
+                summary.failures += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::continuation::MockContinuation;
+    use crate::{RepriceEvent, RepriceSummary, ScanCheckpoint, handler, reprice_items};
+    use aws_sdk_dynamodb::config::http::HttpResponse;
+    use aws_sdk_dynamodb::error::SdkError;
+    use aws_sdk_dynamodb::operation::update_item::{UpdateItemError, UpdateItemOutput};
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use aws_sdk_dynamodb::types::error::ConditionalCheckFailedException;
+    use common::currency::record::CurrencyRecord;
+    use common::event_id::EventId;
+    use common::item_id::ItemId;
+    use common::opensearch::bulk_response::{BulkItemResult, BulkOpResult, BulkResponse};
+    use common::price::domain::FixedFxRate;
+    use common::price::record::PriceRecord;
+    use common::shop_id::ShopId;
+    use item_dynamodb::item_prices_record::ItemPricesRecord;
+    use item_dynamodb::repository::{
+        ItemPricesRecordPage, MockFxRateRepository, MockItemDynamoDbRepository,
+    };
+    use item_opensearch::repository::MockItemOpenSearchRepository;
+    use lambda_runtime::{Context, LambdaEvent};
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::time::{Duration, SystemTime};
+
+    fn mk_distant_deadline() -> SystemTime {
+        SystemTime::now() + Duration::from_secs(900)
+    }
+
+    fn mk_checkpoint() -> ScanCheckpoint {
+        ScanCheckpoint {
+            pk: "item#shop_id#abc#shops_item_id#123".to_string(),
+            sk: "item#materialized".to_string(),
+        }
+    }
+
+    fn mk_last_evaluated_key() -> HashMap<String, AttributeValue> {
+        let checkpoint = mk_checkpoint();
+        HashMap::from([
+            ("pk".to_string(), AttributeValue::S(checkpoint.pk)),
+            ("sk".to_string(), AttributeValue::S(checkpoint.sk)),
+        ])
+    }
+
+    fn mk_record(price_eur: u64, price_usd: Option<u64>) -> ItemPricesRecord {
+        ItemPricesRecord {
+            item_id: ItemId::new(),
+            event_id: EventId::new(),
+            shop_id: ShopId::new(),
+            shops_item_id: "123".into(),
+            price_native: Some(PriceRecord {
+                currency: CurrencyRecord::Eur,
+                amount: price_eur,
+            }),
+            price_eur: Some(price_eur),
+            price_usd,
+            price_gbp: Some(price_eur * 8678 / 10000),
+            price_aud: Some(price_eur * 17780 / 10000),
+            price_cad: Some(price_eur * 15970 / 10000),
+            price_nzd: Some(price_eur * 19470 / 10000),
+            price_chf: Some(price_eur * 9314 / 10000),
+            price_sek: Some(price_eur * 112150 / 10000),
+            price_dkk: Some(price_eur * 74630 / 10000),
+            price_nok: Some(price_eur * 118570 / 10000),
+            price_pln: Some(price_eur * 42645 / 10000),
+            price_czk: Some(price_eur * 246450 / 10000),
+            price_jpy: Some(price_eur * 17223 / 10000),
+            fx_rate_date: None,
+            repriced: None,
+        }
+    }
+
+    fn mk_conditional_check_failed() -> SdkError<UpdateItemError, HttpResponse> {
+        SdkError::service_error(
+            UpdateItemError::ConditionalCheckFailedException(
+                ConditionalCheckFailedException::builder()
+                    .set_item(Some(HashMap::from([(
+                        "event_id".to_string(),
+                        AttributeValue::S(EventId::new().to_string()),
+                    )])))
+                    .build(),
+            ),
+            HttpResponse::new(400u16.try_into().unwrap(), "{}".into()),
+        )
+    }
+
+    fn mk_bulk_response(item_ids: Vec<ItemId>, result: &str) -> BulkResponse {
+        BulkResponse {
+            took: 5,
+            errors: false,
+            items: item_ids
+                .into_iter()
+                .map(|item_id| BulkItemResult::Update {
+                    update: BulkOpResult {
+                        index: "items".to_string(),
+                        id: item_id.to_string(),
+                        version: Some(2),
+                        result: Some(result.to_string()),
+                        status: 200,
+                        error: None,
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_reprice_only_stale_items_across_pages() {
+        let stale = mk_record(10000, Some(11000));
+        let current = mk_record(10000, Some(11670));
+        let stale_item_id = stale.item_id;
+        let mut item_repository = MockItemDynamoDbRepository::default();
+        let first_page = ItemPricesRecordPage {
+            items: vec![stale],
+            last_evaluated_key: Some(HashMap::new()),
+        };
+        let second_page = ItemPricesRecordPage {
+            items: vec![current],
+            last_evaluated_key: None,
+        };
+        item_repository
+            .expect_scan_item_prices_records()
+            .withf(|exclusive_start_key, _| exclusive_start_key.is_none())
+            .times(1)
+            .return_once(|_, _| Box::pin(async { Ok(first_page) }));
+        item_repository
+            .expect_scan_item_prices_records()
+            .withf(|exclusive_start_key, _| exclusive_start_key.is_some())
+            .times(1)
+            .return_once(|_, _| Box::pin(async { Ok(second_page) }));
+        item_repository
+            .expect_update_item_record_prices()
+            .withf(move |record| record.item_id == stale_item_id && record.price_usd == Some(11670))
+            .times(1)
+            .return_once(|_| Box::pin(async { Ok(UpdateItemOutput::builder().build()) }));
+        let mut opensearch_repository = MockItemOpenSearchRepository::default();
+        opensearch_repository
+            .expect_update_item_document_prices()
+            .withf(move |documents| {
+                documents.len() == 1
+                    && documents
+                        .get(&stale_item_id)
+                        .is_some_and(|document| document.price_usd == Some(11670))
+            })
+            .times(1)
+            .return_once(|documents| {
+                let item_ids = documents.into_keys().collect();
+                Box::pin(async move { Ok(mk_bulk_response(item_ids, "updated")) })
+            });
+
+        let actual = reprice_items(
+            &item_repository,
+            &opensearch_repository,
+            &FixedFxRate(),
+            None,
+            mk_distant_deadline(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            RepriceSummary {
+                scanned: 2,
+                repriced: 1,
+                skipped: 0,
+                failures: 0,
+                last_evaluated_key: None,
+            },
+            actual
+        );
+    }
+
+    #[tokio::test]
+    async fn should_skip_items_updated_since_scanning() {
+        let stale = mk_record(10000, Some(11000));
+        let mut item_repository = MockItemDynamoDbRepository::default();
+        item_repository
+            .expect_scan_item_prices_records()
+            .times(1)
+            .return_once(|_, _| {
+                Box::pin(async {
+                    Ok(ItemPricesRecordPage {
+                        items: vec![stale],
+                        last_evaluated_key: None,
+                    })
+                })
+            });
+        item_repository
+            .expect_update_item_record_prices()
+            .times(1)
+            .return_once(|_| Box::pin(async { Err(mk_conditional_check_failed()) }));
+        let mut opensearch_repository = MockItemOpenSearchRepository::default();
+        opensearch_repository
+            .expect_update_item_document_prices()
+            .never();
+
+        let actual = reprice_items(
+            &item_repository,
+            &opensearch_repository,
+            &FixedFxRate(),
+            None,
+            mk_distant_deadline(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            RepriceSummary {
+                scanned: 1,
+                repriced: 0,
+                skipped: 1,
+                failures: 0,
+                last_evaluated_key: None,
+            },
+            actual
+        );
+    }
+
+    #[tokio::test]
+    async fn should_skip_item_documents_not_yet_at_scanned_event() {
+        let stale = mk_record(10000, Some(11000));
+        let mut item_repository = MockItemDynamoDbRepository::default();
+        item_repository
+            .expect_scan_item_prices_records()
+            .times(1)
+            .return_once(|_, _| {
+                Box::pin(async {
+                    Ok(ItemPricesRecordPage {
+                        items: vec![stale],
+                        last_evaluated_key: None,
+                    })
+                })
+            });
+        item_repository
+            .expect_update_item_record_prices()
+            .times(1)
+            .return_once(|_| Box::pin(async { Ok(UpdateItemOutput::builder().build()) }));
+        let mut opensearch_repository = MockItemOpenSearchRepository::default();
+        opensearch_repository
+            .expect_update_item_document_prices()
+            .times(1)
+            .return_once(|documents| {
+                let item_ids = documents.into_keys().collect();
+                Box::pin(async move { Ok(mk_bulk_response(item_ids, "noop")) })
+            });
+
+        let actual = reprice_items(
+            &item_repository,
+            &opensearch_repository,
+            &FixedFxRate(),
+            None,
+            mk_distant_deadline(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            RepriceSummary {
+                scanned: 1,
+                repriced: 0,
+                skipped: 1,
+                failures: 0,
+                last_evaluated_key: None,
+            },
+            actual
+        );
+    }
+
+    #[tokio::test]
+    async fn should_stop_scanning_at_last_evaluated_key_when_deadline_has_passed() {
+        let stale = mk_record(10000, Some(11000));
+        let mut item_repository = MockItemDynamoDbRepository::default();
+        item_repository
+            .expect_scan_item_prices_records()
+            .times(1)
+            .return_once(|_, _| {
+                Box::pin(async {
+                    Ok(ItemPricesRecordPage {
+                        items: vec![stale],
+                        last_evaluated_key: Some(mk_last_evaluated_key()),
+                    })
+                })
+            });
+        item_repository
+            .expect_update_item_record_prices()
+            .times(1)
+            .return_once(|_| Box::pin(async { Ok(UpdateItemOutput::builder().build()) }));
+        let mut opensearch_repository = MockItemOpenSearchRepository::default();
+        opensearch_repository
+            .expect_update_item_document_prices()
+            .times(1)
+            .return_once(|documents| {
+                let item_ids = documents.into_keys().collect();
+                Box::pin(async move { Ok(mk_bulk_response(item_ids, "updated")) })
+            });
+
+        let actual = reprice_items(
+            &item_repository,
+            &opensearch_repository,
+            &FixedFxRate(),
+            None,
+            SystemTime::UNIX_EPOCH,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            RepriceSummary {
+                scanned: 1,
+                repriced: 1,
+                skipped: 0,
+                failures: 0,
+                last_evaluated_key: Some(mk_checkpoint()),
+            },
+            actual
+        );
+    }
+
+    #[tokio::test]
+    async fn should_resume_scanning_at_exclusive_start_key() {
+        let mut item_repository = MockItemDynamoDbRepository::default();
+        item_repository
+            .expect_scan_item_prices_records()
+            .withf(|exclusive_start_key, _| {
+                exclusive_start_key.as_ref() == Some(&mk_last_evaluated_key())
+            })
+            .times(1)
+            .return_once(|_, _| {
+                Box::pin(async {
+                    Ok(ItemPricesRecordPage {
+                        items: vec![],
+                        last_evaluated_key: None,
+                    })
+                })
+            });
+        let opensearch_repository = MockItemOpenSearchRepository::default();
+
+        let actual = reprice_items(
+            &item_repository,
+            &opensearch_repository,
+            &FixedFxRate(),
+            Some(mk_checkpoint()),
+            mk_distant_deadline(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(RepriceSummary::default(), actual);
+    }
+
+    #[tokio::test]
+    async fn should_continue_in_next_invocation_when_running_out_of_time() {
+        let mut item_repository = MockItemDynamoDbRepository::default();
+        item_repository
+            .expect_scan_item_prices_records()
+            .times(1)
+            .return_once(|_, _| {
+                Box::pin(async {
+                    Ok(ItemPricesRecordPage {
+                        items: vec![],
+                        last_evaluated_key: Some(mk_last_evaluated_key()),
+                    })
+                })
+            });
+        let mut fx_rate_repository = MockFxRateRepository::default();
+        fx_rate_repository
+            .expect_get_latest_fx_rate_snapshot_record()
+            .return_once(|| Box::pin(async { Ok(None) }));
+        let opensearch_repository = MockItemOpenSearchRepository::default();
+        let mut continuation = MockContinuation::default();
+        continuation
+            .expect_continue_with()
+            .withf(|event| event.exclusive_start_key == Some(mk_checkpoint()))
+            .times(1)
+            .return_once(|_| Box::pin(async { Ok(()) }));
+        // the default context's deadline has long passed
+        let event = LambdaEvent::new(RepriceEvent::default(), Context::default());
+
+        let actual = handler(
+            &item_repository,
+            &fx_rate_repository,
+            &opensearch_repository,
+            &continuation,
+            event,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(Some(mk_checkpoint()), actual.last_evaluated_key);
+    }
+
+    #[test]
+    fn should_start_anew_when_invoked_as_destination_of_refreshing_fx_rates() {
+        let destination_record = json!({
+            "version": "1.0",
+            "timestamp": "2025-10-17T15:30:12.345Z",
+            "requestContext": {
+                "requestId": "c6af9ac6-7b61-11e6-9a41-93e812345678",
+                "functionArn": "arn:aws:lambda:eu-central-1:123456789012:function:item-lambda-refresh-fx-rate-prod:$LATEST",
+                "condition": "Success",
+                "approximateInvokeCount": 1
+            },
+            "requestPayload": {},
+            "responseContext": {
+                "statusCode": 200,
+                "executedVersion": "$LATEST"
+            },
+            "responsePayload": null
+        });
+
+        let actual: RepriceEvent = serde_json::from_value(destination_record).unwrap();
+
+        assert_eq!(RepriceEvent::default(), actual);
+    }
+
+    #[test]
+    fn should_round_trip_continuation_event() {
+        let expected = RepriceEvent {
+            exclusive_start_key: Some(mk_checkpoint()),
+        };
+
+        let actual: RepriceEvent =
+            serde_json::from_value(serde_json::to_value(&expected).unwrap()).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,61 @@
+use aws_config::BehaviorVersion;
+use aws_sdk_dynamodb::Client;
+use item_dynamodb::repository::ItemDynamoDbRepositoryImpl;
+use item_lambda_reprice::continuation::LambdaContinuation;
+use item_lambda_reprice::{RepriceEvent, handler};
+use item_opensearch::repository::ItemOpenSearchRepositoryImpl;
+use lambda_runtime::{Error, LambdaEvent, run, service_fn};
+use opensearch::http::transport::{SingleNodeConnectionPool, TransportBuilder};
+use std::env;
+use tracing::info;
+use url::Url;
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    tracing_subscriber::fmt()
+        .json()
+        .with_max_level(tracing::Level::INFO)
+        .with_current_span(true)
+        .with_ansi(false)
+        .without_time()
+        .init();
+
+    let aws_config = aws_config::defaults(BehaviorVersion::v2025_08_07())
+        .load()
+        .await;
+
+    let table_name = env::var("DYNAMODB_TABLE_NAME")?;
+    let dynamodb_client = Client::new(&aws_config);
+    let dynamodb_repository = ItemDynamoDbRepositoryImpl::new(&dynamodb_client, &table_name);
+
+    // set by the Lambda runtime
+    let function_name = env::var("AWS_LAMBDA_FUNCTION_NAME")?;
+    let continuation =
+        LambdaContinuation::new(aws_sdk_lambda::Client::new(&aws_config), &function_name);
+
+    let os_endpoint_url = Url::parse(&env::var("OPENSEARCH_ITEMS_DOMAIN_ENDPOINT_URL")?)?;
+    let transport = TransportBuilder::new(SingleNodeConnectionPool::new(os_endpoint_url))
+        .auth(aws_config.try_into()?)
+        .service_name("es")
+        .build()?;
+    let opensearch_client = opensearch::OpenSearch::new(transport);
+    let opensearch_repository = ItemOpenSearchRepositoryImpl::new(&opensearch_client);
+
+    info!(
+        dynamoDbTableName = %table_name,
+        functionName = %function_name,
+        "Lambda cold start completed, clients initialized."
+    );
+
+    run(service_fn(|event: LambdaEvent<RepriceEvent>| async {
+        handler(
+            &dynamodb_repository,
+            &dynamodb_repository,
+            &opensearch_repository,
+            &continuation,
+            event,
+        )
+        .await
+    }))
+    .await
+}
@@ -0,0 +1,406 @@
+pub mod translator;
+
+use crate::translator::{TranslateError, Translator};
+use aws_lambda_events::sqs::{BatchItemFailure, SqsBatchResponse, SqsEvent, SqsMessage};
+use aws_sdk_dynamodb::config::http::HttpResponse;
+use aws_sdk_dynamodb::error::SdkError;
+use aws_sdk_dynamodb::operation::get_item::GetItemError;
+use common::item_id::ItemKey;
+use common::language::domain::Language;
+use common::{batch::Batch, batch::dynamodb::handle_batch_output, has_key::HasKey};
+use futures::StreamExt;
+use futures::stream;
+use item_core::description::Description;
+use item_core::item::Item;
+use item_core::title::Title;
+use item_dynamodb::item_event_record::ItemEventRecord;
+use item_dynamodb::item_event_type_record::ItemEventTypeRecord;
+use item_dynamodb::repository::ItemDynamoDbRepository;
+use item_lambda_common::extract_item_event_record;
+use lambda_runtime::LambdaEvent;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use strum::IntoEnumIterator;
+use tracing::{error, info};
+
+/// Items translated at once, each of which requests one translation per missing language and text.
+pub const TRANSLATE_CONCURRENCY: usize = 10;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TranslateItemError {
+    #[error("Failed reading materialized item: {0}")]
+    Read(#[from] Box<SdkError<GetItemError, HttpResponse>>),
+
+    #[error("Item '{0}' hasn't been materialized yet.")]
+    NotMaterialized(ItemKey),
+
+    #[error(transparent)]
+    Translate(#[from] TranslateError),
+
+    #[error("Failed formatting event timestamp: {0}")]
+    Format(#[from] time::error::Format),
+}
+
+#[tracing::instrument(skip(repository, translator, event), fields(requestId = %event.context.request_id))]
+pub async fn handler(
+    repository: &(impl ItemDynamoDbRepository + Sync),
+    translator: &(impl Translator + Sync),
+    event: LambdaEvent<SqsEvent>,
+) -> Result<SqsBatchResponse, lambda_runtime::Error> {
+    let records_count = event.payload.records.len();
+    info!(total = records_count, "Handler invoked.",);
+
+    let mut failed_message_ids = Vec::new();
+    let mut skipped_count = 0;
+    let mut message_ids: HashMap<ItemKey, String> = HashMap::with_capacity(records_count);
+
+    for message in event.payload.records {
+        if let Some((key, message_id)) =
+            extract_message_data(message, &mut failed_message_ids, &mut skipped_count)
+        {
+            // duplicate deliveries of the same Created-event translate the item only once
+            match message_ids.entry(key) {
+                Entry::Vacant(entry) => {
+                    entry.insert(message_id);
+                }
+                Entry::Occupied(_) => skipped_count += 1,
+            }
+        }
+    }
+
+    let translations = stream::iter(message_ids.keys().cloned())
+        .map(|key| async move {
+            let translation = translate_item(repository, translator, &key).await;
+            (key, translation)
+        })
+        .buffer_unordered(TRANSLATE_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut translated_records = Vec::with_capacity(translations.len());
+    for (key, translation) in translations {
+        match translation {
+            Ok(Some(item_event_record)) => translated_records.push(item_event_record),
+            Ok(None) => {
+                info!(itemKey = %key, "Skipped item with all languages available.");
+                message_ids.remove(&key);
+                skipped_count += 1;
+            }
+            Err(err) => {
+                error!(error = %err, itemKey = %key, "Failed translating item.");
+                if let Some(message_id) = message_ids.remove(&key) {
+                    failed_message_ids.push(message_id);
+                }
+            }
+        }
+    }
+
+    for batch in Batch::<ItemEventRecord, 25>::chunked_from(translated_records.into_iter()) {
+        let item_keys = batch.iter().map(ItemEventRecord::key).collect::<Vec<_>>();
+        let mut failures = Vec::new();
+        match repository.put_item_event_records(batch).await {
+            Ok(output) => {
+                handle_batch_output::<ItemEventRecord>(output, &mut failures);
+            }
+            Err(err) => {
+                error!(error = ?err, "Failed entire batch.");
+                failures = item_keys;
+            }
+        }
+        failures
+            .into_iter()
+            .filter_map(|key| match message_ids.remove(&key) {
+                Some(message_id) => Some(message_id),
+                None => {
+                    error!(
+                        itemKey = %key,
+                        "There exists no message_id for failed ItemEventRecord."
+                    );
+                    None
+                }
+            })
+            .for_each(|message_id| failed_message_ids.push(message_id));
+    }
+
+    let failure_count = failed_message_ids.len();
+    info!(
+        successful = records_count - failure_count - skipped_count,
+        failures = failure_count,
+        skipped = skipped_count,
+        "Handler finished.",
+    );
+    let sqs_batch_response = SqsBatchResponse {
+        batch_item_failures: failed_message_ids
+            .into_iter()
+            .map(|item_identifier| BatchItemFailure { item_identifier })
+            .collect(),
+    };
+    Ok(sqs_batch_response)
+}
+
+fn extract_message_data(
+    message: SqsMessage,
+    failed_message_ids: &mut Vec<String>,
+    skipped_count: &mut usize,
+) -> Option<(ItemKey, String)> {
+    let message_id = message
+        .message_id
+        .clone()
+        .expect("shouldn't receive an SQS-Message without 'message_id' because AWS sets it.");
+    let item_event_record = extract_item_event_record(message, failed_message_ids, skipped_count)?;
+    match item_event_record.event_type {
+        ItemEventTypeRecord::Created => Some((item_event_record.into_item_key(), message_id)),
+        _ => {
+            info!(
+                eventType = ?item_event_record.event_type,
+                "Received non-Created event. Skipping message."
+            );
+            *skipped_count += 1;
+            None
+        }
+    }
+}
+
+/// Translates the materialized item's native title and description into all missing languages.
+///
+/// The materialized item is read instead of the Created-event, so that the emitted event carries
+/// the item's current hash. If it hasn't been materialized yet, the message is retried.
+pub async fn translate_item(
+    repository: &impl ItemDynamoDbRepository,
+    translator: &impl Translator,
+    key: &ItemKey,
+) -> Result<Option<ItemEventRecord>, TranslateItemError> {
+    let item_record = repository
+        .get_item_record(&key.shop_id, &key.shops_item_id)
+        .await
+        .map_err(Box::new)?
+        .ok_or_else(|| TranslateItemError::NotMaterialized(key.clone()))?;
+    let mut item = Item::from(item_record);
+
+    let title_language = item.native_title.localization;
+    let mut title_translations = HashMap::new();
+    for language in Language::iter()
+        .filter(|language| *language != title_language && !item.other_title.contains_key(language))
+    {
+        let translation = translator
+            .translate(&item.native_title.payload, title_language, language)
+            .await?;
+        title_translations.insert(language, Title::from(translation));
+    }
+
+    let mut description_translations = HashMap::new();
+    if let Some(native_description) = &item.native_description {
+        let description_language = native_description.localization;
+        for language in Language::iter().filter(|language| {
+            *language != description_language && !item.other_description.contains_key(language)
+        }) {
+            let translation = translator
+                .translate(&native_description.payload, description_language, language)
+                .await?;
+            description_translations.insert(language, Description::from(translation));
+        }
+    }
+
+    let translated_event_record = item
+        .translate(title_translations, description_translations)
+        .map(ItemEventRecord::try_from)
+        .transpose()?;
+    Ok(translated_event_record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::handler;
+    use crate::translator::StubTranslator;
+    use aws_lambda_events::dynamodb::{EventRecord, StreamRecord};
+    use aws_lambda_events::eventbridge::EventBridgeEvent;
+    use aws_lambda_events::sqs::{SqsEvent, SqsMessage};
+    use aws_sdk_dynamodb::operation::batch_write_item::BatchWriteItemOutput;
+    use common::language::record::{LanguageRecord, TextRecord};
+    use fake::{Fake, Faker};
+    use item_core::item_event::ItemEvent;
+    use item_dynamodb::item_event_record::ItemEventRecord;
+    use item_dynamodb::item_event_type_record::ItemEventTypeRecord;
+    use item_dynamodb::item_record::ItemRecord;
+    use item_dynamodb::repository::MockItemDynamoDbRepository;
+    use lambda_runtime::{Context, LambdaEvent};
+    use std::time::SystemTime;
+    use uuid::Uuid;
+
+    fn mk_event_bridge_payload(item_event_record: &ItemEventRecord) -> String {
+        let event = EventBridgeEvent {
+            version: None,
+            id: None,
+            detail_type: "foo".to_string(),
+            source: "bar".to_string(),
+            account: None,
+            time: None,
+            region: None,
+            resources: None,
+            detail: EventRecord {
+                aws_region: "eu-central-1".to_string(),
+                change: StreamRecord {
+                    approximate_creation_date_time: SystemTime::now().into(),
+                    keys: Default::default(),
+                    new_image: serde_dynamo::to_item(item_event_record).unwrap(),
+                    old_image: Default::default(),
+                    sequence_number: None,
+                    size_bytes: 42,
+                    stream_view_type: None,
+                },
+                event_id: Uuid::new_v4().to_string(),
+                event_name: "INSERT".to_string(),
+                event_source: None,
+                event_version: None,
+                event_source_arn: None,
+                user_identity: None,
+                record_format: None,
+                table_name: None,
+            },
+        };
+        serde_json::to_string(&event).unwrap()
+    }
+
+    fn mk_lambda_event(item_event_records: &[ItemEventRecord]) -> LambdaEvent<SqsEvent> {
+        let records = item_event_records
+            .iter()
+            .map(|item_event_record| SqsMessage {
+                message_id: Some(Faker.fake()),
+                receipt_handle: None,
+                body: Some(mk_event_bridge_payload(item_event_record)),
+                md5_of_body: None,
+                md5_of_message_attributes: None,
+                attributes: Default::default(),
+                message_attributes: Default::default(),
+                event_source_arn: None,
+                event_source: None,
+                aws_region: None,
+            })
+            .collect();
+        LambdaEvent {
+            payload: SqsEvent { records },
+            context: Context::default(),
+        }
+    }
+
+    fn mk_created_event_record() -> ItemEventRecord {
+        let mut item_event_record: ItemEventRecord =
+            ItemEventRecord::try_from(Faker.fake::<ItemEvent>()).unwrap();
+        item_event_record.event_type = ItemEventTypeRecord::Created;
+        item_event_record
+    }
+
+    fn mk_german_item_record(item_event_record: &ItemEventRecord) -> ItemRecord {
+        let mut item_record: ItemRecord = Faker.fake();
+        item_record.shop_id = item_event_record.shop_id.clone();
+        item_record.shops_item_id = item_event_record.shops_item_id.clone();
+        item_record.title_native = TextRecord::new("Pickelhaube", LanguageRecord::De);
+        item_record.title_de = Some("Pickelhaube".to_string());
+        item_record.title_en = Some("Spiked helmet".to_string());
+        item_record.title_fr = None;
+        item_record.title_es = None;
+        item_record.description_native = Some(TextRecord::new("Preußisch", LanguageRecord::De));
+        item_record.description_de = Some("Preußisch".to_string());
+        item_record.description_en = None;
+        item_record.description_fr = None;
+        item_record.description_es = None;
+        item_record
+    }
+
+    #[tokio::test]
+    async fn should_emit_translations_for_missing_languages() {
+        let created_event_record = mk_created_event_record();
+        let item_record = mk_german_item_record(&created_event_record);
+        let lambda_event = mk_lambda_event(&[created_event_record]);
+        let mut repository = MockItemDynamoDbRepository::default();
+        repository
+            .expect_get_item_record()
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(item_record)) }));
+        repository
+            .expect_put_item_event_records()
+            .once()
+            .withf(|batch| {
+                let records = batch.iter().collect::<Vec<_>>();
+                let [record] = records.as_slice() else {
+                    return false;
+                };
+                record.event_type == ItemEventTypeRecord::Translated
+                    && record.title_native.is_none()
+                    && record.title_de.is_none()
+                    && record.title_en.is_none()
+                    && record.title_fr.as_deref() == Some("[Fr] Pickelhaube")
+                    && record.title_es.as_deref() == Some("[Es] Pickelhaube")
+                    && record.description_de.is_none()
+                    && record.description_en.as_deref() == Some("[En] Preußisch")
+                    && record.description_fr.as_deref() == Some("[Fr] Preußisch")
+                    && record.description_es.as_deref() == Some("[Es] Preußisch")
+            })
+            .returning(|_| Box::pin(async { Ok(BatchWriteItemOutput::builder().build()) }));
+
+        let actual = handler(&repository, &StubTranslator, lambda_event)
+            .await
+            .unwrap();
+
+        assert!(actual.batch_item_failures.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_skip_item_when_all_languages_are_available() {
+        let created_event_record = mk_created_event_record();
+        let item_record: ItemRecord = Faker.fake();
+        let lambda_event = mk_lambda_event(&[created_event_record]);
+        let mut repository = MockItemDynamoDbRepository::default();
+        repository
+            .expect_get_item_record()
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(item_record)) }));
+        repository.expect_put_item_event_records().never();
+
+        let actual = handler(&repository, &StubTranslator, lambda_event)
+            .await
+            .unwrap();
+
+        assert!(actual.batch_item_failures.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_fail_message_when_item_is_not_materialized_yet() {
+        let created_event_record = mk_created_event_record();
+        let lambda_event = mk_lambda_event(&[created_event_record]);
+        let expected_message_id = lambda_event.payload.records[0].message_id.clone().unwrap();
+        let mut repository = MockItemDynamoDbRepository::default();
+        repository
+            .expect_get_item_record()
+            .return_once(|_, _| Box::pin(async { Ok(None) }));
+        repository.expect_put_item_event_records().never();
+
+        let actual = handler(&repository, &StubTranslator, lambda_event)
+            .await
+            .unwrap();
+
+        assert_eq!(1, actual.batch_item_failures.len());
+        assert_eq!(
+            expected_message_id,
+            actual.batch_item_failures[0].item_identifier
+        );
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::state_listed(ItemEventTypeRecord::StateListed)]
+    #[case::price_dropped(ItemEventTypeRecord::PriceDropped)]
+    #[case::translated(ItemEventTypeRecord::Translated)]
+    async fn should_skip_non_created_events(#[case] event_type: ItemEventTypeRecord) {
+        let mut item_event_record = mk_created_event_record();
+        item_event_record.event_type = event_type;
+        let lambda_event = mk_lambda_event(&[item_event_record]);
+        let mut repository = MockItemDynamoDbRepository::default();
+        repository.expect_get_item_record().never();
+        repository.expect_put_item_event_records().never();
+
+        let actual = handler(&repository, &StubTranslator, lambda_event)
+            .await
+            .unwrap();
+
+        assert!(actual.batch_item_failures.is_empty());
+    }
+}
@@ -0,0 +1,41 @@
+use aws_config::BehaviorVersion;
+use aws_lambda_events::sqs::SqsEvent;
+use aws_sdk_dynamodb::Client;
+use item_dynamodb::repository::ItemDynamoDbRepositoryImpl;
+use item_lambda_translate::handler;
+use item_lambda_translate::translator::{DEEPL_TRANSLATE_URL, DeepLTranslator};
+use lambda_runtime::{Error, LambdaEvent, run, service_fn};
+use tracing::info;
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    tracing_subscriber::fmt()
+        .json()
+        .with_max_level(tracing::Level::INFO)
+        .with_current_span(true)
+        .with_ansi(false)
+        .without_time()
+        .init();
+
+    let aws_config = aws_config::defaults(BehaviorVersion::v2025_08_07())
+        .load()
+        .await;
+
+    let table_name = std::env::var("DYNAMODB_TABLE_NAME")?;
+    let client = Client::new(&aws_config);
+    let repository = ItemDynamoDbRepositoryImpl::new(&client, &table_name);
+
+    let deepl_auth_key = std::env::var("DEEPL_AUTH_KEY")?;
+    let translator =
+        DeepLTranslator::new(reqwest::Client::new(), DEEPL_TRANSLATE_URL, &deepl_auth_key);
+
+    info!(
+        dynamoDbTableName = %table_name,
+        "Lambda cold start completed, clients initialized."
+    );
+
+    run(service_fn(|event: LambdaEvent<SqsEvent>| async {
+        handler(&repository, &translator, event).await
+    }))
+    .await
+}
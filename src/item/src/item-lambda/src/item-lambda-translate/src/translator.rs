@@ -0,0 +1,163 @@
+use async_trait::async_trait;
+use common::language::domain::Language;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+
+/// DeepL's translation endpoint for paid plans.
+pub const DEEPL_TRANSLATE_URL: &str = "https://api.deepl.com/v2/translate";
+
+#[derive(Debug, thiserror::Error)]
+pub enum TranslateError {
+    #[error("Failed requesting translation: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("Failed (de-)serializing translation: {0}")]
+    Serde(#[from] serde_json::Error),
+
+    #[error("Received no translation.")]
+    Empty,
+}
+
+/// Machine-translates texts between [`Language`]s.
+#[async_trait]
+pub trait Translator {
+    async fn translate(
+        &self,
+        text: &str,
+        source: Language,
+        target: Language,
+    ) -> Result<String, TranslateError>;
+}
+
+/// Prefixes the text with the target language instead of translating it, for tests and local runs.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StubTranslator;
+
+#[async_trait]
+impl Translator for StubTranslator {
+    async fn translate(
+        &self,
+        text: &str,
+        _source: Language,
+        target: Language,
+    ) -> Result<String, TranslateError> {
+        Ok(format!("[{target:?}] {text}"))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeepLTranslator {
+    http_client: reqwest::Client,
+    url: String,
+    auth_key: String,
+}
+
+impl DeepLTranslator {
+    pub fn new(http_client: reqwest::Client, url: &str, auth_key: &str) -> Self {
+        DeepLTranslator {
+            http_client,
+            url: url.to_owned(),
+            auth_key: auth_key.to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct DeepLTranslateRequest<'a> {
+    text: [&'a str; 1],
+    source_lang: &'static str,
+    target_lang: &'static str,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepLTranslateResponse {
+    translations: Vec<DeepLTranslation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepLTranslation {
+    text: String,
+}
+
+fn deepl_source_lang(language: Language) -> &'static str {
+    match language {
+        Language::De => "DE",
+        Language::En => "EN",
+        Language::Fr => "FR",
+        Language::Es => "ES",
+    }
+}
+
+/// DeepL only accepts English as target in a regional variant.
+fn deepl_target_lang(language: Language) -> &'static str {
+    match language {
+        Language::En => "EN-GB",
+        other => deepl_source_lang(other),
+    }
+}
+
+#[async_trait]
+impl Translator for DeepLTranslator {
+    async fn translate(
+        &self,
+        text: &str,
+        source: Language,
+        target: Language,
+    ) -> Result<String, TranslateError> {
+        let request = DeepLTranslateRequest {
+            text: [text],
+            source_lang: deepl_source_lang(source),
+            target_lang: deepl_target_lang(target),
+        };
+        let response = self
+            .http_client
+            .post(&self.url)
+            .header(AUTHORIZATION, format!("DeepL-Auth-Key {}", self.auth_key))
+            .header(CONTENT_TYPE, "application/json")
+            .body(serde_json::to_string(&request)?)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        serde_json::from_str::<DeepLTranslateResponse>(&response)?
+            .translations
+            .into_iter()
+            .next()
+            .map(|translation| translation.text)
+            .ok_or(TranslateError::Empty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DeepLTranslateRequest, StubTranslator, Translator};
+    use common::language::domain::Language;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn should_prefix_target_language_for_stub_translator() {
+        let actual = StubTranslator
+            .translate("Pickelhaube", Language::De, Language::Fr)
+            .await
+            .unwrap();
+
+        assert_eq!("[Fr] Pickelhaube", actual);
+    }
+
+    #[test]
+    fn should_serialize_deepl_translate_request() {
+        let request = DeepLTranslateRequest {
+            text: ["Pickelhaube"],
+            source_lang: "DE",
+            target_lang: "EN-GB",
+        };
+
+        let actual = serde_json::to_value(request).unwrap();
+
+        assert_eq!(
+            json!({"text": ["Pickelhaube"], "source_lang": "DE", "target_lang": "EN-GB"}),
+            actual
+        );
+    }
+}
@@ -1,8 +1,7 @@
 use aws_config::BehaviorVersion;
 use aws_lambda_events::sqs::SqsEvent;
 use aws_sdk_dynamodb::Client;
-use common::price::domain::FixedFxRate;
-use item_dynamodb::repository::ItemDynamoDbRepositoryImpl;
+use item_dynamodb::repository::{FxRateSnapshotCache, ItemDynamoDbRepositoryImpl};
 use item_lambda_write_update::handler;
 use item_service::command_service::CommandItemServiceImpl;
 use lambda_runtime::{Error, LambdaEvent, run, service_fn};
@@ -25,8 +24,8 @@ async fn main() -> Result<(), Error> {
     let table_name = std::env::var("DYNAMODB_TABLE_NAME")?;
     let client = Client::new(&aws_config);
     let dynamodb_repository = ItemDynamoDbRepositoryImpl::new(&client, &table_name);
-    let fx_rate = FixedFxRate::default();
-    let service = CommandItemServiceImpl::new(&dynamodb_repository, &fx_rate);
+    // reloaded once expired, as the rates are refreshed while instances are warm
+    let fx_rate_cache = FxRateSnapshotCache::default();
 
     info!(
         dynamoDbTableName = %table_name,
@@ -34,6 +33,8 @@ async fn main() -> Result<(), Error> {
     );
 
     run(service_fn(|event: LambdaEvent<SqsEvent>| async {
+        let fx_rate = fx_rate_cache.get(&dynamodb_repository).await;
+        let service = CommandItemServiceImpl::new(&dynamodb_repository, fx_rate.as_ref());
         handler(&service, event).await
     }))
     .await
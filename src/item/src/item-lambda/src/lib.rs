@@ -3,5 +3,8 @@ pub use item_lambda_materialize_dynamodb_new;
 pub use item_lambda_materialize_dynamodb_update;
 pub use item_lambda_materialize_opensearch_new;
 pub use item_lambda_materialize_opensearch_update;
+pub use item_lambda_refresh_fx_rate;
+pub use item_lambda_reprice;
+pub use item_lambda_translate;
 pub use item_lambda_write_new;
 pub use item_lambda_write_update;
@@ -32,12 +32,24 @@ pub struct ItemDocument {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub title_en: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_fr: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_es: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub description_de: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub description_en: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_fr: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_es: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub price_eur: Option<u64>,
 
@@ -56,6 +68,27 @@ pub struct ItemDocument {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub price_nzd: Option<u64>,
 
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_chf: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_sek: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_dkk: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_nok: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_pln: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_czk: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_jpy: Option<u64>,
+
     pub state: ItemStateDocument,
 
     pub is_available: bool,
@@ -107,14 +140,25 @@ impl TryFrom<ItemEventRecord> for ItemDocument {
                 .ok_or_else(|| MissingPersistenceField::new(field!(shop_name@ItemEventRecord)))?,
             title_de: event_record.title_de,
             title_en: event_record.title_en,
+            title_fr: event_record.title_fr,
+            title_es: event_record.title_es,
             description_de: event_record.description_de,
             description_en: event_record.description_en,
+            description_fr: event_record.description_fr,
+            description_es: event_record.description_es,
             price_eur: event_record.price_eur,
             price_usd: event_record.price_usd,
             price_gbp: event_record.price_gbp,
             price_aud: event_record.price_aud,
             price_cad: event_record.price_cad,
             price_nzd: event_record.price_nzd,
+            price_chf: event_record.price_chf,
+            price_sek: event_record.price_sek,
+            price_dkk: event_record.price_dkk,
+            price_nok: event_record.price_nok,
+            price_pln: event_record.price_pln,
+            price_czk: event_record.price_czk,
+            price_jpy: event_record.price_jpy,
             state,
             url: event_record
                 .url
@@ -138,14 +182,25 @@ impl From<ItemRecord> for ItemDocument {
             shop_name: record.shop_name,
             title_de: record.title_de,
             title_en: record.title_en,
+            title_fr: record.title_fr,
+            title_es: record.title_es,
             description_de: record.description_de,
             description_en: record.description_en,
+            description_fr: record.description_fr,
+            description_es: record.description_es,
             price_eur: record.price_eur,
-            price_usd: record.price_gbp,
+            price_usd: record.price_usd,
             price_gbp: record.price_gbp,
             price_aud: record.price_aud,
             price_cad: record.price_cad,
             price_nzd: record.price_nzd,
+            price_chf: record.price_chf,
+            price_sek: record.price_sek,
+            price_dkk: record.price_dkk,
+            price_nok: record.price_nok,
+            price_pln: record.price_pln,
+            price_czk: record.price_czk,
+            price_jpy: record.price_jpy,
             state: record.state.into(),
             is_available: matches!(record.state, ItemStateRecord::Available),
             url: record.url,
@@ -176,14 +231,25 @@ mod faker {
                 shop_name: config.fake_with_rng::<ShopName, _>(rng).into(),
                 title_de: Some(config.fake_with_rng::<Title, _>(rng).to_string()),
                 title_en: Some(config.fake_with_rng::<Title, _>(rng).to_string()),
+                title_fr: Some(config.fake_with_rng::<Title, _>(rng).to_string()),
+                title_es: Some(config.fake_with_rng::<Title, _>(rng).to_string()),
                 description_de: Some(config.fake_with_rng::<Description, _>(rng).to_string()),
                 description_en: Some(config.fake_with_rng::<Description, _>(rng).to_string()),
+                description_fr: Some(config.fake_with_rng::<Description, _>(rng).to_string()),
+                description_es: Some(config.fake_with_rng::<Description, _>(rng).to_string()),
                 price_eur: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_usd: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_gbp: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_aud: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_cad: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_nzd: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_chf: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_sek: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_dkk: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_nok: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_pln: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_czk: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_jpy: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 state,
                 is_available: matches!(state, ItemStateDocument::Available),
                 url: Url::parse(&format!(
@@ -0,0 +1,110 @@
+use common::event_id::EventId;
+use item_dynamodb::item_prices_record::ItemPricesRecord;
+use serde::Serialize;
+
+/// Exchanged prices of an [`ItemDocument`](crate::item_document::ItemDocument) as of `event_id`.
+///
+/// Missing prices serialize as `null`, so merging it clears prices exchanged with an older
+/// snapshot. It touches neither `eventId` nor `updated`, see
+/// [`update_item_document_prices`](crate::repository::ItemOpenSearchRepository::update_item_document_prices).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemPricesDocument {
+    #[serde(skip_serializing)]
+    pub event_id: EventId,
+
+    pub price_eur: Option<u64>,
+
+    pub price_usd: Option<u64>,
+
+    pub price_gbp: Option<u64>,
+
+    pub price_aud: Option<u64>,
+
+    pub price_cad: Option<u64>,
+
+    pub price_nzd: Option<u64>,
+
+    pub price_chf: Option<u64>,
+
+    pub price_sek: Option<u64>,
+
+    pub price_dkk: Option<u64>,
+
+    pub price_nok: Option<u64>,
+
+    pub price_pln: Option<u64>,
+
+    pub price_czk: Option<u64>,
+
+    pub price_jpy: Option<u64>,
+}
+
+impl From<ItemPricesRecord> for ItemPricesDocument {
+    fn from(record: ItemPricesRecord) -> Self {
+        ItemPricesDocument {
+            event_id: record.event_id,
+            price_eur: record.price_eur,
+            price_usd: record.price_usd,
+            price_gbp: record.price_gbp,
+            price_aud: record.price_aud,
+            price_cad: record.price_cad,
+            price_nzd: record.price_nzd,
+            price_chf: record.price_chf,
+            price_sek: record.price_sek,
+            price_dkk: record.price_dkk,
+            price_nok: record.price_nok,
+            price_pln: record.price_pln,
+            price_czk: record.price_czk,
+            price_jpy: record.price_jpy,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::item_prices_document::ItemPricesDocument;
+    use common::event_id::EventId;
+    use serde_json::json;
+
+    #[test]
+    fn should_serialize_only_prices_and_missing_as_null_when_merging_into_item_document() {
+        let document = ItemPricesDocument {
+            event_id: EventId::new(),
+            price_eur: Some(100),
+            price_usd: Some(117),
+            price_gbp: None,
+            price_aud: None,
+            price_cad: None,
+            price_nzd: None,
+            price_chf: None,
+            price_sek: None,
+            price_dkk: None,
+            price_nok: None,
+            price_pln: None,
+            price_czk: None,
+            price_jpy: None,
+        };
+
+        let actual = serde_json::to_value(document).unwrap();
+
+        assert_eq!(
+            json!({
+                "priceEur": 100,
+                "priceUsd": 117,
+                "priceGbp": null,
+                "priceAud": null,
+                "priceCad": null,
+                "priceNzd": null,
+                "priceChf": null,
+                "priceSek": null,
+                "priceDkk": null,
+                "priceNok": null,
+                "pricePln": null,
+                "priceCzk": null,
+                "priceJpy": null
+            }),
+            actual
+        );
+    }
+}
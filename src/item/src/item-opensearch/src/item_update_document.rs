@@ -4,11 +4,35 @@ use item_dynamodb::item_event_record::ItemEventRecord;
 use serde::Serialize;
 use time::OffsetDateTime;
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ItemUpdateDocument {
     pub event_id: EventId,
 
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_de: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_en: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_fr: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub title_es: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_de: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_en: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_fr: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description_es: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub price_eur: Option<u64>,
 
@@ -27,6 +51,27 @@ pub struct ItemUpdateDocument {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub price_nzd: Option<u64>,
 
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_chf: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_sek: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_dkk: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_nok: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_pln: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_czk: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_jpy: Option<u64>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<ItemStateDocument>,
 
@@ -42,12 +87,27 @@ impl From<ItemEventRecord> for ItemUpdateDocument {
         let state = event_record.state.map(ItemStateDocument::from);
         ItemUpdateDocument {
             event_id: event_record.event_id,
+            title_de: event_record.title_de,
+            title_en: event_record.title_en,
+            title_fr: event_record.title_fr,
+            title_es: event_record.title_es,
+            description_de: event_record.description_de,
+            description_en: event_record.description_en,
+            description_fr: event_record.description_fr,
+            description_es: event_record.description_es,
             price_eur: event_record.price_eur,
             price_usd: event_record.price_usd,
             price_gbp: event_record.price_gbp,
             price_aud: event_record.price_aud,
             price_cad: event_record.price_cad,
             price_nzd: event_record.price_nzd,
+            price_chf: event_record.price_chf,
+            price_sek: event_record.price_sek,
+            price_dkk: event_record.price_dkk,
+            price_nok: event_record.price_nok,
+            price_pln: event_record.price_pln,
+            price_czk: event_record.price_czk,
+            price_jpy: event_record.price_jpy,
             state,
             is_available: state.map(|state| matches!(state, ItemStateDocument::Available)),
             updated: event_record.timestamp,
@@ -66,12 +126,27 @@ mod faker {
             let state = config.fake_with_rng(rng);
             ItemUpdateDocument {
                 event_id: config.fake_with_rng(rng),
+                title_de: None,
+                title_en: None,
+                title_fr: None,
+                title_es: None,
+                description_de: None,
+                description_en: None,
+                description_fr: None,
+                description_es: None,
                 price_eur: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_usd: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_gbp: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_aud: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_cad: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 price_nzd: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_chf: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_sek: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_dkk: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_nok: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_pln: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_czk: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
+                price_jpy: Some(config.fake_with_rng::<MonetaryAmount, _>(rng).into()),
                 state,
                 is_available: state.map(|state| matches!(state, ItemStateDocument::Available)),
                 updated: OffsetDateTime::now_utc(),
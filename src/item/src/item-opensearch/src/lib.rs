@@ -1,4 +1,5 @@
 pub mod item_document;
+pub mod item_prices_document;
 pub mod item_state_document;
 pub mod item_update_document;
 pub mod repository;
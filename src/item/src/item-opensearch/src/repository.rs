@@ -1,23 +1,83 @@
 use crate::item_document::ItemDocument;
+use crate::item_prices_document::ItemPricesDocument;
 use crate::item_state_document::ItemStateDocument;
 use crate::item_update_document::ItemUpdateDocument;
 use async_trait::async_trait;
-use common::currency::domain::Currency;
+use common::currency::domain::{Currency, HasMinorUnitExponent};
 use common::item_id::ItemId;
 use common::item_state::domain::ItemState;
 use common::language::domain::Language;
-use common::opensearch::{bulk_response::BulkResponse, search_response::SearchResponse};
+use common::opensearch::{
+    bulk_response::BulkResponse, search_after::SearchAfter, search_response::SearchResponse,
+};
 use common::page::Page;
 use common::sort::{Sort, SortOrder};
 use item_core::sort_item_field::SortItemField;
-use opensearch::{BulkOperation, BulkOperations, BulkParts, SearchParts};
+use opensearch::{BulkOperation, BulkOperations, BulkParts, CreatePitParts, SearchParts};
 use search_filter_core::search_filter::SearchFilter;
 use serde::ser::Error;
 use serde_json::json;
 use std::collections::HashMap;
 use std::ops::Deref;
+use strum::IntoEnumIterator;
 use time::format_description::well_known;
 
+pub const STATE_AGGREGATION: &str = "state";
+pub const SHOP_NAME_AGGREGATION: &str = "shopName";
+pub const PRICE_AGGREGATION: &str = "price";
+pub const CREATED_AGGREGATION: &str = "created";
+
+/// How long a point-in-time is kept alive after each search using it.
+pub const POINT_IN_TIME_KEEP_ALIVE: &str = "5m";
+
+/// Title- and description-field analyzed in the given language.
+fn text_fields(language: &Language) -> (&'static str, &'static str) {
+    match language {
+        Language::De => ("titleDe", "descriptionDe"),
+        Language::En => ("titleEn", "descriptionEn"),
+        Language::Fr => ("titleFr", "descriptionFr"),
+        Language::Es => ("titleEs", "descriptionEs"),
+    }
+}
+
+/// Only merges `params.doc` into the document if it's more recent, otherwise results in a `noop`.
+const UPDATE_IF_MORE_RECENT_SCRIPT: &str = "\
+if (ctx._source.updated != null \
+    && !ZonedDateTime.parse(ctx._source.updated).isBefore(ZonedDateTime.parse(params.doc.updated))) { \
+  ctx.op = 'noop'; \
+} else { \
+  ctx._source.putAll(params.doc); \
+}";
+
+/// Only merges `params.doc` into the document if it's still at `params.eventId`, otherwise results in a `noop`.
+const UPDATE_IF_SAME_EVENT_SCRIPT: &str = "\
+if (ctx._source.eventId != params.eventId) { \
+  ctx.op = 'noop'; \
+} else { \
+  ctx._source.putAll(params.doc); \
+}";
+
+/// Wraps the facet's bucket-aggregation into a `filter`-aggregation of the same name, applying
+/// the filters of all other facets but not its own.
+///
+/// The facets' filters are `post_filter`s, so selecting a value of a facet narrows the hits and
+/// the other facets, while that facet still offers its other values.
+fn facet_aggregation(
+    name: &str,
+    aggregation: serde_json::Value,
+    other_filters: Vec<serde_json::Value>,
+) -> serde_json::Value {
+    json!({
+        "filter": { "bool": { "filter": other_filters } },
+        "aggs": { name: aggregation }
+    })
+}
+
+/// Width of the buckets of the [`PRICE_AGGREGATION`] in minor units, i.e. 100 in major units.
+pub fn price_histogram_interval(currency: &Currency) -> u64 {
+    100 * 10u64.pow(currency.minor_unit_exponent().0 as u32)
+}
+
 #[async_trait]
 #[mockall::automock]
 pub trait ItemOpenSearchRepository {
@@ -26,9 +86,21 @@ pub trait ItemOpenSearchRepository {
         documents: Vec<ItemDocument>,
     ) -> Result<BulkResponse, opensearch::Error>;
 
+    /// Applies the updates in the given order, so that multiple updates of the same document
+    /// can be given oldest to newest.
+    ///
+    /// Stale updates, i.e. those not more recent than the document, result in a `noop`.
     async fn update_item_documents(
         &self,
-        updates: HashMap<ItemId, ItemUpdateDocument>,
+        updates: Vec<(ItemId, ItemUpdateDocument)>,
+    ) -> Result<BulkResponse, opensearch::Error>;
+
+    /// Overwrites only the exchanged prices, leaving `eventId` and `updated` untouched.
+    ///
+    /// Updates of documents no longer at the update's `event_id` result in a `noop`.
+    async fn update_item_document_prices(
+        &self,
+        updates: HashMap<ItemId, ItemPricesDocument>,
     ) -> Result<BulkResponse, opensearch::Error>;
 
     async fn search_item_documents(
@@ -38,7 +110,10 @@ pub trait ItemOpenSearchRepository {
         currency: &Currency,
         sort: &Option<Sort<SortItemField>>,
         page: &Option<Page>,
+        search_after: &Option<SearchAfter>,
     ) -> Result<SearchResponse<ItemDocument>, opensearch::Error>;
+
+    async fn open_point_in_time(&self) -> Result<String, opensearch::Error>;
 }
 
 pub struct ItemOpenSearchRepositoryImpl<'a> {
@@ -74,14 +149,50 @@ impl<'a> ItemOpenSearchRepository for ItemOpenSearchRepositoryImpl<'a> {
 
     async fn update_item_documents(
         &self,
-        updates: HashMap<ItemId, ItemUpdateDocument>,
+        updates: Vec<(ItemId, ItemUpdateDocument)>,
     ) -> Result<BulkResponse, opensearch::Error> {
         let mut ops = BulkOperations::new();
         for (_id, doc) in updates {
             ops.push(BulkOperation::update(
                 _id,
                 json!({
-                "doc": doc
+                    "script": {
+                        "source": UPDATE_IF_MORE_RECENT_SCRIPT,
+                        "lang": "painless",
+                        "params": {
+                            "doc": doc
+                        }
+                    }
+                }),
+            ))?;
+        }
+
+        self.client
+            .bulk(BulkParts::Index("items"))
+            .body(vec![ops])
+            .send()
+            .await?
+            .json::<BulkResponse>()
+            .await
+    }
+
+    async fn update_item_document_prices(
+        &self,
+        updates: HashMap<ItemId, ItemPricesDocument>,
+    ) -> Result<BulkResponse, opensearch::Error> {
+        let mut ops = BulkOperations::new();
+        for (_id, doc) in updates {
+            ops.push(BulkOperation::update(
+                _id,
+                json!({
+                    "script": {
+                        "source": UPDATE_IF_SAME_EVENT_SCRIPT,
+                        "lang": "painless",
+                        "params": {
+                            "eventId": doc.event_id,
+                            "doc": doc
+                        }
+                    }
                 }),
             ))?;
         }
@@ -102,29 +213,37 @@ impl<'a> ItemOpenSearchRepository for ItemOpenSearchRepositoryImpl<'a> {
         currency: &Currency,
         sort: &Option<Sort<SortItemField>>,
         page: &Option<Page>,
+        search_after: &Option<SearchAfter>,
     ) -> Result<SearchResponse<ItemDocument>, opensearch::Error> {
         let mut must = vec![];
         let mut filter = vec![];
+        // filters of faceted dimensions only apply to the hits, see facet_aggregation
+        let mut state_filter = vec![];
+        let mut shop_name_filter = vec![];
+        let mut price_filter = vec![];
+        let mut created_filter = vec![];
 
-        let (title_field, description_field) = match language {
-            Language::De => ("titleDe", "descriptionDe"),
-            Language::En => ("titleEn", "descriptionEn"),
-            _ => ("titleDe", "descriptionDe"),
-        };
+        let (title_field, description_field) = text_fields(language);
+        let mut text_query_fields =
+            vec![format!("{title_field}^3"), format!("{description_field}^1")];
+        // Items lacking content in the requested language must still be findable,
+        // yet rank below those matching natively.
+        for other_language in Language::iter().filter(|other| other != language) {
+            let (other_title_field, other_description_field) = text_fields(&other_language);
+            text_query_fields.push(format!("{other_title_field}^0.75"));
+            text_query_fields.push(format!("{other_description_field}^0.25"));
+        }
         must.push(json!({
             "multi_match": {
                 "query": search_filter.item_query.as_ref(),
-                "fields": [
-                    format!("{title_field}^3"),
-                    format!("{description_field}^1"),
-                ],
+                "fields": text_query_fields,
                 "fuzziness": "AUTO",
                 "minimum_should_match": "70%"
             }
         }));
 
         if let Some(shop_name_query) = &search_filter.shop_name_query {
-            must.push(json!({
+            shop_name_filter.push(json!({
                 "match": {
                     "shopName": {
                         "query": shop_name_query.deref(),
@@ -144,7 +263,7 @@ impl<'a> ItemOpenSearchRepository for ItemOpenSearchRepositoryImpl<'a> {
         {
             [] => {}
             [ItemState::Available] => {
-                filter.push(json!({
+                state_filter.push(json!({
                     "term": { "isAvailable": true }
                 }));
             }
@@ -155,7 +274,7 @@ impl<'a> ItemOpenSearchRepository for ItemOpenSearchRepositoryImpl<'a> {
                     .map(|s| s.as_str())
                     .collect();
 
-                filter.push(json!({
+                state_filter.push(json!({
                     "terms": { "state": state_values }
                 }));
             }
@@ -168,12 +287,19 @@ impl<'a> ItemOpenSearchRepository for ItemOpenSearchRepositoryImpl<'a> {
             Currency::Aud => "priceAud",
             Currency::Cad => "priceCad",
             Currency::Nzd => "priceNzd",
+            Currency::Chf => "priceChf",
+            Currency::Sek => "priceSek",
+            Currency::Dkk => "priceDkk",
+            Currency::Nok => "priceNok",
+            Currency::Pln => "pricePln",
+            Currency::Czk => "priceCzk",
+            Currency::Jpy => "priceJpy",
         };
         if let Some(min) = search_filter
             .price_query
             .and_then(|price_query| price_query.min)
         {
-            filter.push(json!({
+            price_filter.push(json!({
                 "range": { price_field: { "gte": min.deref() } }
             }));
         }
@@ -181,7 +307,7 @@ impl<'a> ItemOpenSearchRepository for ItemOpenSearchRepositoryImpl<'a> {
             .price_query
             .and_then(|price_query| price_query.max)
         {
-            filter.push(json!({
+            price_filter.push(json!({
                 "range": { price_field: { "lte": max.deref() } }
             }));
         }
@@ -193,7 +319,7 @@ impl<'a> ItemOpenSearchRepository for ItemOpenSearchRepositoryImpl<'a> {
             let formatted_min = min
                 .format(&well_known::Rfc3339)
                 .map_err(serde_json::Error::custom)?;
-            filter.push(json!({
+            created_filter.push(json!({
                 "range": { "created": { "gte": formatted_min } }
             }));
         }
@@ -204,7 +330,7 @@ impl<'a> ItemOpenSearchRepository for ItemOpenSearchRepositoryImpl<'a> {
             let formatted_max = max
                 .format(&well_known::Rfc3339)
                 .map_err(serde_json::Error::custom)?;
-            filter.push(json!({
+            created_filter.push(json!({
                 "range": { "created": { "lte": formatted_max } }
             }));
         }
@@ -232,49 +358,119 @@ impl<'a> ItemOpenSearchRepository for ItemOpenSearchRepositoryImpl<'a> {
             }));
         }
 
+        let post_filter = [
+            &state_filter[..],
+            &shop_name_filter,
+            &price_filter,
+            &created_filter,
+        ]
+        .concat();
         let mut body = json!({
             "query": {
                 "bool": {
                     "must": must,
                     "filter": filter
                 },
+            },
+            "post_filter": {
+                "bool": { "filter": post_filter }
+            },
+            "aggs": {
+                STATE_AGGREGATION: facet_aggregation(
+                    STATE_AGGREGATION,
+                    json!({ "terms": { "field": "state" } }),
+                    [&shop_name_filter[..], &price_filter, &created_filter].concat(),
+                ),
+                SHOP_NAME_AGGREGATION: facet_aggregation(
+                    SHOP_NAME_AGGREGATION,
+                    json!({ "terms": { "field": "shopName.keyword", "size": 50 } }),
+                    [&state_filter[..], &price_filter, &created_filter].concat(),
+                ),
+                PRICE_AGGREGATION: facet_aggregation(
+                    PRICE_AGGREGATION,
+                    json!({
+                        "histogram": {
+                            "field": price_field,
+                            "interval": price_histogram_interval(currency),
+                            "min_doc_count": 1
+                        }
+                    }),
+                    [&state_filter[..], &shop_name_filter, &created_filter].concat(),
+                ),
+                CREATED_AGGREGATION: facet_aggregation(
+                    CREATED_AGGREGATION,
+                    json!({
+                        "date_histogram": {
+                            "field": "created",
+                            "calendar_interval": "month",
+                            "min_doc_count": 1
+                        }
+                    }),
+                    [&state_filter[..], &shop_name_filter, &price_filter].concat(),
+                )
             }
         });
 
         if let Some(p) = page {
-            body.as_object_mut()
-                .unwrap()
-                .insert("from".to_string(), json!(p.from));
+            // search_after replaces the offset, OpenSearch rejects any 'from' other than 0 then
+            if search_after.is_none() {
+                body.as_object_mut()
+                    .unwrap()
+                    .insert("from".to_string(), json!(p.from));
+            }
             body.as_object_mut()
                 .unwrap()
                 .insert("size".to_string(), json!(p.size));
         }
 
-        if let Some(sort) = sort {
-            let sort_field = match sort.sort {
-                SortItemField::Price => price_field,
-                SortItemField::Created => "created",
-                SortItemField::Updated => "updated",
-            };
-            let order = match sort.order {
-                SortOrder::Asc => "asc",
-                SortOrder::Desc => "desc",
-            };
-            body.as_object_mut().unwrap().insert(
-                "sort".to_string(),
-                json!([
-                    { sort_field: { "order": order, "missing": "_last", } },
-                    { "itemId": { "order": "asc"} }
-                ]),
-            );
+        // itemId as tiebreak keeps the order total, which search_after relies on
+        let sort_field = match sort {
+            Some(sort) => {
+                let sort_field = match sort.sort {
+                    SortItemField::Price => price_field,
+                    SortItemField::Created => "created",
+                    SortItemField::Updated => "updated",
+                };
+                let order = match sort.order {
+                    SortOrder::Asc => "asc",
+                    SortOrder::Desc => "desc",
+                };
+                json!({ sort_field: { "order": order, "missing": "_last", } })
+            }
+            None => json!({ "_score": { "order": "desc" } }),
+        };
+        body.as_object_mut().unwrap().insert(
+            "sort".to_string(),
+            json!([sort_field, { "itemId": { "order": "asc"} }]),
+        );
+
+        if let Some(search_after) = search_after {
+            if !search_after.sort_values.is_empty() {
+                body.as_object_mut()
+                    .unwrap()
+                    .insert("search_after".to_string(), json!(search_after.sort_values));
+            }
+            if let Some(pit_id) = &search_after.pit_id {
+                body.as_object_mut().unwrap().insert(
+                    "pit".to_string(),
+                    json!({ "id": pit_id, "keep_alive": POINT_IN_TIME_KEEP_ALIVE }),
+                );
+            }
         }
 
+        // a point-in-time is already bound to its index, naming it again is rejected
+        let search_parts = match search_after.as_ref().and_then(|sa| sa.pit_id.as_ref()) {
+            Some(_) => SearchParts::None,
+            None => SearchParts::Index(&["items"]),
+        };
+
         let response = self
             .client
-            .search(SearchParts::Index(&["items"]))
+            .search(search_parts)
             .body(body)
             .send()
-            .await?;
+            .await?
+            .error_for_status_code()?;
         let payload = response.text().await?;
         let search_response = serde_json::from_str::<SearchResponse<ItemDocument>>(&payload)
             .map_err(|err| {
@@ -285,4 +481,26 @@ impl<'a> ItemOpenSearchRepository for ItemOpenSearchRepositoryImpl<'a> {
 
         Ok(search_response)
     }
+
+    async fn open_point_in_time(&self) -> Result<String, opensearch::Error> {
+        let response = self
+            .client
+            .create_pit(CreatePitParts::Index(&["items"]))
+            .keep_alive(POINT_IN_TIME_KEEP_ALIVE)
+            .send()
+            .await?
+            .error_for_status_code()?
+            .json::<serde_json::Value>()
+            .await?;
+
+        response["pit_id"]
+            .as_str()
+            .map(str::to_owned)
+            .ok_or_else(|| {
+                serde_json::Error::custom(format!(
+                    "Failed extracting 'pit_id' from create-PIT response '{response}'."
+                ))
+                .into()
+            })
+    }
 }
@@ -8,11 +8,16 @@ use common::price::domain::MonetaryAmount;
 use common::shops_item_id::ShopsItemId;
 use common::sort::{Sort, SortOrder};
 use fake::rand;
+use fake::{Fake, Faker};
 use item_core::sort_item_field::SortItemField;
 use item_opensearch::item_document::ItemDocument;
+use item_opensearch::item_prices_document::ItemPricesDocument;
 use item_opensearch::item_state_document::ItemStateDocument;
 use item_opensearch::item_update_document::ItemUpdateDocument;
-use item_opensearch::repository::{ItemOpenSearchRepository, ItemOpenSearchRepositoryImpl};
+use item_opensearch::repository::{
+    CREATED_AGGREGATION, ItemOpenSearchRepository, ItemOpenSearchRepositoryImpl, PRICE_AGGREGATION,
+    SHOP_NAME_AGGREGATION, STATE_AGGREGATION,
+};
 use opensearch::http::Url;
 use search_filter_core::array_query::AnyOfQuery;
 use search_filter_core::range_query::RangeQuery;
@@ -36,14 +41,25 @@ async fn should_create_item_document() {
         shop_name: "Foo".to_string(),
         title_de: Some("Bar".to_string()),
         title_en: Some("Baz".to_string()),
+        title_fr: Some("Baz".to_string()),
+        title_es: Some("Baz".to_string()),
         description_de: Some("Lorem ipsum dolor sit amet".to_string()),
         description_en: Some("Lorem ipsum dolor sit amet".to_string()),
+        description_fr: Some("Lorem ipsum dolor sit amet".to_string()),
+        description_es: Some("Lorem ipsum dolor sit amet".to_string()),
         price_eur: Some(99),
         price_usd: None,
         price_gbp: None,
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
         state: ItemStateDocument::Listed,
         is_available: false,
         url: Url::parse("https://foo.com/bar").unwrap(),
@@ -75,14 +91,25 @@ async fn should_create_item_documents() {
         shop_name: "Foo".to_string(),
         title_de: Some("Bar".to_string()),
         title_en: Some("Baz".to_string()),
+        title_fr: Some("Baz".to_string()),
+        title_es: Some("Baz".to_string()),
         description_de: Some("Lorem ipsum dolor sit amet".to_string()),
         description_en: Some("Lorem ipsum dolor sit amet".to_string()),
+        description_fr: Some("Lorem ipsum dolor sit amet".to_string()),
+        description_es: Some("Lorem ipsum dolor sit amet".to_string()),
         price_eur: Some(99),
         price_usd: None,
         price_gbp: None,
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
         state: ItemStateDocument::Listed,
         is_available: false,
         url: Url::parse("https://foo.com/bar").unwrap(),
@@ -99,14 +126,25 @@ async fn should_create_item_documents() {
         shop_name: "Foo".to_string(),
         title_de: Some("Bar".to_string()),
         title_en: Some("Baz".to_string()),
+        title_fr: Some("Baz".to_string()),
+        title_es: Some("Baz".to_string()),
         description_de: Some("Lorem ipsum dolor sit amet".to_string()),
         description_en: Some("Lorem ipsum dolor sit amet".to_string()),
+        description_fr: Some("Lorem ipsum dolor sit amet".to_string()),
+        description_es: Some("Lorem ipsum dolor sit amet".to_string()),
         price_eur: Some(99),
         price_usd: None,
         price_gbp: None,
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
         state: ItemStateDocument::Listed,
         is_available: false,
         url: Url::parse("https://foo.com/bar").unwrap(),
@@ -140,14 +178,25 @@ async fn should_update_item_document() {
         shop_name: "Foo".to_string(),
         title_de: Some("Bar".to_string()),
         title_en: Some("Baz".to_string()),
+        title_fr: Some("Baz".to_string()),
+        title_es: Some("Baz".to_string()),
         description_de: Some("Lorem ipsum dolor sit amet".to_string()),
         description_en: Some("Lorem ipsum dolor sit amet".to_string()),
+        description_fr: Some("Lorem ipsum dolor sit amet".to_string()),
+        description_es: Some("Lorem ipsum dolor sit amet".to_string()),
         price_eur: Some(99),
         price_usd: None,
         price_gbp: None,
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
         state: ItemStateDocument::Listed,
         is_available: false,
         url: Url::parse("https://foo.com/bar").unwrap(),
@@ -168,19 +217,34 @@ async fn should_update_item_document() {
     let updated_update_ts = OffsetDateTime::now_utc();
     let update = ItemUpdateDocument {
         event_id: updated_event_id,
+        title_de: None,
+        title_en: None,
+        title_fr: None,
+        title_es: None,
+        description_de: None,
+        description_en: None,
+        description_fr: None,
+        description_es: None,
         price_eur: None,
         price_usd: None,
         price_gbp: None,
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
         state: Some(ItemStateDocument::Sold),
         is_available: None,
         updated: updated_update_ts,
     };
     let repository = ItemOpenSearchRepositoryImpl::new(client);
     let update_response = repository
-        .update_item_documents(HashMap::from([(item_id, update)]))
+        .update_item_documents(vec![(item_id, update)])
         .await
         .unwrap();
     assert!(!update_response.errors);
@@ -196,6 +260,195 @@ async fn should_update_item_document() {
     assert_eq!(expected, actual);
 }
 
+#[localstack_test(services = [OpenSearch()])]
+async fn should_not_update_item_document_when_update_is_stale() {
+    let item_id = ItemId::new();
+    let now = OffsetDateTime::now_utc();
+    let expected = ItemDocument {
+        item_id,
+        event_id: Default::default(),
+        shop_id: Default::default(),
+        shops_item_id: ShopsItemId::from("abcdefgh"),
+        shop_name: "Foo".to_string(),
+        title_de: Some("Bar".to_string()),
+        title_en: Some("Baz".to_string()),
+        title_fr: Some("Baz".to_string()),
+        title_es: Some("Baz".to_string()),
+        description_de: None,
+        description_en: None,
+        description_fr: None,
+        description_es: None,
+        price_eur: Some(99),
+        price_usd: None,
+        price_gbp: None,
+        price_aud: None,
+        price_cad: None,
+        price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        state: ItemStateDocument::Sold,
+        is_available: false,
+        url: Url::parse("https://foo.com/bar").unwrap(),
+        images: vec![],
+        created: now,
+        updated: now,
+    };
+    let client = get_opensearch_client().await;
+    let repository = ItemOpenSearchRepositoryImpl::new(client);
+    let write_response = repository
+        .create_item_documents(vec![expected.clone()])
+        .await
+        .unwrap();
+    assert!(!write_response.errors);
+    refresh_index("items").await;
+
+    let stale_update = ItemUpdateDocument {
+        event_id: EventId::new(),
+        title_de: None,
+        title_en: None,
+        title_fr: None,
+        title_es: None,
+        description_de: None,
+        description_en: None,
+        description_fr: None,
+        description_es: None,
+        price_eur: None,
+        price_usd: None,
+        price_gbp: None,
+        price_aud: None,
+        price_cad: None,
+        price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        state: Some(ItemStateDocument::Available),
+        is_available: Some(true),
+        updated: now - time::Duration::minutes(5),
+    };
+    let update_response = repository
+        .update_item_documents(vec![(item_id, stale_update)])
+        .await
+        .unwrap();
+    assert!(!update_response.errors);
+    assert!(update_response.items[0].clone().unwrap_update().is_noop());
+    refresh_index("items").await;
+
+    let actual = read_by_id("items", item_id).await;
+
+    assert_eq!(expected, actual);
+}
+
+#[rstest::rstest]
+#[test_attr(apply(test))]
+#[case::same_event(true)]
+#[case::other_event(false)]
+#[localstack_test(services = [OpenSearch()])]
+async fn should_update_item_document_prices_only_when_event_id_matches(#[case] same_event: bool) {
+    let item_id = ItemId::new();
+    let now = OffsetDateTime::now_utc();
+    let initial = ItemDocument {
+        item_id,
+        event_id: EventId::new(),
+        shop_id: Default::default(),
+        shops_item_id: ShopsItemId::from("abcdefgh"),
+        shop_name: "Foo".to_string(),
+        title_de: Some("Bar".to_string()),
+        title_en: Some("Baz".to_string()),
+        title_fr: Some("Baz".to_string()),
+        title_es: Some("Baz".to_string()),
+        description_de: None,
+        description_en: None,
+        description_fr: None,
+        description_es: None,
+        price_eur: Some(10000),
+        price_usd: Some(11000),
+        price_gbp: Some(8000),
+        price_aud: None,
+        price_cad: None,
+        price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        state: ItemStateDocument::Available,
+        is_available: true,
+        url: Url::parse("https://foo.com/bar").unwrap(),
+        images: vec![],
+        created: now,
+        updated: now,
+    };
+    let client = get_opensearch_client().await;
+    let repository = ItemOpenSearchRepositoryImpl::new(client);
+    let write_response = repository
+        .create_item_documents(vec![initial.clone()])
+        .await
+        .unwrap();
+    assert!(!write_response.errors);
+    refresh_index("items").await;
+
+    let update = ItemPricesDocument {
+        event_id: if same_event {
+            initial.event_id
+        } else {
+            EventId::new()
+        },
+        price_eur: Some(10000),
+        price_usd: Some(11670),
+        price_gbp: Some(8678),
+        price_aud: Some(17780),
+        price_cad: Some(15970),
+        price_nzd: Some(19470),
+        price_chf: Some(19470),
+        price_sek: Some(19470),
+        price_dkk: Some(19470),
+        price_nok: Some(19470),
+        price_pln: Some(19470),
+        price_czk: Some(19470),
+        price_jpy: Some(19470),
+    };
+    let update_response = repository
+        .update_item_document_prices(HashMap::from([(item_id, update)]))
+        .await
+        .unwrap();
+    assert!(!update_response.errors);
+    assert_eq!(
+        !same_event,
+        update_response.items[0].clone().unwrap_update().is_noop()
+    );
+    refresh_index("items").await;
+
+    let mut expected = initial;
+    if same_event {
+        expected.price_usd = Some(11670);
+        expected.price_gbp = Some(8678);
+        expected.price_aud = Some(17780);
+        expected.price_cad = Some(15970);
+        expected.price_nzd = Some(19470);
+        expected.price_chf = Some(19470);
+        expected.price_sek = Some(19470);
+        expected.price_dkk = Some(19470);
+        expected.price_nok = Some(19470);
+        expected.price_pln = Some(19470);
+        expected.price_czk = Some(19470);
+        expected.price_jpy = Some(19470);
+    }
+    let actual = read_by_id("items", item_id).await;
+
+    assert_eq!(expected, actual);
+}
+
 #[localstack_test(services = [OpenSearch()])]
 async fn should_search_item_documents() {
     let expected = ItemDocument {
@@ -206,14 +459,25 @@ async fn should_search_item_documents() {
         shop_name: "Foo".to_string(),
         title_de: Some("Hallo Welt".to_string()),
         title_en: Some("Baz".to_string()),
+        title_fr: Some("Baz".to_string()),
+        title_es: Some("Baz".to_string()),
         description_de: Some("Lorem ipsum dolor sit amet".to_string()),
         description_en: Some("Lorem ipsum dolor sit amet".to_string()),
+        description_fr: Some("Lorem ipsum dolor sit amet".to_string()),
+        description_es: Some("Lorem ipsum dolor sit amet".to_string()),
         price_eur: Some(99),
         price_usd: None,
         price_gbp: None,
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
         state: ItemStateDocument::Available,
         is_available: false,
         url: Url::parse("https://foo.com/bar").unwrap(),
@@ -241,7 +505,14 @@ async fn should_search_item_documents() {
         updated_query: None,
     };
     let response = repository
-        .search_item_documents(&search_filter, &Language::De, &Currency::Eur, &None, &None)
+        .search_item_documents(
+            &search_filter,
+            &Language::De,
+            &Currency::Eur,
+            &None,
+            &None,
+            &None,
+        )
         .await
         .unwrap();
 
@@ -256,6 +527,62 @@ async fn should_search_item_documents() {
     )
 }
 
+#[localstack_test(services = [OpenSearch()])]
+async fn should_search_item_documents_across_languages_ranking_requested_language_first() {
+    let mk_document = |title_fr: Option<&str>, title_es: Option<&str>| {
+        let mut document: ItemDocument = Faker.fake();
+        document.title_de = None;
+        document.title_en = None;
+        document.title_fr = title_fr.map(str::to_string);
+        document.title_es = title_es.map(str::to_string);
+        document.description_de = None;
+        document.description_en = None;
+        document.description_fr = None;
+        document.description_es = None;
+        document
+    };
+    let french = mk_document(Some("Casque Adrian modèle 1915"), None);
+    let spanish = mk_document(None, Some("Casco Adrian modelo 1915"));
+    let client = get_opensearch_client().await;
+    let repository = ItemOpenSearchRepositoryImpl::new(client);
+    let response = repository
+        .create_item_documents(vec![spanish.clone(), french.clone()])
+        .await
+        .unwrap();
+    assert!(!response.errors);
+    refresh_index("items").await;
+
+    let search_filter = SearchFilter {
+        item_query: "Adrian 1915".try_into().unwrap(),
+        shop_name_query: None,
+        price_query: None,
+        state_query: Default::default(),
+        created_query: None,
+        updated_query: None,
+    };
+    let response = repository
+        .search_item_documents(
+            &search_filter,
+            &Language::Fr,
+            &Currency::Eur,
+            &None,
+            &None,
+            &None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        vec![french.item_id, spanish.item_id],
+        response
+            .hits
+            .hits
+            .into_iter()
+            .map(|hit| hit.source.item_id)
+            .collect::<Vec<_>>()
+    )
+}
+
 #[localstack_test(services = [OpenSearch()])]
 async fn should_search_item_documents_when_all_arguments_are_given() {
     let items = fake::vec![ItemDocument; 1000];
@@ -301,6 +628,7 @@ async fn should_search_item_documents_when_all_arguments_are_given() {
             &Currency::Eur,
             &Some(sort),
             &Some(page),
+            &None,
         )
         .await;
 
@@ -340,7 +668,14 @@ async fn should_search_item_documents_when_states_are_given(#[case] states: &[It
         updated_query: None,
     };
     let response = repository
-        .search_item_documents(&search_filter, &Language::De, &Currency::Eur, &None, &None)
+        .search_item_documents(
+            &search_filter,
+            &Language::De,
+            &Currency::Eur,
+            &None,
+            &None,
+            &None,
+        )
         .await
         .unwrap();
 
@@ -382,13 +717,156 @@ async fn should_search_item_documents_when_no_states_are_given() {
         updated_query: None,
     };
     let response = repository
-        .search_item_documents(&search_filter, &Language::De, &Currency::Eur, &None, &None)
+        .search_item_documents(
+            &search_filter,
+            &Language::De,
+            &Currency::Eur,
+            &None,
+            &None,
+            &None,
+        )
         .await
         .unwrap();
 
     assert_eq!(100u64, response.hits.total.value);
 }
 
+#[localstack_test(services = [OpenSearch()])]
+async fn should_aggregate_item_documents_when_searching() {
+    let items = fake::vec![ItemDocument; 100]
+        .into_iter()
+        .enumerate()
+        .map(|(i, mut item)| {
+            item.title_de = Some("The same title".into());
+            item.shop_name = if i % 4 == 0 { "Foo" } else { "Bar" }.to_string();
+            item.state = if i % 2 == 0 {
+                ItemStateDocument::Sold
+            } else {
+                ItemStateDocument::Available
+            };
+            item.price_eur = Some(if i < 30 { 5000 } else { 25000 });
+            item
+        })
+        .collect::<Vec<_>>();
+    let client = get_opensearch_client().await;
+    let repository = ItemOpenSearchRepositoryImpl::new(client);
+    let response = repository
+        .create_item_documents(items.clone())
+        .await
+        .unwrap();
+    assert!(!response.errors);
+    refresh_index("items").await;
+    tokio::time::sleep(Duration::from_millis(3000)).await;
+
+    let search_filter = SearchFilter {
+        item_query: "The same title".try_into().unwrap(),
+        shop_name_query: None,
+        price_query: None,
+        state_query: AnyOfQuery(HashSet::new()),
+        created_query: None,
+        updated_query: None,
+    };
+    let response = repository
+        .search_item_documents(
+            &search_filter,
+            &Language::De,
+            &Currency::Eur,
+            &None,
+            &None,
+            &None,
+        )
+        .await
+        .unwrap();
+
+    let count_of = |aggregation: &str, key: serde_json::Value| {
+        response
+            .aggregations
+            .buckets(aggregation)
+            .iter()
+            .find(|bucket| bucket.key == key)
+            .map(|bucket| bucket.doc_count)
+    };
+    assert_eq!(Some(50), count_of(STATE_AGGREGATION, "SOLD".into()));
+    assert_eq!(Some(50), count_of(STATE_AGGREGATION, "AVAILABLE".into()));
+    assert_eq!(Some(25), count_of(SHOP_NAME_AGGREGATION, "Foo".into()));
+    assert_eq!(Some(75), count_of(SHOP_NAME_AGGREGATION, "Bar".into()));
+    assert_eq!(Some(30), count_of(PRICE_AGGREGATION, 0.0.into()));
+    assert_eq!(Some(70), count_of(PRICE_AGGREGATION, 20000.0.into()));
+    assert_eq!(
+        100u64,
+        response
+            .aggregations
+            .buckets(CREATED_AGGREGATION)
+            .iter()
+            .map(|bucket| bucket.doc_count)
+            .sum::<u64>()
+    );
+}
+
+#[localstack_test(services = [OpenSearch()])]
+async fn should_keep_other_values_of_filtered_facet_when_searching() {
+    let items = fake::vec![ItemDocument; 100]
+        .into_iter()
+        .enumerate()
+        .map(|(i, mut item)| {
+            item.title_de = Some("The same title".into());
+            item.shop_name = if i % 4 == 0 { "Foo" } else { "Bar" }.to_string();
+            item.state = if i % 2 == 0 {
+                ItemStateDocument::Sold
+            } else {
+                ItemStateDocument::Available
+            };
+            item.price_eur = Some(if i < 30 { 5000 } else { 25000 });
+            item
+        })
+        .collect::<Vec<_>>();
+    let client = get_opensearch_client().await;
+    let repository = ItemOpenSearchRepositoryImpl::new(client);
+    let response = repository
+        .create_item_documents(items.clone())
+        .await
+        .unwrap();
+    assert!(!response.errors);
+    refresh_index("items").await;
+    tokio::time::sleep(Duration::from_millis(3000)).await;
+
+    let search_filter = SearchFilter {
+        item_query: "The same title".try_into().unwrap(),
+        shop_name_query: None,
+        price_query: None,
+        state_query: AnyOfQuery(HashSet::from([ItemState::Sold])),
+        created_query: None,
+        updated_query: None,
+    };
+    let response = repository
+        .search_item_documents(
+            &search_filter,
+            &Language::De,
+            &Currency::Eur,
+            &None,
+            &None,
+            &None,
+        )
+        .await
+        .unwrap();
+
+    let count_of = |aggregation: &str, key: serde_json::Value| {
+        response
+            .aggregations
+            .buckets(aggregation)
+            .iter()
+            .find(|bucket| bucket.key == key)
+            .map(|bucket| bucket.doc_count)
+    };
+    assert_eq!(50, response.hits.total.value);
+    assert_eq!(Some(50), count_of(STATE_AGGREGATION, "SOLD".into()));
+    assert_eq!(Some(50), count_of(STATE_AGGREGATION, "AVAILABLE".into()));
+    assert_eq!(Some(25), count_of(SHOP_NAME_AGGREGATION, "Foo".into()));
+    assert_eq!(Some(25), count_of(SHOP_NAME_AGGREGATION, "Bar".into()));
+    assert_eq!(Some(15), count_of(PRICE_AGGREGATION, 0.0.into()));
+    assert_eq!(Some(35), count_of(PRICE_AGGREGATION, 20000.0.into()));
+}
+
 #[rstest::rstest]
 #[test_attr(apply(test))]
 #[case(RangeQuery { min: Some(0u64.into()), max: Some(999999u64.into()) }, SortOrder::Asc)]
@@ -448,6 +926,7 @@ async fn should_search_item_documents_respecting_order_when_price_range_is_given
                 order: sort_direction,
             }),
             &Some(Page { from: 0, size: 100 }),
+            &None,
         )
         .await
         .unwrap();
@@ -505,6 +984,8 @@ async fn should_search_item_documents_respecting_paging_when_sorted_by_price(#[c
         .into_iter()
         .map(|mut item| {
             item.title_en = Some("The same title".into());
+            item.title_fr = Some("The same title".into());
+            item.title_es = Some("The same title".into());
             item.price_usd = Some(rand::random_range(1500..=20000));
             item
         })
@@ -537,6 +1018,7 @@ async fn should_search_item_documents_respecting_paging_when_sorted_by_price(#[c
                 order: SortOrder::Asc,
             }),
             &Some(page),
+            &None,
         )
         .await
         .unwrap();
@@ -161,6 +161,7 @@ impl<T: FxRate + Sync> CommandItemServiceImpl<'_, T> {
                                     .ok()
                             })
                             .unwrap_or_default();
+                        let fx_rate_date = cmd.price.and(self.fx_rate.snapshot_date());
                         Some(Item::create(
                             cmd.shop_id,
                             cmd.shops_item_id,
@@ -171,6 +172,7 @@ impl<T: FxRate + Sync> CommandItemServiceImpl<'_, T> {
                             cmd.other_description,
                             cmd.price,
                             other_price,
+                            fx_rate_date,
                             cmd.state,
                             cmd.url,
                             cmd.images,
@@ -699,7 +701,7 @@ pub mod tests {
         use common::shops_item_id::ShopsItemId;
         use item_core::hash::ItemHash;
         use item_core::item_event::ItemCommonEventPayload;
-        use item_dynamodb::item_record::ItemRecord;
+        use item_dynamodb::item_record::{ItemRecord, mk_updated_nanos};
         use item_dynamodb::item_state_record::ItemStateRecord;
         use item_dynamodb::repository::ItemDynamoDbRepositoryImpl;
         use std::collections::HashMap;
@@ -740,9 +742,13 @@ pub mod tests {
                 title_native: TextRecord::new("boop", LanguageRecord::De),
                 title_de: None,
                 title_en: None,
+                title_fr: None,
+                title_es: None,
                 description_native: None,
                 description_de: None,
                 description_en: None,
+                description_fr: None,
+                description_es: None,
                 price_native: None,
                 price_eur: None,
                 price_usd: None,
@@ -750,12 +756,22 @@ pub mod tests {
                 price_aud: None,
                 price_cad: None,
                 price_nzd: None,
+                price_chf: None,
+                price_sek: None,
+                price_dkk: None,
+                price_nok: None,
+                price_pln: None,
+                price_czk: None,
+                price_jpy: None,
+                fx_rate_date: None,
+                repriced: None,
                 state: ItemStateRecord::Listed,
                 url: Url::parse("https://beep.bap").unwrap(),
                 images: vec![],
                 hash: ItemHash::new(&None, &ItemState::Listed),
                 created: OffsetDateTime::now_utc(),
                 updated: OffsetDateTime::now_utc(),
+                updated_nanos: mk_updated_nanos(&OffsetDateTime::now_utc()),
             }];
 
             let mut failures: Vec<ItemKey> = vec![];
@@ -856,9 +872,13 @@ pub mod tests {
                 title_native: TextRecord::new("boop", LanguageRecord::De),
                 title_de: None,
                 title_en: None,
+                title_fr: None,
+                title_es: None,
                 description_native: None,
                 description_de: None,
                 description_en: None,
+                description_fr: None,
+                description_es: None,
                 price_native: None,
                 price_eur: None,
                 price_usd: None,
@@ -866,12 +886,22 @@ pub mod tests {
                 price_aud: None,
                 price_cad: None,
                 price_nzd: None,
+                price_chf: None,
+                price_sek: None,
+                price_dkk: None,
+                price_nok: None,
+                price_pln: None,
+                price_czk: None,
+                price_jpy: None,
+                fx_rate_date: None,
+                repriced: None,
                 state: ItemStateRecord::Listed,
                 url: Url::parse("https://beep.bap").unwrap(),
                 images: vec![],
                 hash: ItemHash::new(&None, &ItemState::Listed),
                 created: OffsetDateTime::now_utc(),
                 updated: OffsetDateTime::now_utc(),
+                updated_nanos: mk_updated_nanos(&OffsetDateTime::now_utc()),
             }];
 
             let mut failures: Vec<ItemKey> = vec![];
@@ -4,15 +4,23 @@ use aws_sdk_dynamodb::error::SdkError;
 use common::currency::domain::Currency;
 use common::language::domain::Language;
 use common::localized::Localized;
-use common::price::domain::{MonetaryAmountOverflowError, Price};
+use common::price::domain::{FxRate, MonetaryAmountOverflowError, Price};
 use common::shop_id::ShopId;
 use common::shops_item_id::ShopsItemId;
 use item_core::description::Description;
 use item_core::item::{Item, LocalizedItemView};
+use item_core::item_event::{ItemEventHistoryView, ItemEventView};
+use item_core::price_history::PriceHistory;
 use item_core::title::Title;
+use item_dynamodb::item_event_record::ItemEventRecord;
+use item_dynamodb::item_price_event_record::ItemPriceEventRecord;
 use item_dynamodb::repository::ItemDynamoDbRepository;
 use std::collections::HashMap;
-use tracing::error;
+use time::OffsetDateTime;
+use tracing::{error, warn};
+
+/// Events per page when replaying an item's event log.
+const EVENT_LOG_PAGE_SIZE: u16 = 100;
 
 #[derive(thiserror::Error, Debug)]
 pub enum GetItemError {
@@ -26,6 +34,11 @@ pub enum GetItemError {
     SdkGetItemError(
         #[from] Box<SdkError<aws_sdk_dynamodb::operation::get_item::GetItemError, HttpResponse>>,
     ),
+
+    #[error("Encountered DynamoDB SdkError for Query: {0}")]
+    SdkQueryError(
+        #[from] Box<SdkError<aws_sdk_dynamodb::operation::query::QueryError, HttpResponse>>,
+    ),
 }
 
 #[cfg(feature = "api")]
@@ -47,6 +60,10 @@ pub mod api {
                     error!(error = ?err, "Encountered SdkGetItemError while getting item.");
                     (*err).into()
                 }
+                GetItemError::SdkQueryError(err) => {
+                    error!(error = ?err, "Encountered SdkQueryError while getting item events.");
+                    (*err).into()
+                }
             }
         }
     }
@@ -68,20 +85,46 @@ pub trait GetItemService {
         languages: &[Language],
         currency: &Currency,
     ) -> Result<LocalizedItemView, GetItemError>;
+
+    /// Returns up to `size` events of the item in chronological order, starting after `after`.
+    async fn view_item_events(
+        &self,
+        shop_id: &ShopId,
+        shops_item_id: &ShopsItemId,
+        currency: &Currency,
+        after: Option<OffsetDateTime>,
+        size: u16,
+    ) -> Result<ItemEventHistoryView, GetItemError>;
+
+    /// Returns the item's native prices over time, all exchanged into `currency` at current rates.
+    /// Empty if the item never had a price.
+    ///
+    /// Reads the sparse index `gsi_3`, unless it's unreadable or lacks the item's first price,
+    /// as for events written before the index. Then, replays the item's event log instead.
+    async fn view_price_history(
+        &self,
+        shop_id: &ShopId,
+        shops_item_id: &ShopsItemId,
+        currency: &Currency,
+    ) -> Result<PriceHistory, GetItemError>;
 }
 
-pub struct GetItemServiceImpl<'a> {
+pub struct GetItemServiceImpl<'a, T: FxRate + Sync> {
     repository: &'a (dyn ItemDynamoDbRepository + Sync),
+    fx_rate: &'a T,
 }
 
-impl<'a> GetItemServiceImpl<'a> {
-    pub fn new(repository: &'a (dyn ItemDynamoDbRepository + Sync)) -> Self {
-        Self { repository }
+impl<'a, T: FxRate + Sync> GetItemServiceImpl<'a, T> {
+    pub fn new(repository: &'a (dyn ItemDynamoDbRepository + Sync), fx_rate: &'a T) -> Self {
+        Self {
+            repository,
+            fx_rate,
+        }
     }
 }
 
 #[async_trait]
-impl<'a> GetItemService for GetItemServiceImpl<'a> {
+impl<T: FxRate + Sync> GetItemService for GetItemServiceImpl<'_, T> {
     async fn find_item(
         &self,
         shop_id: &ShopId,
@@ -91,7 +134,7 @@ impl<'a> GetItemService for GetItemServiceImpl<'a> {
             .repository
             .get_item_record(shop_id, shops_item_id)
             .await
-            .map_err(Box::from)?
+            .map_err(Box::new)?
             .ok_or(GetItemError::ItemNotFound(
                 shop_id.clone(),
                 shops_item_id.clone(),
@@ -111,13 +154,13 @@ impl<'a> GetItemService for GetItemServiceImpl<'a> {
             .repository
             .get_item_record(shop_id, shops_item_id)
             .await
-            .map_err(Box::from)?
+            .map_err(Box::new)?
             .ok_or(GetItemError::ItemNotFound(
                 shop_id.clone(),
                 shops_item_id.clone(),
             ))?;
 
-        let mut available_titles: HashMap<Language, Title> = HashMap::with_capacity(3);
+        let mut available_titles: HashMap<Language, Title> = HashMap::with_capacity(5);
         available_titles.insert(
             item_record.title_native.language.into(),
             item_record.title_native.text.into(),
@@ -128,8 +171,14 @@ impl<'a> GetItemService for GetItemServiceImpl<'a> {
         if let Some(title_en) = item_record.title_en {
             available_titles.insert(Language::En, title_en.into());
         }
+        if let Some(title_fr) = item_record.title_fr {
+            available_titles.insert(Language::Fr, title_fr.into());
+        }
+        if let Some(title_es) = item_record.title_es {
+            available_titles.insert(Language::Es, title_es.into());
+        }
 
-        let mut available_descriptions: HashMap<Language, Description> = HashMap::with_capacity(3);
+        let mut available_descriptions: HashMap<Language, Description> = HashMap::with_capacity(5);
         if let Some(description_native) = item_record.description_native {
             available_descriptions.insert(
                 description_native.language.into(),
@@ -142,6 +191,12 @@ impl<'a> GetItemService for GetItemServiceImpl<'a> {
         if let Some(description_en) = item_record.description_en {
             available_descriptions.insert(Language::En, description_en.into());
         }
+        if let Some(description_fr) = item_record.description_fr {
+            available_descriptions.insert(Language::Fr, description_fr.into());
+        }
+        if let Some(description_es) = item_record.description_es {
+            available_descriptions.insert(Language::Es, description_es.into());
+        }
 
         let title = Language::resolve(preferred_languages, available_titles).unwrap_or_else(|| {
             error!(
@@ -172,6 +227,27 @@ impl<'a> GetItemService for GetItemServiceImpl<'a> {
             Currency::Nzd => item_record
                 .price_nzd
                 .map(|amount| Price::new(amount.into(), Currency::Nzd)),
+            Currency::Chf => item_record
+                .price_chf
+                .map(|amount| Price::new(amount.into(), Currency::Chf)),
+            Currency::Sek => item_record
+                .price_sek
+                .map(|amount| Price::new(amount.into(), Currency::Sek)),
+            Currency::Dkk => item_record
+                .price_dkk
+                .map(|amount| Price::new(amount.into(), Currency::Dkk)),
+            Currency::Nok => item_record
+                .price_nok
+                .map(|amount| Price::new(amount.into(), Currency::Nok)),
+            Currency::Pln => item_record
+                .price_pln
+                .map(|amount| Price::new(amount.into(), Currency::Pln)),
+            Currency::Czk => item_record
+                .price_czk
+                .map(|amount| Price::new(amount.into(), Currency::Czk)),
+            Currency::Jpy => item_record
+                .price_jpy
+                .map(|amount| Price::new(amount.into(), Currency::Jpy)),
         };
 
         let item_view = LocalizedItemView {
@@ -189,10 +265,140 @@ impl<'a> GetItemService for GetItemServiceImpl<'a> {
             hash: item_record.hash,
             created: item_record.created,
             updated: item_record.updated,
+            fx_rate_date: item_record.fx_rate_date,
+            repriced: item_record.repriced,
         };
 
         Ok(item_view)
     }
+
+    async fn view_item_events(
+        &self,
+        shop_id: &ShopId,
+        shops_item_id: &ShopsItemId,
+        currency: &Currency,
+        after: Option<OffsetDateTime>,
+        size: u16,
+    ) -> Result<ItemEventHistoryView, GetItemError> {
+        let page = self
+            .repository
+            .query_item_event_records(shop_id, shops_item_id, after, size)
+            .await
+            .map_err(Box::new)?;
+
+        // every item has at least its created-event, so an empty first page means it does not exist
+        if after.is_none() && page.items.is_empty() {
+            return Err(GetItemError::ItemNotFound(
+                shop_id.clone(),
+                shops_item_id.clone(),
+            ));
+        }
+
+        let events = page
+            .items
+            .into_iter()
+            .map(|record| mk_item_event_view(record, currency))
+            .collect();
+
+        Ok(ItemEventHistoryView {
+            events,
+            next: page.last_evaluated_timestamp,
+        })
+    }
+
+    async fn view_price_history(
+        &self,
+        shop_id: &ShopId,
+        shops_item_id: &ShopsItemId,
+        currency: &Currency,
+    ) -> Result<PriceHistory, GetItemError> {
+        let price_history = match self
+            .repository
+            .query_item_price_event_records(shop_id, shops_item_id)
+            .await
+        {
+            Ok(records)
+                if records
+                    .first()
+                    .is_some_and(ItemPriceEventRecord::is_first_price) =>
+            {
+                let mut price_history = PriceHistory::default();
+                for record in records {
+                    price_history.push(record.timestamp, record.price_native.into());
+                }
+                price_history
+            }
+            Ok(_) => self.replay_price_history(shop_id, shops_item_id).await?,
+            Err(err) => {
+                warn!(error = %err, "Failed querying price events. Replaying the event log instead.");
+                self.replay_price_history(shop_id, shops_item_id).await?
+            }
+        };
+
+        Ok(price_history.into_exchanged(self.fx_rate, *currency)?)
+    }
+}
+
+impl<'a, T: FxRate + Sync> GetItemServiceImpl<'a, T> {
+    async fn replay_price_history(
+        &self,
+        shop_id: &ShopId,
+        shops_item_id: &ShopsItemId,
+    ) -> Result<PriceHistory, GetItemError> {
+        let mut price_history = PriceHistory::default();
+        let mut after = None;
+        loop {
+            let page = self
+                .repository
+                .query_item_event_records(shop_id, shops_item_id, after, EVENT_LOG_PAGE_SIZE)
+                .await
+                .map_err(Box::new)?;
+
+            // every item has at least its created-event, so an empty first page means it does not exist
+            if after.is_none() && page.items.is_empty() {
+                return Err(GetItemError::ItemNotFound(
+                    shop_id.clone(),
+                    shops_item_id.clone(),
+                ));
+            }
+
+            for record in page.items {
+                if let Some(price_native) = record.price_native {
+                    price_history.push(record.timestamp, price_native.into());
+                }
+            }
+            match page.last_evaluated_timestamp {
+                Some(last_evaluated_timestamp) => after = Some(last_evaluated_timestamp),
+                None => return Ok(price_history),
+            }
+        }
+    }
+}
+
+fn mk_item_event_view(record: ItemEventRecord, currency: &Currency) -> ItemEventView {
+    let amount = match currency {
+        Currency::Eur => record.price_eur,
+        Currency::Gbp => record.price_gbp,
+        Currency::Usd => record.price_usd,
+        Currency::Aud => record.price_aud,
+        Currency::Cad => record.price_cad,
+        Currency::Nzd => record.price_nzd,
+        Currency::Chf => record.price_chf,
+        Currency::Sek => record.price_sek,
+        Currency::Dkk => record.price_dkk,
+        Currency::Nok => record.price_nok,
+        Currency::Pln => record.price_pln,
+        Currency::Czk => record.price_czk,
+        Currency::Jpy => record.price_jpy,
+    };
+
+    ItemEventView {
+        event_id: record.event_id,
+        event_type: record.event_type.into(),
+        price: amount.map(|amount| Price::new(amount.into(), *currency)),
+        state: record.state.map(Into::into),
+        timestamp: record.timestamp,
+    }
 }
 
 #[cfg(test)]
@@ -204,6 +410,7 @@ mod tests {
             config::http::HttpResponse,
             error::{ConnectorError, SdkError},
         };
+        use common::price::domain::FixedFxRate;
         use common::{shop_id::ShopId, shops_item_id::ShopsItemId};
         use fake::{Fake, Faker};
         use item_dynamodb::repository::MockItemDynamoDbRepository;
@@ -216,6 +423,7 @@ mod tests {
                 .return_once(|_, _| Box::pin(async { Ok(Some(Faker.fake())) }));
             let service = GetItemServiceImpl {
                 repository: &repository,
+                fx_rate: &FixedFxRate(),
             };
             let actual = service.find_item(&ShopId::new(), &ShopsItemId::new()).await;
             assert!(actual.is_ok());
@@ -231,6 +439,7 @@ mod tests {
                 .return_once(|_, _| Box::pin(async { Ok(None) }));
             let service = GetItemServiceImpl {
                 repository: &repository,
+                fx_rate: &FixedFxRate(),
             };
             let actual = service.find_item(&shop_id, &shops_item_id).await;
 
@@ -271,6 +480,7 @@ mod tests {
                 .return_once(|_, _| Box::pin(async { Err(expected) }));
             let service = GetItemServiceImpl {
                 repository: &repository,
+                fx_rate: &FixedFxRate(),
             };
             let actual = service.find_item(&shop_id, &shops_item_id).await;
 
@@ -288,6 +498,7 @@ mod tests {
             config::http::HttpResponse,
             error::{ConnectorError, SdkError},
         };
+        use common::price::domain::FixedFxRate;
         use common::{
             currency::domain::Currency,
             language::{
@@ -309,6 +520,7 @@ mod tests {
                 .return_once(|_, _| Box::pin(async { Ok(Some(Faker.fake())) }));
             let service = GetItemServiceImpl {
                 repository: &repository,
+                fx_rate: &FixedFxRate(),
             };
             let actual = service
                 .view_item(&ShopId::new(), &ShopsItemId::new(), &[], &Currency::Eur)
@@ -324,6 +536,13 @@ mod tests {
         #[case::aud(Currency::Aud, 1000)]
         #[case::cad(Currency::Cad, 4000)]
         #[case::nzd(Currency::Nzd, 42)]
+        #[case::chf(Currency::Chf, 43)]
+        #[case::sek(Currency::Sek, 44)]
+        #[case::dkk(Currency::Dkk, 45)]
+        #[case::nok(Currency::Nok, 46)]
+        #[case::pln(Currency::Pln, 47)]
+        #[case::czk(Currency::Czk, 48)]
+        #[case::jpy(Currency::Jpy, 49)]
         async fn should_respect_currency(#[case] currency: Currency, #[case] expected_amount: u64) {
             let mut repository = MockItemDynamoDbRepository::default();
             let mut expected_record: ItemRecord = Faker.fake();
@@ -333,11 +552,19 @@ mod tests {
             expected_record.price_aud = Some(1000);
             expected_record.price_cad = Some(4000);
             expected_record.price_nzd = Some(42);
+            expected_record.price_chf = Some(43);
+            expected_record.price_sek = Some(44);
+            expected_record.price_dkk = Some(45);
+            expected_record.price_nok = Some(46);
+            expected_record.price_pln = Some(47);
+            expected_record.price_czk = Some(48);
+            expected_record.price_jpy = Some(49);
             repository
                 .expect_get_item_record()
                 .return_once(|_, _| Box::pin(async { Ok(Some(expected_record)) }));
             let service = GetItemServiceImpl {
                 repository: &repository,
+                fx_rate: &FixedFxRate(),
             };
             let actual_price = service
                 .view_item(&ShopId::new(), &ShopsItemId::new(), &[], &currency)
@@ -355,7 +582,8 @@ mod tests {
         #[case(&[De], De, "German")]
         #[case(&[De, En], De, "German")]
         #[case(&[De, Fr], De, "German")]
-        #[case(&[Fr, De, En, Es], De, "German")]
+        #[case(&[Fr, De, En, Es], Fr, "French")]
+        #[case(&[Fr], Fr, "French")]
         #[case(&[En], En, "English")]
         #[case(&[En, De, Fr, Es], En, "English")]
         #[case(&[En, De, Es], En, "English")]
@@ -371,11 +599,14 @@ mod tests {
             expected_record.title_native = TextRecord::new("Spanish", LanguageRecord::Es);
             expected_record.title_de = Some("German".to_string());
             expected_record.title_en = Some("English".to_string());
+            expected_record.title_fr = Some("French".to_string());
+            expected_record.title_es = Some("Spanish".to_string());
             repository
                 .expect_get_item_record()
                 .return_once(|_, _| Box::pin(async { Ok(Some(expected_record)) }));
             let service = GetItemServiceImpl {
                 repository: &repository,
+                fx_rate: &FixedFxRate(),
             };
             let actual_title = service
                 .view_item(
@@ -413,11 +644,14 @@ mod tests {
             expected_record.title_native = TextRecord::new("Spanish", LanguageRecord::Es);
             expected_record.title_de = None;
             expected_record.title_en = None;
+            expected_record.title_fr = None;
+            expected_record.title_es = None;
             repository
                 .expect_get_item_record()
                 .return_once(|_, _| Box::pin(async { Ok(Some(expected_record)) }));
             let service = GetItemServiceImpl {
                 repository: &repository,
+                fx_rate: &FixedFxRate(),
             };
             let actual_title = service
                 .view_item(
@@ -439,7 +673,8 @@ mod tests {
         #[case(&[De], De, "German")]
         #[case(&[De, En], De, "German")]
         #[case(&[De, Fr], De, "German")]
-        #[case(&[Fr, De, En, Es], De, "German")]
+        #[case(&[Fr, De, En, Es], Fr, "French")]
+        #[case(&[Fr], Fr, "French")]
         #[case(&[En], En, "English")]
         #[case(&[En, De, Fr, Es], En, "English")]
         #[case(&[En, De, Es], En, "English")]
@@ -456,11 +691,14 @@ mod tests {
                 Some(TextRecord::new("Spanish", LanguageRecord::Es));
             expected_record.description_de = Some("German".to_string());
             expected_record.description_en = Some("English".to_string());
+            expected_record.description_fr = Some("French".to_string());
+            expected_record.description_es = Some("Spanish".to_string());
             repository
                 .expect_get_item_record()
                 .return_once(|_, _| Box::pin(async { Ok(Some(expected_record)) }));
             let service = GetItemServiceImpl {
                 repository: &repository,
+                fx_rate: &FixedFxRate(),
             };
             let actual_description = service
                 .view_item(
@@ -500,11 +738,14 @@ mod tests {
                 Some(TextRecord::new("Spanish", LanguageRecord::Es));
             expected_record.description_de = None;
             expected_record.description_en = None;
+            expected_record.description_fr = None;
+            expected_record.description_es = None;
             repository
                 .expect_get_item_record()
                 .return_once(|_, _| Box::pin(async { Ok(Some(expected_record)) }));
             let service = GetItemServiceImpl {
                 repository: &repository,
+                fx_rate: &FixedFxRate(),
             };
             let actual_description = service
                 .view_item(
@@ -541,11 +782,14 @@ mod tests {
             expected_record.description_native = None;
             expected_record.description_de = None;
             expected_record.description_en = None;
+            expected_record.description_fr = None;
+            expected_record.description_es = None;
             repository
                 .expect_get_item_record()
                 .return_once(|_, _| Box::pin(async { Ok(Some(expected_record)) }));
             let service = GetItemServiceImpl {
                 repository: &repository,
+                fx_rate: &FixedFxRate(),
             };
             let actual_description = service
                 .view_item(
@@ -570,6 +814,7 @@ mod tests {
                 .return_once(|_, _| Box::pin(async { Ok(None) }));
             let service = GetItemServiceImpl {
                 repository: &repository,
+                fx_rate: &FixedFxRate(),
             };
             let actual = service
                 .view_item(&shop_id, &shops_item_id, &[], &Currency::Eur)
@@ -612,6 +857,7 @@ mod tests {
                 .return_once(|_, _| Box::pin(async { Err(expected) }));
             let service = GetItemServiceImpl {
                 repository: &repository,
+                fx_rate: &FixedFxRate(),
             };
             let actual = service
                 .view_item(&shop_id, &shops_item_id, &[], &Currency::Eur)
@@ -624,4 +870,548 @@ mod tests {
             }
         }
     }
+
+    mod view_item_events {
+        use crate::get_service::{GetItemError, GetItemService, GetItemServiceImpl};
+        use common::{
+            currency::domain::Currency,
+            item_state::domain::ItemState,
+            price::domain::{FixedFxRate, Price},
+            shop_id::ShopId,
+            shops_item_id::ShopsItemId,
+        };
+        use fake::{Fake, Faker};
+        use item_core::item_event::ItemEventType;
+        use item_dynamodb::{
+            item_event_record::ItemEventRecord,
+            item_event_type_record::ItemEventTypeRecord,
+            item_state_record::ItemStateRecord,
+            repository::{ItemEventRecordPage, MockItemDynamoDbRepository},
+        };
+        use time::macros::datetime;
+
+        fn mk_price_dropped_record() -> ItemEventRecord {
+            let mut record: ItemEventRecord = Faker.fake();
+            record.event_type = ItemEventTypeRecord::PriceDropped;
+            record.state = None;
+            record.price_eur = Some(38000);
+            record.price_gbp = Some(32000);
+            record.price_usd = Some(41000);
+            record.price_aud = Some(62000);
+            record.price_cad = Some(56000);
+            record.price_nzd = Some(68000);
+            record.price_chf = Some(35000);
+            record.price_sek = Some(420000);
+            record.price_dkk = Some(283000);
+            record.price_nok = Some(450000);
+            record.price_pln = Some(162000);
+            record.price_czk = Some(935000);
+            record.price_jpy = Some(6500);
+            record.timestamp = datetime!(2025-01-01 0:00 UTC);
+            record
+        }
+
+        #[tokio::test]
+        #[rstest::rstest]
+        #[case::eur(Currency::Eur, 38000)]
+        #[case::gbp(Currency::Gbp, 32000)]
+        #[case::usd(Currency::Usd, 41000)]
+        #[case::aud(Currency::Aud, 62000)]
+        #[case::cad(Currency::Cad, 56000)]
+        #[case::nzd(Currency::Nzd, 68000)]
+        #[case::chf(Currency::Chf, 35000)]
+        #[case::sek(Currency::Sek, 420000)]
+        #[case::dkk(Currency::Dkk, 283000)]
+        #[case::nok(Currency::Nok, 450000)]
+        #[case::pln(Currency::Pln, 162000)]
+        #[case::czk(Currency::Czk, 935000)]
+        #[case::jpy(Currency::Jpy, 6500)]
+        async fn should_return_events_with_price_in_requested_currency(
+            #[case] currency: Currency,
+            #[case] expected_amount: u64,
+        ) {
+            let record = mk_price_dropped_record();
+            let expected_event_id = record.event_id;
+            let mut repository = MockItemDynamoDbRepository::default();
+            repository
+                .expect_query_item_event_records()
+                .return_once(|_, _, _, _| {
+                    Box::pin(async {
+                        Ok(ItemEventRecordPage {
+                            items: vec![record],
+                            last_evaluated_timestamp: None,
+                        })
+                    })
+                });
+            let service = GetItemServiceImpl {
+                repository: &repository,
+                fx_rate: &FixedFxRate(),
+            };
+
+            let actual = service
+                .view_item_events(&ShopId::new(), &ShopsItemId::new(), &currency, None, 10)
+                .await
+                .unwrap();
+
+            assert_eq!(1, actual.events.len());
+            let event = &actual.events[0];
+            assert_eq!(expected_event_id, event.event_id);
+            assert_eq!(ItemEventType::PriceDropped, event.event_type);
+            assert_eq!(
+                Some(Price::new(expected_amount.into(), currency)),
+                event.price
+            );
+            assert_eq!(None, event.state);
+            assert_eq!(datetime!(2025-01-01 0:00 UTC), event.timestamp);
+            assert_eq!(None, actual.next);
+        }
+
+        #[tokio::test]
+        async fn should_return_state_of_state_events() {
+            let mut record: ItemEventRecord = Faker.fake();
+            record.event_type = ItemEventTypeRecord::StateSold;
+            record.state = Some(ItemStateRecord::Sold);
+            record.price_eur = None;
+            let mut repository = MockItemDynamoDbRepository::default();
+            repository
+                .expect_query_item_event_records()
+                .return_once(|_, _, _, _| {
+                    Box::pin(async {
+                        Ok(ItemEventRecordPage {
+                            items: vec![record],
+                            last_evaluated_timestamp: None,
+                        })
+                    })
+                });
+            let service = GetItemServiceImpl {
+                repository: &repository,
+                fx_rate: &FixedFxRate(),
+            };
+
+            let actual = service
+                .view_item_events(
+                    &ShopId::new(),
+                    &ShopsItemId::new(),
+                    &Currency::Eur,
+                    None,
+                    10,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(ItemEventType::StateSold, actual.events[0].event_type);
+            assert_eq!(Some(ItemState::Sold), actual.events[0].state);
+            assert_eq!(None, actual.events[0].price);
+        }
+
+        #[tokio::test]
+        async fn should_pass_after_and_size_and_return_next() {
+            let after = datetime!(2025-01-01 0:00 UTC);
+            let next = datetime!(2025-02-01 0:00 UTC);
+            let mut repository = MockItemDynamoDbRepository::default();
+            repository
+                .expect_query_item_event_records()
+                .withf(move |_, _, actual_after, actual_size| {
+                    *actual_after == Some(after) && *actual_size == 2
+                })
+                .return_once(move |_, _, _, _| {
+                    Box::pin(async move {
+                        Ok(ItemEventRecordPage {
+                            items: vec![mk_price_dropped_record(), mk_price_dropped_record()],
+                            last_evaluated_timestamp: Some(next),
+                        })
+                    })
+                });
+            let service = GetItemServiceImpl {
+                repository: &repository,
+                fx_rate: &FixedFxRate(),
+            };
+
+            let actual = service
+                .view_item_events(
+                    &ShopId::new(),
+                    &ShopsItemId::new(),
+                    &Currency::Eur,
+                    Some(after),
+                    2,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(2, actual.events.len());
+            assert_eq!(Some(next), actual.next);
+        }
+
+        #[tokio::test]
+        async fn should_return_item_not_found_err_when_item_has_no_events() {
+            let shop_id = ShopId::new();
+            let shops_item_id: ShopsItemId = "non-existent".into();
+            let mut repository = MockItemDynamoDbRepository::default();
+            repository
+                .expect_query_item_event_records()
+                .return_once(|_, _, _, _| {
+                    Box::pin(async {
+                        Ok(ItemEventRecordPage {
+                            items: vec![],
+                            last_evaluated_timestamp: None,
+                        })
+                    })
+                });
+            let service = GetItemServiceImpl {
+                repository: &repository,
+                fx_rate: &FixedFxRate(),
+            };
+
+            let actual = service
+                .view_item_events(&shop_id, &shops_item_id, &Currency::Eur, None, 10)
+                .await;
+
+            match actual.unwrap_err() {
+                GetItemError::ItemNotFound(err_shop_id, err_shops_item_id) => {
+                    assert_eq!(err_shop_id, shop_id);
+                    assert_eq!(err_shops_item_id, shops_item_id);
+                }
+                _ => panic!("expected GetItemError::ItemNotFound"),
+            }
+        }
+
+        #[tokio::test]
+        async fn should_return_empty_page_when_no_events_exist_after() {
+            let mut repository = MockItemDynamoDbRepository::default();
+            repository
+                .expect_query_item_event_records()
+                .return_once(|_, _, _, _| {
+                    Box::pin(async {
+                        Ok(ItemEventRecordPage {
+                            items: vec![],
+                            last_evaluated_timestamp: None,
+                        })
+                    })
+                });
+            let service = GetItemServiceImpl {
+                repository: &repository,
+                fx_rate: &FixedFxRate(),
+            };
+
+            let actual = service
+                .view_item_events(
+                    &ShopId::new(),
+                    &ShopsItemId::new(),
+                    &Currency::Eur,
+                    Some(datetime!(2025-01-01 0:00 UTC)),
+                    10,
+                )
+                .await
+                .unwrap();
+
+            assert!(actual.events.is_empty());
+            assert_eq!(None, actual.next);
+        }
+    }
+
+    mod view_price_history {
+        use crate::get_service::{GetItemError, GetItemService, GetItemServiceImpl};
+        use aws_sdk_dynamodb::error::SdkError;
+        use common::{
+            currency::{domain::Currency, record::CurrencyRecord},
+            price::{
+                domain::{FixedFxRate, Price},
+                record::PriceRecord,
+            },
+            shop_id::ShopId,
+            shops_item_id::ShopsItemId,
+        };
+        use fake::{Fake, Faker};
+        use item_dynamodb::{
+            item_event_record::ItemEventRecord,
+            item_event_type_record::ItemEventTypeRecord,
+            item_price_event_record::ItemPriceEventRecord,
+            repository::{ItemEventRecordPage, MockItemDynamoDbRepository},
+        };
+        use time::{OffsetDateTime, macros::datetime};
+
+        fn mk_price(amount: u64) -> PriceRecord {
+            PriceRecord {
+                amount,
+                currency: CurrencyRecord::Eur,
+            }
+        }
+
+        fn mk_record(
+            event_type: ItemEventTypeRecord,
+            timestamp: OffsetDateTime,
+            amount: u64,
+        ) -> ItemPriceEventRecord {
+            ItemPriceEventRecord {
+                event_type,
+                price_native: mk_price(amount),
+                timestamp,
+            }
+        }
+
+        fn mk_event_record(
+            event_type: ItemEventTypeRecord,
+            timestamp: OffsetDateTime,
+            amount: Option<u64>,
+        ) -> ItemEventRecord {
+            let mut record: ItemEventRecord = Faker.fake();
+            record.event_type = event_type;
+            record.timestamp = timestamp;
+            record.price_native = amount.map(mk_price);
+            record
+        }
+
+        #[tokio::test]
+        async fn should_collapse_equal_prices_and_exchange_into_currency() {
+            let mut repository = MockItemDynamoDbRepository::default();
+            repository
+                .expect_query_item_price_event_records()
+                .return_once(|_, _| {
+                    Box::pin(async {
+                        Ok(vec![
+                            mk_record(
+                                ItemEventTypeRecord::Created,
+                                datetime!(2025-01-01 0:00 UTC),
+                                10000,
+                            ),
+                            mk_record(
+                                ItemEventTypeRecord::PriceIncreased,
+                                datetime!(2025-01-05 0:00 UTC),
+                                10000,
+                            ),
+                            mk_record(
+                                ItemEventTypeRecord::PriceIncreased,
+                                datetime!(2025-01-10 0:00 UTC),
+                                20000,
+                            ),
+                        ])
+                    })
+                });
+            repository.expect_query_item_event_records().never();
+            let service = GetItemServiceImpl {
+                repository: &repository,
+                fx_rate: &FixedFxRate(),
+            };
+
+            let actual = service
+                .view_price_history(&ShopId::new(), &ShopsItemId::new(), &Currency::Usd)
+                .await
+                .unwrap();
+
+            assert_eq!(2, actual.points.len());
+            assert_eq!(datetime!(2025-01-01 0:00 UTC), actual.points[0].timestamp);
+            assert_eq!(
+                Price::new(11670u64.into(), Currency::Usd),
+                actual.points[0].price
+            );
+            assert_eq!(datetime!(2025-01-10 0:00 UTC), actual.points[1].timestamp);
+            assert_eq!(
+                Price::new(23340u64.into(), Currency::Usd),
+                actual.points[1].price
+            );
+        }
+
+        #[rstest::rstest]
+        #[case::created(ItemEventTypeRecord::Created)]
+        #[case::price_discovered(ItemEventTypeRecord::PriceDiscovered)]
+        #[tokio::test]
+        async fn should_not_replay_event_log_when_price_events_start_with_first_price(
+            #[case] event_type: ItemEventTypeRecord,
+        ) {
+            let mut repository = MockItemDynamoDbRepository::default();
+            repository
+                .expect_query_item_price_event_records()
+                .return_once(move |_, _| {
+                    Box::pin(async move {
+                        Ok(vec![mk_record(
+                            event_type,
+                            datetime!(2025-01-01 0:00 UTC),
+                            10000,
+                        )])
+                    })
+                });
+            repository.expect_query_item_event_records().never();
+            let service = GetItemServiceImpl {
+                repository: &repository,
+                fx_rate: &FixedFxRate(),
+            };
+
+            let actual = service
+                .view_price_history(&ShopId::new(), &ShopsItemId::new(), &Currency::Eur)
+                .await
+                .unwrap();
+
+            assert_eq!(1, actual.points.len());
+        }
+
+        #[tokio::test]
+        async fn should_replay_event_log_when_price_events_lack_first_price() {
+            let mut repository = MockItemDynamoDbRepository::default();
+            repository
+                .expect_query_item_price_event_records()
+                .return_once(|_, _| {
+                    Box::pin(async {
+                        Ok(vec![mk_record(
+                            ItemEventTypeRecord::PriceDropped,
+                            datetime!(2025-01-10 0:00 UTC),
+                            5000,
+                        )])
+                    })
+                });
+            repository
+                .expect_query_item_event_records()
+                .withf(|_, _, after, _| after.is_none())
+                .return_once(|_, _, _, _| {
+                    Box::pin(async {
+                        Ok(ItemEventRecordPage {
+                            items: vec![
+                                mk_event_record(
+                                    ItemEventTypeRecord::Created,
+                                    datetime!(2025-01-01 0:00 UTC),
+                                    Some(10000),
+                                ),
+                                mk_event_record(
+                                    ItemEventTypeRecord::StateSold,
+                                    datetime!(2025-01-05 0:00 UTC),
+                                    None,
+                                ),
+                            ],
+                            last_evaluated_timestamp: Some(datetime!(2025-01-05 0:00 UTC)),
+                        })
+                    })
+                });
+            repository
+                .expect_query_item_event_records()
+                .withf(|_, _, after, _| *after == Some(datetime!(2025-01-05 0:00 UTC)))
+                .return_once(|_, _, _, _| {
+                    Box::pin(async {
+                        Ok(ItemEventRecordPage {
+                            items: vec![mk_event_record(
+                                ItemEventTypeRecord::PriceDropped,
+                                datetime!(2025-01-10 0:00 UTC),
+                                Some(5000),
+                            )],
+                            last_evaluated_timestamp: None,
+                        })
+                    })
+                });
+            let service = GetItemServiceImpl {
+                repository: &repository,
+                fx_rate: &FixedFxRate(),
+            };
+
+            let actual = service
+                .view_price_history(&ShopId::new(), &ShopsItemId::new(), &Currency::Eur)
+                .await
+                .unwrap();
+
+            assert_eq!(2, actual.points.len());
+            assert_eq!(datetime!(2025-01-01 0:00 UTC), actual.points[0].timestamp);
+            assert_eq!(
+                Price::new(10000u64.into(), Currency::Eur),
+                actual.points[0].price
+            );
+            assert_eq!(datetime!(2025-01-10 0:00 UTC), actual.points[1].timestamp);
+            assert_eq!(
+                Price::new(5000u64.into(), Currency::Eur),
+                actual.points[1].price
+            );
+        }
+
+        #[tokio::test]
+        async fn should_replay_event_log_when_price_events_fail() {
+            let mut repository = MockItemDynamoDbRepository::default();
+            repository
+                .expect_query_item_price_event_records()
+                .return_once(|_, _| {
+                    Box::pin(async { Err(SdkError::construction_failure("Something went wrong")) })
+                });
+            repository
+                .expect_query_item_event_records()
+                .return_once(|_, _, _, _| {
+                    Box::pin(async {
+                        Ok(ItemEventRecordPage {
+                            items: vec![mk_event_record(
+                                ItemEventTypeRecord::Created,
+                                datetime!(2025-01-01 0:00 UTC),
+                                Some(10000),
+                            )],
+                            last_evaluated_timestamp: None,
+                        })
+                    })
+                });
+            let service = GetItemServiceImpl {
+                repository: &repository,
+                fx_rate: &FixedFxRate(),
+            };
+
+            let actual = service
+                .view_price_history(&ShopId::new(), &ShopsItemId::new(), &Currency::Eur)
+                .await
+                .unwrap();
+
+            assert_eq!(1, actual.points.len());
+        }
+
+        #[tokio::test]
+        async fn should_return_empty_price_history_when_item_has_no_price_events() {
+            let mut repository = MockItemDynamoDbRepository::default();
+            repository
+                .expect_query_item_price_event_records()
+                .return_once(|_, _| Box::pin(async { Ok(vec![]) }));
+            repository
+                .expect_query_item_event_records()
+                .return_once(|_, _, _, _| {
+                    Box::pin(async {
+                        Ok(ItemEventRecordPage {
+                            items: vec![mk_event_record(
+                                ItemEventTypeRecord::Created,
+                                datetime!(2025-01-01 0:00 UTC),
+                                None,
+                            )],
+                            last_evaluated_timestamp: None,
+                        })
+                    })
+                });
+            let service = GetItemServiceImpl {
+                repository: &repository,
+                fx_rate: &FixedFxRate(),
+            };
+
+            let actual = service
+                .view_price_history(&ShopId::new(), &ShopsItemId::new(), &Currency::Eur)
+                .await
+                .unwrap();
+
+            assert!(actual.points.is_empty());
+        }
+
+        #[tokio::test]
+        async fn should_return_item_not_found_when_item_has_no_events() {
+            let mut repository = MockItemDynamoDbRepository::default();
+            repository
+                .expect_query_item_price_event_records()
+                .return_once(|_, _| Box::pin(async { Ok(vec![]) }));
+            repository
+                .expect_query_item_event_records()
+                .return_once(|_, _, _, _| {
+                    Box::pin(async {
+                        Ok(ItemEventRecordPage {
+                            items: vec![],
+                            last_evaluated_timestamp: None,
+                        })
+                    })
+                });
+            let service = GetItemServiceImpl {
+                repository: &repository,
+                fx_rate: &FixedFxRate(),
+            };
+
+            let actual = service
+                .view_price_history(&ShopId::new(), &ShopsItemId::new(), &Currency::Eur)
+                .await;
+
+            assert!(matches!(actual, Err(GetItemError::ItemNotFound(_, _))));
+        }
+    }
 }
@@ -1,29 +1,42 @@
 use async_trait::async_trait;
 use common::language::domain::Language;
+use common::opensearch::search_after::SearchAfter;
+use common::opensearch::search_response::Aggregations;
 use common::opensearch::search_result::SearchResult;
 use common::page::Page;
 use common::price::domain::Price;
 use common::sort::Sort;
 use common::{currency::domain::Currency, localized::Localized};
+use item_core::facet::{ItemFacets, RangeFacet, TermFacet};
 use item_core::hash::ItemHash;
 use item_core::sort_item_field::SortItemField;
 use item_core::{description::Description, item::LocalizedItemView, title::Title};
-use item_opensearch::repository::ItemOpenSearchRepository;
+use item_opensearch::item_state_document::ItemStateDocument;
+use item_opensearch::repository::{
+    CREATED_AGGREGATION, ItemOpenSearchRepository, PRICE_AGGREGATION, SHOP_NAME_AGGREGATION,
+    STATE_AGGREGATION, price_histogram_interval,
+};
+use opensearch::http::StatusCode;
 use search_filter_core::search_filter::SearchFilter;
+use serde::Deserialize;
 use std::collections::HashMap;
+use time::{Date, Month, OffsetDateTime, Time};
 use tracing::{error, warn};
 
 #[derive(thiserror::Error, Debug)]
 pub enum SearchItemsError {
     #[error("OpenSearchError: {0}")]
     OpenSearchError(#[from] opensearch::Error),
+
+    #[error("The point-in-time of the search has expired.")]
+    PointInTimeExpired,
 }
 
 #[cfg(feature = "api")]
 pub mod api {
     use crate::query_service::SearchItemsError;
     use common::api::error::ApiError;
-    use common::api::error_code::INTERNAL_SERVER_ERROR;
+    use common::api::error_code::{CURSOR_EXPIRED, INTERNAL_SERVER_ERROR};
     use tracing::error;
 
     impl From<SearchItemsError> for ApiError {
@@ -33,6 +46,9 @@ pub mod api {
                     error!(error = ?err, "Encountered OpenSearchError while searching items.");
                     ApiError::internal_server_error(INTERNAL_SERVER_ERROR)
                 }
+                SearchItemsError::PointInTimeExpired => ApiError::gone(CURSOR_EXPIRED)
+                    .with_query_field("cursor")
+                    .with_message("Cursor has expired, restart the search without it."),
             }
         }
     }
@@ -48,7 +64,11 @@ pub trait QueryItemService {
         currency: &Currency,
         sort: &Option<Sort<SortItemField>>,
         page: &Option<Page>,
-    ) -> Result<SearchResult<LocalizedItemView>, SearchItemsError>;
+        search_after: &Option<SearchAfter>,
+    ) -> Result<SearchResult<LocalizedItemView, ItemFacets>, SearchItemsError>;
+
+    /// Opens a point-in-time so that subsequent pages stay consistent with the first one.
+    async fn open_point_in_time(&self) -> Result<SearchAfter, SearchItemsError>;
 }
 
 pub struct QueryItemServiceImpl<'a> {
@@ -70,11 +90,39 @@ impl<'a> QueryItemService for QueryItemServiceImpl<'a> {
         currency: &Currency,
         sort: &Option<Sort<SortItemField>>,
         page: &Option<Page>,
-    ) -> Result<SearchResult<LocalizedItemView>, SearchItemsError> {
+        search_after: &Option<SearchAfter>,
+    ) -> Result<SearchResult<LocalizedItemView, ItemFacets>, SearchItemsError> {
         let search_response = self
             .repository
-            .search_item_documents(search_filter, language, currency, sort, page)
-            .await?;
+            .search_item_documents(search_filter, language, currency, sort, page, search_after)
+            .await
+            .map_err(|err| match err.status_code() {
+                // OpenSearch answers with 404 once the point-in-time of a cursor has expired
+                Some(StatusCode::NOT_FOUND)
+                    if search_after
+                        .as_ref()
+                        .is_some_and(|search_after| search_after.pit_id.is_some()) =>
+                {
+                    SearchItemsError::PointInTimeExpired
+                }
+                _ => SearchItemsError::from(err),
+            })?;
+
+        let requested_size = page.map(|page| page.size as usize).unwrap_or(10);
+        let next_search_after = search_response
+            .hits
+            .hits
+            .last()
+            .filter(|_| search_response.hits.hits.len() >= requested_size)
+            .map(|last_hit| SearchAfter {
+                sort_values: last_hit.sort.clone(),
+                // OpenSearch may rotate the id, the latest one has to be used for the next page
+                pit_id: search_response.pit_id.clone().or_else(|| {
+                    search_after
+                        .as_ref()
+                        .and_then(|search_after| search_after.pit_id.clone())
+                }),
+            });
 
         if search_response.timed_out {
             warn!(
@@ -90,21 +138,33 @@ impl<'a> QueryItemService for QueryItemServiceImpl<'a> {
         }
 
         let item_views = search_response.hits.hits.into_iter().map(|hit| hit.source).map(|item_document| {
-            let mut available_titles: HashMap<Language, Title> = HashMap::with_capacity(3);
+            let mut available_titles: HashMap<Language, Title> = HashMap::with_capacity(4);
             if let Some(title_de) = item_document.title_de {
                 available_titles.insert(Language::De, title_de.into());
             }
             if let Some(title_en) = item_document.title_en {
                 available_titles.insert(Language::En, title_en.into());
             }
+            if let Some(title_fr) = item_document.title_fr {
+                available_titles.insert(Language::Fr, title_fr.into());
+            }
+            if let Some(title_es) = item_document.title_es {
+                available_titles.insert(Language::Es, title_es.into());
+            }
 
-            let mut available_descriptions: HashMap<Language, Description> = HashMap::with_capacity(3);
+            let mut available_descriptions: HashMap<Language, Description> = HashMap::with_capacity(4);
             if let Some(description_de) = item_document.description_de {
                 available_descriptions.insert(Language::De, description_de.into());
             }
             if let Some(description_en) = item_document.description_en {
                 available_descriptions.insert(Language::En, description_en.into());
             }
+            if let Some(description_fr) = item_document.description_fr {
+                available_descriptions.insert(Language::Fr, description_fr.into());
+            }
+            if let Some(description_es) = item_document.description_es {
+                available_descriptions.insert(Language::Es, description_es.into());
+            }
 
             let title = Language::resolve(&[*language], available_titles).unwrap_or_else(|| {
                 error!(
@@ -135,6 +195,27 @@ impl<'a> QueryItemService for QueryItemServiceImpl<'a> {
                 Currency::Nzd => item_document
                     .price_nzd
                     .map(|amount| Price::new(amount.into(), Currency::Nzd)),
+                Currency::Chf => item_document
+                    .price_chf
+                    .map(|amount| Price::new(amount.into(), Currency::Chf)),
+                Currency::Sek => item_document
+                    .price_sek
+                    .map(|amount| Price::new(amount.into(), Currency::Sek)),
+                Currency::Dkk => item_document
+                    .price_dkk
+                    .map(|amount| Price::new(amount.into(), Currency::Dkk)),
+                Currency::Nok => item_document
+                    .price_nok
+                    .map(|amount| Price::new(amount.into(), Currency::Nok)),
+                Currency::Pln => item_document
+                    .price_pln
+                    .map(|amount| Price::new(amount.into(), Currency::Pln)),
+                Currency::Czk => item_document
+                    .price_czk
+                    .map(|amount| Price::new(amount.into(), Currency::Czk)),
+                Currency::Jpy => item_document
+                    .price_jpy
+                    .map(|amount| Price::new(amount.into(), Currency::Jpy)),
             };
             let state = item_document.state.into();
 
@@ -153,6 +234,8 @@ impl<'a> QueryItemService for QueryItemServiceImpl<'a> {
                 hash: ItemHash::new(&price, &state),
                 created: item_document.created,
                 updated: item_document.updated,
+                fx_rate_date: None,
+                repriced: None,
             }
         })
         .collect::<Vec<_>>();
@@ -160,25 +243,118 @@ impl<'a> QueryItemService for QueryItemServiceImpl<'a> {
         Ok(SearchResult {
             hits: item_views,
             total: search_response.hits.total.value,
+            facets: mk_item_facets(&search_response.aggregations, currency),
+            search_after: next_search_after,
+        })
+    }
+
+    async fn open_point_in_time(&self) -> Result<SearchAfter, SearchItemsError> {
+        let pit_id = self.repository.open_point_in_time().await?;
+        Ok(SearchAfter {
+            sort_values: vec![],
+            pit_id: Some(pit_id),
         })
     }
 }
 
+fn mk_item_facets(aggregations: &Aggregations, currency: &Currency) -> ItemFacets {
+    let state = aggregations
+        .buckets(STATE_AGGREGATION)
+        .iter()
+        .filter_map(|bucket| match ItemStateDocument::deserialize(&bucket.key) {
+            Ok(state) => Some(TermFacet {
+                value: state.into(),
+                count: bucket.doc_count,
+            }),
+            Err(err) => {
+                error!(error = %err, key = %bucket.key, "Failed parsing state-aggregation bucket.");
+                None
+            }
+        })
+        .collect();
+
+    let shop_name = aggregations
+        .buckets(SHOP_NAME_AGGREGATION)
+        .iter()
+        .filter_map(|bucket| {
+            bucket.key.as_str().map(|shop_name| TermFacet {
+                value: shop_name.into(),
+                count: bucket.doc_count,
+            })
+        })
+        .collect();
+
+    let interval = price_histogram_interval(currency);
+    let price = aggregations
+        .buckets(PRICE_AGGREGATION)
+        .iter()
+        .filter_map(|bucket| {
+            bucket.key.as_f64().map(|min| RangeFacet {
+                min: (min as u64).into(),
+                max: (min as u64 + interval).into(),
+                count: bucket.doc_count,
+            })
+        })
+        .collect();
+
+    let created = aggregations
+        .buckets(CREATED_AGGREGATION)
+        .iter()
+        .filter_map(|bucket| {
+            let epoch_millis = bucket.key.as_i64()?;
+            match OffsetDateTime::from_unix_timestamp_nanos(epoch_millis as i128 * 1_000_000) {
+                Ok(min) => Some(RangeFacet {
+                    min,
+                    max: start_of_next_month(min),
+                    count: bucket.doc_count,
+                }),
+                Err(err) => {
+                    error!(error = %err, key = %bucket.key, "Failed parsing created-aggregation bucket.");
+                    None
+                }
+            }
+        })
+        .collect();
+
+    ItemFacets {
+        state,
+        shop_name,
+        price,
+        created,
+    }
+}
+
+fn start_of_next_month(date_time: OffsetDateTime) -> OffsetDateTime {
+    let (year, month) = match date_time.month() {
+        Month::December => (date_time.year() + 1, Month::January),
+        month => (date_time.year(), month.next()),
+    };
+    Date::from_calendar_date(year, month, 1)
+        .map(|date| {
+            date.with_time(Time::MIDNIGHT)
+                .assume_offset(date_time.offset())
+        })
+        .unwrap_or(date_time)
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
 
     use crate::query_service::{QueryItemService, QueryItemServiceImpl};
+    use common::opensearch::search_after::SearchAfter;
     use common::{
         currency::domain::Currency,
         item_state::domain::ItemState,
         language::domain::Language,
         opensearch::search_response::{
-            HitsMetadata, SearchHit, SearchResponse, ShardStats, TotalHits,
+            Aggregations, HitsMetadata, SearchHit, SearchResponse, ShardStats, TotalHits,
         },
         page::Page,
         sort::{Sort, SortOrder},
     };
+    use item_core::facet::{RangeFacet, TermFacet};
+    use item_core::shop_name::ShopName;
     use item_core::sort_item_field::SortItemField;
     use item_opensearch::{item_document::ItemDocument, repository::MockItemOpenSearchRepository};
     use search_filter_core::{
@@ -210,9 +386,12 @@ mod tests {
                         id: item_document.item_id.to_string(),
                         score: None,
                         source: item_document,
+                        sort: vec![],
                     })
                     .collect(),
             },
+            aggregations: Aggregations::default(),
+            pit_id: None,
         }
     }
 
@@ -304,13 +483,13 @@ mod tests {
         let mut repository = MockItemOpenSearchRepository::default();
         repository
             .expect_search_item_documents()
-            .return_once(move |_, _, _, _, _| {
+            .return_once(move |_, _, _, _, _, _| {
                 Box::pin(async move { Ok(mk_search_response(fake::vec![ItemDocument; count])) })
             });
         let service = QueryItemServiceImpl::new(&repository);
 
         let actual = service
-            .search_items(&search_filter, &language, &currency, &sort, &page)
+            .search_items(&search_filter, &language, &currency, &sort, &page, &None)
             .await
             .unwrap();
 
@@ -323,7 +502,7 @@ mod tests {
         let mut repository = MockItemOpenSearchRepository::default();
         repository
             .expect_search_item_documents()
-            .return_once(|_, _, _, _, _| {
+            .return_once(|_, _, _, _, _, _| {
                 Box::pin(async {
                     Err(opensearch::Error::from(serde_json::Error::custom(
                         "Something went wrong.",
@@ -346,6 +525,7 @@ mod tests {
                 &Currency::Eur,
                 &None,
                 &None,
+                &None,
             )
             .await;
 
@@ -360,11 +540,18 @@ mod tests {
     #[case::aud(Currency::Aud, 1000)]
     #[case::cad(Currency::Cad, 4000)]
     #[case::nzd(Currency::Nzd, 42)]
+    #[case::chf(Currency::Chf, 43)]
+    #[case::sek(Currency::Sek, 44)]
+    #[case::dkk(Currency::Dkk, 45)]
+    #[case::nok(Currency::Nok, 46)]
+    #[case::pln(Currency::Pln, 47)]
+    #[case::czk(Currency::Czk, 48)]
+    #[case::jpy(Currency::Jpy, 49)]
     async fn should_respect_currency(#[case] currency: Currency, #[case] expected_amount: u64) {
         let mut repository = MockItemOpenSearchRepository::default();
         repository
             .expect_search_item_documents()
-            .return_once(move |_, _, _, _, _| {
+            .return_once(move |_, _, _, _, _, _| {
                 let items = fake::vec![ItemDocument; 369]
                     .into_iter()
                     .map(|mut item| {
@@ -374,6 +561,13 @@ mod tests {
                         item.price_aud = Some(1000);
                         item.price_cad = Some(4000);
                         item.price_nzd = Some(42);
+                        item.price_chf = Some(43);
+                        item.price_sek = Some(44);
+                        item.price_dkk = Some(45);
+                        item.price_nok = Some(46);
+                        item.price_pln = Some(47);
+                        item.price_czk = Some(48);
+                        item.price_jpy = Some(49);
                         item
                     })
                     .collect();
@@ -395,6 +589,7 @@ mod tests {
                 &currency,
                 &None,
                 &None,
+                &None,
             )
             .await
             .unwrap();
@@ -413,18 +608,24 @@ mod tests {
     #[rstest::rstest]
     #[case(Language::De, "German")]
     #[case(Language::En, "English")]
+    #[case(Language::Fr, "French")]
+    #[case(Language::Es, "Spanish")]
     async fn should_respect_language(#[case] language: Language, #[case] expected: &str) {
         let mut repository = MockItemOpenSearchRepository::default();
         repository
             .expect_search_item_documents()
-            .return_once(move |_, _, _, _, _| {
+            .return_once(move |_, _, _, _, _, _| {
                 let items = fake::vec![ItemDocument; 369]
                     .into_iter()
                     .map(|mut item| {
                         item.title_de = Some("German".to_string());
                         item.title_en = Some("English".to_string());
+                        item.title_fr = Some("French".to_string());
+                        item.title_es = Some("Spanish".to_string());
                         item.description_de = Some("German".to_string());
                         item.description_en = Some("English".to_string());
+                        item.description_fr = Some("French".to_string());
+                        item.description_es = Some("Spanish".to_string());
                         item
                     })
                     .collect();
@@ -446,6 +647,7 @@ mod tests {
                 &Currency::Aud,
                 &None,
                 &None,
+                &None,
             )
             .await
             .unwrap();
@@ -460,4 +662,162 @@ mod tests {
                     && item.description.clone().unwrap().payload.as_ref() == expected)
         );
     }
+
+    #[tokio::test]
+    async fn should_map_aggregations_to_facets() {
+        let mut repository = MockItemOpenSearchRepository::default();
+        repository
+            .expect_search_item_documents()
+            .return_once(move |_, _, _, _, _, _| {
+                let mut search_response = mk_search_response(fake::vec![ItemDocument; 3]);
+                search_response.aggregations = serde_json::from_value(serde_json::json!({
+                    "state": {
+                        "buckets": [
+                            { "key": "SOLD", "doc_count": 123 },
+                            { "key": "AVAILABLE", "doc_count": 456 },
+                            { "key": "BOOP", "doc_count": 1 }
+                        ]
+                    },
+                    "shopName": {
+                        "buckets": [
+                            { "key": "Militaria Mart", "doc_count": 42 }
+                        ]
+                    },
+                    "price": {
+                        "buckets": [
+                            { "key": 0.0, "doc_count": 7 },
+                            { "key": 20000.0, "doc_count": 3 }
+                        ]
+                    },
+                    "created": {
+                        "buckets": [
+                            { "key_as_string": "2024-12-01T00:00:00.000Z", "key": 1733011200000i64, "doc_count": 9 }
+                        ]
+                    }
+                }))
+                .unwrap();
+                Box::pin(async move { Ok(search_response) })
+            });
+        let service = QueryItemServiceImpl::new(&repository);
+
+        let actual = service
+            .search_items(
+                &SearchFilter {
+                    item_query: "Hallo Welten!".try_into().unwrap(),
+                    shop_name_query: None,
+                    price_query: None,
+                    state_query: Default::default(),
+                    created_query: None,
+                    updated_query: None,
+                },
+                &Language::De,
+                &Currency::Eur,
+                &None,
+                &None,
+                &None,
+            )
+            .await
+            .unwrap()
+            .facets;
+
+        assert_eq!(
+            vec![
+                TermFacet {
+                    value: ItemState::Sold,
+                    count: 123
+                },
+                TermFacet {
+                    value: ItemState::Available,
+                    count: 456
+                },
+            ],
+            actual.state
+        );
+        assert_eq!(
+            vec![TermFacet {
+                value: ShopName::from("Militaria Mart"),
+                count: 42
+            }],
+            actual.shop_name
+        );
+        assert_eq!(
+            vec![
+                RangeFacet {
+                    min: 0u64.into(),
+                    max: 10000u64.into(),
+                    count: 7
+                },
+                RangeFacet {
+                    min: 20000u64.into(),
+                    max: 30000u64.into(),
+                    count: 3
+                },
+            ],
+            actual.price
+        );
+        assert_eq!(
+            vec![RangeFacet {
+                min: datetime!(2024-12-01 0:00 UTC),
+                max: datetime!(2025-01-01 0:00 UTC),
+                count: 9
+            }],
+            actual.created
+        );
+    }
+
+    #[tokio::test]
+    #[rstest::rstest]
+    #[case::full_page(20, Some(Page { from: 0, size: 20 }), true)]
+    #[case::last_page(19, Some(Page { from: 0, size: 20 }), false)]
+    #[case::default_size(10, None, true)]
+    #[case::empty(0, Some(Page { from: 0, size: 20 }), false)]
+    async fn should_return_search_after_of_last_hit_when_more_hits_may_exist(
+        #[case] count: usize,
+        #[case] page: Option<Page>,
+        #[case] expect_next: bool,
+    ) {
+        let mut repository = MockItemOpenSearchRepository::default();
+        repository
+            .expect_search_item_documents()
+            .return_once(move |_, _, _, _, _, _| {
+                let mut search_response = mk_search_response(fake::vec![ItemDocument; count]);
+                for (i, hit) in search_response.hits.hits.iter_mut().enumerate() {
+                    hit.sort = vec![serde_json::json!(i), serde_json::json!(hit.id)];
+                }
+                search_response.pit_id = Some("rotated-pit".to_string());
+                Box::pin(async move { Ok(search_response) })
+            });
+        let service = QueryItemServiceImpl::new(&repository);
+
+        let actual = service
+            .search_items(
+                &SearchFilter {
+                    item_query: "Hallo Welten!".try_into().unwrap(),
+                    shop_name_query: None,
+                    price_query: None,
+                    state_query: Default::default(),
+                    created_query: None,
+                    updated_query: None,
+                },
+                &Language::De,
+                &Currency::Eur,
+                &None,
+                &page,
+                &Some(SearchAfter {
+                    sort_values: vec![],
+                    pit_id: Some("initial-pit".to_string()),
+                }),
+            )
+            .await
+            .unwrap();
+
+        match actual.search_after {
+            Some(search_after) => {
+                assert!(expect_next);
+                assert_eq!(serde_json::json!(count - 1), search_after.sort_values[0]);
+                assert_eq!(Some("rotated-pit".to_string()), search_after.pit_id);
+            }
+            None => assert!(!expect_next),
+        }
+    }
 }
@@ -1,2 +1,3 @@
 pub use scrape_core;
+pub use scrape_runner;
 pub use scrape_static;
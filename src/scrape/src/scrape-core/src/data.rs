@@ -23,7 +23,8 @@ pub enum ScrapeItemChangeCommandData {
     Update(UpdateItemCommandData),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ScrapeItem {
     pub shop_id: ShopId,
     pub shops_item_id: ShopsItemId,
@@ -104,10 +105,7 @@ impl ScrapeItem {
         match shop_universe.get(&self.shops_item_id) {
             None => Some(Create(self.into())),
             Some(previous_hash) => {
-                let new_price = self.price.map(Price::from);
-                let new_state = self.state.into();
-                let new_hash = ItemHash::new(&new_price, &new_state);
-                if previous_hash == &new_hash {
+                if previous_hash == &self.hash() {
                     None
                 } else {
                     Some(Update(self.into()))
@@ -116,6 +114,11 @@ impl ScrapeItem {
         }
     }
 
+    /// Hash of the price and state, changes of which are published as updates.
+    pub fn hash(&self) -> ItemHash {
+        ItemHash::new(&self.price.map(Price::from), &self.state.into())
+    }
+
     pub fn item_key(&self) -> ItemKey {
         ItemKey {
             shop_id: self.shop_id.clone(),
@@ -0,0 +1,319 @@
+use crate::data::ScrapeItem;
+use crate::spec::{ScrapeError, ScrapeEvent, Scraper, ScraperConfig};
+use async_stream::stream;
+use async_trait::async_trait;
+use futures::StreamExt;
+use futures::stream::{self, BoxStream};
+use std::collections::{HashSet, VecDeque};
+use tracing::{info, warn};
+use url::Url;
+
+/// Upper bound of listings visited by a single [`scrape_discovered`], guarding against
+/// crawling endlessly, e.g. through ever-changing pagination links.
+pub const MAX_LISTINGS: usize = 10_000;
+
+/// URLs found on a listing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiscoveredUrls {
+    pub item_urls: Vec<Url>,
+
+    /// Further listings, e.g. the sitemaps of a sitemap index or the next page of a category.
+    pub listing_urls: Vec<Url>,
+}
+
+/// Discovery of a shop's items by crawling listings instead of walking numbered catalog pages,
+/// which misses shops with category-based pagination or listing orders shifting during a crawl.
+///
+/// Implementors override [`Scraper::scrape`] with [`scrape_discovered`].
+#[async_trait]
+pub trait ItemDiscovery<Client: Send + Sync>: Scraper<Client> {
+    /// Listings the crawl starts from, e.g. `sitemap.xml` or category pages.
+    fn seed_urls(&self) -> Vec<Url>;
+
+    async fn discover(
+        &self,
+        client: &Client,
+        scraper_config: ScraperConfig,
+        listing_url: &Url,
+    ) -> Result<DiscoveredUrls, ScrapeError>;
+
+    /// Scrapes the item's own page, none if it turns out not to be an item.
+    async fn scrape_item_page(
+        &self,
+        client: &Client,
+        scraper_config: ScraperConfig,
+        item_url: &Url,
+    ) -> Result<Option<ScrapeItem>, ScrapeError>;
+}
+
+/// Crawls all listings breadth-first starting at the [`ItemDiscovery::seed_urls`] and then
+/// scrapes each distinct item URL.
+///
+/// Failing listings and item pages are yielded as errors without ending the stream, so that the
+/// remaining items are still scraped while the scrape counts as incomplete.
+/// The same holds for reaching [`MAX_LISTINGS`], as the listings left may hold further items.
+/// Item pages are scraped concurrently according to [`ScraperConfig::detail_concurrency`].
+pub fn scrape_discovered<'a, Client: Send + Sync, S: ItemDiscovery<Client>>(
+    scraper: &'a S,
+    client: &'a Client,
+    scraper_config: ScraperConfig,
+) -> BoxStream<'a, Result<ScrapeEvent, ScrapeError>> {
+    info!(
+        shopId = scraper.shop_id_str(),
+        shopName = scraper.shop_name_str(),
+        config = ?scraper_config,
+        "Starting to discover and scrape."
+    );
+    Box::pin(stream! {
+        let mut listing_queue = VecDeque::from(scraper.seed_urls());
+        let mut visited_listings = HashSet::new();
+        let mut item_urls = Vec::new();
+        let mut seen_item_urls = HashSet::new();
+
+        while let Some(listing_url) = listing_queue.pop_front() {
+            if visited_listings.len() >= MAX_LISTINGS {
+                warn!(shopId = scraper.shop_id_str(), "Reached maximum number of listings.");
+                yield Err(ScrapeError::MaxListingsReached { max_listings: MAX_LISTINGS });
+                break;
+            }
+            if !visited_listings.insert(without_fragment(listing_url.clone())) {
+                continue;
+            }
+            let discover_res = scraper_config
+                .retry_policy
+                .retry(|| scraper.discover(client, scraper_config, &listing_url))
+                .await;
+            match discover_res {
+                Ok(discovered) => {
+                    for item_url in discovered.item_urls.into_iter().map(without_fragment) {
+                        if seen_item_urls.insert(item_url.clone()) {
+                            item_urls.push(item_url);
+                        }
+                    }
+                    listing_queue.extend(discovered.listing_urls);
+                }
+                Err(err) => yield Err(err),
+            }
+            if let Some(duration) = scraper_config.page_delay {
+                tokio::time::sleep(duration).await;
+            }
+        }
+        info!(
+            shopId = scraper.shop_id_str(),
+            listings = visited_listings.len(),
+            total = item_urls.len(),
+            "Discovered items."
+        );
+
+        let mut item_pages = stream::iter(item_urls)
+            .map(|item_url| async move {
+                scraper_config
+                    .retry_policy
+                    .retry(|| scraper.scrape_item_page(client, scraper_config, &item_url))
+                    .await
+            })
+            .buffered(scraper_config.detail_concurrency.unwrap_or(1).max(1));
+        while let Some(item_page_res) = item_pages.next().await {
+            match item_page_res {
+                Ok(Some(scrape_item)) => yield Ok(ScrapeEvent::Item(scrape_item)),
+                Ok(None) => {}
+                Err(err) => yield Err(err),
+            }
+        }
+    })
+}
+
+fn without_fragment(mut url: Url) -> Url {
+    url.set_fragment(None);
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::ScrapeItem;
+    use crate::discovery::{DiscoveredUrls, ItemDiscovery, MAX_LISTINGS, scrape_discovered};
+    use crate::spec::{ScrapeError, ScrapeEvent, Scraper, ScraperConfig};
+    use async_trait::async_trait;
+    use common::language::data::{LanguageData, LocalizedTextData};
+    use futures::StreamExt;
+    use item_data::item_state_data::ItemStateData;
+    use std::sync::Mutex;
+    use url::Url;
+
+    struct DummyClient;
+
+    /// Sitemap index linking two sitemaps and a broken one, listing overlapping items.
+    #[derive(Default)]
+    struct DummyDiscovery {
+        scraped_item_urls: Mutex<Vec<String>>,
+    }
+
+    fn url(path: &str) -> Url {
+        Url::parse("https://shop.example.com")
+            .unwrap()
+            .join(path)
+            .unwrap()
+    }
+
+    #[async_trait]
+    impl Scraper<DummyClient> for DummyDiscovery {
+        fn shop_id_str(&self) -> &str {
+            "dummy-id"
+        }
+
+        fn shop_name_str(&self) -> &str {
+            "dummy-name"
+        }
+    }
+
+    #[async_trait]
+    impl ItemDiscovery<DummyClient> for DummyDiscovery {
+        fn seed_urls(&self) -> Vec<Url> {
+            vec![url("/sitemap.xml")]
+        }
+
+        async fn discover(
+            &self,
+            _: &DummyClient,
+            _: ScraperConfig,
+            listing_url: &Url,
+        ) -> Result<DiscoveredUrls, ScrapeError> {
+            match listing_url.path() {
+                "/sitemap.xml" => Ok(DiscoveredUrls {
+                    item_urls: vec![],
+                    listing_urls: vec![
+                        url("/sitemap-1.xml"),
+                        url("/sitemap-2.xml"),
+                        url("/sitemap-broken.xml"),
+                        url("/sitemap.xml"),
+                    ],
+                }),
+                "/sitemap-1.xml" => Ok(DiscoveredUrls {
+                    item_urls: vec![url("/item/1"), url("/item/2"), url("/item/2#reviews")],
+                    listing_urls: vec![],
+                }),
+                "/sitemap-2.xml" => Ok(DiscoveredUrls {
+                    item_urls: vec![url("/item/2"), url("/item/3"), url("/not-an-item")],
+                    listing_urls: vec![url("/sitemap-1.xml")],
+                }),
+                _ => Err(ScrapeError::ClientError("boom".into())),
+            }
+        }
+
+        async fn scrape_item_page(
+            &self,
+            _: &DummyClient,
+            _: ScraperConfig,
+            item_url: &Url,
+        ) -> Result<Option<ScrapeItem>, ScrapeError> {
+            self.scraped_item_urls
+                .lock()
+                .unwrap()
+                .push(item_url.path().to_string());
+            let Some(shops_item_id) = item_url.path().strip_prefix("/item/") else {
+                return Ok(None);
+            };
+            Ok(Some(ScrapeItem {
+                shop_id: self.shop_id_str().into(),
+                shops_item_id: shops_item_id.into(),
+                shop_name: self.shop_name_str().to_string(),
+                native_title: LocalizedTextData::new("boop", LanguageData::De),
+                other_title: Default::default(),
+                native_description: None,
+                other_description: Default::default(),
+                price: None,
+                state: ItemStateData::Available,
+                url: item_url.clone(),
+                images: vec![],
+            }))
+        }
+    }
+
+    /// Paginates endlessly, one item per page.
+    struct EndlessDiscovery;
+
+    #[async_trait]
+    impl Scraper<DummyClient> for EndlessDiscovery {
+        fn shop_id_str(&self) -> &str {
+            "dummy-id"
+        }
+
+        fn shop_name_str(&self) -> &str {
+            "dummy-name"
+        }
+    }
+
+    #[async_trait]
+    impl ItemDiscovery<DummyClient> for EndlessDiscovery {
+        fn seed_urls(&self) -> Vec<Url> {
+            vec![url("/page/1")]
+        }
+
+        async fn discover(
+            &self,
+            _: &DummyClient,
+            _: ScraperConfig,
+            listing_url: &Url,
+        ) -> Result<DiscoveredUrls, ScrapeError> {
+            let page = listing_url
+                .path()
+                .trim_start_matches("/page/")
+                .parse::<usize>()
+                .unwrap();
+            Ok(DiscoveredUrls {
+                item_urls: vec![url(&format!("/item/{page}"))],
+                listing_urls: vec![url(&format!("/page/{}", page + 1))],
+            })
+        }
+
+        async fn scrape_item_page(
+            &self,
+            _: &DummyClient,
+            _: ScraperConfig,
+            _: &Url,
+        ) -> Result<Option<ScrapeItem>, ScrapeError> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn should_scrape_each_discovered_item_once() {
+        let scraper = DummyDiscovery::default();
+        let scraper_config = ScraperConfig {
+            detail_concurrency: Some(2),
+            ..Default::default()
+        };
+
+        let actual = scrape_discovered(&scraper, &DummyClient, scraper_config)
+            .collect::<Vec<_>>()
+            .await;
+
+        let shops_item_ids = actual
+            .iter()
+            .filter_map(|res| match res {
+                Ok(ScrapeEvent::Item(item)) => Some(item.shops_item_id.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["1", "2", "3"], shops_item_ids);
+        assert_eq!(1, actual.iter().filter(|res| res.is_err()).count());
+        assert_eq!(
+            vec!["/item/1", "/item/2", "/item/3", "/not-an-item"],
+            *scraper.scraped_item_urls.lock().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_yield_error_when_reaching_max_listings() {
+        let actual = scrape_discovered(&EndlessDiscovery, &DummyClient, ScraperConfig::default())
+            .collect::<Vec<_>>()
+            .await;
+
+        assert!(matches!(
+            actual.as_slice(),
+            [Err(ScrapeError::MaxListingsReached {
+                max_listings: MAX_LISTINGS
+            })]
+        ));
+    }
+}
@@ -1,3 +1,10 @@
 pub mod data;
+pub mod discovery;
+pub mod page_cache;
+pub mod rate_limit;
+pub mod registry;
+pub mod retry;
+pub mod robots;
 pub mod service;
 pub mod spec;
+pub mod validation;
@@ -0,0 +1,288 @@
+use async_trait::async_trait;
+use aws_sdk_dynamodb::error::SdkError;
+use aws_sdk_dynamodb::operation::batch_write_item::BatchWriteItemError;
+use aws_sdk_dynamodb::operation::query::QueryError;
+use aws_sdk_dynamodb::types::AttributeValue;
+use common::batch::Batch;
+use common::shop_id::ShopId;
+use common::shops_item_id::ShopsItemId;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::error;
+
+/// Pages not scraped for this long are forgotten, see the table's TTL on `expires_at`.
+pub const PAGE_RECORD_TTL: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// Validators of a catalog page as of its last scrape.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct PageValidators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+
+    /// Hash of the body, detecting unchanged pages of hosts not supporting conditional requests.
+    pub content_hash: String,
+
+    /// Items scraped from the page, which count as seen as long as the page is unchanged.
+    pub shops_item_ids: Vec<ShopsItemId>,
+}
+
+impl PageValidators {
+    pub fn content_hash(body: &str) -> String {
+        blake3::hash(body.as_bytes()).to_hex().to_string()
+    }
+}
+
+/// Validators of a single shop's catalog pages by URL for a single scrape.
+#[derive(Debug, Default)]
+pub struct PageCache {
+    previous: HashMap<String, PageValidators>,
+    scraped: Mutex<HashMap<String, PageValidators>>,
+}
+
+impl PageCache {
+    pub fn new(previous: HashMap<String, PageValidators>) -> Self {
+        PageCache {
+            previous,
+            scraped: Mutex::default(),
+        }
+    }
+
+    /// Validators of the page as of the previous scrape.
+    pub fn previous(&self, url: &str) -> Option<&PageValidators> {
+        self.previous.get(url)
+    }
+
+    /// Remembers the page's validators as of this scrape.
+    ///
+    /// Must only be called after the page's items were successfully scraped.
+    pub fn insert(&self, url: String, validators: PageValidators) {
+        self.scraped
+            .lock()
+            .expect("shouldn't fail because the lock is never held while panicking")
+            .insert(url, validators);
+    }
+
+    /// Takes the pages whose validators changed during this scrape.
+    pub fn take_scraped(&self) -> HashMap<String, PageValidators> {
+        std::mem::take(
+            &mut *self
+                .scraped
+                .lock()
+                .expect("shouldn't fail because the lock is never held while panicking"),
+        )
+    }
+}
+
+/// Client sending conditional requests for catalog pages, e.g. `If-None-Match`,
+/// so that unchanged pages are neither downloaded nor parsed again.
+///
+/// Unchanged pages are scraped as [`crate::spec::ScrapePage::Unchanged`].
+pub trait ConditionalRequests: Sized {
+    fn with_page_cache(&self, page_cache: Arc<PageCache>) -> Self;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PageCacheError {
+    #[error("Encountered DynamoDB SdkError for Query: {0}")]
+    SdkQueryError(#[from] Box<SdkError<QueryError>>),
+
+    #[error("Encountered DynamoDB SdkError for BatchWriteItem: {0}")]
+    SdkBatchWriteItemError(#[from] Box<SdkError<BatchWriteItemError>>),
+
+    #[error("Failed saving {0} pages.")]
+    Unprocessed(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct PageRecord {
+    pk: String,
+    sk: String,
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    content_hash: String,
+    shops_item_ids: Vec<ShopsItemId>,
+
+    /// Epoch seconds.
+    expires_at: u64,
+}
+
+fn page_pk(shop_id: &ShopId) -> String {
+    format!("scrape#shop_id#{shop_id}")
+}
+
+#[async_trait]
+pub trait PageCacheRepository {
+    /// Validators of the shop's pages by URL.
+    async fn load_pages(
+        &self,
+        shop_id: &ShopId,
+    ) -> Result<HashMap<String, PageValidators>, PageCacheError>;
+
+    async fn save_pages(
+        &self,
+        shop_id: &ShopId,
+        pages: HashMap<String, PageValidators>,
+    ) -> Result<(), PageCacheError>;
+}
+
+/// Stores pages alongside the items without any GSI-attributes, so that they're neither part
+/// of the shop's universe nor streamed.
+#[derive(Debug, Clone)]
+pub struct PageCacheRepositoryImpl<'a> {
+    client: &'a aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl<'a> PageCacheRepositoryImpl<'a> {
+    pub fn new(client: &'a aws_sdk_dynamodb::Client, table: impl Into<String>) -> Self {
+        Self {
+            client,
+            table: table.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl<'a> PageCacheRepository for PageCacheRepositoryImpl<'a> {
+    async fn load_pages(
+        &self,
+        shop_id: &ShopId,
+    ) -> Result<HashMap<String, PageValidators>, PageCacheError> {
+        let pages = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .key_condition_expression("#pk = :pk_val AND begins_with(#sk, :sk_prefix)")
+            .expression_attribute_names("#pk", "pk")
+            .expression_attribute_names("#sk", "sk")
+            .expression_attribute_values(":pk_val", AttributeValue::S(page_pk(shop_id)))
+            .expression_attribute_values(":sk_prefix", AttributeValue::S("page#".to_string()))
+            .into_paginator()
+            .send()
+            .try_collect()
+            .await
+            .map_err(Box::new)?
+            .into_iter()
+            .flat_map(|qo| qo.items.unwrap_or_default())
+            .map(serde_dynamo::from_item::<_, PageRecord>)
+            .filter_map(|result| match result {
+                Ok(record) => Some((
+                    record.url,
+                    PageValidators {
+                        etag: record.etag,
+                        last_modified: record.last_modified,
+                        content_hash: record.content_hash,
+                        shops_item_ids: record.shops_item_ids,
+                    },
+                )),
+                Err(err) => {
+                    error!(error = %err, type = %std::any::type_name::<PageRecord>(), "Failed deserializing PageRecord.");
+                    None
+                }
+            })
+            .collect();
+
+        Ok(pages)
+    }
+
+    async fn save_pages(
+        &self,
+        shop_id: &ShopId,
+        pages: HashMap<String, PageValidators>,
+    ) -> Result<(), PageCacheError> {
+        let expires_at = (SystemTime::now() + PAGE_RECORD_TTL)
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let records = pages.into_iter().map(|(url, validators)| PageRecord {
+            pk: page_pk(shop_id),
+            sk: format!("page#{url}"),
+            url,
+            etag: validators.etag,
+            last_modified: validators.last_modified,
+            content_hash: validators.content_hash,
+            shops_item_ids: validators.shops_item_ids,
+            expires_at,
+        });
+
+        let mut unprocessed_count = 0;
+        for batch in Batch::<_, 25>::chunked_from(records) {
+            let output = self
+                .client
+                .batch_write_item()
+                .request_items(&self.table, batch.into_dynamodb_write_requests())
+                .send()
+                .await
+                .map_err(Box::new)?;
+            unprocessed_count += output
+                .unprocessed_items
+                .unwrap_or_default()
+                .values()
+                .map(Vec::len)
+                .sum::<usize>();
+        }
+
+        if unprocessed_count == 0 {
+            Ok(())
+        } else {
+            Err(PageCacheError::Unprocessed(unprocessed_count))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::page_cache::{PageCache, PageValidators};
+    use std::collections::HashMap;
+
+    #[test]
+    fn should_only_return_pages_scraped_during_this_scrape() {
+        let previous = PageValidators {
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None,
+            content_hash: PageValidators::content_hash("<html>1</html>"),
+            shops_item_ids: vec!["1".into()],
+        };
+        let page_cache = PageCache::new(HashMap::from([
+            (
+                "https://shop.example.com?pg=1".to_string(),
+                previous.clone(),
+            ),
+            (
+                "https://shop.example.com?pg=2".to_string(),
+                previous.clone(),
+            ),
+        ]));
+        let scraped = PageValidators {
+            etag: Some("\"v2\"".to_string()),
+            ..previous.clone()
+        };
+
+        page_cache.insert("https://shop.example.com?pg=2".to_string(), scraped.clone());
+
+        assert_eq!(
+            Some(&previous),
+            page_cache.previous("https://shop.example.com?pg=2")
+        );
+        assert_eq!(
+            HashMap::from([("https://shop.example.com?pg=2".to_string(), scraped)]),
+            page_cache.take_scraped()
+        );
+        assert!(page_cache.take_scraped().is_empty());
+    }
+
+    #[test]
+    fn should_hash_content_deterministically() {
+        assert_eq!(
+            PageValidators::content_hash("<html>1</html>"),
+            PageValidators::content_hash("<html>1</html>")
+        );
+        assert_ne!(
+            PageValidators::content_hash("<html>1</html>"),
+            PageValidators::content_hash("<html>2</html>")
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// Token-bucket rate limit of requests to a host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Number of requests that may be sent at once after being idle.
+    burst: u32,
+
+    /// Interval in which a single token is refilled.
+    refill_interval: Duration,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum RateLimitError {
+    #[error("Refill interval of a rate limit must not be zero.")]
+    ZeroRefillInterval,
+}
+
+impl RateLimit {
+    /// Fails if `refill_interval` is zero, which would never limit requests.
+    pub fn new(burst: u32, refill_interval: Duration) -> Result<Self, RateLimitError> {
+        if refill_interval.is_zero() {
+            return Err(RateLimitError::ZeroRefillInterval);
+        }
+        Ok(RateLimit {
+            burst,
+            refill_interval,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct TokenBucket {
+    rate_limit: RateLimit,
+    state: Mutex<TokenBucketState>,
+}
+
+#[derive(Debug)]
+struct TokenBucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    pub fn new(rate_limit: RateLimit) -> Self {
+        TokenBucket {
+            rate_limit,
+            state: Mutex::new(TokenBucketState {
+                tokens: rate_limit.burst.max(1) as f64,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a token is available and takes it.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().await;
+                let now = Instant::now();
+                let refilled = now.duration_since(state.last_refill).as_secs_f64()
+                    / self.rate_limit.refill_interval.as_secs_f64();
+                state.tokens = (state.tokens + refilled).min(self.rate_limit.burst.max(1) as f64);
+                state.last_refill = now;
+                if state.tokens >= 1.0 {
+                    state.tokens -= 1.0;
+                    return;
+                }
+                self.rate_limit.refill_interval.mul_f64(1.0 - state.tokens)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// [`TokenBucket`]s of hosts by their name, e.g. `shop.example.com`, so that all requests of
+/// a client to a host share its rate limit, regardless of the scrape sending them.
+#[derive(Debug)]
+pub struct HostTokenBuckets {
+    rate_limit: RateLimit,
+    buckets: Mutex<HashMap<String, Arc<TokenBucket>>>,
+}
+
+impl HostTokenBuckets {
+    pub fn new(rate_limit: RateLimit) -> Self {
+        HostTokenBuckets {
+            rate_limit,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Waits until a token of the host's bucket is available and takes it.
+    pub async fn acquire(&self, host: &str) {
+        let bucket = self
+            .buckets
+            .lock()
+            .await
+            .entry(host.to_string())
+            .or_insert_with(|| Arc::new(TokenBucket::new(self.rate_limit)))
+            .clone();
+        bucket.acquire().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rate_limit::{HostTokenBuckets, RateLimit, RateLimitError, TokenBucket};
+    use std::time::Duration;
+    use tokio::time::Instant;
+
+    #[test]
+    fn should_reject_zero_refill_interval() {
+        assert_eq!(
+            Err(RateLimitError::ZeroRefillInterval),
+            RateLimit::new(4, Duration::ZERO)
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_allow_burst_without_waiting() {
+        let token_bucket = TokenBucket::new(RateLimit::new(5, Duration::from_secs(1)).unwrap());
+        let start = Instant::now();
+
+        for _ in 0..5 {
+            token_bucket.acquire().await;
+        }
+
+        assert_eq!(Duration::ZERO, start.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_wait_for_refill_when_burst_exhausted() {
+        let token_bucket = TokenBucket::new(RateLimit::new(2, Duration::from_secs(1)).unwrap());
+        let start = Instant::now();
+
+        for _ in 0..5 {
+            token_bucket.acquire().await;
+        }
+
+        assert!(start.elapsed() >= Duration::from_secs(3));
+        assert!(start.elapsed() < Duration::from_secs(4));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_share_token_bucket_of_host() {
+        let host_token_buckets =
+            HostTokenBuckets::new(RateLimit::new(2, Duration::from_secs(1)).unwrap());
+        let start = Instant::now();
+
+        for _ in 0..2 {
+            host_token_buckets.acquire("shop.example.com").await;
+        }
+        host_token_buckets.acquire("other-shop.example.com").await;
+        assert_eq!(Duration::ZERO, start.elapsed());
+
+        host_token_buckets.acquire("shop.example.com").await;
+        assert_eq!(Duration::from_secs(1), start.elapsed());
+    }
+}
@@ -0,0 +1,97 @@
+use crate::spec::Scraper;
+use common::shop_id::ShopId;
+use std::collections::HashMap;
+use tracing::warn;
+
+/// Scrapers by the [`ShopId`] of the shop they scrape.
+pub struct ScraperRegistry<Client: Send + Sync> {
+    scrapers: HashMap<ShopId, Box<dyn Scraper<Client>>>,
+}
+
+impl<Client: Send + Sync> Default for ScraperRegistry<Client> {
+    fn default() -> Self {
+        ScraperRegistry {
+            scrapers: HashMap::new(),
+        }
+    }
+}
+
+impl<Client: Send + Sync> ScraperRegistry<Client> {
+    /// Registers the scraper, replacing any previously registered one for the same shop.
+    pub fn register(&mut self, scraper: impl Scraper<Client> + 'static) {
+        let shop_id = ShopId::from(scraper.shop_id_str());
+        if let Some(replaced) = self.scrapers.insert(shop_id, Box::new(scraper)) {
+            warn!(
+                shopId = replaced.shop_id_str(),
+                "Replaced previously registered Scraper."
+            );
+        }
+    }
+
+    pub fn get(&self, shop_id: &ShopId) -> Option<&dyn Scraper<Client>> {
+        self.scrapers.get(shop_id).map(Box::as_ref)
+    }
+
+    pub fn shop_ids(&self) -> impl Iterator<Item = &ShopId> {
+        self.scrapers.keys()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::ScraperRegistry;
+    use crate::spec::{ScrapeError, ScrapePage, Scraper, ScraperConfig};
+    use async_trait::async_trait;
+    use common::shop_id::ShopId;
+
+    struct DummyClient;
+    struct DummyScraper(&'static str, &'static str);
+
+    #[async_trait]
+    impl Scraper<DummyClient> for DummyScraper {
+        fn shop_id_str(&self) -> &'static str {
+            self.0
+        }
+
+        fn shop_name_str(&self) -> &'static str {
+            self.1
+        }
+
+        async fn scrape_page(
+            &self,
+            _: &DummyClient,
+            _: ScraperConfig,
+            _: u32,
+        ) -> Result<ScrapePage, ScrapeError> {
+            Ok(ScrapePage::Items(vec![]))
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::first("foo", Some("Foo"))]
+    #[case::second("bar", Some("Bar"))]
+    #[case::unknown("baz", None)]
+    fn should_get_scraper_by_shop_id(#[case] shop_id: &str, #[case] expected: Option<&str>) {
+        let mut registry = ScraperRegistry::default();
+        registry.register(DummyScraper("foo", "Foo"));
+        registry.register(DummyScraper("bar", "Bar"));
+
+        let actual = registry
+            .get(&ShopId::from(shop_id))
+            .map(|scraper| scraper.shop_name_str());
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn should_replace_scraper_when_registered_for_same_shop_id() {
+        let mut registry = ScraperRegistry::default();
+        registry.register(DummyScraper("foo", "Foo"));
+        registry.register(DummyScraper("foo", "Other Foo"));
+
+        let actual = registry.get(&ShopId::from("foo")).unwrap();
+
+        assert_eq!("Other Foo", actual.shop_name_str());
+        assert_eq!(1, registry.shop_ids().count());
+    }
+}
@@ -0,0 +1,213 @@
+use crate::spec::ScrapeError;
+use std::time::Duration;
+use tracing::warn;
+
+/// Retries of transient [`ScrapeError`]s with exponential backoff and jitter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts including the first one.
+    pub max_attempts: u32,
+
+    /// Backoff before the second attempt, doubled for each further attempt.
+    pub initial_backoff: Duration,
+
+    /// Upper bound for the backoff.
+    /// Giving up instead if the server asks to retry after a longer duration.
+    pub max_backoff: Duration,
+
+    pub retryable_status_codes: &'static [u16],
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            retryable_status_codes: &[429, 500, 502, 503, 504],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retries.
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_retryable(&self, err: &ScrapeError) -> bool {
+        match err {
+            ScrapeError::ClientError(_) => false,
+            ScrapeError::TransientClientError(_) => true,
+            ScrapeError::Disallowed { .. } => false,
+            ScrapeError::MaxListingsReached { .. } => false,
+            ScrapeError::HttpStatusError { status, .. } => {
+                self.retryable_status_codes.contains(status)
+            }
+        }
+    }
+
+    /// Backoff after the given failed attempt, starting at 1, or none if not to be retried.
+    ///
+    /// A server's `Retry-After` takes precedence over the exponential backoff.
+    pub fn backoff(&self, attempt: u32, err: &ScrapeError) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.is_retryable(err) {
+            return None;
+        }
+        if let ScrapeError::HttpStatusError {
+            retry_after: Some(retry_after),
+            ..
+        } = err
+        {
+            return (*retry_after <= self.max_backoff).then_some(*retry_after);
+        }
+
+        let exponential = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt - 1))
+            .min(self.max_backoff);
+        // Equal jitter, i.e. somewhere between half and the full exponential backoff
+        let half = exponential / 2;
+        let jitter = Duration::from_nanos(fastrand::u64(0..=half.as_nanos() as u64));
+        Some(half + jitter)
+    }
+
+    /// Runs `f` until it succeeds or the error isn't to be retried anymore.
+    pub async fn retry<T, F, Fut>(&self, mut f: F) -> Result<T, ScrapeError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, ScrapeError>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match f().await {
+                Ok(value) => return Ok(value),
+                Err(err) => match self.backoff(attempt, &err) {
+                    Some(backoff) => {
+                        warn!(
+                            error = ?err,
+                            attempt,
+                            backoffMillis = backoff.as_millis(),
+                            "Retrying after transient error."
+                        );
+                        tokio::time::sleep(backoff).await;
+                        attempt += 1;
+                    }
+                    None => return Err(err),
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::retry::RetryPolicy;
+    use crate::spec::ScrapeError;
+    use std::time::Duration;
+
+    fn mk_status_error(status: u16, retry_after: Option<Duration>) -> ScrapeError {
+        ScrapeError::HttpStatusError {
+            status,
+            retry_after,
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::too_many_requests(mk_status_error(429, None), true)]
+    #[case::internal_server_error(mk_status_error(500, None), true)]
+    #[case::service_unavailable(mk_status_error(503, None), true)]
+    #[case::not_found(mk_status_error(404, None), false)]
+    #[case::transient(ScrapeError::TransientClientError("timeout".into()), true)]
+    #[case::client(ScrapeError::ClientError("invalid".into()), false)]
+    #[case::disallowed(ScrapeError::Disallowed { url: "https://shop.example.com/admin".to_string() }, false)]
+    #[case::max_listings_reached(ScrapeError::MaxListingsReached { max_listings: 10 }, false)]
+    fn should_classify_retryable_errors(#[case] err: ScrapeError, #[case] expected: bool) {
+        assert_eq!(expected, RetryPolicy::default().is_retryable(&err));
+    }
+
+    #[rstest::rstest]
+    #[case::first(1, 250, 500)]
+    #[case::second(2, 500, 1000)]
+    #[case::third(3, 1000, 2000)]
+    #[case::capped(10, 2500, 5000)]
+    fn should_backoff_exponentially_with_jitter(
+        #[case] attempt: u32,
+        #[case] min_millis: u64,
+        #[case] max_millis: u64,
+    ) {
+        let retry_policy = RetryPolicy {
+            max_attempts: 20,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(5),
+            ..Default::default()
+        };
+
+        for _ in 0..100 {
+            let actual = retry_policy
+                .backoff(attempt, &mk_status_error(503, None))
+                .unwrap();
+            assert!(actual >= Duration::from_millis(min_millis));
+            assert!(actual <= Duration::from_millis(max_millis));
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::honored(Duration::from_secs(3), Some(Duration::from_secs(3)))]
+    #[case::too_long(Duration::from_secs(60), None)]
+    fn should_honor_retry_after(#[case] retry_after: Duration, #[case] expected: Option<Duration>) {
+        let actual = RetryPolicy::default().backoff(1, &mk_status_error(429, Some(retry_after)));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn should_not_backoff_when_attempts_exhausted() {
+        let actual = RetryPolicy::default().backoff(3, &mk_status_error(503, None));
+
+        assert_eq!(None, actual);
+    }
+
+    #[tokio::test]
+    async fn should_retry_until_successful() {
+        let retry_policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..Default::default()
+        };
+        let mut attempts = 0;
+
+        let actual = retry_policy
+            .retry(|| {
+                attempts += 1;
+                let attempt = attempts;
+                async move {
+                    if attempt < 3 {
+                        Err(mk_status_error(503, None))
+                    } else {
+                        Ok(attempt)
+                    }
+                }
+            })
+            .await;
+
+        assert_eq!(3, actual.unwrap());
+    }
+
+    #[tokio::test]
+    async fn should_give_up_when_not_retryable() {
+        let mut attempts = 0;
+
+        let actual = RetryPolicy::default()
+            .retry(|| {
+                attempts += 1;
+                async { Err::<(), _>(mk_status_error(404, None)) }
+            })
+            .await;
+
+        assert!(actual.is_err());
+        assert_eq!(1, attempts);
+    }
+}
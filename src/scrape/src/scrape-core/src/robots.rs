@@ -0,0 +1,390 @@
+use crate::spec::{ScrapeError, ScraperConfig};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+/// Token identifying the scrapers in robots.txt `User-agent` lines.
+pub const USER_AGENT_TOKEN: &str = "blitzfilter-bot";
+
+/// User-Agent of all scrapers' requests, with a contact URL for the shops' operators.
+pub const USER_AGENT: &str = concat!(
+    "blitzfilter-bot/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/blitzfilter)"
+);
+
+/// Cached robots.txt are refetched after a day at the latest, see RFC 9309.
+pub const ROBOTS_TXT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Rules of a robots.txt applying to [`USER_AGENT_TOKEN`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RobotsTxt {
+    rules: Vec<Rule>,
+    crawl_delay: Option<Duration>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    allow: bool,
+    pattern: String,
+}
+
+#[derive(Debug, Default)]
+struct Group {
+    user_agents: Vec<String>,
+    rules: Vec<Rule>,
+    crawl_delay: Option<Duration>,
+}
+
+impl RobotsTxt {
+    /// Allows everything, e.g. if the host has no robots.txt.
+    pub fn allow_all() -> Self {
+        RobotsTxt::default()
+    }
+
+    /// Disallows everything, e.g. if the host's robots.txt is unreachable.
+    pub fn disallow_all() -> Self {
+        RobotsTxt {
+            rules: vec![Rule {
+                allow: false,
+                pattern: "/".to_string(),
+            }],
+            crawl_delay: None,
+        }
+    }
+
+    /// Parses the groups of the robots.txt, keeping the one of [`USER_AGENT_TOKEN`] or
+    /// else the one of `*`.
+    pub fn parse(robots_txt: &str) -> Self {
+        let mut groups: Vec<Group> = Vec::new();
+        let mut in_user_agent_lines = false;
+        for line in robots_txt.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim().to_lowercase().as_str() {
+                "user-agent" => {
+                    if !in_user_agent_lines {
+                        groups.push(Group::default());
+                    }
+                    in_user_agent_lines = true;
+                    if let Some(group) = groups.last_mut() {
+                        group.user_agents.push(value.to_lowercase());
+                    }
+                }
+                key @ ("allow" | "disallow") => {
+                    in_user_agent_lines = false;
+                    // An empty Disallow allows everything, which is also the default
+                    if let Some(group) = groups.last_mut()
+                        && !value.is_empty()
+                    {
+                        group.rules.push(Rule {
+                            allow: key == "allow",
+                            pattern: value.to_string(),
+                        });
+                    }
+                }
+                "crawl-delay" => {
+                    in_user_agent_lines = false;
+                    if let Some(group) = groups.last_mut() {
+                        group.crawl_delay = value
+                            .parse::<f64>()
+                            .ok()
+                            .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+                            .map(Duration::from_secs_f64);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let token = USER_AGENT_TOKEN.to_lowercase();
+        let group = groups
+            .iter()
+            .position(|group| {
+                group
+                    .user_agents
+                    .iter()
+                    .any(|agent| token.contains(agent.as_str()) && agent != "*")
+            })
+            .or_else(|| {
+                groups
+                    .iter()
+                    .position(|group| group.user_agents.iter().any(|agent| agent == "*"))
+            })
+            .map(|i| groups.swap_remove(i))
+            .unwrap_or_default();
+        RobotsTxt {
+            rules: group.rules,
+            crawl_delay: group.crawl_delay,
+        }
+    }
+
+    /// Whether the path, including its query, may be fetched.
+    ///
+    /// The most specific, i.e. longest, matching rule wins. Allow wins ties.
+    pub fn is_allowed(&self, path_and_query: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|rule| matches(&rule.pattern, path_and_query))
+            .max_by_key(|rule| (rule.pattern.len(), rule.allow))
+            .is_none_or(|rule| rule.allow)
+    }
+
+    pub fn crawl_delay(&self) -> Option<Duration> {
+        self.crawl_delay
+    }
+}
+
+// Supports the wildcard `*` and the end-anchor `$`
+fn matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+    let mut parts = pattern.split('*');
+    let Some(first) = parts.next() else {
+        return true;
+    };
+    let Some(mut rest) = path.strip_prefix(first) else {
+        return false;
+    };
+    let parts = parts.collect::<Vec<_>>();
+    for (i, part) in parts.iter().enumerate() {
+        let is_last = i == parts.len() - 1;
+        if is_last && anchored {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    !anchored || rest.is_empty()
+}
+
+/// robots.txt of hosts by their origin, e.g. `https://shop.example.com`.
+#[derive(Debug, Default)]
+pub struct RobotsCache {
+    entries: Mutex<HashMap<String, (Arc<RobotsTxt>, Instant)>>,
+}
+
+impl RobotsCache {
+    /// The origin's cached robots.txt, fetching it if absent or expired.
+    ///
+    /// `fetch` yields the body of a successful response or none if there is no robots.txt,
+    /// i.e. the host responded with a client error.
+    /// Failures disallow everything without being cached, so that they are retried.
+    pub async fn get_or_fetch<F, Fut>(&self, origin: &str, fetch: F) -> Arc<RobotsTxt>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Option<String>, ScrapeError>>,
+    {
+        if let Some((robots_txt, fetched_at)) = self.entries.lock().await.get(origin)
+            && fetched_at.elapsed() < ROBOTS_TXT_TTL
+        {
+            return robots_txt.clone();
+        }
+
+        let robots_txt = match fetch().await {
+            Ok(Some(robots_txt)) => Arc::new(RobotsTxt::parse(&robots_txt)),
+            Ok(None) => Arc::new(RobotsTxt::allow_all()),
+            Err(err) => {
+                warn!(error = ?err, origin, "Failed fetching robots.txt. Disallowing everything.");
+                return Arc::new(RobotsTxt::disallow_all());
+            }
+        };
+        info!(
+            origin,
+            crawlDelayMillis = robots_txt.crawl_delay().map(|delay| delay.as_millis()),
+            "Fetched robots.txt."
+        );
+        self.entries
+            .lock()
+            .await
+            .insert(origin.to_string(), (robots_txt.clone(), Instant::now()));
+        robots_txt
+    }
+}
+
+/// Client complying with the robots.txt of the hosts it requests.
+#[async_trait]
+pub trait Politeness {
+    /// The host's `Crawl-delay`, e.g. `shop.example.com`.
+    async fn crawl_delay(&self, host: &str) -> Option<Duration>;
+}
+
+/// Raises the page delay to the `Crawl-delay` of the shop's main host, i.e. its first one.
+pub async fn with_crawl_delay(
+    client: &(impl Politeness + Sync),
+    hosts: &[String],
+    scraper_config: ScraperConfig,
+) -> ScraperConfig {
+    let Some(host) = hosts.first() else {
+        return scraper_config;
+    };
+    let Some(crawl_delay) = client.crawl_delay(host).await else {
+        return scraper_config;
+    };
+    ScraperConfig {
+        page_delay: Some(
+            scraper_config
+                .page_delay
+                .map_or(crawl_delay, |page_delay| page_delay.max(crawl_delay)),
+        ),
+        ..scraper_config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::robots::{Politeness, RobotsCache, RobotsTxt, with_crawl_delay};
+    use crate::spec::{ScrapeError, ScraperConfig};
+    use async_trait::async_trait;
+    use std::time::Duration;
+
+    const ROBOTS_TXT: &str = r#"
+# Example robots.txt
+User-agent: Googlebot
+Disallow: /
+
+User-agent: *
+Disallow: /admin/
+Disallow: /search
+Disallow: /*?sort=
+Disallow: /*.pdf$
+Allow: /admin/public/
+Crawl-delay: 2.5
+
+User-agent: BadBot
+User-agent: OtherBot
+Disallow: /
+"#;
+
+    #[rstest::rstest]
+    #[case::root("/", true)]
+    #[case::item("/shop.php?code=A1001", true)]
+    #[case::admin("/admin/users", false)]
+    #[case::more_specific_allow("/admin/public/logo.png", true)]
+    #[case::prefix("/searchresults", false)]
+    #[case::wildcard("/category/helmets?sort=price", false)]
+    #[case::wildcard_not_matching("/category/helmets?page=2", true)]
+    #[case::anchored("/catalog.pdf", false)]
+    #[case::anchored_not_at_end("/catalog.pdf.html", true)]
+    fn should_match_rules_of_wildcard_group(#[case] path: &str, #[case] expected: bool) {
+        let robots_txt = RobotsTxt::parse(ROBOTS_TXT);
+
+        assert_eq!(expected, robots_txt.is_allowed(path));
+    }
+
+    #[test]
+    fn should_parse_crawl_delay() {
+        let robots_txt = RobotsTxt::parse(ROBOTS_TXT);
+
+        assert_eq!(Some(Duration::from_millis(2500)), robots_txt.crawl_delay());
+    }
+
+    #[test]
+    fn should_prefer_own_group() {
+        let robots_txt = RobotsTxt::parse(
+            "User-agent: *\nDisallow: /\n\nUser-agent: blitzfilter-bot\nDisallow: /private\n",
+        );
+
+        assert!(robots_txt.is_allowed("/shop"));
+        assert!(!robots_txt.is_allowed("/private/1"));
+    }
+
+    #[rstest::rstest]
+    #[case::empty("")]
+    #[case::empty_disallow("User-agent: *\nDisallow:\n")]
+    #[case::only_other_agents("User-agent: Googlebot\nDisallow: /\n")]
+    fn should_allow_everything(#[case] robots_txt: &str) {
+        assert!(RobotsTxt::parse(robots_txt).is_allowed("/anything"));
+    }
+
+    #[test]
+    fn should_disallow_everything() {
+        assert!(!RobotsTxt::disallow_all().is_allowed("/"));
+        assert!(!RobotsTxt::disallow_all().is_allowed("/shop.php"));
+    }
+
+    #[tokio::test]
+    async fn should_cache_fetched_robots_txt_per_origin() {
+        let cache = RobotsCache::default();
+        let mut fetches = 0;
+
+        for _ in 0..3 {
+            cache
+                .get_or_fetch("https://shop.example.com", || {
+                    fetches += 1;
+                    async { Ok(Some("User-agent: *\nDisallow: /admin\n".to_string())) }
+                })
+                .await;
+        }
+        let actual = cache
+            .get_or_fetch("https://other.example.com", || {
+                fetches += 1;
+                async { Ok(None) }
+            })
+            .await;
+
+        assert_eq!(2, fetches);
+        assert!(actual.is_allowed("/admin"));
+    }
+
+    #[tokio::test]
+    async fn should_disallow_everything_without_caching_when_fetch_fails() {
+        let cache = RobotsCache::default();
+        let mut fetches = 0;
+
+        for _ in 0..2 {
+            let actual = cache
+                .get_or_fetch("https://shop.example.com", || {
+                    fetches += 1;
+                    async { Err(ScrapeError::TransientClientError("timeout".into())) }
+                })
+                .await;
+            assert!(!actual.is_allowed("/"));
+        }
+
+        assert_eq!(2, fetches);
+    }
+
+    struct DummyClient(Option<Duration>);
+
+    #[async_trait]
+    impl Politeness for DummyClient {
+        async fn crawl_delay(&self, _: &str) -> Option<Duration> {
+            self.0
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::no_crawl_delay(None, Some(1), Some(1))]
+    #[case::longer_crawl_delay(Some(5), Some(1), Some(5))]
+    #[case::shorter_crawl_delay(Some(1), Some(5), Some(5))]
+    #[case::no_page_delay(Some(3), None, Some(3))]
+    #[tokio::test]
+    async fn should_raise_page_delay_to_crawl_delay(
+        #[case] crawl_delay: Option<u64>,
+        #[case] page_delay: Option<u64>,
+        #[case] expected: Option<u64>,
+    ) {
+        let client = DummyClient(crawl_delay.map(Duration::from_secs));
+        let scraper_config = ScraperConfig {
+            page_delay: page_delay.map(Duration::from_secs),
+            ..Default::default()
+        };
+
+        let actual =
+            with_crawl_delay(&client, &["shop.example.com".to_string()], scraper_config).await;
+
+        assert_eq!(expected.map(Duration::from_secs), actual.page_delay);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::data::{ScrapeItem, ScrapeItemChangeCommandData};
 use async_trait::async_trait;
+use aws_sdk_dynamodb::operation::batch_get_item::BatchGetItemError;
 use aws_sdk_dynamodb::operation::query::QueryError;
 use aws_sdk_sqs::config::http::HttpResponse;
 use aws_sdk_sqs::error::SdkError;
@@ -8,10 +9,96 @@ use common::batch::Batch;
 use common::has_key::HasKey;
 use common::item_id::ItemKey;
 use common::shop_id::ShopId;
+use common::shops_item_id::ShopsItemId;
+use item_core::hash::ItemHash;
+use item_dynamodb::item_state_record::ItemStateRecord;
 use item_dynamodb::repository::ItemDynamoDbRepository;
+use item_service::item_command_data::UpdateItemCommandData;
+use item_service::item_state_command_data::ItemStateCommandData;
 use serde::Serialize;
-use std::collections::HashMap;
-use tracing::{error, info};
+use std::collections::{HashMap, HashSet};
+use tracing::{error, info, warn};
+
+/// Guards against broken scrapers removing large parts of a shop at once.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RemovalThreshold {
+    /// Share of a shop's not yet removed items that may be removed at once.
+    pub max_ratio: f64,
+
+    /// Number of items that may be removed at once regardless of `max_ratio`, so that small shops
+    /// aren't stuck.
+    pub min_count: usize,
+}
+
+impl Default for RemovalThreshold {
+    fn default() -> Self {
+        RemovalThreshold {
+            max_ratio: 0.2,
+            min_count: 5,
+        }
+    }
+}
+
+impl RemovalThreshold {
+    fn permits(&self, removed_count: usize, total_count: usize) -> bool {
+        removed_count <= self.min_count
+            || removed_count as f64 <= self.max_ratio * total_count as f64
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PublishRemovedItemsError {
+    #[error(
+        "Refusing to remove {removed_count} of {total_count} items of shop '{shop_id}' because it exceeds the RemovalThreshold."
+    )]
+    RemovalThresholdExceeded {
+        shop_id: ShopId,
+        removed_count: usize,
+        total_count: usize,
+    },
+
+    #[error("Refusing to remove all items of shop '{0}' because none have been seen.")]
+    NothingSeen(ShopId),
+
+    #[error("Failed publishing removal for {} items.", .0.len())]
+    PublishFailures(Vec<ItemKey>),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum QueryOnOfferItemsError {
+    #[error("Encountered DynamoDB SdkError for Query: {0}")]
+    SdkQueryError(#[from] Box<SdkError<QueryError>>),
+
+    #[error("Encountered DynamoDB SdkError for BatchGetItem: {0}")]
+    SdkBatchGetItemError(#[from] Box<SdkError<BatchGetItemError>>),
+
+    #[error("Failed getting the state of {} items.", .0.len())]
+    Unprocessed(Vec<ItemKey>),
+}
+
+/// A shop's items that are on offer, i.e. neither sold nor removed.
+///
+/// Seen items are taken to be on offer without reading their state, because the scrape is about to
+/// update it anyway.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OnOfferItems {
+    /// Number of the shop's known items on offer, whether seen or not.
+    pub count: usize,
+
+    /// Known items on offer that haven't been seen.
+    pub unseen: Vec<ItemKey>,
+}
+
+/// Outcome of publishing ScrapeItems.
+///
+/// Skipped items haven't changed since they were last published.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublishScrapeItemsSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub failures: Vec<ItemKey>,
+}
 
 #[derive(Clone)]
 pub struct PublishScrapeItemsImpl<'a> {
@@ -19,6 +106,7 @@ pub struct PublishScrapeItemsImpl<'a> {
     pub sqs_client: &'a aws_sdk_sqs::Client,
     pub sqs_create_url: String,
     pub sqs_update_url: String,
+    pub removal_threshold: RemovalThreshold,
 }
 
 #[async_trait]
@@ -26,7 +114,33 @@ pub trait PublishScrapeItemService {
     async fn publish_scrape_items(
         &self,
         scrape_items: Vec<ScrapeItem>,
-    ) -> Result<(), impl IntoIterator<Item = ItemKey>>;
+    ) -> PublishScrapeItemsSummary;
+
+    /// The shop's known items on offer, see [`OnOfferItems`].
+    ///
+    /// Reads the state of unseen items from the table, because `gsi_1` doesn't project it.
+    async fn query_on_offer_items(
+        &self,
+        shop_id: &ShopId,
+        seen: &HashSet<ShopsItemId>,
+    ) -> Result<OnOfferItems, QueryOnOfferItemsError>;
+
+    /// Hashes of all the shop's items, see [`ScrapeItem::hash`].
+    async fn query_known_hashes(
+        &self,
+        shop_id: &ShopId,
+    ) -> Result<HashMap<ShopsItemId, ItemHash>, Box<SdkError<QueryError>>>;
+
+    /// Publishes updates to [`ItemStateCommandData::Removed`] for all items of the shop that
+    /// are on offer but haven't been seen, returning how many.
+    ///
+    /// Must only be called after a complete and successful scrape of the shop.
+    async fn publish_removed_items(
+        &self,
+        shop_id: &ShopId,
+        seen: &HashSet<ShopsItemId>,
+        on_offer: OnOfferItems,
+    ) -> Result<usize, PublishRemovedItemsError>;
 }
 
 #[async_trait]
@@ -34,7 +148,7 @@ impl<'a> PublishScrapeItemService for PublishScrapeItemsImpl<'a> {
     async fn publish_scrape_items(
         &self,
         scrape_items: Vec<ScrapeItem>,
-    ) -> Result<(), impl IntoIterator<Item = ItemKey>> {
+    ) -> PublishScrapeItemsSummary {
         let total_count = scrape_items.len();
         let grouped: HashMap<ShopId, Vec<ScrapeItem>> =
             scrape_items
@@ -67,42 +181,187 @@ impl<'a> PublishScrapeItemService for PublishScrapeItemsImpl<'a> {
         let skipped_count =
             total_count - assessed_create.len() - assessed_update.len() - failures.len();
 
+        let mut created_count = 0;
         for batch_create in Batch::<_, 10>::chunked_from(assessed_create.into_iter()) {
             let ids_keys = batch_create
                 .iter()
                 .enumerate()
                 .map(|(i, item)| (i.to_string(), item.key()))
                 .collect::<HashMap<_, _>>();
+            let batch_len = ids_keys.len();
+            let failures_before = failures.len();
             let send_msg_batch_res = self.publish(&self.sqs_create_url, batch_create).await;
             handle_message_batch_result(send_msg_batch_res, ids_keys, &mut failures);
+            created_count += batch_len - (failures.len() - failures_before);
         }
 
+        let mut updated_count = 0;
         for batch_update in Batch::<_, 10>::chunked_from(assessed_update.into_iter()) {
             let ids_keys = batch_update
                 .iter()
                 .enumerate()
                 .map(|(i, item)| (i.to_string(), item.key()))
                 .collect::<HashMap<_, _>>();
+            let batch_len = ids_keys.len();
+            let failures_before = failures.len();
             let send_msg_batch_res = self.publish(&self.sqs_update_url, batch_update).await;
             handle_message_batch_result(send_msg_batch_res, ids_keys, &mut failures);
+            updated_count += batch_len - (failures.len() - failures_before);
         }
 
-        let failures_len = failures.len();
         info!(
-            successful = total_count - failures_len - skipped_count,
-            failures = failures_len,
+            successful = created_count + updated_count,
+            failures = failures.len(),
             skipped = skipped_count,
             "Handled multiple ScrapeItems."
         );
 
+        PublishScrapeItemsSummary {
+            created: created_count,
+            updated: updated_count,
+            skipped: skipped_count,
+            failures,
+        }
+    }
+
+    async fn query_on_offer_items(
+        &self,
+        shop_id: &ShopId,
+        seen: &HashSet<ShopsItemId>,
+    ) -> Result<OnOfferItems, QueryOnOfferItemsError> {
+        let (seen_keys, unseen_keys): (Vec<_>, Vec<_>) = self
+            .dynamodb_repository
+            .query_item_hashes(shop_id, true)
+            .await
+            .map_err(Box::new)?
+            .into_iter()
+            .map(|item_summary_hash| item_summary_hash.key())
+            .partition(|item_key| seen.contains(&item_key.shops_item_id));
+
+        let mut unseen = Vec::new();
+        let mut unprocessed = Vec::new();
+        for batch in Batch::<_, 100>::chunked_from(unseen_keys.into_iter()) {
+            let batch_result = self
+                .dynamodb_repository
+                .get_item_states(&batch)
+                .await
+                .map_err(Box::new)?;
+            unseen.extend(
+                batch_result
+                    .items
+                    .into_iter()
+                    .filter(|item_summary_state| is_on_offer(&item_summary_state.state))
+                    .map(|item_summary_state| item_summary_state.key()),
+            );
+            if let Some(batch_unprocessed) = batch_result.unprocessed {
+                unprocessed.extend(batch_unprocessed);
+            }
+        }
+
+        if unprocessed.is_empty() {
+            Ok(OnOfferItems {
+                count: seen_keys.len() + unseen.len(),
+                unseen,
+            })
+        } else {
+            Err(QueryOnOfferItemsError::Unprocessed(unprocessed))
+        }
+    }
+
+    async fn query_known_hashes(
+        &self,
+        shop_id: &ShopId,
+    ) -> Result<HashMap<ShopsItemId, ItemHash>, Box<SdkError<QueryError>>> {
+        let shop_universe = self
+            .dynamodb_repository
+            .query_item_hashes(shop_id, true)
+            .await
+            .map_err(Box::new)?;
+        Ok(shop_universe
+            .into_iter()
+            .map(|item_summary_hash| (item_summary_hash.shops_item_id, item_summary_hash.hash))
+            .collect())
+    }
+
+    async fn publish_removed_items(
+        &self,
+        shop_id: &ShopId,
+        seen: &HashSet<ShopsItemId>,
+        on_offer: OnOfferItems,
+    ) -> Result<usize, PublishRemovedItemsError> {
+        let removed = assess_removed(shop_id, seen, on_offer, &self.removal_threshold)
+            .inspect_err(|err| warn!(error = %err, shopId = %shop_id, "Skipping removals."))?;
+        let removed_count = removed.len();
+
+        let mut failures = Vec::new();
+        for batch_update in Batch::<_, 10>::chunked_from(removed.into_iter()) {
+            let ids_keys = batch_update
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (i.to_string(), item.key()))
+                .collect::<HashMap<_, _>>();
+            let send_msg_batch_res = self.publish(&self.sqs_update_url, batch_update).await;
+            handle_message_batch_result(send_msg_batch_res, ids_keys, &mut failures);
+        }
+
+        info!(
+            shopId = %shop_id,
+            successful = removed_count - failures.len(),
+            failures = failures.len(),
+            "Published removed items."
+        );
+
         if failures.is_empty() {
-            Ok(())
+            Ok(removed_count)
         } else {
-            Err(failures)
+            Err(PublishRemovedItemsError::PublishFailures(failures))
         }
     }
 }
 
+fn is_on_offer(state: &ItemStateRecord) -> bool {
+    !matches!(state, ItemStateRecord::Sold | ItemStateRecord::Removed)
+}
+
+/// Updates to [`ItemStateCommandData::Removed`] for the unseen items on offer.
+///
+/// Sold items are neither removed nor counted towards the threshold. Shops delist them eventually,
+/// which isn't a removal, and marking them as such would lose that they have been sold.
+fn assess_removed(
+    shop_id: &ShopId,
+    seen: &HashSet<ShopsItemId>,
+    on_offer: OnOfferItems,
+    removal_threshold: &RemovalThreshold,
+) -> Result<Vec<UpdateItemCommandData>, PublishRemovedItemsError> {
+    let total_count = on_offer.count;
+
+    let removed = on_offer
+        .unseen
+        .into_iter()
+        .map(|item_key| UpdateItemCommandData {
+            shop_id: item_key.shop_id,
+            shops_item_id: item_key.shops_item_id,
+            price: None,
+            state: Some(ItemStateCommandData::Removed),
+        })
+        .collect::<Vec<_>>();
+    let removed_count = removed.len();
+
+    if removed_count == 0 {
+        Ok(removed)
+    } else if seen.is_empty() {
+        Err(PublishRemovedItemsError::NothingSeen(shop_id.clone()))
+    } else if removal_threshold.permits(removed_count, total_count) {
+        Ok(removed)
+    } else {
+        Err(PublishRemovedItemsError::RemovalThresholdExceeded {
+            shop_id: shop_id.clone(),
+            removed_count,
+            total_count,
+        })
+    }
+}
+
 fn handle_message_batch_result(
     res: Result<SendMessageBatchOutput, SdkError<SendMessageBatchError, HttpResponse>>,
     ids_keys: HashMap<String, ItemKey>,
@@ -167,3 +426,106 @@ impl<'a> PublishScrapeItemsImpl<'a> {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::service::{
+        OnOfferItems, PublishRemovedItemsError, RemovalThreshold, assess_removed, is_on_offer,
+    };
+    use common::item_id::ItemKey;
+    use common::shop_id::ShopId;
+    use common::shops_item_id::ShopsItemId;
+    use item_dynamodb::item_state_record::ItemStateRecord;
+    use item_service::item_state_command_data::ItemStateCommandData;
+    use std::collections::HashSet;
+
+    fn mk_on_offer(shop_id: &ShopId, count: usize, seen_count: usize) -> OnOfferItems {
+        OnOfferItems {
+            count,
+            unseen: (seen_count..count)
+                .map(|i| ItemKey {
+                    shop_id: shop_id.clone(),
+                    shops_item_id: i.to_string().into(),
+                })
+                .collect(),
+        }
+    }
+
+    fn mk_seen(ids: impl IntoIterator<Item = usize>) -> HashSet<ShopsItemId> {
+        ids.into_iter().map(|i| i.to_string().into()).collect()
+    }
+
+    #[test]
+    fn should_remove_unseen_items() {
+        let shop_id = ShopId::new();
+        let on_offer = mk_on_offer(&shop_id, 100, 90);
+        let seen = mk_seen(0..90);
+
+        let actual =
+            assess_removed(&shop_id, &seen, on_offer, &RemovalThreshold::default()).unwrap();
+
+        assert_eq!(10, actual.len());
+        assert!(actual.iter().all(|update| {
+            update.state == Some(ItemStateCommandData::Removed)
+                && update.price.is_none()
+                && !seen.contains(&update.shops_item_id)
+        }));
+    }
+
+    #[rstest::rstest]
+    #[case::listed(ItemStateRecord::Listed, true)]
+    #[case::available(ItemStateRecord::Available, true)]
+    #[case::reserved(ItemStateRecord::Reserved, true)]
+    #[case::sold(ItemStateRecord::Sold, false)]
+    #[case::removed(ItemStateRecord::Removed, false)]
+    fn should_be_on_offer_unless_sold_or_removed(
+        #[case] state: ItemStateRecord,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(expected, is_on_offer(&state));
+    }
+
+    #[test]
+    fn should_not_remove_anything_when_nothing_seen() {
+        let shop_id = ShopId::new();
+        let on_offer = mk_on_offer(&shop_id, 3, 0);
+
+        let actual = assess_removed(
+            &shop_id,
+            &HashSet::new(),
+            on_offer,
+            &RemovalThreshold::default(),
+        );
+
+        assert!(matches!(
+            actual,
+            Err(PublishRemovedItemsError::NothingSeen(_))
+        ));
+    }
+
+    #[rstest::rstest]
+    #[case::below_ratio(100, 80, true)]
+    #[case::above_ratio(100, 79, false)]
+    #[case::within_min_count(10, 5, true)]
+    #[case::above_min_count(10, 4, false)]
+    fn should_respect_removal_threshold(
+        #[case] on_offer_count: usize,
+        #[case] seen_count: usize,
+        #[case] expected_ok: bool,
+    ) {
+        let shop_id = ShopId::new();
+        let on_offer = mk_on_offer(&shop_id, on_offer_count, seen_count);
+        let seen = mk_seen(0..seen_count);
+
+        let actual = assess_removed(&shop_id, &seen, on_offer, &RemovalThreshold::default());
+
+        if expected_ok {
+            assert_eq!(on_offer_count - seen_count, actual.unwrap().len());
+        } else {
+            assert!(matches!(
+                actual,
+                Err(PublishRemovedItemsError::RemovalThresholdExceeded { .. })
+            ));
+        }
+    }
+}
@@ -1,56 +1,182 @@
 use crate::data::ScrapeItem;
-use async_stream::try_stream;
+use crate::retry::RetryPolicy;
+use async_stream::stream;
 use async_trait::async_trait;
-use futures::stream::BoxStream;
+use common::shops_item_id::ShopsItemId;
+use futures::StreamExt;
+use futures::stream::{self, BoxStream};
 use std::error::Error;
 use std::time::Duration;
-use tracing::info;
+use tracing::{info, warn};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct ScraperConfig {
     pub page_delay: Option<Duration>,
+
+    /// Maximum number of concurrent detail-page requests per page.
+    /// Scraping detail-pages is disabled if absent.
+    pub detail_concurrency: Option<usize>,
+
+    /// Applies to each page- and detail-page request.
+    pub retry_policy: RetryPolicy,
+
+    /// Stops scraping shops listing their items newest-first, see [`Scraper::lists_newest_first`],
+    /// after this many consecutive items are known and unchanged. Disabled if absent.
+    pub stop_after_known_items: Option<usize>,
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum ScrapeError {
     #[error("Encountered Client-Error while scraping.")]
     ClientError(#[from] Box<dyn Error + Send + Sync>),
+
+    #[error("Encountered transient Client-Error while scraping, e.g. a timeout.")]
+    TransientClientError(Box<dyn Error + Send + Sync>),
+
+    #[error("Received HTTP-Status {status} while scraping.")]
+    HttpStatusError {
+        status: u16,
+        retry_after: Option<Duration>,
+    },
+
+    #[error("The shop's robots.txt disallows scraping '{url}'.")]
+    Disallowed { url: String },
+
+    /// The crawl stopped before visiting all listings, so items may remain undiscovered.
+    #[error("Reached the maximum of {max_listings} listings while discovering items.")]
+    MaxListingsReached { max_listings: usize },
+}
+
+/// A catalog page scraped by [`Scraper::scrape_page`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScrapePage {
+    Items(Vec<ScrapeItem>),
+
+    /// The page is unchanged since the previous scrape, so its items weren't scraped again.
+    Unchanged {
+        url: String,
+        shops_item_ids: Vec<ShopsItemId>,
+    },
+}
+
+/// Yielded by [`Scraper::scrape`].
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum ScrapeEvent {
+    Item(ScrapeItem),
+
+    /// The page is unchanged since the previous scrape, so its items weren't scraped again but
+    /// still count as seen.
+    PageUnchanged {
+        url: String,
+        shops_item_ids: Vec<ShopsItemId>,
+    },
+}
+
+impl ScrapeEvent {
+    pub fn into_item(self) -> Option<ScrapeItem> {
+        match self {
+            ScrapeEvent::Item(scrape_item) => Some(scrape_item),
+            ScrapeEvent::PageUnchanged { .. } => None,
+        }
+    }
 }
 
 #[async_trait]
 pub trait Scraper<Client: Send + Sync>: Send + Sync {
-    fn shop_id_str(&self) -> &'static str;
-    fn shop_name_str(&self) -> &'static str;
+    fn shop_id_str(&self) -> &str;
+    fn shop_name_str(&self) -> &str;
 
+    /// Hosts the shop's item URLs and images are served from, including their subdomains.
+    /// Items on other hosts are rejected by the [`crate::validation::ScrapeItemValidator`],
+    /// which doesn't check hosts if there are none.
+    fn hosts(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Whether the shop's catalog lists its newest items first, so that scraping can stop
+    /// once it only encounters known items, see [`ScraperConfig::stop_after_known_items`].
+    fn lists_newest_first(&self) -> bool {
+        false
+    }
+
+    /// Scrapes the catalog page `page_num`, starting at 1, for the default [`Scraper::scrape`].
+    /// The scrape ends with the first page without items, unless it's [`ScrapePage::Unchanged`].
+    ///
+    /// Scrapers discovering their items differently override [`Scraper::scrape`] instead,
+    /// e.g. with [`crate::discovery::scrape_discovered`].
     async fn scrape_page(
         &self,
-        client: &Client,
-        scraper_config: ScraperConfig,
-        page_num: u32,
-    ) -> Result<Vec<ScrapeItem>, ScrapeError>;
+        _client: &Client,
+        _scraper_config: ScraperConfig,
+        _page_num: u32,
+    ) -> Result<ScrapePage, ScrapeError> {
+        Ok(ScrapePage::Items(vec![]))
+    }
+
+    /// Whether the item scraped from a page lacks information only available on its detail-page,
+    /// e.g. a truncated description.
+    fn needs_details(&self, _scrape_item: &ScrapeItem) -> bool {
+        false
+    }
+
+    /// Completes the item with information from its detail-page.
+    async fn scrape_details(
+        &self,
+        _client: &Client,
+        _scraper_config: ScraperConfig,
+        _scrape_item: &mut ScrapeItem,
+    ) -> Result<(), ScrapeError> {
+        Ok(())
+    }
 
     fn scrape<'a>(
         &'a self,
         client: &'a Client,
         scraper_config: ScraperConfig,
-    ) -> BoxStream<'a, Result<ScrapeItem, ScrapeError>> {
+    ) -> BoxStream<'a, Result<ScrapeEvent, ScrapeError>> {
         info!(
             shopId = self.shop_id_str(),
             shopName = self.shop_name_str(),
             config = ?scraper_config,
             "Starting to scrape."
         );
-        Box::pin(try_stream! {
+        Box::pin(stream! {
             let mut i: u32 = 1;
             loop {
-                let items = self.scrape_page(client, scraper_config, i).await?;
-                let items_count = items.len();
-                info!(shopId = self.shop_id_str(), page = i, total = items_count, "Scraped page.");
-                if items_count == 0 {
-                    break;
-                }
-                for item in items {
-                    yield item;
+                let page_res = scraper_config
+                    .retry_policy
+                    .retry(|| self.scrape_page(client, scraper_config, i))
+                    .await;
+                match page_res {
+                    Ok(ScrapePage::Items(mut items)) => {
+                        if let Some(detail_concurrency) = scraper_config.detail_concurrency {
+                            items = stream::iter(items)
+                                .map(|item| complete_details(self, client, scraper_config, item))
+                                .buffered(detail_concurrency.max(1))
+                                .collect()
+                                .await;
+                        }
+                        info!(shopId = self.shop_id_str(), page = i, total = items.len(), "Scraped page.");
+                        if items.is_empty() {
+                            break;
+                        }
+                        for item in items {
+                            yield Ok(ScrapeEvent::Item(item));
+                        }
+                    }
+                    Ok(ScrapePage::Unchanged { url, shops_item_ids }) => {
+                        info!(shopId = self.shop_id_str(), page = i, total = shops_item_ids.len(), "Page is unchanged.");
+                        // The last page was empty already
+                        if shops_item_ids.is_empty() {
+                            break;
+                        }
+                        yield Ok(ScrapeEvent::PageUnchanged { url, shops_item_ids });
+                    }
+                    Err(err) => {
+                        yield Err(err);
+                        break;
+                    }
                 }
                 if let Some(duration) = scraper_config.page_delay {
                     tokio::time::sleep(duration).await;
@@ -61,14 +187,53 @@ pub trait Scraper<Client: Send + Sync>: Send + Sync {
     }
 }
 
+/// Scrapes the details of the item if needed.
+/// Failures are logged and the item is returned as scraped from the page.
+async fn complete_details<Client: Send + Sync, S: Scraper<Client> + ?Sized>(
+    scraper: &S,
+    client: &Client,
+    scraper_config: ScraperConfig,
+    scrape_item: ScrapeItem,
+) -> ScrapeItem {
+    if !scraper.needs_details(&scrape_item) {
+        return scrape_item;
+    }
+    let details_res = scraper_config
+        .retry_policy
+        .retry(|| {
+            let mut detailed_item = scrape_item.clone();
+            async move {
+                scraper
+                    .scrape_details(client, scraper_config, &mut detailed_item)
+                    .await
+                    .map(|_| detailed_item)
+            }
+        })
+        .await;
+    match details_res {
+        Ok(detailed_item) => detailed_item,
+        Err(err) => {
+            warn!(
+                error = ?err,
+                shopId = scraper.shop_id_str(),
+                shopsItemId = %scrape_item.shops_item_id,
+                "Failed scraping details."
+            );
+            scrape_item
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::data::ScrapeItem;
-    use crate::spec::{ScrapeError, Scraper, ScraperConfig};
+    use crate::retry::RetryPolicy;
+    use crate::spec::{ScrapeError, ScrapeEvent, ScrapePage, Scraper, ScraperConfig};
     use async_trait::async_trait;
     use common::language::data::{LanguageData, LocalizedTextData};
     use futures::StreamExt;
     use item_data::item_state_data::ItemStateData;
+    use std::sync::atomic::{AtomicU32, Ordering};
     use std::time::{Duration, SystemTime};
     use url::Url;
 
@@ -90,7 +255,7 @@ mod tests {
             _: &DummyClient,
             _: ScraperConfig,
             page_num: u32,
-        ) -> Result<Vec<ScrapeItem>, ScrapeError> {
+        ) -> Result<ScrapePage, ScrapeError> {
             let mk_scrape_item = || ScrapeItem {
                 shop_id: Default::default(),
                 shops_item_id: Default::default(),
@@ -107,28 +272,202 @@ mod tests {
                 url: Url::parse("https://foo.bar").unwrap(),
                 images: vec![],
             };
+            let items = match page_num {
+                1 => (0..20).map(|_| mk_scrape_item()).collect::<Vec<_>>(),
+                2 => (0..20).map(|_| mk_scrape_item()).collect::<Vec<_>>(),
+                3 => (0..20).map(|_| mk_scrape_item()).collect::<Vec<_>>(),
+                4 => (0..20).map(|_| mk_scrape_item()).collect::<Vec<_>>(),
+                5 => (0..1).map(|_| mk_scrape_item()).collect::<Vec<_>>(),
+                _ => vec![],
+            };
+            Ok(ScrapePage::Items(items))
+        }
+
+        fn needs_details(&self, scrape_item: &ScrapeItem) -> bool {
+            scrape_item.native_description.is_none()
+        }
+
+        async fn scrape_details(
+            &self,
+            _: &DummyClient,
+            _: ScraperConfig,
+            scrape_item: &mut ScrapeItem,
+        ) -> Result<(), ScrapeError> {
+            scrape_item.native_description = Some(LocalizedTextData {
+                text: "full description".to_string(),
+                language: LanguageData::De,
+            });
+            Ok(())
+        }
+    }
+
+    /// Responds with 503 to the first `failures` requests of page 2.
+    struct FlakyScraper {
+        failures: u32,
+        attempts: AtomicU32,
+    }
+
+    #[async_trait]
+    impl Scraper<DummyClient> for FlakyScraper {
+        fn shop_id_str(&self) -> &'static str {
+            "flaky-id"
+        }
+
+        fn shop_name_str(&self) -> &'static str {
+            "flaky-name"
+        }
+
+        async fn scrape_page(
+            &self,
+            client: &DummyClient,
+            scraper_config: ScraperConfig,
+            page_num: u32,
+        ) -> Result<ScrapePage, ScrapeError> {
+            if page_num == 2 && self.attempts.fetch_add(1, Ordering::SeqCst) < self.failures {
+                return Err(ScrapeError::HttpStatusError {
+                    status: 503,
+                    retry_after: None,
+                });
+            }
+            DummyScraper
+                .scrape_page(client, scraper_config, page_num)
+                .await
+        }
+    }
+
+    /// Page 2 and the empty last page are unchanged since the previous scrape.
+    struct UnchangedScraper;
+
+    #[async_trait]
+    impl Scraper<DummyClient> for UnchangedScraper {
+        fn shop_id_str(&self) -> &'static str {
+            "unchanged-id"
+        }
+
+        fn shop_name_str(&self) -> &'static str {
+            "unchanged-name"
+        }
+
+        async fn scrape_page(
+            &self,
+            client: &DummyClient,
+            scraper_config: ScraperConfig,
+            page_num: u32,
+        ) -> Result<ScrapePage, ScrapeError> {
             match page_num {
-                1 => Ok((0..20).map(|_| mk_scrape_item()).collect::<Vec<_>>()),
-                2 => Ok((0..20).map(|_| mk_scrape_item()).collect::<Vec<_>>()),
-                3 => Ok((0..20).map(|_| mk_scrape_item()).collect::<Vec<_>>()),
-                4 => Ok((0..20).map(|_| mk_scrape_item()).collect::<Vec<_>>()),
-                5 => Ok((0..1).map(|_| mk_scrape_item()).collect::<Vec<_>>()),
-                _ => Ok(vec![]),
+                2 => Ok(ScrapePage::Unchanged {
+                    url: "https://foo.bar?pg=2".to_string(),
+                    shops_item_ids: vec!["1".into(), "2".into()],
+                }),
+                6.. => Ok(ScrapePage::Unchanged {
+                    url: format!("https://foo.bar?pg={page_num}"),
+                    shops_item_ids: vec![],
+                }),
+                _ => {
+                    DummyScraper
+                        .scrape_page(client, scraper_config, page_num)
+                        .await
+                }
             }
         }
     }
 
+    #[tokio::test]
+    async fn should_continue_after_unchanged_pages_until_unchanged_empty_page() {
+        let actual = UnchangedScraper
+            .scrape(&DummyClient, ScraperConfig::default())
+            .collect::<Vec<_>>()
+            .await;
+
+        assert!(actual.iter().all(Result::is_ok));
+        assert_eq!(
+            61,
+            actual
+                .iter()
+                .filter(|result| matches!(result, Ok(ScrapeEvent::Item(_))))
+                .count()
+        );
+        let unchanged = actual
+            .iter()
+            .filter_map(|result| match result {
+                Ok(ScrapeEvent::PageUnchanged { shops_item_ids, .. }) => Some(shops_item_ids.len()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec![2], unchanged);
+    }
+
+    #[rstest::rstest]
+    #[case::recovered(2, 81, false)]
+    #[case::exhausted(3, 20, true)]
+    #[tokio::test]
+    async fn should_retry_transient_errors_of_pages(
+        #[case] failures: u32,
+        #[case] expected_count: usize,
+        #[case] expected_err: bool,
+    ) {
+        let scraper = FlakyScraper {
+            failures,
+            attempts: AtomicU32::new(0),
+        };
+        let scraper_config = ScraperConfig {
+            retry_policy: RetryPolicy {
+                initial_backoff: Duration::from_millis(1),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let actual = scraper
+            .scrape(&DummyClient, scraper_config)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(
+            expected_count,
+            actual.iter().filter(|result| result.is_ok()).count()
+        );
+        assert_eq!(expected_err, actual.iter().any(|result| result.is_err()));
+    }
+
     #[tokio::test]
     async fn should_scrape_all_pages_until_no_items_returned() {
         let actual = DummyScraper
             .scrape(&DummyClient, ScraperConfig::default())
-            .filter_map(|result| async { result.ok() })
+            .filter_map(|result| async { result.ok().and_then(ScrapeEvent::into_item) })
             .collect::<Vec<_>>()
             .await;
 
         assert_eq!(81, actual.len());
     }
 
+    #[rstest::rstest]
+    #[case::enabled(Some(4), 81)]
+    #[case::disabled(None, 0)]
+    #[tokio::test]
+    async fn should_scrape_details_when_enabled(
+        #[case] detail_concurrency: Option<usize>,
+        #[case] expected: usize,
+    ) {
+        let actual = DummyScraper
+            .scrape(
+                &DummyClient,
+                ScraperConfig {
+                    detail_concurrency,
+                    ..Default::default()
+                },
+            )
+            .filter_map(|result| async { result.ok().and_then(ScrapeEvent::into_item) })
+            .filter(|item| {
+                let has_details = item.native_description.is_some();
+                async move { has_details }
+            })
+            .count()
+            .await;
+
+        assert_eq!(expected, actual);
+    }
+
     #[rstest::rstest]
     #[case(50)]
     #[case(100)]
@@ -143,9 +482,10 @@ mod tests {
                 &DummyClient,
                 ScraperConfig {
                     page_delay: Some(Duration::from_millis(delay_ms)),
+                    ..Default::default()
                 },
             )
-            .filter_map(|result| async { result.ok() })
+            .filter_map(|result| async { result.ok().and_then(ScrapeEvent::into_item) })
             .collect::<Vec<_>>()
             .await;
         let t2 = SystemTime::now();
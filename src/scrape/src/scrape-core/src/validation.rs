@@ -0,0 +1,364 @@
+use crate::data::ScrapeItem;
+use common::currency::domain::{Currency, HasMinorUnitExponent};
+use common::price::data::PriceData;
+use common::price::domain::FxRate;
+use common::price::fx_rate::FxRateSnapshot;
+use common::shops_item_id::ShopsItemId;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use url::Url;
+
+/// Sanity checks of ScrapeItems before they are published, so that a single broken selector
+/// can't flood the catalog with junk.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ValidationRules {
+    /// Prices above are implausible, in major units of EUR.
+    /// Prices in other currencies are exchanged to EUR before being compared.
+    pub price_ceiling: u64,
+
+    /// Lowercase titles that are really boilerplate of the shop's page, e.g. `sold`.
+    pub boilerplate_titles: &'static [&'static str],
+
+    /// Share by which the number of scraped items may drop below the number of the shop's
+    /// known items before the scrape is considered broken.
+    pub max_item_count_drop: f64,
+
+    /// Share of the scraped items whose publishing may fail before the scrape is considered
+    /// failed.
+    pub max_failure_share: f64,
+}
+
+impl Default for ValidationRules {
+    fn default() -> Self {
+        ValidationRules {
+            price_ceiling: 1_000_000,
+            boilerplate_titles: &[
+                "sold",
+                "reserved",
+                "untitled",
+                "no title",
+                "item",
+                "product",
+                "n/a",
+                "more info",
+                "read more",
+                "click here",
+                "add to basket",
+                "add to cart",
+            ],
+            max_item_count_drop: 0.5,
+            max_failure_share: 0.1,
+        }
+    }
+}
+
+impl ValidationRules {
+    /// Whether the scrape's item count dropped implausibly versus the shop's known items.
+    /// Shops without known items can't drop.
+    pub fn is_item_count_drop(&self, scraped_count: usize, known_count: usize) -> bool {
+        known_count > 0
+            && (scraped_count as f64) < (1.0 - self.max_item_count_drop) * known_count as f64
+    }
+
+    /// Whether publishing failed for too many of the scraped items.
+    pub fn is_failure_share_exceeded(&self, failures: usize, scraped_count: usize) -> bool {
+        failures as f64 > self.max_failure_share * scraped_count as f64
+    }
+}
+
+/// Why a ScrapeItem is quarantined instead of published.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RejectReason {
+    EmptyTitle,
+    BoilerplateTitle(String),
+    ForeignUrlHost(Option<String>),
+    ForeignImageHost(Option<String>),
+    ZeroPrice,
+    PriceAboveCeiling(PriceData),
+    DuplicateShopsItemId,
+}
+
+impl Display for RejectReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RejectReason::EmptyTitle => write!(f, "title is empty"),
+            RejectReason::BoilerplateTitle(title) => write!(f, "title '{title}' is boilerplate"),
+            RejectReason::ForeignUrlHost(host) => write!(f, "URL is on foreign host {host:?}"),
+            RejectReason::ForeignImageHost(host) => {
+                write!(f, "image is on foreign host {host:?}")
+            }
+            RejectReason::ZeroPrice => write!(f, "price is zero"),
+            RejectReason::PriceAboveCeiling(price) => {
+                write!(
+                    f,
+                    "price {} {:?} is above ceiling",
+                    price.amount, price.currency
+                )
+            }
+            RejectReason::DuplicateShopsItemId => write!(f, "ShopsItemId was already scraped"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedScrapeItem {
+    pub scrape_item: Box<ScrapeItem>,
+    pub reasons: Vec<RejectReason>,
+}
+
+/// Validates the ScrapeItems of a single scrape of a shop.
+#[derive(Debug)]
+pub struct ScrapeItemValidator {
+    rules: ValidationRules,
+    hosts: Vec<String>,
+    seen: HashSet<ShopsItemId>,
+    fx_rate: FxRateSnapshot,
+}
+
+impl ScrapeItemValidator {
+    /// Item URLs and images must be on one of the `hosts` or their subdomains,
+    /// see [`crate::spec::Scraper::hosts`]. Hosts aren't checked if there are none.
+    ///
+    /// Prices are exchanged with the bundled rates, which are precise enough for a ceiling.
+    pub fn new(rules: ValidationRules, hosts: Vec<String>) -> Self {
+        ScrapeItemValidator {
+            rules,
+            hosts: hosts
+                .into_iter()
+                .map(|host| host.trim_start_matches("www.").to_lowercase())
+                .collect(),
+            seen: HashSet::new(),
+            fx_rate: FxRateSnapshot::bundled(),
+        }
+    }
+
+    pub fn validate(&mut self, scrape_item: ScrapeItem) -> Result<ScrapeItem, RejectedScrapeItem> {
+        let mut reasons = Vec::new();
+
+        let title = scrape_item.native_title.text.trim();
+        if title.is_empty() {
+            reasons.push(RejectReason::EmptyTitle);
+        } else if self
+            .rules
+            .boilerplate_titles
+            .contains(&title.to_lowercase().as_str())
+        {
+            reasons.push(RejectReason::BoilerplateTitle(title.to_string()));
+        }
+
+        if !self.is_own_host(&scrape_item.url) {
+            reasons.push(RejectReason::ForeignUrlHost(
+                scrape_item.url.host_str().map(String::from),
+            ));
+        }
+        if let Some(image) = scrape_item
+            .images
+            .iter()
+            .find(|image| !self.is_own_host(image))
+        {
+            reasons.push(RejectReason::ForeignImageHost(
+                image.host_str().map(String::from),
+            ));
+        }
+
+        if let Some(price) = scrape_item.price {
+            if price.amount == 0 {
+                reasons.push(RejectReason::ZeroPrice);
+            } else if self.is_above_ceiling(&price) {
+                reasons.push(RejectReason::PriceAboveCeiling(price));
+            }
+        }
+
+        if !self.seen.insert(scrape_item.shops_item_id.clone()) {
+            reasons.push(RejectReason::DuplicateShopsItemId);
+        }
+
+        if reasons.is_empty() {
+            Ok(scrape_item)
+        } else {
+            Err(RejectedScrapeItem {
+                scrape_item: Box::new(scrape_item),
+                reasons,
+            })
+        }
+    }
+
+    fn is_own_host(&self, url: &Url) -> bool {
+        if self.hosts.is_empty() {
+            return true;
+        }
+        let Some(host) = url.host_str().map(str::to_lowercase) else {
+            return false;
+        };
+        self.hosts
+            .iter()
+            .any(|own| host == *own || host.ends_with(&format!(".{own}")))
+    }
+
+    fn is_above_ceiling(&self, price: &PriceData) -> bool {
+        let exponent = Currency::Eur.minor_unit_exponent().0 as u32;
+        let ceiling = 10u64
+            .checked_pow(exponent)
+            .and_then(|factor| self.rules.price_ceiling.checked_mul(factor))
+            .unwrap_or(u64::MAX);
+        // a price too large to even be exchanged is above any ceiling
+        self.fx_rate
+            .exchange(price.currency.into(), Currency::Eur, price.amount.into())
+            .map_or(true, |amount| *amount > ceiling)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::ScrapeItem;
+    use crate::validation::{RejectReason, ScrapeItemValidator, ValidationRules};
+    use common::currency::data::CurrencyData;
+    use common::language::data::{LanguageData, LocalizedTextData};
+    use common::price::data::PriceData;
+    use item_data::item_state_data::ItemStateData;
+    use url::Url;
+
+    fn mk_scrape_item(shops_item_id: &str) -> ScrapeItem {
+        ScrapeItem {
+            shop_id: "shop".into(),
+            shops_item_id: shops_item_id.into(),
+            shop_name: "Shop".to_string(),
+            native_title: LocalizedTextData::new("Steel helmet M35", LanguageData::De),
+            other_title: Default::default(),
+            native_description: None,
+            other_description: Default::default(),
+            price: Some(PriceData::new(CurrencyData::Eur, 123450)),
+            state: ItemStateData::Available,
+            url: Url::parse("https://www.shop.example.com/item/1").unwrap(),
+            images: vec![Url::parse("https://img.shop.example.com/1.jpg").unwrap()],
+        }
+    }
+
+    fn mk_validator() -> ScrapeItemValidator {
+        ScrapeItemValidator::new(
+            ValidationRules::default(),
+            vec!["www.shop.example.com".to_string()],
+        )
+    }
+
+    #[test]
+    fn should_accept_valid_item() {
+        let scrape_item = mk_scrape_item("1");
+
+        let actual = mk_validator().validate(scrape_item.clone());
+
+        assert_eq!(Ok(scrape_item), actual);
+    }
+
+    #[rstest::rstest]
+    #[case::empty_title(
+        |item: &mut ScrapeItem| item.native_title.text = "  ".to_string(),
+        RejectReason::EmptyTitle
+    )]
+    #[case::boilerplate_title(
+        |item: &mut ScrapeItem| item.native_title.text = " SOLD ".to_string(),
+        RejectReason::BoilerplateTitle("SOLD".to_string())
+    )]
+    #[case::foreign_url(
+        |item: &mut ScrapeItem| item.url = Url::parse("https://evil.example.com/1").unwrap(),
+        RejectReason::ForeignUrlHost(Some("evil.example.com".to_string()))
+    )]
+    #[case::foreign_image(
+        |item: &mut ScrapeItem| item.images.push(Url::parse("https://ads.example.net/banner.gif").unwrap()),
+        RejectReason::ForeignImageHost(Some("ads.example.net".to_string()))
+    )]
+    #[case::zero_price(
+        |item: &mut ScrapeItem| item.price = Some(PriceData::new(CurrencyData::Eur, 0)),
+        RejectReason::ZeroPrice
+    )]
+    #[case::price_above_ceiling(
+        |item: &mut ScrapeItem| item.price = Some(PriceData::new(CurrencyData::Eur, 100_000_001)),
+        RejectReason::PriceAboveCeiling(PriceData::new(CurrencyData::Eur, 100_000_001))
+    )]
+    #[case::price_above_ceiling_when_exchanged(
+        |item: &mut ScrapeItem| item.price = Some(PriceData::new(CurrencyData::Gbp, 100_000_000)),
+        RejectReason::PriceAboveCeiling(PriceData::new(CurrencyData::Gbp, 100_000_000))
+    )]
+    #[case::price_above_ceiling_without_minor_units(
+        |item: &mut ScrapeItem| item.price = Some(PriceData::new(CurrencyData::Jpy, 500_000_000)),
+        RejectReason::PriceAboveCeiling(PriceData::new(CurrencyData::Jpy, 500_000_000))
+    )]
+    fn should_reject_item_with_reason(
+        #[case] malform: fn(&mut ScrapeItem),
+        #[case] expected: RejectReason,
+    ) {
+        let mut scrape_item = mk_scrape_item("1");
+        malform(&mut scrape_item);
+
+        let actual = mk_validator().validate(scrape_item).unwrap_err();
+
+        assert_eq!(vec![expected], actual.reasons);
+    }
+
+    #[rstest::rstest]
+    #[case::eur(PriceData::new(CurrencyData::Eur, 100_000_000))]
+    #[case::jpy(PriceData::new(CurrencyData::Jpy, 5_000_000))]
+    #[case::jpy_above_ceiling_in_own_major_units(PriceData::new(CurrencyData::Jpy, 50_000_000))]
+    fn should_accept_price_below_ceiling_in_eur(#[case] price: PriceData) {
+        let mut scrape_item = mk_scrape_item("1");
+        scrape_item.price = Some(price);
+
+        let actual = mk_validator().validate(scrape_item);
+
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn should_reject_duplicate_shops_item_id() {
+        let mut validator = mk_validator();
+
+        assert!(validator.validate(mk_scrape_item("1")).is_ok());
+        assert!(validator.validate(mk_scrape_item("2")).is_ok());
+        let actual = validator.validate(mk_scrape_item("1")).unwrap_err();
+
+        assert_eq!(vec![RejectReason::DuplicateShopsItemId], actual.reasons);
+    }
+
+    #[test]
+    fn should_not_check_hosts_when_none_given() {
+        let mut scrape_item = mk_scrape_item("1");
+        scrape_item.url = Url::parse("https://anywhere.example.org/1").unwrap();
+
+        let actual =
+            ScrapeItemValidator::new(ValidationRules::default(), vec![]).validate(scrape_item);
+
+        assert!(actual.is_ok());
+    }
+
+    #[rstest::rstest]
+    #[case::no_known_items(0, 0, false)]
+    #[case::grown(120, 100, false)]
+    #[case::small_drop(60, 100, false)]
+    #[case::exactly_half(50, 100, false)]
+    #[case::large_drop(49, 100, true)]
+    #[case::nothing_scraped(0, 100, true)]
+    fn should_detect_item_count_drop(
+        #[case] scraped_count: usize,
+        #[case] known_count: usize,
+        #[case] expected: bool,
+    ) {
+        let actual = ValidationRules::default().is_item_count_drop(scraped_count, known_count);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest::rstest]
+    #[case::no_failures(0, 100, false)]
+    #[case::few_failures(10, 100, false)]
+    #[case::many_failures(11, 100, true)]
+    #[case::nothing_scraped(0, 0, false)]
+    fn should_detect_exceeded_failure_share(
+        #[case] failures: usize,
+        #[case] scraped_count: usize,
+        #[case] expected: bool,
+    ) {
+        let actual = ValidationRules::default().is_failure_share_exceeded(failures, scraped_count);
+
+        assert_eq!(expected, actual);
+    }
+}
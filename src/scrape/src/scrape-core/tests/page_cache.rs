@@ -0,0 +1,61 @@
+use common::shop_id::ShopId;
+use item_dynamodb::repository::{ItemDynamoDbRepository, ItemDynamoDbRepositoryImpl};
+use scrape_core::page_cache::{PageCacheRepository, PageCacheRepositoryImpl, PageValidators};
+use std::collections::HashMap;
+use test_api::*;
+
+fn mk_pages(n: usize) -> HashMap<String, PageValidators> {
+    (1..=n)
+        .map(|i| {
+            (
+                format!("https://shop.example.com/shop.php?pg={i}"),
+                PageValidators {
+                    etag: Some(format!("\"etag-{i}\"")),
+                    last_modified: (i % 2 == 0)
+                        .then(|| "Wed, 01 Jan 2020 12:00:00 GMT".to_string()),
+                    content_hash: PageValidators::content_hash(&format!("<html>{i}</html>")),
+                    shops_item_ids: (1..=i).map(|j| format!("{i}-{j}").into()).collect(),
+                },
+            )
+        })
+        .collect()
+}
+
+#[rstest::rstest]
+#[test_attr(apply(test))]
+#[case::one(1)]
+#[case::onehundred(100)]
+#[localstack_test(services = [DynamoDB()])]
+async fn should_save_and_load_pages(#[case] n: usize) {
+    let repository = PageCacheRepositoryImpl::new(get_dynamodb_client().await, "table_1");
+    let shop_id = ShopId::new();
+    let other_shop_id = ShopId::new();
+    let expected = mk_pages(n);
+
+    repository
+        .save_pages(&shop_id, expected.clone())
+        .await
+        .unwrap();
+    repository
+        .save_pages(&other_shop_id, mk_pages(3))
+        .await
+        .unwrap();
+    let actual = repository.load_pages(&shop_id).await.unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[localstack_test(services = [DynamoDB()])]
+async fn should_not_include_pages_in_shop_universe() {
+    let repository = PageCacheRepositoryImpl::new(get_dynamodb_client().await, "table_1");
+    let item_repository = ItemDynamoDbRepositoryImpl::new(get_dynamodb_client().await, "table_1");
+    let shop_id = ShopId::new();
+
+    repository.save_pages(&shop_id, mk_pages(5)).await.unwrap();
+    let actual = item_repository
+        .query_item_hashes(&shop_id, true)
+        .await
+        .unwrap();
+
+    assert!(actual.is_empty());
+}
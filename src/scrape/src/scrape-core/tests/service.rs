@@ -7,14 +7,14 @@ use common::{
 };
 use item_core::hash::ItemHash;
 use item_data::item_state_data::ItemStateData;
-use item_dynamodb::item_record::ItemRecord;
+use item_dynamodb::item_record::{ItemRecord, mk_updated_nanos};
 use item_dynamodb::item_state_record::ItemStateRecord;
 use item_dynamodb::repository::{ItemDynamoDbRepository, ItemDynamoDbRepositoryImpl};
 use scrape_core::{
     data::ScrapeItem,
-    service::{PublishScrapeItemService, PublishScrapeItemsImpl},
+    service::{PublishRemovedItemsError, PublishScrapeItemService, PublishScrapeItemsImpl},
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use test_api::*;
 use time::macros::datetime;
 use url::Url;
@@ -76,9 +76,13 @@ fn mk_item_record(id: usize, shop_id: &ShopId) -> ItemRecord {
         title_native: TextRecord::new("Boopsie whoop", LanguageRecord::En),
         title_de: None,
         title_en: None,
+        title_fr: None,
+        title_es: None,
         description_native: None,
         description_de: None,
         description_en: None,
+        description_fr: None,
+        description_es: None,
         price_native: None,
         price_eur: None,
         price_usd: None,
@@ -86,12 +90,22 @@ fn mk_item_record(id: usize, shop_id: &ShopId) -> ItemRecord {
         price_aud: None,
         price_cad: None,
         price_nzd: None,
+        price_chf: None,
+        price_sek: None,
+        price_dkk: None,
+        price_nok: None,
+        price_pln: None,
+        price_czk: None,
+        price_jpy: None,
+        fx_rate_date: None,
+        repriced: None,
         state: ItemStateRecord::Listed,
         url: Url::parse(&format!("https://example.com/{id}")).unwrap(),
         images: vec![],
         hash: ItemHash::new(&None, &ItemState::Listed),
         created: datetime!(2007 - 12 - 24 18:21 UTC),
         updated: datetime!(2007 - 12 - 24 18:21 UTC),
+        updated_nanos: mk_updated_nanos(&(datetime!(2007 - 12 - 24 18:21 UTC))),
     }
 }
 
@@ -112,14 +126,15 @@ async fn should_publish_scrape_items_for_create_for_single_shop_id(#[case] n: us
         sqs_client: get_sqs_client().await,
         sqs_create_url: CREATE_ITEM_SQS.queue_url(),
         sqs_update_url: UPDATE_ITEM_SQS.queue_url(),
+        removal_threshold: Default::default(),
     };
     let shop_id = ShopId::new();
     let scrape_items = (1..=n)
         .map(|i| mk_scrape_item(i, &shop_id))
         .collect::<Vec<_>>();
 
-    let publish_res = service.publish_scrape_items(scrape_items).await;
-    assert!(publish_res.is_ok());
+    let summary = service.publish_scrape_items(scrape_items).await;
+    assert!(summary.failures.is_empty());
 
     let mut actual_count: usize = 0;
     loop {
@@ -158,6 +173,7 @@ async fn should_publish_scrape_items_for_create_for_multiple_shop_ids(#[case] n:
         sqs_client: get_sqs_client().await,
         sqs_create_url: CREATE_ITEM_SQS.queue_url(),
         sqs_update_url: UPDATE_ITEM_SQS.queue_url(),
+        removal_threshold: Default::default(),
     };
     let shop_ids = HashMap::from([
         (0, ShopId::new()),
@@ -174,8 +190,8 @@ async fn should_publish_scrape_items_for_create_for_multiple_shop_ids(#[case] n:
         .map(|i| mk_scrape_item(i, shop_ids.get(&(i % 9)).unwrap()))
         .collect::<Vec<_>>();
 
-    let publish_res = service.publish_scrape_items(scrape_items).await;
-    assert!(publish_res.is_ok());
+    let summary = service.publish_scrape_items(scrape_items).await;
+    assert!(summary.failures.is_empty());
 
     let mut actual_count: usize = 0;
     loop {
@@ -214,6 +230,7 @@ async fn should_publish_scrape_items_for_update_for_single_shop_id(#[case] n: us
         sqs_client: get_sqs_client().await,
         sqs_create_url: CREATE_ITEM_SQS.queue_url(),
         sqs_update_url: UPDATE_ITEM_SQS.queue_url(),
+        removal_threshold: Default::default(),
     };
     let dynamodb_write_repository =
         &ItemDynamoDbRepositoryImpl::new(get_dynamodb_client().await, "table_1");
@@ -232,8 +249,8 @@ async fn should_publish_scrape_items_for_update_for_single_shop_id(#[case] n: us
         .map(|i| mk_scrape_item(i, &shop_id))
         .collect::<Vec<_>>();
 
-    let publish_res = service.publish_scrape_items(scrape_items).await;
-    assert!(publish_res.is_ok());
+    let summary = service.publish_scrape_items(scrape_items).await;
+    assert!(summary.failures.is_empty());
 
     // Verify Queue-Content
     let mut actual_count: usize = 0;
@@ -273,6 +290,7 @@ async fn should_publish_scrape_items_for_update_for_multiple_shop_ids(#[case] n:
         sqs_client: get_sqs_client().await,
         sqs_create_url: CREATE_ITEM_SQS.queue_url(),
         sqs_update_url: UPDATE_ITEM_SQS.queue_url(),
+        removal_threshold: Default::default(),
     };
     let dynamodb_write_repository =
         &ItemDynamoDbRepositoryImpl::new(get_dynamodb_client().await, "table_1");
@@ -303,8 +321,8 @@ async fn should_publish_scrape_items_for_update_for_multiple_shop_ids(#[case] n:
         .map(|i| mk_scrape_item(i, shop_ids.get(&(i % 9)).unwrap()))
         .collect::<Vec<_>>();
 
-    let publish_res = service.publish_scrape_items(scrape_items).await;
-    assert!(publish_res.is_ok());
+    let summary = service.publish_scrape_items(scrape_items).await;
+    assert!(summary.failures.is_empty());
 
     // Verify Queue-Content
     let mut actual_count: usize = 0;
@@ -346,6 +364,7 @@ async fn should_publish_scrape_items_for_downstream_command_mix_for_single_shop_
         sqs_client: get_sqs_client().await,
         sqs_create_url: CREATE_ITEM_SQS.queue_url(),
         sqs_update_url: UPDATE_ITEM_SQS.queue_url(),
+        removal_threshold: Default::default(),
     };
     let dynamodb_write_repository =
         &ItemDynamoDbRepositoryImpl::new(get_dynamodb_client().await, "table_1");
@@ -368,8 +387,8 @@ async fn should_publish_scrape_items_for_downstream_command_mix_for_single_shop_
         .map(|i| mk_scrape_item(i, &shop_id))
         .collect::<Vec<_>>();
 
-    let publish_res = service.publish_scrape_items(scrape_items).await;
-    assert!(publish_res.is_ok());
+    let summary = service.publish_scrape_items(scrape_items).await;
+    assert!(summary.failures.is_empty());
 
     // Verify Queue-Contents
     let mut actual_count_create: usize = 0;
@@ -408,6 +427,9 @@ async fn should_publish_scrape_items_for_downstream_command_mix_for_single_shop_
 
     assert_eq!(n / 3, actual_count_update);
     assert_eq!(n, actual_count_create + actual_count_update);
+    assert_eq!(actual_count_create, summary.created);
+    assert_eq!(actual_count_update, summary.updated);
+    assert_eq!(0, summary.skipped);
 }
 
 #[rstest::rstest]
@@ -429,6 +451,7 @@ async fn should_publish_scrape_items_for_downstream_command_mix_for_multiple_sho
         sqs_client: get_sqs_client().await,
         sqs_create_url: CREATE_ITEM_SQS.queue_url(),
         sqs_update_url: UPDATE_ITEM_SQS.queue_url(),
+        removal_threshold: Default::default(),
     };
     let dynamodb_write_repository =
         &ItemDynamoDbRepositoryImpl::new(get_dynamodb_client().await, "table_1");
@@ -461,8 +484,8 @@ async fn should_publish_scrape_items_for_downstream_command_mix_for_multiple_sho
         .map(|i| mk_scrape_item(i, shop_ids.get(&(i % 9)).unwrap()))
         .collect::<Vec<_>>();
 
-    let publish_res = service.publish_scrape_items(scrape_items).await;
-    assert!(publish_res.is_ok());
+    let summary = service.publish_scrape_items(scrape_items).await;
+    assert!(summary.failures.is_empty());
 
     // Verify Queue-Contents
     let mut actual_count_create: usize = 0;
@@ -502,3 +525,163 @@ async fn should_publish_scrape_items_for_downstream_command_mix_for_multiple_sho
     assert_eq!(n / 3, actual_count_update);
     assert_eq!(n, actual_count_create + actual_count_update);
 }
+
+#[rstest::rstest]
+#[test_attr(apply(test))]
+#[case::none_removed(100, 100)]
+#[case::some_removed(100, 90)]
+#[case::min_count_removed(10, 5)]
+#[localstack_test(services = [DynamoDB(), UPDATE_ITEM_SQS])]
+async fn should_publish_removed_items_for_unseen_items(
+    #[case] n: usize,
+    #[case] seen_count: usize,
+) {
+    let service = PublishScrapeItemsImpl {
+        dynamodb_repository: &ItemDynamoDbRepositoryImpl::new(
+            get_dynamodb_client().await,
+            "table_1",
+        ),
+        sqs_client: get_sqs_client().await,
+        sqs_create_url: CREATE_ITEM_SQS.queue_url(),
+        sqs_update_url: UPDATE_ITEM_SQS.queue_url(),
+        removal_threshold: Default::default(),
+    };
+    let dynamodb_write_repository =
+        &ItemDynamoDbRepositoryImpl::new(get_dynamodb_client().await, "table_1");
+
+    // Simulate materialized view
+    let shop_id = ShopId::new();
+    for batch in Batch::<_, 25>::chunked_from((1..=n).map(|i| mk_item_record(i, &shop_id))) {
+        dynamodb_write_repository
+            .put_item_records(batch)
+            .await
+            .unwrap();
+    }
+
+    let seen = (1..=seen_count)
+        .map(|i| i.to_string().into())
+        .collect::<HashSet<_>>();
+    let on_offer = service.query_on_offer_items(&shop_id, &seen).await.unwrap();
+    assert_eq!(n, on_offer.count);
+    let publish_res = service
+        .publish_removed_items(&shop_id, &seen, on_offer)
+        .await;
+    assert_eq!(n - seen_count, publish_res.unwrap());
+
+    // Verify Queue-Content
+    let mut actual_count: usize = 0;
+    loop {
+        let received = service
+            .sqs_client
+            .receive_message()
+            .queue_url(UPDATE_ITEM_SQS.queue_url())
+            .max_number_of_messages(10)
+            .send()
+            .await
+            .unwrap();
+        if received.messages().is_empty() {
+            break;
+        } else {
+            actual_count += received.messages().len();
+        }
+    }
+
+    assert_eq!(n - seen_count, actual_count)
+}
+
+#[localstack_test(services = [DynamoDB(), UPDATE_ITEM_SQS])]
+async fn should_query_on_offer_items_without_sold_and_removed_ones() {
+    let service = PublishScrapeItemsImpl {
+        dynamodb_repository: &ItemDynamoDbRepositoryImpl::new(
+            get_dynamodb_client().await,
+            "table_1",
+        ),
+        sqs_client: get_sqs_client().await,
+        sqs_create_url: CREATE_ITEM_SQS.queue_url(),
+        sqs_update_url: UPDATE_ITEM_SQS.queue_url(),
+        removal_threshold: Default::default(),
+    };
+    let dynamodb_write_repository =
+        &ItemDynamoDbRepositoryImpl::new(get_dynamodb_client().await, "table_1");
+
+    // Simulate materialized view
+    let shop_id = ShopId::new();
+    let item_records = (1..=150).map(|i| {
+        let mut item_record = mk_item_record(i, &shop_id);
+        item_record.state = match i {
+            ..=100 => ItemStateRecord::Listed,
+            101..=120 => ItemStateRecord::Sold,
+            _ => ItemStateRecord::Removed,
+        };
+        item_record
+    });
+    for batch in Batch::<_, 25>::chunked_from(item_records) {
+        dynamodb_write_repository
+            .put_item_records(batch)
+            .await
+            .unwrap();
+    }
+
+    let seen = (1..=90)
+        .map(|i| i.to_string().into())
+        .collect::<HashSet<_>>();
+    let actual = service.query_on_offer_items(&shop_id, &seen).await.unwrap();
+
+    assert_eq!(100, actual.count);
+    let mut actual_unseen = actual
+        .unseen
+        .into_iter()
+        .map(|item_key| item_key.shops_item_id.to_string())
+        .collect::<Vec<_>>();
+    actual_unseen.sort_by_key(|shops_item_id| shops_item_id.parse::<usize>().unwrap());
+    let expected_unseen = (91..=100).map(|i| i.to_string()).collect::<Vec<_>>();
+    assert_eq!(expected_unseen, actual_unseen);
+}
+
+#[localstack_test(services = [DynamoDB(), UPDATE_ITEM_SQS])]
+async fn should_not_publish_removed_items_when_exceeding_removal_threshold() {
+    let service = PublishScrapeItemsImpl {
+        dynamodb_repository: &ItemDynamoDbRepositoryImpl::new(
+            get_dynamodb_client().await,
+            "table_1",
+        ),
+        sqs_client: get_sqs_client().await,
+        sqs_create_url: CREATE_ITEM_SQS.queue_url(),
+        sqs_update_url: UPDATE_ITEM_SQS.queue_url(),
+        removal_threshold: Default::default(),
+    };
+    let dynamodb_write_repository =
+        &ItemDynamoDbRepositoryImpl::new(get_dynamodb_client().await, "table_1");
+
+    // Simulate materialized view
+    let shop_id = ShopId::new();
+    for batch in Batch::<_, 25>::chunked_from((1..=100).map(|i| mk_item_record(i, &shop_id))) {
+        dynamodb_write_repository
+            .put_item_records(batch)
+            .await
+            .unwrap();
+    }
+
+    let seen = (1..=10)
+        .map(|i| i.to_string().into())
+        .collect::<HashSet<_>>();
+    let on_offer = service.query_on_offer_items(&shop_id, &seen).await.unwrap();
+    assert_eq!(100, on_offer.count);
+    let publish_res = service
+        .publish_removed_items(&shop_id, &seen, on_offer)
+        .await;
+    assert!(matches!(
+        publish_res,
+        Err(PublishRemovedItemsError::RemovalThresholdExceeded { .. })
+    ));
+
+    let received = service
+        .sqs_client
+        .receive_message()
+        .queue_url(UPDATE_ITEM_SQS.queue_url())
+        .max_number_of_messages(10)
+        .send()
+        .await
+        .unwrap();
+    assert!(received.messages().is_empty());
+}
@@ -0,0 +1,818 @@
+use common::shop_id::ShopId;
+use common::shops_item_id::ShopsItemId;
+use futures::StreamExt;
+use item_core::hash::ItemHash;
+use lambda_runtime::LambdaEvent;
+use scrape_core::data::ScrapeItem;
+use scrape_core::page_cache::{ConditionalRequests, PageCache, PageCacheRepository};
+use scrape_core::registry::ScraperRegistry;
+use scrape_core::robots::{Politeness, with_crawl_delay};
+use scrape_core::service::PublishScrapeItemService;
+use scrape_core::spec::{ScrapeEvent, Scraper, ScraperConfig};
+use scrape_core::validation::{ScrapeItemValidator, ValidationRules};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{error, info, warn};
+
+/// Number of ScrapeItems published at once.
+///
+/// Each chunk queries the hashes of the whole shop, so it shouldn't be too small.
+pub const CHUNK_SIZE: usize = 200;
+
+/// Payload of the scheduled invocation, e.g. the constant input of an EventBridge rule.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrapeShopEvent {
+    pub shop_id: ShopId,
+
+    /// Scrapes all pages unconditionally and without stopping early, e.g. for a daily rule
+    /// catching changes only visible on detail-pages.
+    #[serde(default)]
+    pub full: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ScrapeRunSummary {
+    pub scraped: usize,
+    pub created: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub failures: usize,
+
+    /// Quarantined by the [`ScrapeItemValidator`] instead of being published.
+    pub rejected: usize,
+    pub removed: usize,
+    pub complete: bool,
+
+    /// Fewer items were scraped than plausible given the shop's known items,
+    /// see [`ValidationRules::max_item_count_drop`].
+    pub item_count_dropped: bool,
+
+    /// Pages unchanged since the previous scrape, whose items weren't scraped again.
+    pub unchanged_pages: usize,
+
+    /// Stopped after encountering only known items, see [`ScraperConfig::stop_after_known_items`].
+    pub stopped_early: bool,
+}
+
+#[tracing::instrument(
+    skip(registry, client, validation_rules, service, page_cache_repository, event),
+    fields(requestId = %event.context.request_id, shopId = %event.payload.shop_id, full = event.payload.full)
+)]
+pub async fn handler<Client: Politeness + ConditionalRequests + Send + Sync>(
+    registry: &ScraperRegistry<Client>,
+    client: &Client,
+    scraper_config: ScraperConfig,
+    validation_rules: ValidationRules,
+    service: &(impl PublishScrapeItemService + Sync),
+    page_cache_repository: &(impl PageCacheRepository + Sync),
+    event: LambdaEvent<ScrapeShopEvent>,
+) -> Result<(), lambda_runtime::Error> {
+    let shop_id = event.payload.shop_id;
+    info!("Handler invoked.");
+
+    let scraper = match registry.get(&shop_id) {
+        Some(scraper) => scraper,
+        None => {
+            error!("There exists no Scraper for the shop.");
+            return Err(format!("There exists no Scraper for shop '{shop_id}'.").into());
+        }
+    };
+
+    let (previous_pages, scraper_config) = if event.payload.full {
+        let scraper_config = ScraperConfig {
+            stop_after_known_items: None,
+            ..scraper_config
+        };
+        (HashMap::new(), scraper_config)
+    } else {
+        let previous_pages = page_cache_repository
+            .load_pages(&shop_id)
+            .await
+            .unwrap_or_else(|err| {
+                warn!(error = %err, "Failed loading PageCache. Scraping all pages unconditionally.");
+                HashMap::new()
+            });
+        (previous_pages, scraper_config)
+    };
+    let page_cache = Arc::new(PageCache::new(previous_pages));
+    let client = client.with_page_cache(page_cache.clone());
+
+    let summary = scrape_shop(scraper, &client, scraper_config, validation_rules, service).await;
+    info!(
+        scraped = summary.scraped,
+        created = summary.created,
+        updated = summary.updated,
+        skipped = summary.skipped,
+        failures = summary.failures,
+        rejected = summary.rejected,
+        removed = summary.removed,
+        complete = summary.complete,
+        itemCountDropped = summary.item_count_dropped,
+        unchangedPages = summary.unchanged_pages,
+        stoppedEarly = summary.stopped_early,
+        "Handler finished."
+    );
+
+    // Unchanged pages aren't published again, so their failed items would be lost
+    if summary.failures > 0 {
+        warn!("Skipping saving PageCache because publishing failed.");
+    } else if let Err(err) = page_cache_repository
+        .save_pages(&shop_id, page_cache.take_scraped())
+        .await
+    {
+        error!(error = %err, "Failed saving PageCache.");
+    }
+
+    if !summary.complete {
+        error!("Scrape of the shop is incomplete.");
+        return Err(format!("Scrape of shop '{shop_id}' is incomplete.").into());
+    }
+    if validation_rules.is_failure_share_exceeded(summary.failures, summary.scraped) {
+        error!("Publishing failed for too many items.");
+        return Err(format!(
+            "Publishing failed for {} of {} items of shop '{shop_id}'.",
+            summary.failures, summary.scraped
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Streams the shop's valid ScrapeItems in chunks of [`CHUNK_SIZE`] into the service.
+/// Invalid ones are quarantined, i.e. logged with the reasons of their rejection.
+///
+/// Items no longer seen are only removed if the scrape completed without errors, didn't stop
+/// early and the item count didn't drop implausibly. Quarantined items and the items of
+/// unchanged pages count as seen.
+///
+/// The page delay is raised to the `Crawl-delay` of the shop's robots.txt.
+pub async fn scrape_shop<Client: Politeness + Send + Sync>(
+    scraper: &dyn Scraper<Client>,
+    client: &Client,
+    scraper_config: ScraperConfig,
+    validation_rules: ValidationRules,
+    service: &(impl PublishScrapeItemService + Sync),
+) -> ScrapeRunSummary {
+    let shop_id = ShopId::from(scraper.shop_id_str());
+    let mut summary = ScrapeRunSummary {
+        complete: true,
+        ..Default::default()
+    };
+    let mut seen = HashSet::new();
+    let hosts = scraper.hosts();
+    let scraper_config = with_crawl_delay(client, &hosts, scraper_config).await;
+    let mut validator = ScrapeItemValidator::new(validation_rules, hosts);
+    let mut known_streak = match scraper_config.stop_after_known_items {
+        Some(max_streak) if scraper.lists_newest_first() => {
+            match service.query_known_hashes(&shop_id).await {
+                Ok(known_hashes) => Some(KnownStreak::new(known_hashes, max_streak)),
+                Err(err) => {
+                    warn!(error = %err, "Failed querying known hashes. Scraping without stopping early.");
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+
+    let mut scrape_items = Vec::with_capacity(CHUNK_SIZE);
+    let mut stream = scraper.scrape(client, scraper_config);
+    while let Some(scrape_event_res) = stream.next().await {
+        match scrape_event_res {
+            Ok(ScrapeEvent::Item(scrape_item)) => {
+                summary.scraped += 1;
+                seen.insert(scrape_item.shops_item_id.clone());
+                if let Some(known_streak) = known_streak.as_mut() {
+                    known_streak.observe(&scrape_item);
+                }
+                match validator.validate(scrape_item) {
+                    Ok(scrape_item) => scrape_items.push(scrape_item),
+                    Err(rejected) => {
+                        warn!(
+                            shopsItemId = %rejected.scrape_item.shops_item_id,
+                            reasons = rejected
+                                .reasons
+                                .iter()
+                                .map(ToString::to_string)
+                                .collect::<Vec<_>>()
+                                .join("; "),
+                            payload = ?rejected.scrape_item,
+                            "Quarantined invalid ScrapeItem."
+                        );
+                        summary.rejected += 1;
+                    }
+                }
+            }
+            Ok(ScrapeEvent::PageUnchanged { shops_item_ids, .. }) => {
+                summary.unchanged_pages += 1;
+                if let Some(known_streak) = known_streak.as_mut() {
+                    known_streak.extend(shops_item_ids.len());
+                }
+                seen.extend(shops_item_ids);
+            }
+            Err(err) => {
+                error!(error = ?err, "Failed scraping shop.");
+                summary.complete = false;
+            }
+        }
+        if scrape_items.len() >= CHUNK_SIZE {
+            publish(service, std::mem::take(&mut scrape_items), &mut summary).await;
+        }
+        if known_streak.as_ref().is_some_and(KnownStreak::is_reached) {
+            info!(
+                seen = seen.len(),
+                "Stopping early because only known items remain."
+            );
+            summary.stopped_early = true;
+            break;
+        }
+    }
+    drop(stream);
+    if !scrape_items.is_empty() {
+        publish(service, scrape_items, &mut summary).await;
+    }
+
+    if !summary.complete {
+        warn!("Skipping removals because the scrape is incomplete.");
+        return summary;
+    }
+    if summary.stopped_early {
+        info!("Skipping removals because the scrape stopped early.");
+        return summary;
+    }
+    let on_offer = match service.query_on_offer_items(&shop_id, &seen).await {
+        Ok(on_offer) if validation_rules.is_item_count_drop(seen.len(), on_offer.count) => {
+            error!(
+                seen = seen.len(),
+                known = on_offer.count,
+                "Skipping removals because the item count dropped implausibly."
+            );
+            summary.item_count_dropped = true;
+            return summary;
+        }
+        Ok(on_offer) => on_offer,
+        Err(err) => {
+            error!(error = %err, "Failed querying items on offer. Skipping removals.");
+            return summary;
+        }
+    };
+    match service
+        .publish_removed_items(&shop_id, &seen, on_offer)
+        .await
+    {
+        Ok(removed_count) => summary.removed = removed_count,
+        Err(err) => error!(error = %err, "Failed publishing removed items."),
+    }
+
+    summary
+}
+
+async fn publish(
+    service: &(impl PublishScrapeItemService + Sync),
+    scrape_items: Vec<ScrapeItem>,
+    summary: &mut ScrapeRunSummary,
+) {
+    let published = service.publish_scrape_items(scrape_items).await;
+    summary.created += published.created;
+    summary.updated += published.updated;
+    summary.skipped += published.skipped;
+    summary.failures += published.failures.len();
+}
+
+/// Consecutive items that are known with an identical hash.
+struct KnownStreak {
+    known_hashes: HashMap<ShopsItemId, ItemHash>,
+    max_streak: usize,
+    streak: usize,
+}
+
+impl KnownStreak {
+    fn new(known_hashes: HashMap<ShopsItemId, ItemHash>, max_streak: usize) -> Self {
+        KnownStreak {
+            known_hashes,
+            max_streak,
+            streak: 0,
+        }
+    }
+
+    fn observe(&mut self, scrape_item: &ScrapeItem) {
+        if self.known_hashes.get(&scrape_item.shops_item_id) == Some(&scrape_item.hash()) {
+            self.streak += 1;
+        } else {
+            self.streak = 0;
+        }
+    }
+
+    /// Items of unchanged pages are known and unchanged by definition.
+    fn extend(&mut self, count: usize) {
+        self.streak += count;
+    }
+
+    fn is_reached(&self) -> bool {
+        self.streak >= self.max_streak
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CHUNK_SIZE, ScrapeRunSummary, ScrapeShopEvent, handler, scrape_shop};
+    use async_trait::async_trait;
+    use aws_sdk_dynamodb::error::SdkError;
+    use aws_sdk_dynamodb::operation::query::QueryError;
+    use common::has_key::HasKey;
+    use common::language::data::{LanguageData, LocalizedTextData};
+    use common::shop_id::ShopId;
+    use common::shops_item_id::ShopsItemId;
+    use item_core::hash::ItemHash;
+    use item_data::item_state_data::ItemStateData;
+    use lambda_runtime::{Context, LambdaEvent};
+    use scrape_core::data::ScrapeItem;
+    use scrape_core::page_cache::{
+        ConditionalRequests, PageCache, PageCacheError, PageCacheRepository, PageValidators,
+    };
+    use scrape_core::registry::ScraperRegistry;
+    use scrape_core::robots::Politeness;
+    use scrape_core::service::{
+        OnOfferItems, PublishRemovedItemsError, PublishScrapeItemService,
+        PublishScrapeItemsSummary, QueryOnOfferItemsError,
+    };
+    use scrape_core::spec::{ScrapeError, ScrapePage, Scraper, ScraperConfig};
+    use scrape_core::validation::ValidationRules;
+    use std::collections::{HashMap, HashSet};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use url::Url;
+
+    struct DummyClient;
+
+    #[async_trait]
+    impl Politeness for DummyClient {
+        async fn crawl_delay(&self, _: &str) -> Option<Duration> {
+            None
+        }
+    }
+
+    impl ConditionalRequests for DummyClient {
+        fn with_page_cache(&self, _: Arc<PageCache>) -> Self {
+            DummyClient
+        }
+    }
+
+    /// Scrapes `pages` pages of `page_size` items and fails on page `failing_page`.
+    /// Pages in `unchanged_pages` are unchanged since the previous scrape.
+    /// All items are on `https://foo.bar`.
+    #[derive(Default)]
+    struct DummyScraper {
+        pages: u32,
+        page_size: usize,
+        failing_page: Option<u32>,
+        unchanged_pages: Vec<u32>,
+        newest_first: bool,
+        hosts: Vec<String>,
+    }
+
+    impl DummyScraper {
+        fn mk_scrape_item(&self, page_num: u32, i: usize) -> ScrapeItem {
+            ScrapeItem {
+                shop_id: "dummy-id".into(),
+                shops_item_id: format!("{page_num}-{i}").into(),
+                shop_name: "dummy-name".to_string(),
+                native_title: LocalizedTextData {
+                    text: "boop".to_string(),
+                    language: LanguageData::De,
+                },
+                other_title: Default::default(),
+                native_description: None,
+                other_description: Default::default(),
+                price: None,
+                state: ItemStateData::Available,
+                url: Url::parse("https://foo.bar").unwrap(),
+                images: vec![],
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Scraper<DummyClient> for DummyScraper {
+        fn shop_id_str(&self) -> &'static str {
+            "dummy-id"
+        }
+
+        fn shop_name_str(&self) -> &'static str {
+            "dummy-name"
+        }
+
+        fn hosts(&self) -> Vec<String> {
+            self.hosts.clone()
+        }
+
+        fn lists_newest_first(&self) -> bool {
+            self.newest_first
+        }
+
+        async fn scrape_page(
+            &self,
+            _: &DummyClient,
+            _: ScraperConfig,
+            page_num: u32,
+        ) -> Result<ScrapePage, ScrapeError> {
+            if self.failing_page == Some(page_num) {
+                return Err(ScrapeError::ClientError("boom".into()));
+            }
+            if page_num > self.pages {
+                return Ok(ScrapePage::Items(vec![]));
+            }
+            let items = (0..self.page_size).map(|i| self.mk_scrape_item(page_num, i));
+            if self.unchanged_pages.contains(&page_num) {
+                return Ok(ScrapePage::Unchanged {
+                    url: format!("https://foo.bar?pg={page_num}"),
+                    shops_item_ids: items.map(|item| item.shops_item_id).collect(),
+                });
+            }
+            Ok(ScrapePage::Items(items.collect()))
+        }
+    }
+
+    /// Creates every even and skips every odd chunk, or fails all of them if `failing`.
+    #[derive(Default)]
+    struct DummyService {
+        failing: bool,
+        known_count: usize,
+        known_hashes: HashMap<ShopsItemId, ItemHash>,
+        published: Mutex<Vec<usize>>,
+        seen: Mutex<Option<HashSet<ShopsItemId>>>,
+    }
+
+    #[async_trait]
+    impl PublishScrapeItemService for DummyService {
+        async fn publish_scrape_items(
+            &self,
+            scrape_items: Vec<ScrapeItem>,
+        ) -> PublishScrapeItemsSummary {
+            let mut published = self.published.lock().unwrap();
+            let summary = if self.failing {
+                PublishScrapeItemsSummary {
+                    failures: scrape_items.iter().map(HasKey::key).collect(),
+                    ..Default::default()
+                }
+            } else if published.len().is_multiple_of(2) {
+                PublishScrapeItemsSummary {
+                    created: scrape_items.len(),
+                    ..Default::default()
+                }
+            } else {
+                PublishScrapeItemsSummary {
+                    skipped: scrape_items.len(),
+                    ..Default::default()
+                }
+            };
+            published.push(scrape_items.len());
+            summary
+        }
+
+        async fn query_on_offer_items(
+            &self,
+            _: &ShopId,
+            _: &HashSet<ShopsItemId>,
+        ) -> Result<OnOfferItems, QueryOnOfferItemsError> {
+            Ok(OnOfferItems {
+                count: self.known_count,
+                unseen: vec![],
+            })
+        }
+
+        async fn query_known_hashes(
+            &self,
+            _: &ShopId,
+        ) -> Result<HashMap<ShopsItemId, ItemHash>, Box<SdkError<QueryError>>> {
+            Ok(self.known_hashes.clone())
+        }
+
+        async fn publish_removed_items(
+            &self,
+            _: &ShopId,
+            seen: &HashSet<ShopsItemId>,
+            _: OnOfferItems,
+        ) -> Result<usize, PublishRemovedItemsError> {
+            *self.seen.lock().unwrap() = Some(seen.clone());
+            Ok(3)
+        }
+    }
+
+    #[derive(Default)]
+    struct DummyPageCacheRepository {
+        saved: Mutex<Option<HashMap<String, PageValidators>>>,
+    }
+
+    #[async_trait]
+    impl PageCacheRepository for DummyPageCacheRepository {
+        async fn load_pages(
+            &self,
+            _: &ShopId,
+        ) -> Result<HashMap<String, PageValidators>, PageCacheError> {
+            Ok(HashMap::new())
+        }
+
+        async fn save_pages(
+            &self,
+            _: &ShopId,
+            pages: HashMap<String, PageValidators>,
+        ) -> Result<(), PageCacheError> {
+            *self.saved.lock().unwrap() = Some(pages);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn should_publish_scraped_items_in_chunks() {
+        let scraper = DummyScraper {
+            pages: 5,
+            page_size: 90,
+            failing_page: None,
+            ..Default::default()
+        };
+        let service = DummyService::default();
+
+        let actual = scrape_shop(
+            &scraper,
+            &DummyClient,
+            ScraperConfig::default(),
+            ValidationRules::default(),
+            &service,
+        )
+        .await;
+
+        assert_eq!(
+            vec![CHUNK_SIZE, CHUNK_SIZE, 50],
+            *service.published.lock().unwrap()
+        );
+        assert_eq!(
+            ScrapeRunSummary {
+                scraped: 450,
+                created: 250,
+                updated: 0,
+                skipped: 200,
+                failures: 0,
+                rejected: 0,
+                removed: 3,
+                complete: true,
+                item_count_dropped: false,
+                unchanged_pages: 0,
+                stopped_early: false,
+            },
+            actual
+        );
+        assert_eq!(450, service.seen.lock().unwrap().as_ref().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn should_not_publish_removed_items_when_scrape_incomplete() {
+        let scraper = DummyScraper {
+            pages: 5,
+            page_size: 90,
+            failing_page: Some(4),
+            ..Default::default()
+        };
+        let service = DummyService::default();
+
+        let actual = scrape_shop(
+            &scraper,
+            &DummyClient,
+            ScraperConfig::default(),
+            ValidationRules::default(),
+            &service,
+        )
+        .await;
+
+        assert_eq!(270, actual.scraped);
+        assert_eq!(0, actual.removed);
+        assert!(!actual.complete);
+        assert!(service.seen.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn should_quarantine_invalid_items() {
+        let scraper = DummyScraper {
+            pages: 2,
+            page_size: 10,
+            hosts: vec!["other.host".to_string()],
+            ..Default::default()
+        };
+        let service = DummyService::default();
+
+        let actual = scrape_shop(
+            &scraper,
+            &DummyClient,
+            ScraperConfig::default(),
+            ValidationRules::default(),
+            &service,
+        )
+        .await;
+
+        assert_eq!(20, actual.scraped);
+        assert_eq!(20, actual.rejected);
+        assert!(service.published.lock().unwrap().is_empty());
+        assert_eq!(20, service.seen.lock().unwrap().as_ref().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn should_not_publish_removed_items_when_item_count_dropped() {
+        let scraper = DummyScraper {
+            pages: 1,
+            page_size: 40,
+            ..Default::default()
+        };
+        let service = DummyService {
+            known_count: 100,
+            ..Default::default()
+        };
+
+        let actual = scrape_shop(
+            &scraper,
+            &DummyClient,
+            ScraperConfig::default(),
+            ValidationRules::default(),
+            &service,
+        )
+        .await;
+
+        assert_eq!(40, actual.created);
+        assert_eq!(0, actual.removed);
+        assert!(actual.complete);
+        assert!(actual.item_count_dropped);
+        assert!(service.seen.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn should_count_items_of_unchanged_pages_as_seen() {
+        let scraper = DummyScraper {
+            pages: 5,
+            page_size: 90,
+            unchanged_pages: vec![2, 3],
+            ..Default::default()
+        };
+        let service = DummyService::default();
+
+        let actual = scrape_shop(
+            &scraper,
+            &DummyClient,
+            ScraperConfig::default(),
+            ValidationRules::default(),
+            &service,
+        )
+        .await;
+
+        assert_eq!(270, actual.scraped);
+        assert_eq!(2, actual.unchanged_pages);
+        assert!(actual.complete);
+        assert_eq!(3, actual.removed);
+        assert_eq!(450, service.seen.lock().unwrap().as_ref().unwrap().len());
+    }
+
+    #[rstest::rstest]
+    #[case::newest_first(true, 100, true)]
+    #[case::not_newest_first(false, 450, false)]
+    #[tokio::test]
+    async fn should_stop_early_when_only_known_items_remain(
+        #[case] newest_first: bool,
+        #[case] expected_scraped: usize,
+        #[case] expected_stopped_early: bool,
+    ) {
+        let scraper = DummyScraper {
+            pages: 5,
+            page_size: 90,
+            newest_first,
+            ..Default::default()
+        };
+        // Only the first page is new
+        let service = DummyService {
+            known_hashes: (2..=5)
+                .flat_map(|page_num| (0..90).map(move |i| (page_num, i)))
+                .map(|(page_num, i)| scraper.mk_scrape_item(page_num, i))
+                .map(|item| (item.shops_item_id.clone(), item.hash()))
+                .collect(),
+            ..Default::default()
+        };
+        let scraper_config = ScraperConfig {
+            stop_after_known_items: Some(10),
+            ..Default::default()
+        };
+
+        let actual = scrape_shop(
+            &scraper,
+            &DummyClient,
+            scraper_config,
+            ValidationRules::default(),
+            &service,
+        )
+        .await;
+
+        assert_eq!(expected_scraped, actual.scraped);
+        assert_eq!(expected_stopped_early, actual.stopped_early);
+        assert_eq!(
+            expected_stopped_early,
+            service.seen.lock().unwrap().is_none()
+        );
+    }
+
+    #[rstest::rstest]
+    #[case::registered("dummy-id", true)]
+    #[case::unknown("unknown-id", false)]
+    #[tokio::test]
+    async fn should_look_up_scraper_by_shop_id(#[case] shop_id: &str, #[case] expected_ok: bool) {
+        let mut registry = ScraperRegistry::default();
+        registry.register(DummyScraper {
+            pages: 1,
+            page_size: 10,
+            failing_page: None,
+            ..Default::default()
+        });
+        let service = DummyService::default();
+        let page_cache_repository = DummyPageCacheRepository::default();
+        let event = LambdaEvent::new(
+            ScrapeShopEvent {
+                shop_id: shop_id.into(),
+                full: false,
+            },
+            Context::default(),
+        );
+
+        let actual = handler(
+            &registry,
+            &DummyClient,
+            ScraperConfig::default(),
+            ValidationRules::default(),
+            &service,
+            &page_cache_repository,
+            event,
+        )
+        .await;
+
+        assert_eq!(expected_ok, actual.is_ok());
+        assert_eq!(expected_ok, !service.published.lock().unwrap().is_empty());
+        assert_eq!(
+            expected_ok,
+            page_cache_repository.saved.lock().unwrap().is_some()
+        );
+    }
+
+    #[rstest::rstest]
+    #[case::complete(None, false, true)]
+    #[case::incomplete(Some(2), false, false)]
+    #[case::publishing_failed(None, true, false)]
+    #[tokio::test]
+    async fn should_fail_when_scrape_incomplete_or_publishing_failed(
+        #[case] failing_page: Option<u32>,
+        #[case] failing_service: bool,
+        #[case] expected_ok: bool,
+    ) {
+        let mut registry = ScraperRegistry::default();
+        registry.register(DummyScraper {
+            pages: 3,
+            page_size: 10,
+            failing_page,
+            ..Default::default()
+        });
+        let service = DummyService {
+            failing: failing_service,
+            ..Default::default()
+        };
+        let page_cache_repository = DummyPageCacheRepository::default();
+        let event = LambdaEvent::new(
+            ScrapeShopEvent {
+                shop_id: "dummy-id".into(),
+                full: false,
+            },
+            Context::default(),
+        );
+
+        let actual = handler(
+            &registry,
+            &DummyClient,
+            ScraperConfig::default(),
+            ValidationRules::default(),
+            &service,
+            &page_cache_repository,
+            event,
+        )
+        .await;
+
+        assert_eq!(expected_ok, actual.is_ok());
+        assert_eq!(
+            !failing_service,
+            page_cache_repository.saved.lock().unwrap().is_some()
+        );
+    }
+
+    #[rstest::rstest]
+    #[case::incremental(r#"{"shopId":"dummy-id"}"#, false)]
+    #[case::full(r#"{"shopId":"dummy-id","full":true}"#, true)]
+    fn should_deserialize_scrape_shop_event(#[case] json: &str, #[case] expected_full: bool) {
+        let actual: ScrapeShopEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(ShopId::from("dummy-id"), actual.shop_id);
+        assert_eq!(expected_full, actual.full);
+    }
+}
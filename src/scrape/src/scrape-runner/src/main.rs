@@ -0,0 +1,72 @@
+use aws_config::BehaviorVersion;
+use item_dynamodb::repository::ItemDynamoDbRepositoryImpl;
+use lambda_runtime::{Error, LambdaEvent, run, service_fn};
+use scrape_core::page_cache::PageCacheRepositoryImpl;
+use scrape_core::rate_limit::RateLimit;
+use scrape_core::retry::RetryPolicy;
+use scrape_core::service::PublishScrapeItemsImpl;
+use scrape_core::spec::ScraperConfig;
+use scrape_core::validation::ValidationRules;
+use scrape_runner::{ScrapeShopEvent, handler};
+use scrape_static::scraper_registry;
+use std::time::Duration;
+use tracing::info;
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    tracing_subscriber::fmt()
+        .json()
+        .with_max_level(tracing::Level::INFO)
+        .with_current_span(true)
+        .with_ansi(false)
+        .without_time()
+        .init();
+
+    let aws_config = aws_config::defaults(BehaviorVersion::v2025_08_07())
+        .load()
+        .await;
+
+    let table_name = std::env::var("DYNAMODB_TABLE_NAME")?;
+    let sqs_create_url = std::env::var("SQS_CREATE_URL")?;
+    let sqs_update_url = std::env::var("SQS_UPDATE_URL")?;
+    let dynamodb_client = aws_sdk_dynamodb::Client::new(&aws_config);
+    let sqs_client = aws_sdk_sqs::Client::new(&aws_config);
+    let dynamodb_repository = ItemDynamoDbRepositoryImpl::new(&dynamodb_client, &table_name);
+    let service = PublishScrapeItemsImpl {
+        dynamodb_repository: &dynamodb_repository,
+        sqs_client: &sqs_client,
+        sqs_create_url,
+        sqs_update_url,
+        removal_threshold: Default::default(),
+    };
+    let page_cache_repository = PageCacheRepositoryImpl::new(&dynamodb_client, &table_name);
+    let http_client = scrape_static::http::Client::default()
+        .with_rate_limit(RateLimit::new(4, Duration::from_millis(500))?);
+    let registry = scraper_registry();
+    let scraper_config = ScraperConfig {
+        page_delay: Some(Duration::from_secs(1)),
+        detail_concurrency: Some(4),
+        retry_policy: RetryPolicy::default(),
+        stop_after_known_items: Some(50),
+    };
+
+    info!(
+        dynamoDbTableName = %table_name,
+        shopIds = ?registry.shop_ids().collect::<Vec<_>>(),
+        "Lambda cold start completed, client initialized."
+    );
+
+    run(service_fn(|event: LambdaEvent<ScrapeShopEvent>| async {
+        handler(
+            &registry,
+            &http_client,
+            scraper_config,
+            ValidationRules::default(),
+            &service,
+            &page_cache_repository,
+            event,
+        )
+        .await
+    }))
+    .await
+}
@@ -0,0 +1,347 @@
+use crate::http::{Client, get_text};
+use crate::json_ld::{JsonLdShop, extract_scrape_items};
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use reqwest::Url;
+use scrape_core::data::ScrapeItem;
+use scrape_core::discovery::{DiscoveredUrls, ItemDiscovery, scrape_discovered};
+use scrape_core::spec::{ScrapeError, ScrapeEvent, Scraper, ScraperConfig};
+use scraper::{Html, Selector};
+use serde::Deserialize;
+use tracing::warn;
+
+/// Specs of shops scraped by a [`CrawlScraper`], one JSON config file each.
+pub const SHOP_SPECS: &[&str] = &[];
+
+/// Spec of a shop whose items are discovered from its sitemaps or category pages,
+/// usually deserialized from a JSON config file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrawlScraperSpec {
+    #[serde(flatten)]
+    pub shop: JsonLdShop,
+
+    /// Sitemaps or sitemap indexes, e.g. `https://shop.example.com/sitemap.xml`.
+    #[serde(default)]
+    pub sitemap_urls: Vec<Url>,
+
+    /// Category pages listing items, paginated by `next_page_selector`.
+    #[serde(default)]
+    pub category_urls: Vec<Url>,
+
+    /// Only URLs starting with one of the prefixes are items, e.g. `https://shop.example.com/product/`.
+    /// Sitemaps usually list more than just items. All URLs are items if there are none.
+    #[serde(default)]
+    pub item_url_prefixes: Vec<String>,
+
+    /// Selects the links to items on a category page.
+    #[serde(default)]
+    pub item_link_selector: Option<String>,
+
+    /// Selects the link to the next page of a category page.
+    #[serde(default)]
+    pub next_page_selector: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CrawlScraperSpecError {
+    #[error("CrawlScraperSpec is malformed: {0}")]
+    Malformed(#[from] serde_json::Error),
+
+    #[error("CrawlScraperSpec contains invalid selector '{0}'.")]
+    InvalidSelector(String),
+
+    #[error("CrawlScraperSpec has neither sitemapUrls nor categoryUrls.")]
+    MissingSeeds,
+}
+
+/// Generic [`Scraper`] discovering a shop's items from its sitemaps or category pages,
+/// extracting each item from the JSON-LD `Product` of its page.
+#[derive(Debug)]
+pub struct CrawlScraper {
+    spec: CrawlScraperSpec,
+    item_link_selector: Option<Selector>,
+    next_page_selector: Option<Selector>,
+}
+
+fn parse_selector(selector: &str) -> Result<Selector, CrawlScraperSpecError> {
+    Selector::parse(selector)
+        .map_err(|_| CrawlScraperSpecError::InvalidSelector(selector.to_string()))
+}
+
+impl TryFrom<CrawlScraperSpec> for CrawlScraper {
+    type Error = CrawlScraperSpecError;
+
+    fn try_from(spec: CrawlScraperSpec) -> Result<Self, Self::Error> {
+        if spec.sitemap_urls.is_empty() && spec.category_urls.is_empty() {
+            return Err(CrawlScraperSpecError::MissingSeeds);
+        }
+        Ok(CrawlScraper {
+            item_link_selector: spec
+                .item_link_selector
+                .as_deref()
+                .map(parse_selector)
+                .transpose()?,
+            next_page_selector: spec
+                .next_page_selector
+                .as_deref()
+                .map(parse_selector)
+                .transpose()?,
+            spec,
+        })
+    }
+}
+
+impl CrawlScraper {
+    pub fn from_json(json: &str) -> Result<Self, CrawlScraperSpecError> {
+        serde_json::from_str::<CrawlScraperSpec>(json)?.try_into()
+    }
+
+    fn is_item_url(&self, url: &Url) -> bool {
+        self.spec.item_url_prefixes.is_empty()
+            || self
+                .spec
+                .item_url_prefixes
+                .iter()
+                .any(|prefix| url.as_str().starts_with(prefix))
+    }
+
+    /// Extracts the items and nested sitemaps of a sitemap or sitemap index.
+    pub fn parse_sitemap(&self, xml: &str) -> DiscoveredUrls {
+        // html5ever is lenient enough for sitemaps' simple XML
+        let document = Html::parse_document(xml);
+        let locs = |selector: &str| {
+            document
+                .select(&Selector::parse(selector).unwrap())
+                .filter_map(|loc| Url::parse(loc.text().collect::<String>().trim()).ok())
+                .collect::<Vec<_>>()
+        };
+        DiscoveredUrls {
+            item_urls: locs("url > loc")
+                .into_iter()
+                .filter(|url| self.is_item_url(url))
+                .collect(),
+            listing_urls: locs("sitemap > loc"),
+        }
+    }
+
+    /// Extracts the item links and the next page's link of a category page.
+    pub fn parse_category_page(&self, html: &str, page_url: &Url) -> DiscoveredUrls {
+        let document = Html::parse_document(html);
+        let links = |selector: Option<&Selector>| {
+            selector
+                .map(|selector| {
+                    document
+                        .select(selector)
+                        .filter_map(|link| link.attr("href"))
+                        .filter_map(|href| page_url.join(href.trim()).ok())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+        DiscoveredUrls {
+            item_urls: links(self.item_link_selector.as_ref())
+                .into_iter()
+                .filter(|url| self.is_item_url(url))
+                .collect(),
+            listing_urls: links(self.next_page_selector.as_ref())
+                .into_iter()
+                .take(1)
+                .collect(),
+        }
+    }
+}
+
+fn is_sitemap(body: &str) -> bool {
+    body.contains("<urlset") || body.contains("<sitemapindex")
+}
+
+#[async_trait]
+impl Scraper<Client> for CrawlScraper {
+    fn shop_id_str(&self) -> &str {
+        &self.spec.shop.shop_id
+    }
+
+    fn shop_name_str(&self) -> &str {
+        &self.spec.shop.shop_name
+    }
+
+    fn hosts(&self) -> Vec<String> {
+        let mut hosts = Vec::new();
+        for url in self
+            .spec
+            .sitemap_urls
+            .iter()
+            .chain(&self.spec.category_urls)
+        {
+            if let Some(host) = url.host_str()
+                && !hosts.iter().any(|known| known == host)
+            {
+                hosts.push(host.to_string());
+            }
+        }
+        hosts
+    }
+
+    fn scrape<'a>(
+        &'a self,
+        client: &'a Client,
+        scraper_config: ScraperConfig,
+    ) -> BoxStream<'a, Result<ScrapeEvent, ScrapeError>> {
+        scrape_discovered(self, client, scraper_config)
+    }
+}
+
+#[async_trait]
+impl ItemDiscovery<Client> for CrawlScraper {
+    fn seed_urls(&self) -> Vec<Url> {
+        self.spec
+            .sitemap_urls
+            .iter()
+            .chain(&self.spec.category_urls)
+            .cloned()
+            .collect()
+    }
+
+    async fn discover(
+        &self,
+        client: &Client,
+        _: ScraperConfig,
+        listing_url: &Url,
+    ) -> Result<DiscoveredUrls, ScrapeError> {
+        let body = get_text(client, listing_url.clone()).await?;
+        if is_sitemap(&body) {
+            Ok(self.parse_sitemap(&body))
+        } else {
+            Ok(self.parse_category_page(&body, listing_url))
+        }
+    }
+
+    async fn scrape_item_page(
+        &self,
+        client: &Client,
+        _: ScraperConfig,
+        item_url: &Url,
+    ) -> Result<Option<ScrapeItem>, ScrapeError> {
+        let html = get_text(client, item_url.clone()).await?;
+        let scrape_item = extract_scrape_items(&html, &self.spec.shop, item_url)
+            .into_iter()
+            .next();
+        if scrape_item.is_none() {
+            warn!(
+                shopId = self.spec.shop.shop_id,
+                url = %item_url,
+                "Found no JSON-LD Product on item page."
+            );
+        }
+        Ok(scrape_item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crawl::{CrawlScraper, CrawlScraperSpecError, SHOP_SPECS};
+    use scrape_core::discovery::ItemDiscovery;
+    use scrape_core::spec::Scraper;
+    use url::Url;
+
+    const EXAMPLE_SPEC: &str = include_str!("../fixtures/crawl/example-shop.json");
+    const SITEMAP_INDEX: &str = include_str!("../fixtures/crawl/sitemap-index.xml");
+    const SITEMAP_PRODUCTS: &str = include_str!("../fixtures/crawl/sitemap-products.xml");
+    const CATEGORY_PAGE: &str = include_str!("../fixtures/crawl/category-helmets-page-1.html");
+
+    fn urls(urls: &[&str]) -> Vec<Url> {
+        urls.iter().map(|url| Url::parse(url).unwrap()).collect()
+    }
+
+    #[test]
+    fn should_seed_from_sitemaps_and_categories() {
+        let scraper = CrawlScraper::from_json(EXAMPLE_SPEC).unwrap();
+
+        assert_eq!(
+            urls(&[
+                "https://shop.example.com/sitemap.xml",
+                "https://shop.example.com/category/helmets",
+            ]),
+            scraper.seed_urls()
+        );
+        assert_eq!(vec!["shop.example.com".to_string()], scraper.hosts());
+    }
+
+    #[test]
+    fn should_parse_sitemap_index() {
+        let scraper = CrawlScraper::from_json(EXAMPLE_SPEC).unwrap();
+
+        let actual = scraper.parse_sitemap(SITEMAP_INDEX);
+
+        assert!(actual.item_urls.is_empty());
+        assert_eq!(
+            urls(&[
+                "https://shop.example.com/sitemap-products.xml",
+                "https://shop.example.com/sitemap-pages.xml",
+            ]),
+            actual.listing_urls
+        );
+    }
+
+    #[test]
+    fn should_parse_item_urls_of_sitemap() {
+        let scraper = CrawlScraper::from_json(EXAMPLE_SPEC).unwrap();
+
+        let actual = scraper.parse_sitemap(SITEMAP_PRODUCTS);
+
+        assert_eq!(
+            urls(&[
+                "https://shop.example.com/product/steel-helmet-m35",
+                "https://shop.example.com/product/iron-cross?variant=1&color=black",
+            ]),
+            actual.item_urls
+        );
+        assert!(actual.listing_urls.is_empty());
+    }
+
+    #[test]
+    fn should_parse_category_page() {
+        let scraper = CrawlScraper::from_json(EXAMPLE_SPEC).unwrap();
+        let page_url = Url::parse("https://shop.example.com/category/helmets").unwrap();
+
+        let actual = scraper.parse_category_page(CATEGORY_PAGE, &page_url);
+
+        assert_eq!(
+            urls(&[
+                "https://shop.example.com/product/steel-helmet-m35",
+                "https://shop.example.com/product/steel-helmet-m40#gallery",
+            ]),
+            actual.item_urls
+        );
+        assert_eq!(
+            urls(&["https://shop.example.com/category/helmets?page=2"]),
+            actual.listing_urls
+        );
+    }
+
+    #[rstest::rstest]
+    #[case::missing_seeds(r#"{"shopId":"x","shopName":"X","language":"de"}"#, "MissingSeeds")]
+    #[case::invalid_selector(
+        r#"{"shopId":"x","shopName":"X","language":"de","categoryUrls":["https://x"],"itemLinkSelector":"a["}"#,
+        "InvalidSelector"
+    )]
+    #[case::malformed(r#"{"shopId":"x"}"#, "Malformed")]
+    fn should_reject_invalid_spec(#[case] json: &str, #[case] expected: &str) {
+        let actual = CrawlScraper::from_json(json).unwrap_err();
+
+        let actual = match actual {
+            CrawlScraperSpecError::Malformed(_) => "Malformed",
+            CrawlScraperSpecError::InvalidSelector(_) => "InvalidSelector",
+            CrawlScraperSpecError::MissingSeeds => "MissingSeeds",
+        };
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn should_build_scrapers_from_all_shop_specs() {
+        for shop_spec in SHOP_SPECS {
+            assert!(CrawlScraper::from_json(shop_spec).is_ok());
+        }
+    }
+}
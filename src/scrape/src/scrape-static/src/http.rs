@@ -0,0 +1,290 @@
+use async_trait::async_trait;
+use reqwest::header::{
+    ETAG, HeaderName, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, RETRY_AFTER,
+};
+use reqwest::{IntoUrl, Response, StatusCode, Url};
+use scrape_core::data::ScrapeItem;
+use scrape_core::page_cache::{ConditionalRequests, PageCache, PageValidators};
+use scrape_core::rate_limit::{HostTokenBuckets, RateLimit};
+use scrape_core::robots::{Politeness, RobotsCache, RobotsTxt, USER_AGENT};
+use scrape_core::spec::{ScrapeError, ScrapePage};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// HTTP-Client of all scrapers, sending [`USER_AGENT`] and complying with the robots.txt of
+/// the hosts it requests.
+///
+/// Catalog pages are requested conditionally if it has a [`PageCache`], see [`get_page`].
+/// Requests to a host are rate limited across all scrapes if it has a [`RateLimit`].
+#[derive(Debug, Clone)]
+pub struct Client {
+    http: reqwest::Client,
+    robots: Arc<RobotsCache>,
+    page_cache: Option<Arc<PageCache>>,
+    token_buckets: Option<Arc<HostTokenBuckets>>,
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Client::new(
+            reqwest::Client::builder()
+                .user_agent(USER_AGENT)
+                .build()
+                .expect("shouldn't fail building reqwest::Client because its config is static."),
+        )
+    }
+}
+
+impl Client {
+    pub fn new(http: reqwest::Client) -> Self {
+        Client {
+            http,
+            robots: Arc::new(RobotsCache::default()),
+            page_cache: None,
+            token_buckets: None,
+        }
+    }
+
+    pub fn with_rate_limit(self, rate_limit: RateLimit) -> Self {
+        Client {
+            token_buckets: Some(Arc::new(HostTokenBuckets::new(rate_limit))),
+            ..self
+        }
+    }
+
+    async fn robots_txt(&self, origin: String) -> Arc<RobotsTxt> {
+        self.robots
+            .get_or_fetch(&origin, || async {
+                let response = self
+                    .http
+                    .get(format!("{origin}/robots.txt"))
+                    .send()
+                    .await
+                    .map_err(into_scrape_error)?;
+                let status = response.status();
+                if status.is_success() {
+                    response.text().await.map(Some).map_err(into_scrape_error)
+                } else if status.is_client_error() {
+                    // There is no robots.txt
+                    Ok(None)
+                } else {
+                    Err(ScrapeError::HttpStatusError {
+                        status: status.as_u16(),
+                        retry_after: extract_retry_after(&response),
+                    })
+                }
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl Politeness for Client {
+    async fn crawl_delay(&self, host: &str) -> Option<Duration> {
+        self.robots_txt(format!("https://{host}"))
+            .await
+            .crawl_delay()
+    }
+}
+
+impl ConditionalRequests for Client {
+    fn with_page_cache(&self, page_cache: Arc<PageCache>) -> Self {
+        Client {
+            page_cache: Some(page_cache),
+            ..self.clone()
+        }
+    }
+}
+
+/// Fetches the body of a successful response.
+///
+/// Paths disallowed by the host's robots.txt fail with [`ScrapeError::Disallowed`].
+/// Timeouts and connection failures are transient, unsuccessful status codes map to
+/// [`ScrapeError::HttpStatusError`] so that the `RetryPolicy` can decide about retrying.
+pub async fn get_text(client: &Client, url: impl IntoUrl) -> Result<String, ScrapeError> {
+    let url = url.into_url().map_err(into_scrape_error)?;
+    let response = send(client, url, None).await?;
+    successful(response)?
+        .text()
+        .await
+        .map_err(into_scrape_error)
+}
+
+/// Fetches the catalog page and parses its items like [`get_text`].
+///
+/// If the client has a [`PageCache`], the page is requested conditionally. Pages unchanged
+/// since the previous scrape, i.e. responded with `304 Not Modified` or with an identical body,
+/// are [`ScrapePage::Unchanged`] without being parsed.
+pub async fn get_page<F>(
+    client: &Client,
+    url: impl IntoUrl,
+    parse: F,
+) -> Result<ScrapePage, ScrapeError>
+where
+    F: FnOnce(&str) -> Result<Vec<ScrapeItem>, ScrapeError>,
+{
+    let url = url.into_url().map_err(into_scrape_error)?;
+    let Some(page_cache) = &client.page_cache else {
+        return parse(&get_text(client, url).await?).map(ScrapePage::Items);
+    };
+    let previous = page_cache.previous(url.as_str());
+    let response = send(client, url.clone(), previous).await?;
+    let unchanged = |previous: &PageValidators| ScrapePage::Unchanged {
+        url: url.to_string(),
+        shops_item_ids: previous.shops_item_ids.clone(),
+    };
+    if let Some(previous) = previous
+        && response.status() == StatusCode::NOT_MODIFIED
+    {
+        return Ok(unchanged(previous));
+    }
+
+    let etag = header_value(&response, ETAG);
+    let last_modified = header_value(&response, LAST_MODIFIED);
+    let body = successful(response)?
+        .text()
+        .await
+        .map_err(into_scrape_error)?;
+    let content_hash = PageValidators::content_hash(&body);
+    if let Some(previous) = previous
+        && previous.content_hash == content_hash
+    {
+        if previous.etag != etag || previous.last_modified != last_modified {
+            page_cache.insert(
+                url.to_string(),
+                PageValidators {
+                    etag,
+                    last_modified,
+                    content_hash,
+                    shops_item_ids: previous.shops_item_ids.clone(),
+                },
+            );
+        }
+        return Ok(unchanged(previous));
+    }
+
+    let scrape_items = parse(&body)?;
+    page_cache.insert(
+        url.to_string(),
+        PageValidators {
+            etag,
+            last_modified,
+            content_hash,
+            shops_item_ids: scrape_items
+                .iter()
+                .map(|scrape_item| scrape_item.shops_item_id.clone())
+                .collect(),
+        },
+    );
+    Ok(ScrapePage::Items(scrape_items))
+}
+
+async fn send(
+    client: &Client,
+    url: Url,
+    previous: Option<&PageValidators>,
+) -> Result<Response, ScrapeError> {
+    let robots_txt = client.robots_txt(url.origin().ascii_serialization()).await;
+    if !robots_txt.is_allowed(&path_and_query(&url)) {
+        return Err(ScrapeError::Disallowed {
+            url: url.to_string(),
+        });
+    }
+    if let Some(token_buckets) = &client.token_buckets
+        && let Some(host) = url.host_str()
+    {
+        token_buckets.acquire(host).await;
+    }
+    let mut request = client.http.get(url);
+    if let Some(etag) = previous.and_then(|previous| previous.etag.as_deref()) {
+        request = request.header(IF_NONE_MATCH, etag);
+    }
+    if let Some(last_modified) = previous.and_then(|previous| previous.last_modified.as_deref()) {
+        request = request.header(IF_MODIFIED_SINCE, last_modified);
+    }
+    request.send().await.map_err(into_scrape_error)
+}
+
+fn successful(response: Response) -> Result<Response, ScrapeError> {
+    let status = response.status();
+    if status.is_success() {
+        Ok(response)
+    } else {
+        Err(ScrapeError::HttpStatusError {
+            status: status.as_u16(),
+            retry_after: extract_retry_after(&response),
+        })
+    }
+}
+
+fn header_value(response: &Response, name: HeaderName) -> Option<String> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(String::from)
+}
+
+/// Host of the URL, e.g. of a shop's configured base URL.
+pub fn host_of(url: &str) -> Option<String> {
+    reqwest::Url::parse(url).ok()?.host_str().map(String::from)
+}
+
+fn path_and_query(url: &Url) -> String {
+    match url.query() {
+        Some(query) => format!("{}?{query}", url.path()),
+        None => url.path().to_string(),
+    }
+}
+
+fn into_scrape_error(err: reqwest::Error) -> ScrapeError {
+    if err.is_timeout() || err.is_connect() {
+        ScrapeError::TransientClientError(Box::new(err))
+    } else {
+        ScrapeError::ClientError(Box::new(err))
+    }
+}
+
+fn extract_retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|retry_after| retry_after.to_str().ok())
+        .and_then(|retry_after| parse_retry_after(retry_after, SystemTime::now()))
+}
+
+// Either delay-seconds or an HTTP-date
+fn parse_retry_after(retry_after: &str, now: SystemTime) -> Option<Duration> {
+    let retry_after = retry_after.trim();
+    match retry_after.parse::<u64>() {
+        Ok(seconds) => Some(Duration::from_secs(seconds)),
+        Err(_) => httpdate::parse_http_date(retry_after)
+            .ok()
+            .map(|date| date.duration_since(now).unwrap_or_default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::parse_retry_after;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[rstest::rstest]
+    #[case::seconds("120", Some(Duration::from_secs(120)))]
+    #[case::http_date("Wed, 01 Jan 2020 12:00:30 GMT", Some(Duration::from_secs(30)))]
+    #[case::http_date_in_past("Wed, 01 Jan 2020 11:00:00 GMT", Some(Duration::ZERO))]
+    #[case::invalid("soon", None)]
+    fn should_parse_retry_after(#[case] retry_after: &str, #[case] expected: Option<Duration>) {
+        // Wed, 01 Jan 2020 12:00:00 GMT
+        let now = UNIX_EPOCH + Duration::from_secs(1_577_880_000);
+
+        let actual = parse_retry_after(retry_after, now);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn should_not_parse_negative_seconds() {
+        assert_eq!(None, parse_retry_after("-1", SystemTime::now()));
+    }
+}
@@ -0,0 +1,403 @@
+use crate::http::{Client, get_page, host_of};
+use crate::text::parse_currency_code;
+use async_trait::async_trait;
+use common::language::data::{LanguageData, LocalizedTextData};
+use common::price::data::PriceData;
+use item_data::item_state_data::ItemStateData;
+use reqwest::Url;
+use scrape_core::data::ScrapeItem;
+use scrape_core::spec::{ScrapeError, ScrapePage, Scraper, ScraperConfig};
+use scraper::{Html, Selector};
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::warn;
+
+/// Specs of shops scraped by a [`JsonLdScraper`], one JSON config file each.
+pub const SHOP_SPECS: &[&str] = &[];
+
+/// Shop whose items are extracted from the embedded schema.org JSON-LD.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonLdShop {
+    pub shop_id: String,
+    pub shop_name: String,
+    pub language: LanguageData,
+}
+
+/// Spec of a shop scraped by a [`JsonLdScraper`], usually deserialized from a JSON config file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonLdScraperSpec {
+    #[serde(flatten)]
+    pub shop: JsonLdShop,
+
+    /// URL of a catalog page with `{page}` as placeholder for the page number starting at 1.
+    pub page_url_template: String,
+
+    /// Whether the catalog lists the newest items first, see [`Scraper::lists_newest_first`].
+    #[serde(default)]
+    pub newest_first: bool,
+}
+
+/// Generic [`Scraper`] of a shop whose catalog pages embed all items as schema.org `Product`s.
+#[derive(Debug)]
+pub struct JsonLdScraper {
+    spec: JsonLdScraperSpec,
+}
+
+impl JsonLdScraper {
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        Ok(JsonLdScraper {
+            spec: serde_json::from_str(json)?,
+        })
+    }
+
+    pub fn page_url(&self, page_num: u32) -> String {
+        self.spec
+            .page_url_template
+            .replace("{page}", &page_num.to_string())
+    }
+}
+
+#[async_trait]
+impl Scraper<Client> for JsonLdScraper {
+    fn shop_id_str(&self) -> &str {
+        &self.spec.shop.shop_id
+    }
+
+    fn shop_name_str(&self) -> &str {
+        &self.spec.shop.shop_name
+    }
+
+    fn hosts(&self) -> Vec<String> {
+        host_of(&self.spec.page_url_template).into_iter().collect()
+    }
+
+    fn lists_newest_first(&self) -> bool {
+        self.spec.newest_first
+    }
+
+    async fn scrape_page(
+        &self,
+        client: &Client,
+        _: ScraperConfig,
+        page_num: u32,
+    ) -> Result<ScrapePage, ScrapeError> {
+        let page_url = Url::parse(&self.page_url(page_num)).map_err(Box::from)?;
+        get_page(client, page_url.clone(), |html| {
+            Ok(extract_scrape_items(html, &self.spec.shop, &page_url))
+        })
+        .await
+    }
+}
+
+/// Extracts all schema.org `Product`s embedded as `application/ld+json` into ScrapeItems.
+///
+/// Products without a name are skipped. Products without their own URL are assumed to be
+/// described by the page itself, e.g. a detail-page.
+pub fn extract_scrape_items(html: &str, shop: &JsonLdShop, page_url: &Url) -> Vec<ScrapeItem> {
+    let document = Html::parse_document(html);
+    let mut products = Vec::new();
+    for script in
+        document.select(&Selector::parse(r#"script[type="application/ld+json"]"#).unwrap())
+    {
+        let json = script.text().collect::<String>();
+        match serde_json::from_str::<Value>(&json) {
+            Ok(value) => collect_products(value, &mut products),
+            Err(err) => warn!(
+                error = %err,
+                shopId = shop.shop_id,
+                url = %page_url,
+                "Failed parsing JSON-LD."
+            ),
+        }
+    }
+
+    products
+        .iter()
+        .filter_map(|product| into_scrape_item(product, shop, page_url))
+        .collect()
+}
+
+fn collect_products(value: Value, products: &mut Vec<Value>) {
+    match value {
+        Value::Array(values) => values
+            .into_iter()
+            .for_each(|value| collect_products(value, products)),
+        Value::Object(mut object) => {
+            if let Some(graph) = object.remove("@graph") {
+                collect_products(graph, products);
+            }
+            if let Some(item_list_elements) = object.remove("itemListElement") {
+                collect_products(item_list_elements, products);
+            }
+            // ListItems wrap the actual Product
+            if has_type(&object, "ListItem") {
+                if let Some(item) = object.remove("item") {
+                    collect_products(item, products);
+                }
+            } else if has_type(&object, "Product") {
+                products.push(Value::Object(object));
+            }
+        }
+        _ => {}
+    }
+}
+
+fn has_type(object: &serde_json::Map<String, Value>, expected: &str) -> bool {
+    match object.get("@type") {
+        Some(Value::String(actual)) => strip_schema_org(actual) == expected,
+        Some(Value::Array(actuals)) => actuals
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|actual| strip_schema_org(actual) == expected),
+        _ => false,
+    }
+}
+
+fn strip_schema_org(value: &str) -> &str {
+    value
+        .strip_prefix("https://schema.org/")
+        .or_else(|| value.strip_prefix("http://schema.org/"))
+        .unwrap_or(value)
+}
+
+fn into_scrape_item(product: &Value, shop: &JsonLdShop, page_url: &Url) -> Option<ScrapeItem> {
+    let title = as_text(product.get("name")?)?;
+    let offer = product.get("offers").and_then(first);
+    let url = product
+        .get("url")
+        .or_else(|| offer.and_then(|offer| offer.get("url")))
+        .and_then(Value::as_str)
+        .and_then(|url| page_url.join(url).ok())
+        .unwrap_or_else(|| page_url.clone());
+    let shops_item_id = ["sku", "productID"]
+        .into_iter()
+        .find_map(|key| product.get(key).and_then(as_text))
+        .unwrap_or_else(|| url.path().trim_matches('/').to_string());
+
+    Some(ScrapeItem {
+        shop_id: shop.shop_id.as_str().into(),
+        shops_item_id: shops_item_id.into(),
+        shop_name: shop.shop_name.clone(),
+        native_title: LocalizedTextData::new(title, shop.language),
+        other_title: Default::default(),
+        native_description: product
+            .get("description")
+            .and_then(as_text)
+            .map(|description| LocalizedTextData::new(description, shop.language)),
+        other_description: Default::default(),
+        price: offer.and_then(extract_price),
+        state: offer
+            .and_then(|offer| offer.get("availability"))
+            .and_then(Value::as_str)
+            .map(availability_into_state)
+            .unwrap_or(ItemStateData::Listed),
+        images: product
+            .get("image")
+            .map(extract_image_urls)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|image| page_url.join(&image).ok())
+            .collect(),
+        url,
+    })
+}
+
+fn first(value: &Value) -> Option<&Value> {
+    match value {
+        Value::Array(values) => values.first(),
+        value => Some(value),
+    }
+}
+
+fn as_text(value: &Value) -> Option<String> {
+    let text = match value {
+        Value::String(text) => text.split_whitespace().collect::<Vec<_>>().join(" "),
+        Value::Number(number) => number.to_string(),
+        _ => return None,
+    };
+    Some(text).filter(|text| !text.is_empty())
+}
+
+// price may be a number or a string, AggregateOffers only have a lowPrice
+fn extract_price(offer: &Value) -> Option<PriceData> {
+    let amount = ["price", "lowPrice"]
+        .into_iter()
+        .filter_map(|key| offer.get(key))
+        .find_map(|price| match price {
+            Value::Number(number) => number.as_f64(),
+            Value::String(text) => text.trim().parse::<f64>().ok(),
+            _ => None,
+        })?;
+    let currency = offer
+        .get("priceCurrency")
+        .and_then(Value::as_str)
+        .and_then(parse_currency_code)?;
+    PriceData::new_f64(currency, amount).ok()
+}
+
+fn extract_image_urls(image: &Value) -> Vec<String> {
+    match image {
+        Value::String(url) => vec![url.clone()],
+        Value::Array(images) => images.iter().flat_map(extract_image_urls).collect(),
+        Value::Object(image_object) => image_object
+            .get("url")
+            .or_else(|| image_object.get("contentUrl"))
+            .and_then(Value::as_str)
+            .map(|url| vec![url.to_string()])
+            .unwrap_or_default(),
+        _ => vec![],
+    }
+}
+
+/// Maps schema.org's `ItemAvailability`, e.g. `https://schema.org/InStock`.
+pub fn availability_into_state(availability: &str) -> ItemStateData {
+    match strip_schema_org(availability) {
+        "InStock" | "InStoreOnly" | "OnlineOnly" | "LimitedAvailability" => {
+            ItemStateData::Available
+        }
+        "SoldOut" | "OutOfStock" | "Discontinued" => ItemStateData::Sold,
+        "Reserved" => ItemStateData::Reserved,
+        _ => ItemStateData::Listed,
+    }
+}
+
+/// Fills what the item lacks with the information from the JSON-LD `Product` of its detail-page.
+/// A [`ItemStateData::Listed`] item's state is unknown and therefore also filled.
+pub fn fill_missing(scrape_item: &mut ScrapeItem, product: ScrapeItem) {
+    if scrape_item.state == ItemStateData::Listed {
+        scrape_item.state = product.state;
+    }
+    if scrape_item.native_description.is_none() {
+        scrape_item.native_description = product.native_description;
+    }
+    if scrape_item.price.is_none() {
+        scrape_item.price = product.price;
+    }
+    if scrape_item.images.len() < product.images.len() {
+        scrape_item.images = product.images;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::json_ld::{
+        JsonLdScraper, JsonLdShop, SHOP_SPECS, availability_into_state, extract_scrape_items,
+        fill_missing,
+    };
+    use common::currency::data::CurrencyData;
+    use common::language::data::{LanguageData, LocalizedTextData};
+    use common::price::data::PriceData;
+    use item_data::item_state_data::ItemStateData;
+    use url::Url;
+
+    const EXAMPLE_CATALOG: &str = include_str!("../fixtures/json_ld/example-catalog.html");
+    const EXAMPLE_DETAIL: &str = include_str!("../fixtures/json_ld/example-detail.html");
+
+    fn mk_shop() -> JsonLdShop {
+        JsonLdShop {
+            shop_id: "example-shop".to_string(),
+            shop_name: "Example Shop".to_string(),
+            language: LanguageData::En,
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::in_stock("https://schema.org/InStock", ItemStateData::Available)]
+    #[case::http_limited("http://schema.org/LimitedAvailability", ItemStateData::Available)]
+    #[case::short_in_stock("InStock", ItemStateData::Available)]
+    #[case::sold_out("https://schema.org/SoldOut", ItemStateData::Sold)]
+    #[case::out_of_stock("https://schema.org/OutOfStock", ItemStateData::Sold)]
+    #[case::discontinued("https://schema.org/Discontinued", ItemStateData::Sold)]
+    #[case::pre_order("https://schema.org/PreOrder", ItemStateData::Listed)]
+    #[case::unknown("https://example.com/Whatever", ItemStateData::Listed)]
+    fn should_map_availability_into_state(
+        #[case] availability: &str,
+        #[case] expected: ItemStateData,
+    ) {
+        assert_eq!(expected, availability_into_state(availability));
+    }
+
+    #[test]
+    fn should_extract_products_of_item_list_and_graph() {
+        let page_url = Url::parse("https://shop.example.com/catalog?page=1").unwrap();
+
+        let actual = extract_scrape_items(EXAMPLE_CATALOG, &mk_shop(), &page_url);
+
+        assert_eq!(3, actual.len());
+
+        let helmet = &actual[0];
+        assert_eq!("H-35", helmet.shops_item_id.to_string());
+        assert_eq!(
+            LocalizedTextData::new("Steel helmet M35", LanguageData::En),
+            helmet.native_title
+        );
+        assert_eq!(
+            Some(PriceData::new(CurrencyData::Eur, 123450)),
+            helmet.price
+        );
+        assert_eq!(ItemStateData::Available, helmet.state);
+        assert_eq!(
+            Url::parse("https://shop.example.com/products/helmet-m35").unwrap(),
+            helmet.url
+        );
+        assert_eq!(
+            vec![
+                Url::parse("https://shop.example.com/img/helmet-1.jpg").unwrap(),
+                Url::parse("https://cdn.example.com/helmet-2.jpg").unwrap(),
+            ],
+            helmet.images
+        );
+
+        let medal = &actual[1];
+        assert_eq!("products/medal", medal.shops_item_id.to_string());
+        assert_eq!(Some(PriceData::new(CurrencyData::Gbp, 9900)), medal.price);
+        assert_eq!(ItemStateData::Sold, medal.state);
+
+        let badge = &actual[2];
+        assert_eq!("B-7", badge.shops_item_id.to_string());
+        assert_eq!(Some(PriceData::new(CurrencyData::Usd, 4500)), badge.price);
+        assert_eq!(ItemStateData::Listed, badge.state);
+    }
+
+    #[test]
+    fn should_fill_missing_fields_from_detail_page() {
+        let page_url = Url::parse("https://shop.example.com/products/helmet-m35").unwrap();
+        let mut scrape_item = extract_scrape_items(EXAMPLE_CATALOG, &mk_shop(), &page_url)
+            .into_iter()
+            .next()
+            .unwrap();
+        scrape_item.native_description = None;
+        scrape_item.price = None;
+        scrape_item.images = vec![];
+        scrape_item.state = ItemStateData::Listed;
+
+        let product = extract_scrape_items(EXAMPLE_DETAIL, &mk_shop(), &page_url)
+            .into_iter()
+            .next()
+            .unwrap();
+        fill_missing(&mut scrape_item, product);
+
+        assert_eq!(
+            Some(LocalizedTextData::new(
+                "Original paint, liner complete.",
+                LanguageData::En
+            )),
+            scrape_item.native_description
+        );
+        assert_eq!(
+            Some(PriceData::new(CurrencyData::Eur, 123450)),
+            scrape_item.price
+        );
+        assert_eq!(ItemStateData::Available, scrape_item.state);
+        assert_eq!(1, scrape_item.images.len());
+    }
+
+    #[test]
+    fn should_build_scrapers_from_all_shop_specs() {
+        for shop_spec in SHOP_SPECS {
+            assert!(JsonLdScraper::from_json(shop_spec).is_ok());
+        }
+    }
+}
@@ -1 +1,91 @@
+use crate::http::Client;
+use scrape_core::registry::ScraperRegistry;
+
+pub mod crawl;
+pub mod http;
+pub mod json_ld;
 pub mod militariamart;
+#[cfg(test)]
+mod replay;
+pub mod selector;
+pub mod shopify;
+mod text;
+pub mod woocommerce;
+
+/// Registry of the scrapers of all shops.
+pub fn scraper_registry() -> ScraperRegistry<Client> {
+    let mut registry = ScraperRegistry::default();
+    for shop in militariamart::SHOPS {
+        registry.register(shop.clone());
+    }
+    for shop in shopify::SHOPS {
+        registry.register(shop.clone());
+    }
+    for shop in woocommerce::SHOPS {
+        registry.register(shop.clone());
+    }
+    for shop_spec in selector::SHOP_SPECS {
+        registry.register(
+            selector::SelectorScraper::from_json(shop_spec)
+                .expect("shouldn't fail because all shop specs are tested."),
+        );
+    }
+    for shop_spec in json_ld::SHOP_SPECS {
+        registry.register(
+            json_ld::JsonLdScraper::from_json(shop_spec)
+                .expect("shouldn't fail because all shop specs are tested."),
+        );
+    }
+    for shop_spec in crawl::SHOP_SPECS {
+        registry.register(
+            crawl::CrawlScraper::from_json(shop_spec)
+                .expect("shouldn't fail because all shop specs are tested."),
+        );
+    }
+    registry
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scraper_registry;
+    use common::shop_id::ShopId;
+    use serde::Deserialize;
+
+    const APPLICATION_TEMPLATE: &str = include_str!("../../../../../cfn/application.yaml");
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ScheduleInput {
+        shop_id: ShopId,
+    }
+
+    #[test]
+    fn should_register_all_scheduled_shops() {
+        let registry = scraper_registry();
+        let scheduled_shop_ids = APPLICATION_TEMPLATE
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("Input: "))
+            .map(|input| input.trim_matches('\''))
+            .filter_map(|input| serde_json::from_str::<ScheduleInput>(input).ok())
+            .map(|input| input.shop_id)
+            .collect::<Vec<_>>();
+
+        assert!(!scheduled_shop_ids.is_empty());
+        for shop_id in scheduled_shop_ids {
+            assert!(
+                registry.get(&shop_id).is_some(),
+                "{shop_id} isn't registered"
+            );
+        }
+    }
+
+    #[test]
+    fn should_schedule_all_registered_shops() {
+        for shop_id in scraper_registry().shop_ids() {
+            assert!(
+                APPLICATION_TEMPLATE.contains(&format!("\"shopId\": \"{shop_id}\"")),
+                "{shop_id} isn't scheduled"
+            );
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::http::{Client, get_page, get_text, host_of};
 use async_trait::async_trait;
 use common::currency::data::CurrencyData;
 use common::language::data::{LanguageData, LocalizedTextData};
@@ -5,13 +6,24 @@ use common::price::data::PriceData;
 use common::shop_id::ShopId;
 use common::shops_item_id::ShopsItemId;
 use item_data::item_state_data::ItemStateData;
-use reqwest::{Client, Url};
+use reqwest::Url;
 use scrape_core::data::ScrapeItem;
-use scrape_core::spec::{ScrapeError, Scraper, ScraperConfig};
+use scrape_core::spec::{ScrapeError, ScrapePage, Scraper, ScraperConfig};
 use scraper::{ElementRef, Html, Selector};
 use tracing::{info, warn};
 
-#[derive(Debug)]
+/// Shops hosted on MilitariaMart which are scraped by the scrape-runner.
+///
+/// Each needs its schedule rules in `cfn/application.yaml`.
+pub const SHOPS: &[MilitariaMart] = &[MilitariaMart {
+    id: "militariamart",
+    url: "https://www.militariamart.com",
+    name: "MilitariaMart",
+    shop_dimension: None,
+    language: LanguageData::En,
+}];
+
+#[derive(Debug, Clone)]
 pub struct MilitariaMart {
     pub id: &'static str,
     pub url: &'static str,
@@ -20,37 +32,10 @@ pub struct MilitariaMart {
     pub language: LanguageData,
 }
 
-#[async_trait]
-impl Scraper<Client> for MilitariaMart {
-    fn shop_id_str(&self) -> &'static str {
-        self.id
-    }
-
-    fn shop_name_str(&self) -> &'static str {
-        self.name
-    }
-
-    async fn scrape_page(
-        &self,
-        client: &Client,
-        _: ScraperConfig,
-        page_num: u32,
-    ) -> Result<Vec<ScrapeItem>, ScrapeError> {
-        let html = client
-            .get(format!(
-                "{}/shop.php?d={}&pg={}",
-                &self.url,
-                &self.shop_dimension.unwrap_or(1),
-                page_num
-            ))
-            .send()
-            .await
-            .map_err(Box::from)?
-            .text()
-            .await
-            .map_err(Box::from)?;
-        let document = Html::parse_document(&html);
-        let shop_items = document
+impl MilitariaMart {
+    fn parse_page(&self, html: &str, page_num: u32) -> Vec<ScrapeItem> {
+        let document = Html::parse_document(html);
+        document
             .select(&Selector::parse("div.shopitem > div.inner-wrapper").unwrap())
             .filter_map(|shop_item| match extract_shops_item_id(shop_item) {
                 None => {
@@ -101,9 +86,69 @@ impl Scraper<Client> for MilitariaMart {
                     }
                 }
             })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Scraper<Client> for MilitariaMart {
+    fn shop_id_str(&self) -> &'static str {
+        self.id
+    }
+
+    fn shop_name_str(&self) -> &'static str {
+        self.name
+    }
+
+    fn hosts(&self) -> Vec<String> {
+        host_of(self.url).into_iter().collect()
+    }
+
+    async fn scrape_page(
+        &self,
+        client: &Client,
+        _: ScraperConfig,
+        page_num: u32,
+    ) -> Result<ScrapePage, ScrapeError> {
+        let page_url = format!(
+            "{}/shop.php?d={}&pg={}",
+            &self.url,
+            &self.shop_dimension.unwrap_or(1),
+            page_num
+        );
+        get_page(client, page_url, |html| Ok(self.parse_page(html, page_num))).await
+    }
+
+    fn needs_details(&self, scrape_item: &ScrapeItem) -> bool {
+        scrape_item.images.len() <= 1
+            || scrape_item
+                .native_description
+                .as_ref()
+                .is_some_and(|description| is_truncated(&description.text))
+    }
+
+    async fn scrape_details(
+        &self,
+        client: &Client,
+        _: ScraperConfig,
+        scrape_item: &mut ScrapeItem,
+    ) -> Result<(), ScrapeError> {
+        let html = get_text(client, scrape_item.url.clone()).await?;
+        let document = Html::parse_document(&html);
+
+        if let Some(description) = extract_detail_description(&document) {
+            scrape_item.native_description =
+                Some(LocalizedTextData::new(description, self.language));
+        }
+        let images = extract_detail_relative_image_urls(&document)
+            .into_iter()
+            .filter_map(|relative_url| Url::parse(&format!("{}/{}", &self.url, relative_url)).ok())
             .collect::<Vec<_>>();
+        if images.len() > scrape_item.images.len() {
+            scrape_item.images = images;
+        }
 
-        Ok(shop_items)
+        Ok(())
     }
 }
 
@@ -127,10 +172,9 @@ fn extract_title(shop_item: ElementRef) -> Option<String> {
 }
 
 fn extract_description(shop_item: ElementRef) -> Option<String> {
-    // See aws-backend#7
     // This only gathers the description for the catalog-page.
-    // It may have been shortened. If so, it ends with '...'.
-    // If it does, go to the items page and parse full description there
+    // It may have been shortened. If so, it ends with '...' and the full description
+    // is scraped from the items page, see MilitariaMart::scrape_details.
     shop_item
         .select(&Selector::parse("div.block-text > p.itemDescription").unwrap())
         .next()
@@ -158,6 +202,13 @@ fn extract_price(
                     "AUD" => Some(CurrencyData::Aud),
                     "CAD" => Some(CurrencyData::Cad),
                     "NZD" => Some(CurrencyData::Nzd),
+                    "CHF" => Some(CurrencyData::Chf),
+                    "SEK" => Some(CurrencyData::Sek),
+                    "DKK" => Some(CurrencyData::Dkk),
+                    "NOK" => Some(CurrencyData::Nok),
+                    "PLN" => Some(CurrencyData::Pln),
+                    "CZK" => Some(CurrencyData::Czk),
+                    "JPY" => Some(CurrencyData::Jpy),
                     invalid => {
                         warn!(
                             shopId = shop_id.to_string(),
@@ -222,3 +273,130 @@ fn extract_relative_image_urls(shop_item: ElementRef) -> Vec<String> {
         .map(|relative_url| vec![relative_url])
         .unwrap_or_default()
 }
+
+fn is_truncated(description: &str) -> bool {
+    description.ends_with("...") || description.ends_with('\u{2026}')
+}
+
+fn extract_detail_description(document: &Html) -> Option<String> {
+    document
+        .select(&Selector::parse("div.shopitem p.itemDescription").unwrap())
+        .next()
+        .map(|desc_elem| {
+            desc_elem
+                .text()
+                .flat_map(str::split_whitespace)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|description| !description.is_empty())
+}
+
+fn extract_detail_relative_image_urls(document: &Html) -> Vec<String> {
+    let mut relative_urls: Vec<String> = Vec::new();
+    for img in document.select(&Selector::parse("div.shopitem div.block-image img").unwrap()) {
+        if let Some(relative_url) = img.attr("src")
+            && !relative_urls.iter().any(|url| url == relative_url)
+        {
+            relative_urls.push(relative_url.to_string());
+        }
+    }
+    relative_urls
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::Client;
+    use crate::militariamart::{
+        SHOPS, extract_detail_description, extract_detail_relative_image_urls, is_truncated,
+    };
+    use common::language::data::{LanguageData, LocalizedTextData};
+    use item_data::item_state_data::ItemStateData;
+    use reqwest::Url;
+    use scrape_core::data::ScrapeItem;
+    use scrape_core::spec::Scraper;
+    use scraper::Html;
+
+    const DETAIL_PAGE: &str = r#"
+        <html><body>
+          <div class="shopitem">
+            <div class="block-image">
+              <a href="images/1.jpg"><img src="images/1.jpg"></a>
+              <a href="images/2.jpg"><img src="images/2.jpg"></a>
+              <a href="images/1.jpg"><img src="images/1.jpg"></a>
+              <a href="images/3.jpg"><img src="images/3.jpg"></a>
+            </div>
+            <div class="block-text">
+              <p class="itemDescription">
+                A fine helmet with original liner.<br>
+                Chinstrap is missing.
+              </p>
+            </div>
+          </div>
+        </body></html>
+    "#;
+
+    #[rstest::rstest]
+    #[case::three_dots("A fine helmet with...", true)]
+    #[case::ellipsis("A fine helmet with\u{2026}", true)]
+    #[case::complete("A fine helmet.", false)]
+    fn should_detect_truncated_description(#[case] description: &str, #[case] expected: bool) {
+        assert_eq!(expected, is_truncated(description));
+    }
+
+    #[rstest::rstest]
+    #[case::truncated_description(Some("A fine helmet with..."), 2, true)]
+    #[case::complete_description(Some("A fine helmet."), 2, false)]
+    #[case::no_description(None, 2, false)]
+    #[case::single_image(Some("A fine helmet."), 1, true)]
+    #[case::no_image(None, 0, true)]
+    fn should_need_details_when_description_is_truncated_or_images_are_missing(
+        #[case] description: Option<&str>,
+        #[case] image_count: usize,
+        #[case] expected: bool,
+    ) {
+        let scrape_item = ScrapeItem {
+            shop_id: "militariamart".into(),
+            shops_item_id: "12345".into(),
+            shop_name: "MilitariaMart".to_string(),
+            native_title: LocalizedTextData::new("Pickelhaube", LanguageData::En),
+            other_title: Default::default(),
+            native_description: description
+                .map(|description| LocalizedTextData::new(description, LanguageData::En)),
+            other_description: Default::default(),
+            price: None,
+            state: ItemStateData::Available,
+            url: Url::parse("https://www.militariamart.com/shop.php?code=12345").unwrap(),
+            images: (1..=image_count)
+                .map(|i| {
+                    Url::parse(&format!("https://www.militariamart.com/images/{i}.jpg")).unwrap()
+                })
+                .collect(),
+        };
+
+        let actual = Scraper::<Client>::needs_details(&SHOPS[0], &scrape_item);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn should_extract_full_description_from_detail_page() {
+        let document = Html::parse_document(DETAIL_PAGE);
+
+        let actual = extract_detail_description(&document);
+
+        assert_eq!(
+            Some("A fine helmet with original liner. Chinstrap is missing.".to_string()),
+            actual
+        );
+    }
+
+    #[test]
+    fn should_extract_distinct_images_from_detail_page() {
+        let document = Html::parse_document(DETAIL_PAGE);
+
+        let actual = extract_detail_relative_image_urls(&document);
+
+        assert_eq!(vec!["images/1.jpg", "images/2.jpg", "images/3.jpg"], actual);
+    }
+}
@@ -0,0 +1,438 @@
+//! Record/replay of shop HTTP responses for offline scraper tests.
+//!
+//! A [`ReplayServer`] stands in for a shop's origin and answers requests by path and query
+//! from the shop's fixture corpus, e.g. `fixtures/replay/militariamart-example`.
+//! Scrapers under test are pointed at [`ReplayServer::url`] instead of the shop's origin.
+//! Absolute URLs of the origin in served bodies, e.g. in sitemaps, are rewritten to the server.
+//!
+//! Running the tests with `SCRAPE_RECORD=1` records responses missing from the corpus
+//! from the live origin and rewrites the golden files of [`assert_golden`]:
+//!
+//! ```sh
+//! SCRAPE_RECORD=1 cargo test -p scrape-static replay
+//! ```
+//!
+//! Review the recorded fixtures and golden files like any other change before committing them.
+
+use reqwest::Client;
+use scrape_core::data::ScrapeItem;
+use scrape_core::robots::USER_AGENT;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+const RECORD_ENV: &str = "SCRAPE_RECORD";
+const INDEX_FILE: &str = "index.json";
+
+pub fn is_recording() -> bool {
+    std::env::var(RECORD_ENV).is_ok_and(|record| !record.is_empty() && record != "0")
+}
+
+/// Response recorded for a path and query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default)]
+    content_type: Option<String>,
+
+    /// File in the corpus holding the body.
+    body_file: String,
+}
+
+/// Index of a shop's fixture corpus by path and query, e.g. `/shop.php?d=1&pg=1`.
+type Index = BTreeMap<String, RecordedResponse>;
+
+struct Corpus {
+    dir: PathBuf,
+    origin: String,
+    url: String,
+    index: Mutex<Index>,
+}
+
+pub struct ReplayServer {
+    url: String,
+    origin: String,
+    handle: JoinHandle<()>,
+}
+
+impl ReplayServer {
+    /// Serves the corpus in `dir` recorded from `origin`, e.g. `https://shop.example.com`.
+    pub async fn start(dir: impl AsRef<Path>, origin: &str) -> Self {
+        let dir = dir.as_ref().to_path_buf();
+        let index = match std::fs::read_to_string(dir.join(INDEX_FILE)) {
+            Ok(index) => serde_json::from_str(&index)
+                .unwrap_or_else(|err| panic!("shouldn't fail parsing {INDEX_FILE}: {err}")),
+            Err(_) => Index::new(),
+        };
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("shouldn't fail binding to a free local port");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let corpus = Arc::new(Corpus {
+            dir,
+            origin: origin.trim_end_matches('/').to_string(),
+            url: url.clone(),
+            index: Mutex::new(index),
+        });
+        let handle = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve(stream, corpus.clone()));
+            }
+        });
+
+        ReplayServer {
+            url,
+            origin: origin.trim_end_matches('/').to_string(),
+            handle,
+        }
+    }
+
+    /// Base URL to point the scraper under test at, e.g. `http://127.0.0.1:54321`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Replaces the server's URL with the shop's origin so that snapshots are stable.
+    pub fn to_origin(&self, text: &str) -> String {
+        text.replace(&self.url, &self.origin)
+    }
+}
+
+impl Drop for ReplayServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn serve(stream: TcpStream, corpus: Arc<Corpus>) {
+    let mut stream = BufReader::new(stream);
+    let mut request_line = String::new();
+    if stream.read_line(&mut request_line).await.is_err() {
+        return;
+    }
+    // Drain the headers, requests of scrapers don't have a body
+    let mut header = String::new();
+    while stream
+        .read_line(&mut header)
+        .await
+        .is_ok_and(|read| read > 2)
+    {
+        header.clear();
+    }
+
+    let path_and_query = request_line
+        .split_whitespace()
+        .nth(1)
+        .unwrap_or("/")
+        .to_string();
+    let (status, content_type, body) = match lookup(&corpus, &path_and_query).await {
+        Some(response) => response,
+        None if is_recording() => record(&corpus, &path_and_query).await,
+        None => (
+            404,
+            Some("text/plain".to_string()),
+            format!("No fixture recorded for '{path_and_query}'. Record it with {RECORD_ENV}=1.")
+                .into_bytes(),
+        ),
+    };
+
+    let body = match String::from_utf8(body) {
+        Ok(text) => text.replace(&corpus.origin, &corpus.url).into_bytes(),
+        Err(err) => err.into_bytes(),
+    };
+
+    let mut head = format!(
+        "HTTP/1.1 {status} {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        reason_phrase(status),
+        body.len()
+    );
+    if let Some(content_type) = content_type {
+        head.push_str(&format!("Content-Type: {content_type}\r\n"));
+    }
+    head.push_str("\r\n");
+    let stream = stream.get_mut();
+    let _ = stream.write_all(head.as_bytes()).await;
+    let _ = stream.write_all(&body).await;
+    let _ = stream.shutdown().await;
+}
+
+async fn lookup(corpus: &Corpus, path_and_query: &str) -> Option<(u16, Option<String>, Vec<u8>)> {
+    let response = corpus.index.lock().await.get(path_and_query)?.clone();
+    let body = std::fs::read(corpus.dir.join(&response.body_file)).unwrap_or_else(|err| {
+        panic!(
+            "shouldn't fail reading recorded body '{}': {err}",
+            response.body_file
+        )
+    });
+    Some((response.status, response.content_type, body))
+}
+
+async fn record(corpus: &Corpus, path_and_query: &str) -> (u16, Option<String>, Vec<u8>) {
+    let response = Client::builder()
+        .user_agent(USER_AGENT)
+        .build()
+        .expect("shouldn't fail building Client for recording")
+        .get(format!("{}{}", corpus.origin, path_and_query))
+        .send()
+        .await
+        .unwrap_or_else(|err| panic!("shouldn't fail recording '{path_and_query}': {err}"));
+    let status = response.status().as_u16();
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .map(String::from);
+    let body = response
+        .bytes()
+        .await
+        .expect("shouldn't fail reading body of recorded response")
+        .to_vec();
+
+    let mut index = corpus.index.lock().await;
+    let body_file = body_file_name(path_and_query, content_type.as_deref(), index.len());
+    std::fs::create_dir_all(&corpus.dir).expect("shouldn't fail creating corpus directory");
+    std::fs::write(corpus.dir.join(&body_file), &body).expect("shouldn't fail writing body");
+    index.insert(
+        path_and_query.to_string(),
+        RecordedResponse {
+            status,
+            content_type: content_type.clone(),
+            body_file,
+        },
+    );
+    std::fs::write(
+        corpus.dir.join(INDEX_FILE),
+        serde_json::to_string_pretty(&*index).unwrap() + "\n",
+    )
+    .expect("shouldn't fail writing index");
+
+    (status, content_type, body)
+}
+
+// Readable and unique, e.g. `003-shop.php_d_1_pg_1.html`
+fn body_file_name(path_and_query: &str, content_type: Option<&str>, seq: usize) -> String {
+    let name = path_and_query
+        .trim_start_matches('/')
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .take(80)
+        .collect::<String>();
+    let extension = match content_type {
+        Some(content_type) if content_type.contains("json") => "json",
+        Some(content_type) if content_type.contains("xml") => "xml",
+        Some(content_type) if content_type.contains("html") => "html",
+        _ => "txt",
+    };
+    format!("{seq:03}-{name}.{extension}")
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        404 => "Not Found",
+        _ => "Recorded",
+    }
+}
+
+/// Asserts that the items equal the golden file's snapshot, or rewrites it when recording.
+///
+/// URLs of the [`ReplayServer`] are replaced by the shop's origin.
+pub fn assert_golden(golden_file: impl AsRef<Path>, items: &[ScrapeItem], server: &ReplayServer) {
+    let golden_file = golden_file.as_ref();
+    // Serializing through a Value sorts the keys, e.g. of `otherTitle`
+    let actual = serde_json::to_value(items)
+        .and_then(|items| serde_json::to_string_pretty(&items))
+        .expect("shouldn't fail serializing ScrapeItems");
+    let actual = server.to_origin(&actual) + "\n";
+
+    if is_recording() {
+        if let Some(dir) = golden_file.parent() {
+            std::fs::create_dir_all(dir).expect("shouldn't fail creating golden file's directory");
+        }
+        std::fs::write(golden_file, actual).expect("shouldn't fail writing golden file");
+        return;
+    }
+    let expected = std::fs::read_to_string(golden_file).unwrap_or_else(|err| {
+        panic!(
+            "shouldn't fail reading golden file '{}': {err}. Create it with {RECORD_ENV}=1.",
+            golden_file.display()
+        )
+    });
+    assert_eq!(
+        expected,
+        actual,
+        "ScrapeItems differ from golden file '{}'. Review and rewrite it with {RECORD_ENV}=1 if intended.",
+        golden_file.display()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crawl::CrawlScraper;
+    use crate::http::Client;
+    use crate::militariamart::MilitariaMart;
+    use crate::replay::{ReplayServer, assert_golden, body_file_name};
+    use common::language::data::LanguageData;
+    use futures::{StreamExt, TryStreamExt};
+    use scrape_core::page_cache::{ConditionalRequests, PageCache};
+    use scrape_core::retry::RetryPolicy;
+    use scrape_core::spec::{ScrapeEvent, Scraper, ScraperConfig};
+    use std::sync::Arc;
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/replay");
+
+    fn mk_scraper_config() -> ScraperConfig {
+        ScraperConfig {
+            page_delay: None,
+            detail_concurrency: Some(2),
+            retry_policy: RetryPolicy::none(),
+            stop_after_known_items: None,
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::html(
+        "/shop.php?d=1&pg=1",
+        Some("text/html; charset=UTF-8"),
+        0,
+        "000-shop.php_d_1_pg_1.html"
+    )]
+    #[case::json(
+        "/products.json?page=2",
+        Some("application/json"),
+        12,
+        "012-products.json_page_2.json"
+    )]
+    #[case::unknown("/robots.txt", None, 3, "003-robots.txt.txt")]
+    fn should_name_body_file(
+        #[case] path_and_query: &str,
+        #[case] content_type: Option<&str>,
+        #[case] seq: usize,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(expected, body_file_name(path_and_query, content_type, seq));
+    }
+
+    #[tokio::test]
+    async fn should_replay_militariamart_example() {
+        let server = ReplayServer::start(
+            format!("{FIXTURES}/militariamart-example"),
+            "https://example.militariamart.com",
+        )
+        .await;
+        let scraper = MilitariaMart {
+            id: "militariamart-example",
+            url: server.url().to_string().leak(),
+            name: "Example Militaria",
+            shop_dimension: None,
+            language: LanguageData::En,
+        };
+
+        let actual = scraper
+            .scrape(&Client::default(), mk_scraper_config())
+            .try_filter_map(|scrape_event| async { Ok(scrape_event.into_item()) })
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_golden(
+            format!("{FIXTURES}/militariamart-example/golden.json"),
+            &actual,
+            &server,
+        );
+    }
+
+    #[tokio::test]
+    async fn should_skip_unchanged_pages_of_militariamart_example() {
+        let server = ReplayServer::start(
+            format!("{FIXTURES}/militariamart-example"),
+            "https://example.militariamart.com",
+        )
+        .await;
+        let scraper = MilitariaMart {
+            id: "militariamart-example",
+            url: server.url().to_string().leak(),
+            name: "Example Militaria",
+            shop_dimension: None,
+            language: LanguageData::En,
+        };
+        let page_cache = Arc::new(PageCache::default());
+        let scraped = scraper
+            .scrape(
+                &Client::default().with_page_cache(page_cache.clone()),
+                mk_scraper_config(),
+            )
+            .try_filter_map(|scrape_event| async { Ok(scrape_event.into_item()) })
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        let page_cache = Arc::new(PageCache::new(page_cache.take_scraped()));
+
+        let actual = scraper
+            .scrape(
+                &Client::default().with_page_cache(page_cache),
+                mk_scraper_config(),
+            )
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(1, actual.len());
+        match &actual[0] {
+            Ok(ScrapeEvent::PageUnchanged { shops_item_ids, .. }) => assert_eq!(
+                scraped
+                    .iter()
+                    .map(|scrape_item| scrape_item.shops_item_id.clone())
+                    .collect::<Vec<_>>(),
+                *shops_item_ids
+            ),
+            other => panic!("expected unchanged page, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_replay_crawl_example() {
+        let server = ReplayServer::start(
+            format!("{FIXTURES}/crawl-example"),
+            "https://shop.example.com",
+        )
+        .await;
+        let scraper = CrawlScraper::from_json(&format!(
+            r#"{{
+                "shopId": "crawl-example",
+                "shopName": "Example Crawl Shop",
+                "language": "en",
+                "sitemapUrls": ["{0}/sitemap.xml"],
+                "categoryUrls": ["{0}/category/helmets"],
+                "itemUrlPrefixes": ["{0}/product/"],
+                "itemLinkSelector": "ul.products a.product-link"
+            }}"#,
+            server.url()
+        ))
+        .unwrap();
+
+        let actual = scraper
+            .scrape(&Client::default(), mk_scraper_config())
+            .try_filter_map(|scrape_event| async { Ok(scrape_event.into_item()) })
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_golden(
+            format!("{FIXTURES}/crawl-example/golden.json"),
+            &actual,
+            &server,
+        );
+    }
+}
@@ -0,0 +1,575 @@
+use crate::http::{Client, get_page, get_text};
+use crate::json_ld;
+use async_trait::async_trait;
+use common::currency::data::CurrencyData;
+use common::language::data::{LanguageData, LocalizedTextData};
+use common::price::data::PriceData;
+use item_data::item_state_data::ItemStateData;
+use reqwest::Url;
+use scrape_core::data::ScrapeItem;
+use scrape_core::spec::{ScrapeError, ScrapePage, Scraper, ScraperConfig};
+use scraper::{ElementRef, Html, Selector};
+use serde::Deserialize;
+use std::collections::HashMap;
+use tracing::warn;
+
+/// Specs of shops scraped by a [`SelectorScraper`], one JSON config file each.
+pub const SHOP_SPECS: &[&str] = &[];
+
+/// Spec of a shop scraped by a [`SelectorScraper`], usually deserialized from a JSON config file.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectorScraperSpec {
+    pub shop_id: String,
+    pub shop_name: String,
+    pub language: LanguageData,
+
+    /// URL of a catalog page with `{page}` as placeholder for the page number starting at 1.
+    pub page_url_template: String,
+
+    /// Selects the container of each item on a catalog page.
+    /// All field selectors are relative to it.
+    pub item_selector: String,
+
+    pub shops_item_id: FieldSpec,
+    pub title: FieldSpec,
+    #[serde(default)]
+    pub description: Option<FieldSpec>,
+    #[serde(default)]
+    pub price: Option<PriceSpec>,
+    #[serde(default)]
+    pub state: Option<StateSpec>,
+
+    /// Link to the item's page, relative to `base_url`.
+    pub url: FieldSpec,
+    pub base_url: Url,
+
+    /// All matching elements are images.
+    #[serde(default)]
+    pub images: Option<FieldSpec>,
+
+    /// Base of relative image URLs, defaults to `base_url`.
+    #[serde(default)]
+    pub image_url_base: Option<Url>,
+
+    /// Completes items lacking a description, price or images with the JSON-LD `Product`
+    /// embedded in their detail-page.
+    #[serde(default)]
+    pub json_ld_details: bool,
+
+    /// Whether the catalog lists the newest items first, see [`Scraper::lists_newest_first`].
+    #[serde(default)]
+    pub newest_first: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldSpec {
+    pub selector: String,
+
+    /// Attribute holding the value. The element's text is used if absent.
+    #[serde(default)]
+    pub attribute: Option<String>,
+
+    /// Stripped from the value if present, e.g. `?code=` of a relative link.
+    #[serde(default)]
+    pub strip_prefix: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceSpec {
+    #[serde(flatten)]
+    pub field: FieldSpec,
+
+    pub locale: PriceLocale,
+
+    /// Used if the price's text contains neither an ISO-code nor an unambiguous symbol.
+    #[serde(default)]
+    pub default_currency: Option<CurrencyData>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PriceLocale {
+    /// `1,234.56`
+    En,
+
+    /// `1.234,56`
+    De,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateSpec {
+    #[serde(flatten)]
+    pub field: FieldSpec,
+
+    /// Trimmed text of the field to the state it represents.
+    pub mapping: HashMap<String, ItemStateData>,
+
+    /// Used if the field is absent or its text isn't mapped.
+    #[serde(default = "default_state")]
+    pub default: ItemStateData,
+}
+
+fn default_state() -> ItemStateData {
+    ItemStateData::Listed
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SelectorScraperSpecError {
+    #[error("SelectorScraperSpec is malformed: {0}")]
+    Malformed(#[from] serde_json::Error),
+
+    #[error("SelectorScraperSpec contains invalid selector '{0}'.")]
+    InvalidSelector(String),
+
+    #[error("SelectorScraperSpec's pageUrlTemplate lacks the placeholder '{{page}}'.")]
+    MissingPagePlaceholder,
+}
+
+/// Generic [`Scraper`] of a shop whose catalog pages are described by a [`SelectorScraperSpec`].
+#[derive(Debug)]
+pub struct SelectorScraper {
+    spec: SelectorScraperSpec,
+    item_selector: Selector,
+    shops_item_id: CompiledField,
+    title: CompiledField,
+    description: Option<CompiledField>,
+    price: Option<CompiledField>,
+    state: Option<CompiledField>,
+    url: CompiledField,
+    images: Option<CompiledField>,
+}
+
+#[derive(Debug)]
+struct CompiledField {
+    selector: Selector,
+    attribute: Option<String>,
+    strip_prefix: Option<String>,
+}
+
+impl TryFrom<&FieldSpec> for CompiledField {
+    type Error = SelectorScraperSpecError;
+
+    fn try_from(field: &FieldSpec) -> Result<Self, Self::Error> {
+        Ok(CompiledField {
+            selector: parse_selector(&field.selector)?,
+            attribute: field.attribute.clone(),
+            strip_prefix: field.strip_prefix.clone(),
+        })
+    }
+}
+
+impl CompiledField {
+    fn extract(&self, container: ElementRef) -> Option<String> {
+        container
+            .select(&self.selector)
+            .find_map(|elem| self.extract_value(elem))
+    }
+
+    fn extract_all(&self, container: ElementRef) -> Vec<String> {
+        let mut values: Vec<String> = Vec::new();
+        for value in container
+            .select(&self.selector)
+            .filter_map(|elem| self.extract_value(elem))
+        {
+            if !values.contains(&value) {
+                values.push(value);
+            }
+        }
+        values
+    }
+
+    fn extract_value(&self, elem: ElementRef) -> Option<String> {
+        let value = match &self.attribute {
+            Some(attribute) => elem.attr(attribute)?.trim().to_string(),
+            None => elem
+                .text()
+                .flat_map(str::split_whitespace)
+                .collect::<Vec<_>>()
+                .join(" "),
+        };
+        let value = match &self.strip_prefix {
+            Some(prefix) => value.strip_prefix(prefix.as_str()).unwrap_or(&value),
+            None => &value,
+        };
+        Some(value.to_string()).filter(|value| !value.is_empty())
+    }
+}
+
+fn parse_selector(selector: &str) -> Result<Selector, SelectorScraperSpecError> {
+    Selector::parse(selector)
+        .map_err(|_| SelectorScraperSpecError::InvalidSelector(selector.to_string()))
+}
+
+impl TryFrom<SelectorScraperSpec> for SelectorScraper {
+    type Error = SelectorScraperSpecError;
+
+    fn try_from(spec: SelectorScraperSpec) -> Result<Self, Self::Error> {
+        if !spec.page_url_template.contains("{page}") {
+            return Err(SelectorScraperSpecError::MissingPagePlaceholder);
+        }
+        Ok(SelectorScraper {
+            item_selector: parse_selector(&spec.item_selector)?,
+            shops_item_id: (&spec.shops_item_id).try_into()?,
+            title: (&spec.title).try_into()?,
+            description: spec
+                .description
+                .as_ref()
+                .map(TryInto::try_into)
+                .transpose()?,
+            price: spec
+                .price
+                .as_ref()
+                .map(|price| (&price.field).try_into())
+                .transpose()?,
+            state: spec
+                .state
+                .as_ref()
+                .map(|state| (&state.field).try_into())
+                .transpose()?,
+            url: (&spec.url).try_into()?,
+            images: spec.images.as_ref().map(TryInto::try_into).transpose()?,
+            spec,
+        })
+    }
+}
+
+impl SelectorScraper {
+    pub fn from_json(json: &str) -> Result<Self, SelectorScraperSpecError> {
+        serde_json::from_str::<SelectorScraperSpec>(json)?.try_into()
+    }
+
+    pub fn page_url(&self, page_num: u32) -> String {
+        self.spec
+            .page_url_template
+            .replace("{page}", &page_num.to_string())
+    }
+
+    /// Extracts all items of a catalog page.
+    /// Items lacking a ShopsItemId, title or valid URL are skipped.
+    pub fn parse_page(&self, html: &str) -> Vec<ScrapeItem> {
+        let document = Html::parse_document(html);
+        document
+            .select(&self.item_selector)
+            .filter_map(|container| self.parse_item(container))
+            .collect()
+    }
+
+    fn parse_item(&self, container: ElementRef) -> Option<ScrapeItem> {
+        let Some(shops_item_id) = self.shops_item_id.extract(container) else {
+            warn!(shopId = self.spec.shop_id, "Failed extracting ShopsItemId.");
+            return None;
+        };
+        let Some(title) = self.title.extract(container) else {
+            warn!(
+                shopId = self.spec.shop_id,
+                shopsItemId = shops_item_id,
+                "Failed extracting title."
+            );
+            return None;
+        };
+        let Some(url) = self
+            .url
+            .extract(container)
+            .and_then(|url| self.spec.base_url.join(&url).ok())
+        else {
+            warn!(
+                shopId = self.spec.shop_id,
+                shopsItemId = shops_item_id,
+                "Failed extracting URL."
+            );
+            return None;
+        };
+
+        let image_url_base = self
+            .spec
+            .image_url_base
+            .as_ref()
+            .unwrap_or(&self.spec.base_url);
+        let images = self
+            .images
+            .as_ref()
+            .map(|images| images.extract_all(container))
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|image| image_url_base.join(&image).ok())
+            .collect();
+
+        Some(ScrapeItem {
+            shop_id: self.spec.shop_id.as_str().into(),
+            shops_item_id: shops_item_id.into(),
+            shop_name: self.spec.shop_name.clone(),
+            native_title: LocalizedTextData::new(title, self.spec.language),
+            other_title: Default::default(),
+            native_description: self
+                .description
+                .as_ref()
+                .and_then(|description| description.extract(container))
+                .map(|description| LocalizedTextData::new(description, self.spec.language)),
+            other_description: Default::default(),
+            price: self.extract_price(container),
+            state: self.extract_state(container),
+            url,
+            images,
+        })
+    }
+
+    fn extract_price(&self, container: ElementRef) -> Option<PriceData> {
+        let price_spec = self.spec.price.as_ref()?;
+        let price_text = self.price.as_ref()?.extract(container)?;
+        parse_price(&price_text, price_spec.locale, price_spec.default_currency)
+    }
+
+    fn extract_state(&self, container: ElementRef) -> ItemStateData {
+        let Some(state_spec) = &self.spec.state else {
+            return default_state();
+        };
+        self.state
+            .as_ref()
+            .and_then(|state| state.extract(container))
+            .and_then(|state_text| state_spec.mapping.get(&state_text).copied())
+            .unwrap_or(state_spec.default)
+    }
+}
+
+/// Parses prices like `1.234,56 €` or `USD 1,234.56`.
+pub fn parse_price(
+    price_text: &str,
+    locale: PriceLocale,
+    default_currency: Option<CurrencyData>,
+) -> Option<PriceData> {
+    let currency = parse_currency(price_text).or(default_currency)?;
+    let number = price_text
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == '.' || *c == ',')
+        .collect::<String>();
+    let number = match locale {
+        PriceLocale::En => number.replace(',', ""),
+        PriceLocale::De => number.replace('.', "").replace(',', "."),
+    };
+    let amount = number.trim_matches('.').parse::<f64>().ok()?;
+    PriceData::new_f64(currency, amount).ok()
+}
+
+fn parse_currency(price_text: &str) -> Option<CurrencyData> {
+    let upper = price_text.to_uppercase();
+    [
+        ("EUR", CurrencyData::Eur),
+        ("GBP", CurrencyData::Gbp),
+        ("USD", CurrencyData::Usd),
+        ("AUD", CurrencyData::Aud),
+        ("CAD", CurrencyData::Cad),
+        ("NZD", CurrencyData::Nzd),
+        ("CHF", CurrencyData::Chf),
+        ("SEK", CurrencyData::Sek),
+        ("DKK", CurrencyData::Dkk),
+        ("NOK", CurrencyData::Nok),
+        ("PLN", CurrencyData::Pln),
+        ("CZK", CurrencyData::Czk),
+        ("JPY", CurrencyData::Jpy),
+        ("€", CurrencyData::Eur),
+        ("£", CurrencyData::Gbp),
+        ("¥", CurrencyData::Jpy),
+        ("KČ", CurrencyData::Czk),
+        ("ZŁ", CurrencyData::Pln),
+    ]
+    .into_iter()
+    .find_map(|(pattern, currency)| upper.contains(pattern).then_some(currency))
+}
+
+#[async_trait]
+impl Scraper<Client> for SelectorScraper {
+    fn shop_id_str(&self) -> &str {
+        &self.spec.shop_id
+    }
+
+    fn shop_name_str(&self) -> &str {
+        &self.spec.shop_name
+    }
+
+    fn hosts(&self) -> Vec<String> {
+        [Some(&self.spec.base_url), self.spec.image_url_base.as_ref()]
+            .into_iter()
+            .flatten()
+            .filter_map(|url| url.host_str().map(String::from))
+            .collect()
+    }
+
+    fn lists_newest_first(&self) -> bool {
+        self.spec.newest_first
+    }
+
+    async fn scrape_page(
+        &self,
+        client: &Client,
+        _: ScraperConfig,
+        page_num: u32,
+    ) -> Result<ScrapePage, ScrapeError> {
+        get_page(client, self.page_url(page_num), |html| {
+            Ok(self.parse_page(html))
+        })
+        .await
+    }
+
+    fn needs_details(&self, scrape_item: &ScrapeItem) -> bool {
+        self.spec.json_ld_details
+            && (scrape_item.native_description.is_none()
+                || scrape_item.price.is_none()
+                || scrape_item.images.is_empty())
+    }
+
+    async fn scrape_details(
+        &self,
+        client: &Client,
+        _: ScraperConfig,
+        scrape_item: &mut ScrapeItem,
+    ) -> Result<(), ScrapeError> {
+        let html = get_text(client, scrape_item.url.clone()).await?;
+        let shop = json_ld::JsonLdShop {
+            shop_id: self.spec.shop_id.clone(),
+            shop_name: self.spec.shop_name.clone(),
+            language: self.spec.language,
+        };
+        if let Some(product) = json_ld::extract_scrape_items(&html, &shop, &scrape_item.url)
+            .into_iter()
+            .next()
+        {
+            json_ld::fill_missing(scrape_item, product);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::selector::{
+        PriceLocale, SHOP_SPECS, SelectorScraper, SelectorScraperSpecError, parse_price,
+    };
+    use common::currency::data::CurrencyData;
+    use common::language::data::{LanguageData, LocalizedTextData};
+    use common::price::data::PriceData;
+    use item_data::item_state_data::ItemStateData;
+    use scrape_core::spec::Scraper;
+    use url::Url;
+
+    const EXAMPLE_SPEC: &str = include_str!("../fixtures/selector/example-shop.json");
+    const EXAMPLE_PAGE: &str = include_str!("../fixtures/selector/example-shop-page-1.html");
+
+    #[rstest::rstest]
+    #[case::en_iso_code("1,234.56 EUR", PriceLocale::En, None, Some((CurrencyData::Eur, 123456)))]
+    #[case::en_leading_code("USD 99.90", PriceLocale::En, None, Some((CurrencyData::Usd, 9990)))]
+    #[case::de_symbol("1.234,56 €", PriceLocale::De, None, Some((CurrencyData::Eur, 123456)))]
+    #[case::de_whole("450,- €", PriceLocale::De, None, Some((CurrencyData::Eur, 45000)))]
+    #[case::pound_symbol("£75", PriceLocale::En, None, Some((CurrencyData::Gbp, 7500)))]
+    #[case::zero_decimal("¥12,800", PriceLocale::En, None, Some((CurrencyData::Jpy, 12800)))]
+    #[case::ch_apostrophe_grouping("CHF 1'250.00", PriceLocale::En, None, Some((CurrencyData::Chf, 125000)))]
+    #[case::cz_symbol("4.500 Kč", PriceLocale::De, None, Some((CurrencyData::Czk, 450000)))]
+    #[case::default_currency("$120.00", PriceLocale::En, Some(CurrencyData::Cad), Some((CurrencyData::Cad, 12000)))]
+    #[case::unknown_currency("$120.00", PriceLocale::En, None, None)]
+    #[case::no_amount("Price on request EUR", PriceLocale::En, None, None)]
+    fn should_parse_price(
+        #[case] price_text: &str,
+        #[case] locale: PriceLocale,
+        #[case] default_currency: Option<CurrencyData>,
+        #[case] expected: Option<(CurrencyData, u64)>,
+    ) {
+        let actual = parse_price(price_text, locale, default_currency);
+
+        assert_eq!(
+            expected.map(|(currency, amount)| PriceData::new(currency, amount)),
+            actual
+        );
+    }
+
+    #[test]
+    fn should_build_page_url_from_template() {
+        let scraper = SelectorScraper::from_json(EXAMPLE_SPEC).unwrap();
+
+        assert_eq!(
+            "https://shop.example.com/catalog?page=3",
+            scraper.page_url(3)
+        );
+        assert_eq!("example-shop", scraper.shop_id_str());
+        assert_eq!("Example Shop", scraper.shop_name_str());
+    }
+
+    #[test]
+    fn should_parse_catalog_page_of_fixture() {
+        let scraper = SelectorScraper::from_json(EXAMPLE_SPEC).unwrap();
+
+        let actual = scraper.parse_page(EXAMPLE_PAGE);
+
+        assert_eq!(3, actual.len());
+
+        let helmet = &actual[0];
+        assert_eq!("example-shop", helmet.shop_id.to_string());
+        assert_eq!("A-1001", helmet.shops_item_id.to_string());
+        assert_eq!(
+            LocalizedTextData::new("Steel helmet M35", LanguageData::De),
+            helmet.native_title
+        );
+        assert_eq!(
+            Some(LocalizedTextData::new(
+                "Original paint, liner complete.",
+                LanguageData::De
+            )),
+            helmet.native_description
+        );
+        assert_eq!(
+            Some(PriceData::new(CurrencyData::Eur, 123456)),
+            helmet.price
+        );
+        assert_eq!(ItemStateData::Available, helmet.state);
+        assert_eq!(
+            Url::parse("https://shop.example.com/item/A-1001").unwrap(),
+            helmet.url
+        );
+        assert_eq!(
+            vec![
+                Url::parse("https://img.example.com/a-1001-front.jpg").unwrap(),
+                Url::parse("https://img.example.com/a-1001-back.jpg").unwrap(),
+            ],
+            helmet.images
+        );
+
+        let medal = &actual[1];
+        assert_eq!("A-1002", medal.shops_item_id.to_string());
+        assert_eq!(None, medal.native_description);
+        assert_eq!(ItemStateData::Sold, medal.state);
+        assert!(medal.images.is_empty());
+
+        let badge = &actual[2];
+        assert_eq!("A-1004", badge.shops_item_id.to_string());
+        assert_eq!(None, badge.price);
+        assert_eq!(ItemStateData::Listed, badge.state);
+    }
+
+    #[rstest::rstest]
+    #[case::invalid_selector(
+        r#"{"shopId":"x","shopName":"X","language":"de","pageUrlTemplate":"https://x/{page}","itemSelector":"div[","shopsItemId":{"selector":"a"},"title":{"selector":"a"},"url":{"selector":"a"},"baseUrl":"https://x"}"#
+    )]
+    #[case::missing_placeholder(
+        r#"{"shopId":"x","shopName":"X","language":"de","pageUrlTemplate":"https://x/","itemSelector":"div","shopsItemId":{"selector":"a"},"title":{"selector":"a"},"url":{"selector":"a"},"baseUrl":"https://x"}"#
+    )]
+    #[case::malformed(r#"{"shopId":"x"}"#)]
+    fn should_reject_invalid_spec(#[case] json: &str) {
+        let actual = SelectorScraper::from_json(json);
+
+        assert!(matches!(
+            actual,
+            Err(SelectorScraperSpecError::InvalidSelector(_)
+                | SelectorScraperSpecError::MissingPagePlaceholder
+                | SelectorScraperSpecError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn should_build_scrapers_from_all_shop_specs() {
+        for shop_spec in SHOP_SPECS {
+            assert!(SelectorScraper::from_json(shop_spec).is_ok());
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use crate::http::{Client, get_page, host_of};
+use crate::text::html_to_text;
+use async_trait::async_trait;
+use common::currency::data::CurrencyData;
+use common::language::data::{LanguageData, LocalizedTextData};
+use common::price::data::PriceData;
+use item_data::item_state_data::ItemStateData;
+use reqwest::Url;
+use scrape_core::data::ScrapeItem;
+use scrape_core::spec::{ScrapeError, ScrapePage, Scraper, ScraperConfig};
+use serde::Deserialize;
+use tracing::warn;
+
+/// Serves the product images of all Shopify shops.
+const SHOPIFY_CDN_HOST: &str = "cdn.shopify.com";
+
+/// Shops running on Shopify which are scraped by the scrape-runner.
+pub const SHOPS: &[Shopify] = &[];
+
+/// Scrapes a Shopify storefront by its public `/products.json`.
+#[derive(Debug, Clone)]
+pub struct Shopify {
+    pub id: &'static str,
+    pub url: &'static str,
+    pub name: &'static str,
+    pub language: LanguageData,
+
+    /// Shopify's products.json lacks the currency, it's the shop's default one.
+    pub currency: CurrencyData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProductsPage {
+    products: Vec<Product>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Product {
+    id: u64,
+    title: String,
+    handle: String,
+    #[serde(default)]
+    body_html: Option<String>,
+    #[serde(default)]
+    variants: Vec<Variant>,
+    #[serde(default)]
+    images: Vec<Image>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Variant {
+    price: String,
+    #[serde(default)]
+    available: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct Image {
+    src: String,
+}
+
+impl Shopify {
+    pub fn page_url(&self, page_num: u32) -> String {
+        format!("{}/products.json?limit=250&page={}", self.url, page_num)
+    }
+
+    /// Maps all products of a products.json page.
+    /// Products without a valid URL are skipped.
+    pub fn parse_page(&self, json: &str) -> Result<Vec<ScrapeItem>, serde_json::Error> {
+        let page = serde_json::from_str::<ProductsPage>(json)?;
+        Ok(page
+            .products
+            .into_iter()
+            .filter_map(|product| self.map_product(product))
+            .collect())
+    }
+
+    fn map_product(&self, product: Product) -> Option<ScrapeItem> {
+        let Ok(url) = Url::parse(&format!("{}/products/{}", self.url, product.handle)) else {
+            warn!(
+                shopId = self.id,
+                shopsItemId = product.id,
+                "Failed building URL of product."
+            );
+            return None;
+        };
+
+        Some(ScrapeItem {
+            shop_id: self.id.into(),
+            shops_item_id: product.id.to_string().into(),
+            shop_name: self.name.to_string(),
+            native_title: LocalizedTextData::new(product.title, self.language),
+            other_title: Default::default(),
+            native_description: product
+                .body_html
+                .as_deref()
+                .and_then(html_to_text)
+                .map(|description| LocalizedTextData::new(description, self.language)),
+            other_description: Default::default(),
+            price: self.extract_price(&product.variants),
+            state: extract_state(&product.variants),
+            url,
+            images: product
+                .images
+                .into_iter()
+                .filter_map(|image| Url::parse(&image.src).ok())
+                .collect(),
+        })
+    }
+
+    // Items with several variants are listed at the price of the cheapest available one
+    fn extract_price(&self, variants: &[Variant]) -> Option<PriceData> {
+        let any_available = variants.iter().any(|variant| variant.available);
+        variants
+            .iter()
+            .filter(|variant| variant.available || !any_available)
+            .filter_map(|variant| variant.price.trim().parse::<f64>().ok())
+            .min_by(f64::total_cmp)
+            .and_then(|amount| PriceData::new_f64(self.currency, amount).ok())
+    }
+}
+
+fn extract_state(variants: &[Variant]) -> ItemStateData {
+    if variants.is_empty() {
+        ItemStateData::Listed
+    } else if variants.iter().any(|variant| variant.available) {
+        ItemStateData::Available
+    } else {
+        ItemStateData::Sold
+    }
+}
+
+#[async_trait]
+impl Scraper<Client> for Shopify {
+    fn shop_id_str(&self) -> &str {
+        self.id
+    }
+
+    fn shop_name_str(&self) -> &str {
+        self.name
+    }
+
+    fn hosts(&self) -> Vec<String> {
+        host_of(self.url)
+            .into_iter()
+            .chain([SHOPIFY_CDN_HOST.to_string()])
+            .collect()
+    }
+
+    async fn scrape_page(
+        &self,
+        client: &Client,
+        _: ScraperConfig,
+        page_num: u32,
+    ) -> Result<ScrapePage, ScrapeError> {
+        get_page(client, self.page_url(page_num), |json| {
+            Ok(self.parse_page(json).map_err(Box::from)?)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::shopify::Shopify;
+    use common::currency::data::CurrencyData;
+    use common::language::data::{LanguageData, LocalizedTextData};
+    use common::price::data::PriceData;
+    use item_data::item_state_data::ItemStateData;
+    use url::Url;
+
+    const EXAMPLE_PAGE: &str = include_str!("../fixtures/shopify/products-page-1.json");
+
+    fn mk_shop() -> Shopify {
+        Shopify {
+            id: "example-shopify",
+            url: "https://shop.example.com",
+            name: "Example Shopify",
+            language: LanguageData::En,
+            currency: CurrencyData::Eur,
+        }
+    }
+
+    #[test]
+    fn should_build_page_url() {
+        assert_eq!(
+            "https://shop.example.com/products.json?limit=250&page=2",
+            mk_shop().page_url(2)
+        );
+    }
+
+    #[test]
+    fn should_parse_products_page_of_fixture() {
+        let actual = mk_shop().parse_page(EXAMPLE_PAGE).unwrap();
+
+        assert_eq!(3, actual.len());
+
+        let helmet = &actual[0];
+        assert_eq!("example-shopify", helmet.shop_id.to_string());
+        assert_eq!("7301", helmet.shops_item_id.to_string());
+        assert_eq!(
+            LocalizedTextData::new("Steel helmet M35", LanguageData::En),
+            helmet.native_title
+        );
+        assert_eq!(
+            Some(LocalizedTextData::new(
+                "Original paint, liner complete.",
+                LanguageData::En
+            )),
+            helmet.native_description
+        );
+        assert_eq!(
+            Some(PriceData::new(CurrencyData::Eur, 123450)),
+            helmet.price
+        );
+        assert_eq!(ItemStateData::Available, helmet.state);
+        assert_eq!(
+            Url::parse("https://shop.example.com/products/steel-helmet-m35").unwrap(),
+            helmet.url
+        );
+        assert_eq!(2, helmet.images.len());
+
+        let medal = &actual[1];
+        assert_eq!(None, medal.native_description);
+        assert_eq!(Some(PriceData::new(CurrencyData::Eur, 9900)), medal.price);
+        assert_eq!(ItemStateData::Sold, medal.state);
+
+        let badge = &actual[2];
+        assert_eq!(None, badge.price);
+        assert_eq!(ItemStateData::Listed, badge.state);
+    }
+
+    #[test]
+    fn should_fail_parsing_malformed_page() {
+        assert!(mk_shop().parse_page(r#"{"items":[]}"#).is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use common::currency::data::CurrencyData;
+use scraper::Html;
+use serde::Deserialize;
+use serde::de::IntoDeserializer;
+use serde::de::value::Error;
+
+/// Text of an HTML fragment with collapsed whitespace, e.g. of a product's `body_html`.
+pub fn html_to_text(html: &str) -> Option<String> {
+    let text = Html::parse_fragment(html)
+        .root_element()
+        .text()
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    Some(text).filter(|text| !text.is_empty())
+}
+
+/// Parses an ISO 4217 code like `EUR`, case-insensitive.
+pub fn parse_currency_code(code: &str) -> Option<CurrencyData> {
+    let code = code.trim().to_uppercase();
+    CurrencyData::deserialize(IntoDeserializer::<Error>::into_deserializer(code.as_str())).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::text::{html_to_text, parse_currency_code};
+    use common::currency::data::CurrencyData;
+
+    #[rstest::rstest]
+    #[case::plain("Steel helmet", Some("Steel helmet"))]
+    #[case::markup(
+        "<p>Original <b>paint</b>,</p>\n<p>liner   complete.</p>",
+        Some("Original paint, liner complete.")
+    )]
+    #[case::empty_markup("<p> </p>", None)]
+    #[case::empty("", None)]
+    fn should_convert_html_to_text(#[case] html: &str, #[case] expected: Option<&str>) {
+        assert_eq!(expected.map(str::to_string), html_to_text(html));
+    }
+
+    #[rstest::rstest]
+    #[case::upper("EUR", Some(CurrencyData::Eur))]
+    #[case::lower("gbp", Some(CurrencyData::Gbp))]
+    #[case::padded(" USD ", Some(CurrencyData::Usd))]
+    #[case::zero_decimal("JPY", Some(CurrencyData::Jpy))]
+    #[case::unsupported("HUF", None)]
+    #[case::symbol("€", None)]
+    fn should_parse_currency_code(#[case] code: &str, #[case] expected: Option<CurrencyData>) {
+        assert_eq!(expected, parse_currency_code(code));
+    }
+}
@@ -0,0 +1,219 @@
+use crate::http::{Client, get_page, host_of};
+use crate::text::{html_to_text, parse_currency_code};
+use async_trait::async_trait;
+use common::currency::domain::HasMinorUnitExponent;
+use common::language::data::{LanguageData, LocalizedTextData};
+use common::price::data::PriceData;
+use item_data::item_state_data::ItemStateData;
+use reqwest::Url;
+use scrape_core::data::ScrapeItem;
+use scrape_core::spec::{ScrapeError, ScrapePage, Scraper, ScraperConfig};
+use serde::Deserialize;
+use tracing::warn;
+
+/// Shops running on WooCommerce which are scraped by the scrape-runner.
+pub const SHOPS: &[WooCommerce] = &[];
+
+/// Scrapes a WooCommerce storefront by its public Store API.
+#[derive(Debug, Clone)]
+pub struct WooCommerce {
+    pub id: &'static str,
+    pub url: &'static str,
+    pub name: &'static str,
+    pub language: LanguageData,
+}
+
+#[derive(Debug, Deserialize)]
+struct Product {
+    id: u64,
+    name: String,
+    permalink: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    short_description: String,
+    prices: Prices,
+    #[serde(default)]
+    is_in_stock: bool,
+    #[serde(default)]
+    images: Vec<Image>,
+}
+
+// Amounts are strings in the currency's minor unit, e.g. "123450" for 1.234,50 €.
+// Variable products are listed at the price of their cheapest variation.
+#[derive(Debug, Deserialize)]
+struct Prices {
+    #[serde(default)]
+    price: String,
+    currency_code: String,
+    currency_minor_unit: u8,
+}
+
+#[derive(Debug, Deserialize)]
+struct Image {
+    src: String,
+}
+
+impl WooCommerce {
+    pub fn page_url(&self, page_num: u32) -> String {
+        format!(
+            "{}/wp-json/wc/store/v1/products?per_page=100&page={}",
+            self.url, page_num
+        )
+    }
+
+    /// Maps all products of a Store API page.
+    /// Products without a valid permalink are skipped.
+    pub fn parse_page(&self, json: &str) -> Result<Vec<ScrapeItem>, serde_json::Error> {
+        let products = serde_json::from_str::<Vec<Product>>(json)?;
+        Ok(products
+            .into_iter()
+            .filter_map(|product| self.map_product(product))
+            .collect())
+    }
+
+    fn map_product(&self, product: Product) -> Option<ScrapeItem> {
+        let Ok(url) = Url::parse(&product.permalink) else {
+            warn!(
+                shopId = self.id,
+                shopsItemId = product.id,
+                "Failed parsing permalink of product."
+            );
+            return None;
+        };
+        // Names are HTML-escaped, e.g. `Helmet &amp; liner`
+        let title = html_to_text(&product.name)?;
+
+        Some(ScrapeItem {
+            shop_id: self.id.into(),
+            shops_item_id: product.id.to_string().into(),
+            shop_name: self.name.to_string(),
+            native_title: LocalizedTextData::new(title, self.language),
+            other_title: Default::default(),
+            native_description: html_to_text(&product.description)
+                .or_else(|| html_to_text(&product.short_description))
+                .map(|description| LocalizedTextData::new(description, self.language)),
+            other_description: Default::default(),
+            price: extract_price(&product.prices),
+            state: if product.is_in_stock {
+                ItemStateData::Available
+            } else {
+                ItemStateData::Sold
+            },
+            url,
+            images: product
+                .images
+                .into_iter()
+                .filter_map(|image| Url::parse(&image.src).ok())
+                .collect(),
+        })
+    }
+}
+
+fn extract_price(prices: &Prices) -> Option<PriceData> {
+    let currency = parse_currency_code(&prices.currency_code)?;
+    let amount = prices.price.trim().parse::<u64>().ok()?;
+    let exponent = currency.minor_unit_exponent().0;
+    let amount = if prices.currency_minor_unit <= exponent {
+        amount.checked_mul(10u64.checked_pow((exponent - prices.currency_minor_unit) as u32)?)?
+    } else {
+        amount / 10u64.checked_pow((prices.currency_minor_unit - exponent) as u32)?
+    };
+    Some(PriceData::new(currency, amount))
+}
+
+#[async_trait]
+impl Scraper<Client> for WooCommerce {
+    fn shop_id_str(&self) -> &str {
+        self.id
+    }
+
+    fn shop_name_str(&self) -> &str {
+        self.name
+    }
+
+    fn hosts(&self) -> Vec<String> {
+        host_of(self.url).into_iter().collect()
+    }
+
+    async fn scrape_page(
+        &self,
+        client: &Client,
+        _: ScraperConfig,
+        page_num: u32,
+    ) -> Result<ScrapePage, ScrapeError> {
+        get_page(client, self.page_url(page_num), |json| {
+            Ok(self.parse_page(json).map_err(Box::from)?)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::woocommerce::WooCommerce;
+    use common::currency::data::CurrencyData;
+    use common::language::data::{LanguageData, LocalizedTextData};
+    use common::price::data::PriceData;
+    use item_data::item_state_data::ItemStateData;
+    use url::Url;
+
+    const EXAMPLE_PAGE: &str = include_str!("../fixtures/woocommerce/products-page-1.json");
+
+    fn mk_shop() -> WooCommerce {
+        WooCommerce {
+            id: "example-woocommerce",
+            url: "https://shop.example.com",
+            name: "Example WooCommerce",
+            language: LanguageData::En,
+        }
+    }
+
+    #[test]
+    fn should_build_page_url() {
+        assert_eq!(
+            "https://shop.example.com/wp-json/wc/store/v1/products?per_page=100&page=2",
+            mk_shop().page_url(2)
+        );
+    }
+
+    #[test]
+    fn should_parse_products_page_of_fixture() {
+        let actual = mk_shop().parse_page(EXAMPLE_PAGE).unwrap();
+
+        assert_eq!(2, actual.len());
+
+        let helmet = &actual[0];
+        assert_eq!("example-woocommerce", helmet.shop_id.to_string());
+        assert_eq!("501", helmet.shops_item_id.to_string());
+        assert_eq!(
+            LocalizedTextData::new("Steel helmet M35 & liner", LanguageData::En),
+            helmet.native_title
+        );
+        assert_eq!(
+            Some(LocalizedTextData::new(
+                "Original paint, liner complete.",
+                LanguageData::En
+            )),
+            helmet.native_description
+        );
+        assert_eq!(
+            Some(PriceData::new(CurrencyData::Eur, 123450)),
+            helmet.price
+        );
+        assert_eq!(ItemStateData::Available, helmet.state);
+        assert_eq!(
+            Url::parse("https://shop.example.com/product/steel-helmet-m35/").unwrap(),
+            helmet.url
+        );
+        assert_eq!(2, helmet.images.len());
+
+        let medal = &actual[1];
+        assert_eq!(
+            Some(LocalizedTextData::new("Ribbon included.", LanguageData::En)),
+            medal.native_description
+        );
+        assert_eq!(Some(PriceData::new(CurrencyData::Gbp, 9900)), medal.price);
+        assert_eq!(ItemStateData::Sold, medal.state);
+    }
+}
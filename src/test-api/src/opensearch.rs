@@ -215,7 +215,6 @@ async fn set_up_indices() -> Result<Response, Error> {
 /// preserving the index structure and mappings.
 async fn clear_index_data(index: &str) -> Result<Response, Error> {
     use opensearch::DeleteByQueryParts;
-    use serde_json::json;
 
     let query = json!({
         "query": {